@@ -2,11 +2,12 @@
 #![allow(deprecated)]
 use bip39::{Language, Mnemonic};
 use bitcoin::bip32::{IntoDerivationPath, Xpriv, Xpub};
-use bitcoin::hashes::Hash;
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::hex::{DisplayHex, FromHex};
 use bitcoin::sighash::EcdsaSighashType;
 use bitcoin::{
-    Amount, Network, OutPoint, Psbt, ScriptBuf, Sequence, Transaction as BtcTransaction, TxIn,
-    TxOut as BtcTxOut, Txid, Witness,
+    Address, Amount, CompressedPublicKey, Network, OutPoint, Psbt, ScriptBuf, Sequence,
+    Transaction as BtcTransaction, TxIn, TxOut as BtcTxOut, Txid, Witness,
 };
 use bitcoin::{Script, WPubkeyHash};
 use ddk_dlc::secp_utils;
@@ -15,22 +16,75 @@ use ddk_dlc::{
     OracleInfo as DlcOracleInfo, PartyParams as DlcPartyParams, Payout as DlcPayout,
     TxInputInfo as DlcTxInputInfo,
 };
+use miniscript::descriptor::{Descriptor, DescriptorPublicKey};
+use miniscript::ForEachKey;
 use secp256k1_zkp::{
     ecdsa::Signature as EcdsaSignature, Message, PublicKey, Scalar, Secp256k1, SecretKey,
     XOnlyPublicKey,
 };
-use secp256k1_zkp::{schnorr::Signature as SchnorrSignature, All, EcdsaAdaptorSignature};
+use secp256k1_zkp::{
+    schnorr::Signature as SchnorrSignature, All, EcdsaAdaptorSignature, VerifyOnly,
+};
+use std::collections::HashSet;
 use std::str::FromStr;
 use std::sync::OnceLock;
+use zeroize::{Zeroize, Zeroizing};
 
 uniffi::include_scaffolding!("ddk_ffi");
 
+// Contexts are built once, on first use from any thread, and then shared
+// immutably: secp256k1 contexts are `Sync` and never mutated after creation, so
+// concurrent FFI calls can use them without locking.
 static SECP_CONTEXT: OnceLock<Secp256k1<All>> = OnceLock::new();
+static VERIFICATION_CONTEXT: OnceLock<Secp256k1<VerifyOnly>> = OnceLock::new();
 
+/// Context used for signing and key derivation. It is randomized when created,
+/// which hardens signing against side-channel attacks.
 pub fn get_secp_context() -> &'static Secp256k1<All> {
     SECP_CONTEXT.get_or_init(Secp256k1::new)
 }
 
+/// Context used for signature verification. CET adaptor signature checks go
+/// through ddk-dlc, which requires the full context from [`get_secp_context`].
+pub fn get_verification_context() -> &'static Secp256k1<VerifyOnly> {
+    VERIFICATION_CONTEXT.get_or_init(Secp256k1::verification_only)
+}
+
+/// Secret key parsed from caller-provided bytes that is overwritten when dropped.
+///
+/// Signing functions hold their keys in this guard and wrap the caller's key
+/// bytes in [`Zeroizing`], so neither outlives the call in memory. This is best
+/// effort: `SecretKey` is `Copy`, and copies made inside ddk-dlc or secp256k1
+/// are not covered.
+pub struct SecretKeyGuard(SecretKey);
+
+impl SecretKeyGuard {
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, secp256k1_zkp::UpstreamError> {
+        SecretKey::from_slice(bytes).map(SecretKeyGuard)
+    }
+}
+
+impl std::ops::Deref for SecretKeyGuard {
+    type Target = SecretKey;
+
+    fn deref(&self) -> &SecretKey {
+        &self.0
+    }
+}
+
+impl Zeroize for SecretKeyGuard {
+    fn zeroize(&mut self) {
+        self.0.non_secure_erase();
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+impl Drop for SecretKeyGuard {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
@@ -44,6 +98,20 @@ const DUST_LIMIT: u64 = 1000;
 /// See: <https://github.com/discreetlogcontracts/dlcspecs/blob/master/Transactions.md#fees>
 pub const P2WPKH_WITNESS_SIZE: usize = 107;
 
+/// Approximate serialized size of an unsigned CET with a single funding input
+/// and two P2WPKH payout outputs.
+const APPROX_CET_SIZE: u64 = 113;
+
+/// Length of a P2WPKH script pubkey (OP_0 + 20-byte key hash)
+const P2WPKH_SCRIPT_PUBKEY_LEN: usize = 22;
+
+/// Length of a P2TR script pubkey (OP_1 + 32-byte x-only key)
+const P2TR_SCRIPT_PUBKEY_LEN: usize = 34;
+
+/// Serialized size of an ECDSA adaptor signature: a 33-byte R, a 33-byte R',
+/// a 32-byte s' and a 64-byte DLEQ proof.
+const ADAPTOR_SIGNATURE_SIZE: usize = 162;
+
 // Error type implementation
 #[derive(Debug, thiserror::Error)]
 pub enum DLCError {
@@ -81,6 +149,29 @@ pub enum ExtendedKey {
     InvalidDerivationPath,
 }
 
+impl DLCError {
+    /// Stable numeric code of the error, for bindings that only receive it as a
+    /// string. Each extended key error has its own code. Codes are never
+    /// renumbered or reused.
+    pub fn code(&self) -> u32 {
+        match self {
+            DLCError::InvalidSignature => 1,
+            DLCError::InvalidPublicKey => 2,
+            DLCError::InvalidTransaction => 3,
+            DLCError::InsufficientFunds => 4,
+            DLCError::InvalidArgument(_) => 5,
+            DLCError::SerializationError => 6,
+            DLCError::Secp256k1Error(_) => 7,
+            DLCError::MiniscriptError => 8,
+            DLCError::InvalidNetwork => 9,
+            DLCError::KeyError(ExtendedKey::InvalidMnemonic) => 10,
+            DLCError::KeyError(ExtendedKey::InvalidXpriv) => 11,
+            DLCError::KeyError(ExtendedKey::InvalidXpub) => 12,
+            DLCError::KeyError(ExtendedKey::InvalidDerivationPath) => 13,
+        }
+    }
+}
+
 impl From<ddk_dlc::Error> for DLCError {
     fn from(err: ddk_dlc::Error) -> Self {
         match err {
@@ -156,7 +247,8 @@ pub struct DlcInputInfo {
 #[derive(Clone)]
 pub struct PartyParams {
     pub fund_pubkey: Vec<u8>,
-    pub change_script_pubkey: Vec<u8>,
+    /// `None` when the party's inputs exactly cover collateral and fees
+    pub change_script_pubkey: Option<Vec<u8>>,
     pub change_serial_id: u64,
     pub payout_script_pubkey: Vec<u8>,
     pub payout_serial_id: u64,
@@ -176,7 +268,9 @@ pub struct DlcTransactions {
 
 #[derive(Clone)]
 pub struct AdaptorSignature {
+    /// Canonical 162-byte encoding, DLEQ proof included
     pub signature: Vec<u8>,
+    /// Left empty, the proof is part of `signature`
     pub proof: Vec<u8>,
 }
 
@@ -218,6 +312,136 @@ pub struct CetAdaptorSignatureDebugInfo {
     pub cet_raw: Vec<u8>,
 }
 
+/// How the payouts of a CET differ from those of the refund transaction.
+#[derive(Clone)]
+pub struct CetRefundDiff {
+    /// Whether both transactions spend the same funding outpoint
+    pub same_funding_outpoint: bool,
+    /// Whether both transactions pay exactly the same outputs
+    pub identical_payouts: bool,
+    /// Outputs paid by the CET but not by the refund
+    pub cet_only_outputs: Vec<TxOutput>,
+    /// Outputs paid by the refund but not by the CET
+    pub refund_only_outputs: Vec<TxOutput>,
+}
+
+/// Projected cost of building a numeric contract.
+#[derive(Clone)]
+pub struct ContractCost {
+    /// Upper bound on the number of CETs (one per possible outcome)
+    pub cet_count: u64,
+    /// Approximate bytes of unsigned CET data
+    pub transaction_bytes: u64,
+    /// Approximate adaptor signing operations (one per oracle digit per CET)
+    pub adaptor_signing_operations: u64,
+}
+
+/// Everything needed to resume settling a contract after a restart.
+#[derive(Clone)]
+pub struct SettlementState {
+    /// Fund, CETs and refund transactions of the contract
+    pub dlc_transactions: DlcTransactions,
+    /// Counterparty adaptor signatures, one per CET
+    pub adaptor_signatures: Vec<AdaptorSignature>,
+    /// Oracles whose attestations unlock the CETs
+    pub oracle_infos: Vec<OracleInfo>,
+    /// 32-byte contract identifier
+    pub contract_id: Vec<u8>,
+}
+
+/// Transactions and signatures of a contract that went through offer, accept
+/// and sign.
+#[derive(Clone)]
+pub struct CompletedContract {
+    /// Fund, CETs and refund transactions, unsigned
+    pub dlc_transactions: DlcTransactions,
+    /// Value of the 2-of-2 fund output spent by the CETs and refund
+    pub fund_output_value: u64,
+    /// Offer party's adaptor signatures, one per CET
+    pub offer_adaptor_signatures: Vec<AdaptorSignature>,
+    /// Accept party's adaptor signatures, one per CET
+    pub accept_adaptor_signatures: Vec<AdaptorSignature>,
+    /// Refund transaction carrying both parties' signatures
+    pub signed_refund: Transaction,
+}
+
+/// Arguments of `create_dlc_transactions` for one contract of a batch.
+#[derive(Clone)]
+pub struct DlcTransactionRequest {
+    pub outcomes: Vec<Payout>,
+    pub local_params: PartyParams,
+    pub remote_params: PartyParams,
+    pub refund_locktime: u32,
+    pub fee_rate: u64,
+    pub fund_lock_time: u32,
+    pub cet_lock_time: u32,
+    pub fund_output_serial_id: u64,
+    pub contract_flags: u8,
+}
+
+/// One input of a funding transaction that a party has to sign.
+#[derive(Clone)]
+pub struct SigningStep {
+    /// Index of the input in the funding transaction
+    pub input_index: u32,
+    /// "witness" for a native segwit input (P2WPKH, P2WSH or P2TR), otherwise
+    /// "p2sh-p2wpkh", "p2sh-p2wsh" or "p2sh" from the redeem script
+    pub script_type: String,
+    /// Value of the spent output, needed for the segwit sighash
+    pub amount: u64,
+}
+
+/// Full witness stack for one input of a transaction.
+#[derive(Clone)]
+pub struct WitnessEntry {
+    /// Index of the input in the transaction
+    pub input_index: u32,
+    /// Witness items, bottom of the stack first
+    pub items: Vec<Vec<u8>>,
+}
+
+/// The two halves of a BIP340 Schnorr signature.
+#[derive(Clone)]
+pub struct SchnorrParts {
+    /// 32-byte x-only nonce point R
+    pub nonce: Vec<u8>,
+    /// 32-byte s value
+    pub s_value: Vec<u8>,
+}
+
+/// A CET along with which payout outputs were left out for being dust.
+#[derive(Clone)]
+pub struct CetBuildResult {
+    pub transaction: Transaction,
+    /// The offer (local) payout was under the dust limit and has no output
+    pub offer_output_dropped: bool,
+    /// The accept (remote) payout was under the dust limit and has no output
+    pub accept_output_dropped: bool,
+}
+
+/// Which fields of two `PartyParams` differ.
+#[derive(Clone)]
+pub struct PartyParamsDiff {
+    pub fund_pubkey: bool,
+    pub change_script_pubkey: bool,
+    pub payout_script_pubkey: bool,
+    pub input_amount: bool,
+    pub collateral: bool,
+    /// The inputs spend different outpoints or differ in script sig or witness length
+    pub inputs: bool,
+    /// The DLC inputs differ in anything but their serial ids
+    pub dlc_inputs: bool,
+    /// The change, payout or input serial ids differ
+    pub serial_ids: bool,
+}
+
+/// Relative share of the collateral each party receives for an outcome.
+#[derive(Clone)]
+pub struct PayoutRatio {
+    pub offer: u32,
+    pub accept: u32,
+}
+
 // Conversion helpers
 pub fn btc_tx_to_transaction(tx: &BtcTransaction) -> Transaction {
     use bitcoin::consensus::Encodable;
@@ -266,6 +490,31 @@ pub fn add_signature_to_transaction(
     Ok(btc_tx_to_transaction(&tx))
 }
 
+/// Set the full witness stacks of several inputs in one pass. Every index is
+/// checked before any witness is written.
+pub fn add_witnesses_to_transaction(
+    tx: Transaction,
+    witnesses: Vec<WitnessEntry>,
+) -> Result<Transaction, DLCError> {
+    let mut tx = transaction_to_btc_tx(&tx).map_err(|_| DLCError::InvalidTransaction)?;
+    if let Some(entry) = witnesses
+        .iter()
+        .find(|entry| entry.input_index as usize >= tx.input.len())
+    {
+        return Err(DLCError::InvalidArgument(format!(
+            "Input index {} out of range for {} inputs",
+            entry.input_index,
+            tx.input.len()
+        )));
+    }
+
+    for entry in witnesses {
+        tx.input[entry.input_index as usize].witness = Witness::from_slice(&entry.items);
+    }
+
+    Ok(btc_tx_to_transaction(&tx))
+}
+
 pub fn plz_work() -> String {
     "heyhowareya".to_string()
 }
@@ -325,8 +574,38 @@ pub fn tx_input_info_to_rust(input: &TxInputInfo) -> Result<DlcTxInputInfo, DLCE
     })
 }
 
-/// Convert UniFFI PartyParams to rust-dlc PartyParams
-pub fn party_params_to_rust(params: &PartyParams) -> Result<DlcPartyParams, DLCError> {
+/// Convert UniFFI PartyParams to rust-dlc PartyParams for a contract with
+/// `total_collateral` funded at `fee_rate`.
+///
+/// A party without a change script whose inputs would leave non-dust change is
+/// rejected: ddk-dlc would otherwise emit that change to an empty script,
+/// spendable by anyone.
+pub fn party_params_to_rust(
+    params: &PartyParams,
+    total_collateral: u64,
+    fee_rate: u64,
+) -> Result<DlcPartyParams, DLCError> {
+    let rust_params = convert_party_params(params)?;
+    if params.change_script_pubkey.is_none() {
+        let (change_output, _, _) = rust_params.get_change_output_and_fees(
+            Amount::from_sat(total_collateral),
+            fee_rate,
+            Amount::ZERO,
+        )?;
+        if change_output.value.to_sat() >= DUST_LIMIT {
+            return Err(DLCError::InvalidArgument(format!(
+                "No change script provided but inputs leave {} sats of change",
+                change_output.value.to_sat()
+            )));
+        }
+    }
+
+    Ok(rust_params)
+}
+
+/// Convert UniFFI PartyParams to rust-dlc PartyParams without checking the
+/// change, for callers that only inspect the params and build no transaction.
+fn convert_party_params(params: &PartyParams) -> Result<DlcPartyParams, DLCError> {
     let fund_pubkey =
         PublicKey::from_slice(&params.fund_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
 
@@ -340,7 +619,9 @@ pub fn party_params_to_rust(params: &PartyParams) -> Result<DlcPartyParams, DLCE
 
     Ok(DlcPartyParams {
         fund_pubkey,
-        change_script_pubkey: ScriptBuf::from(params.change_script_pubkey.clone()),
+        change_script_pubkey: ScriptBuf::from(
+            params.change_script_pubkey.clone().unwrap_or_default(),
+        ),
         change_serial_id: params.change_serial_id,
         payout_script_pubkey: ScriptBuf::from(params.payout_script_pubkey.clone()),
         payout_serial_id: params.payout_serial_id,
@@ -361,6 +642,47 @@ pub fn rust_dlc_transactions_to_uniffi(dlc_txs: RustDlcTransactions) -> DlcTrans
     }
 }
 
+/// Reject funding inputs that appear more than once across both parties.
+fn validate_unique_funding_inputs(
+    local_params: &DlcPartyParams,
+    remote_params: &DlcPartyParams,
+) -> Result<(), DLCError> {
+    let mut seen = HashSet::new();
+    let outpoints = [local_params, remote_params]
+        .into_iter()
+        .flat_map(|params| {
+            params.inputs.iter().map(|input| input.outpoint).chain(
+                params
+                    .dlc_inputs
+                    .iter()
+                    .map(|input| OutPoint::new(input.fund_tx.compute_txid(), input.fund_vout)),
+            )
+        });
+
+    for outpoint in outpoints {
+        if !seen.insert(outpoint) {
+            return Err(DLCError::InvalidArgument(
+                "duplicate funding input".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Total collateral of a contract between two parties.
+fn contract_total_collateral(
+    local_params: &PartyParams,
+    remote_params: &PartyParams,
+) -> Result<u64, DLCError> {
+    local_params
+        .collateral
+        .checked_add(remote_params.collateral)
+        .ok_or(DLCError::InvalidArgument(
+            "Total collateral overflows".to_string(),
+        ))
+}
+
 /// Create a funding script pubkey for DLC transactions
 pub fn create_fund_tx_locking_script(
     local_fund_pubkey: Vec<u8>,
@@ -375,7 +697,9 @@ pub fn create_fund_tx_locking_script(
     Ok(script.to_bytes())
 }
 
-/// Create complete DLC transactions
+/// Create complete DLC transactions. The refund locktime must be after the CET
+/// locktime, otherwise the refund could be spent before any CET. Both must be
+/// block heights or both timestamps.
 pub fn create_dlc_transactions(
     outcomes: Vec<Payout>,
     local_params: PartyParams,
@@ -387,24 +711,12 @@ pub fn create_dlc_transactions(
     fund_output_serial_id: u64,
     contract_flags: u8,
 ) -> Result<DlcTransactions, DLCError> {
-    // Convert UniFFI types to rust-dlc types
-    let rust_local_params = party_params_to_rust(&local_params)?;
-    let rust_remote_params = party_params_to_rust(&remote_params)?;
-
-    // Convert outcomes to payouts
-    let payouts: Vec<DlcPayout> = outcomes
-        .iter()
-        .map(|outcome| DlcPayout {
-            offer: Amount::from_sat(outcome.offer),
-            accept: Amount::from_sat(outcome.accept),
-        })
-        .collect();
+    validate_locktime_order(refund_locktime, cet_lock_time)?;
 
-    // Use rust-dlc library to create transactions
-    let dlc_txs = ddk_dlc::create_dlc_transactions(
-        &rust_local_params,
-        &rust_remote_params,
-        &payouts,
+    create_dlc_transactions_unchecked(
+        outcomes,
+        local_params,
+        remote_params,
         refund_locktime,
         fee_rate,
         fund_lock_time,
@@ -412,14 +724,11 @@ pub fn create_dlc_transactions(
         fund_output_serial_id,
         contract_flags,
     )
-    .map_err(DLCError::from)?;
-
-    // Convert back to UniFFI types
-    Ok(rust_dlc_transactions_to_uniffi(dlc_txs))
 }
 
-/// Create spliced DLC transactions
-pub fn create_spliced_dlc_transactions(
+/// Same as [`create_dlc_transactions`] without the locktime ordering check, for
+/// callers that knowingly allow the refund to be spendable before the CETs.
+pub fn create_dlc_transactions_unchecked(
     outcomes: Vec<Payout>,
     local_params: PartyParams,
     remote_params: PartyParams,
@@ -430,110 +739,219 @@ pub fn create_spliced_dlc_transactions(
     fund_output_serial_id: u64,
     contract_flags: u8,
 ) -> Result<DlcTransactions, DLCError> {
-    // Convert UniFFI types to rust-dlc types
-    let rust_local_params = party_params_to_rust(&local_params)?;
-    let rust_remote_params = party_params_to_rust(&remote_params)?;
-
-    // Convert outcomes to payouts
-    let payouts: Vec<DlcPayout> = outcomes
-        .iter()
-        .map(|outcome| DlcPayout {
-            offer: Amount::from_sat(outcome.offer),
-            accept: Amount::from_sat(outcome.accept),
-        })
-        .collect();
-
-    // Use rust-dlc library to create spliced transactions
-    let dlc_txs = ddk_dlc::create_spliced_dlc_transactions(
-        &rust_local_params,
-        &rust_remote_params,
-        &payouts,
+    let request = DlcTransactionRequest {
+        outcomes,
+        local_params,
+        remote_params,
         refund_locktime,
         fee_rate,
         fund_lock_time,
         cet_lock_time,
         fund_output_serial_id,
         contract_flags,
-    )
-    .map_err(DLCError::from)?;
-
-    // Convert back to UniFFI types
-    Ok(rust_dlc_transactions_to_uniffi(dlc_txs))
+    };
+    // Convert UniFFI types to rust-dlc types
+    let total_collateral =
+        contract_total_collateral(&request.local_params, &request.remote_params)?;
+    let rust_local_params =
+        party_params_to_rust(&request.local_params, total_collateral, request.fee_rate)?;
+    let rust_remote_params =
+        party_params_to_rust(&request.remote_params, total_collateral, request.fee_rate)?;
+
+    build_dlc_transactions(&request, &rust_local_params, &rust_remote_params)
 }
 
-/// Create a single CET
-pub fn create_cet(
-    local_output: TxOutput,
-    local_payout_serial_id: u64,
-    remote_output: TxOutput,
-    remote_payout_serial_id: u64,
-    fund_tx_id: String,
-    fund_vout: u32,
-    lock_time: u32,
-) -> Result<Transaction, DLCError> {
-    let txid = Txid::from_str(&fund_tx_id)
-        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
-
-    let local_btc_output = BtcTxOut {
-        value: Amount::from_sat(local_output.value),
-        script_pubkey: ScriptBuf::from(local_output.script_pubkey),
+/// Same as [`create_dlc_transactions`], with every CET also paying `fee_output`
+/// (e.g. a coordinator fee) ordered among the payouts by `fee_output_serial_id`.
+/// The fee comes out of the collateral, so each payout's offer + accept + fee
+/// must equal the total collateral. The parties also put up the CET fee for the
+/// extra output, split as ddk-dlc splits the CET base weight. The refund does
+/// not pay the fee output and returns that extra CET fee to the parties. With
+/// no fee output this is exactly [`create_dlc_transactions`].
+pub fn create_dlc_transactions_with_fee_output(
+    outcomes: Vec<Payout>,
+    local_params: PartyParams,
+    remote_params: PartyParams,
+    refund_locktime: u32,
+    fee_rate: u64,
+    fund_lock_time: u32,
+    cet_lock_time: u32,
+    fund_output_serial_id: u64,
+    contract_flags: u8,
+    fee_output: Option<TxOutput>,
+    fee_output_serial_id: u64,
+) -> Result<DlcTransactions, DLCError> {
+    let Some(fee_output) = fee_output else {
+        return create_dlc_transactions(
+            outcomes,
+            local_params,
+            remote_params,
+            refund_locktime,
+            fee_rate,
+            fund_lock_time,
+            cet_lock_time,
+            fund_output_serial_id,
+            contract_flags,
+        );
     };
 
-    let remote_btc_output = BtcTxOut {
-        value: Amount::from_sat(remote_output.value),
-        script_pubkey: ScriptBuf::from(remote_output.script_pubkey),
+    if fee_output_serial_id == local_params.payout_serial_id
+        || fee_output_serial_id == remote_params.payout_serial_id
+    {
+        return Err(DLCError::InvalidArgument(
+            "Fee output serial id must differ from the payout serial ids".to_string(),
+        ));
+    }
+    // Dropping the output from the CETs would hand its value to the miners
+    if fee_output.value < DUST_LIMIT {
+        return Err(DLCError::InvalidArgument(format!(
+            "Fee output value ({}) is below the dust limit ({})",
+            fee_output.value, DUST_LIMIT
+        )));
+    }
+    if fee_output.script_pubkey.is_empty() {
+        return Err(DLCError::InvalidArgument(
+            "Fee output script pubkey is empty".to_string(),
+        ));
+    }
+    let total_collateral = local_params
+        .collateral
+        .checked_add(remote_params.collateral)
+        .ok_or(DLCError::InvalidArgument(
+            "Total collateral overflows".to_string(),
+        ))?;
+    for (i, outcome) in outcomes.iter().enumerate() {
+        let total = outcome
+            .offer
+            .checked_add(outcome.accept)
+            .and_then(|payout| payout.checked_add(fee_output.value));
+        if total != Some(total_collateral) {
+            return Err(DLCError::InvalidArgument(format!(
+                "Payout {}: offer ({}) + accept ({}) + fee ({}) does not equal total collateral ({})",
+                i, outcome.offer, outcome.accept, fee_output.value, total_collateral
+            )));
+        }
+    }
+
+    // ddk-dlc reserves the CET fee for two outputs. The fee output's vbytes
+    // are reserved by raising the collateral, paid by both parties unless one
+    // of them funds the whole contract.
+    let fee_output_vsize = 8
+        + bitcoin::VarInt(fee_output.script_pubkey.len() as u64).size() as u64
+        + fee_output.script_pubkey.len() as u64;
+    let extra_cet_fee = fee_output_vsize
+        .checked_mul(fee_rate)
+        .filter(|fee| total_collateral.checked_add(*fee).is_some())
+        .ok_or(DLCError::InvalidArgument(
+            "Fee output CET fee overflows".to_string(),
+        ))?;
+    let (local_extra, remote_extra) = if remote_params.collateral == 0 {
+        (extra_cet_fee, 0)
+    } else if local_params.collateral == 0 {
+        (0, extra_cet_fee)
+    } else {
+        (extra_cet_fee - extra_cet_fee / 2, extra_cet_fee / 2)
+    };
+    let reserving_params = |params: &PartyParams, extra: u64| PartyParams {
+        collateral: params.collateral + extra,
+        ..params.clone()
     };
 
-    let fund_tx_input = TxIn {
-        previous_output: OutPoint {
-            txid,
-            vout: fund_vout,
-        },
-        script_sig: ScriptBuf::new(),
-        sequence: Sequence::ZERO,
-        witness: Witness::new(),
+    // The fund and refund transactions do not depend on the payouts, so build
+    // them from payouts with the fee folded into the offer side, then rebuild
+    // the CETs with the fee as its own output.
+    let folded_outcomes = outcomes
+        .iter()
+        .map(|outcome| Payout {
+            offer: outcome.offer + fee_output.value + extra_cet_fee,
+            accept: outcome.accept,
+        })
+        .collect();
+    let mut dlc_txs = create_dlc_transactions(
+        folded_outcomes,
+        reserving_params(&local_params, local_extra),
+        reserving_params(&remote_params, remote_extra),
+        refund_locktime,
+        fee_rate,
+        fund_lock_time,
+        cet_lock_time,
+        fund_output_serial_id,
+        contract_flags,
+    )?;
+    let Some(cet) = dlc_txs.cets.first() else {
+        return Ok(dlc_txs);
     };
+    let cet_input = transaction_to_btc_tx(cet)?.input.remove(0);
 
-    let btc_tx = ddk_dlc::create_cet(
-        local_btc_output,
-        local_payout_serial_id,
-        remote_btc_output,
-        remote_payout_serial_id,
-        &fund_tx_input,
-        lock_time,
-    );
+    let output = |value: u64, script_pubkey: &[u8]| BtcTxOut {
+        value: Amount::from_sat(value),
+        script_pubkey: ScriptBuf::from_bytes(script_pubkey.to_vec()),
+    };
+    dlc_txs.cets = outcomes
+        .iter()
+        .map(|outcome| {
+            let mut outputs = [
+                (
+                    local_params.payout_serial_id,
+                    output(outcome.offer, &local_params.payout_script_pubkey),
+                ),
+                (
+                    remote_params.payout_serial_id,
+                    output(outcome.accept, &remote_params.payout_script_pubkey),
+                ),
+                (
+                    fee_output_serial_id,
+                    output(fee_output.value, &fee_output.script_pubkey),
+                ),
+            ];
+            outputs.sort_by_key(|(serial_id, _)| *serial_id);
+            let cet = BtcTransaction {
+                version: bitcoin::transaction::Version::TWO,
+                lock_time: bitcoin::absolute::LockTime::from_consensus(cet_lock_time),
+                input: vec![cet_input.clone()],
+                output: outputs
+                    .into_iter()
+                    .map(|(_, output)| output)
+                    .filter(|output| output.value.to_sat() >= DUST_LIMIT)
+                    .collect(),
+            };
+            btc_tx_to_transaction(&cet)
+        })
+        .collect();
 
-    Ok(btc_tx_to_transaction(&btc_tx))
+    Ok(dlc_txs)
 }
 
-/// Create multiple CETs
-pub fn create_cets(
-    fund_tx_id: String,
-    fund_vout: u32,
-    local_final_script_pubkey: Vec<u8>,
-    remote_final_script_pubkey: Vec<u8>,
-    outcomes: Vec<Payout>,
-    lock_time: u32,
-    local_serial_id: u64,
-    remote_serial_id: u64,
-) -> Result<Vec<Transaction>, DLCError> {
-    let txid = Txid::from_str(&fund_tx_id)
-        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
-
-    let fund_tx_input = TxIn {
-        previous_output: OutPoint {
-            txid,
-            vout: fund_vout,
-        },
-        script_sig: ScriptBuf::new(),
-        sequence: Sequence::ZERO,
-        witness: Witness::new(),
-    };
+fn validate_locktime_order(refund_locktime: u32, cet_lock_time: u32) -> Result<(), DLCError> {
+    // Block heights and timestamps cannot be ordered against each other
+    let refund = bitcoin::absolute::LockTime::from_consensus(refund_locktime);
+    let cet = bitcoin::absolute::LockTime::from_consensus(cet_lock_time);
+    if !refund.is_same_unit(cet) {
+        return Err(DLCError::InvalidArgument(format!(
+            "Refund locktime ({}) and CET locktime ({}) must both be block heights or both timestamps",
+            refund_locktime, cet_lock_time
+        )));
+    }
+    if refund_locktime <= cet_lock_time {
+        return Err(DLCError::InvalidArgument(format!(
+            "Refund locktime ({}) must be after CET locktime ({})",
+            refund_locktime, cet_lock_time
+        )));
+    }
+    Ok(())
+}
 
-    let local_script = Script::from_bytes(&local_final_script_pubkey);
-    let remote_script = Script::from_bytes(&remote_final_script_pubkey);
+/// Build the transactions of a contract whose party params are already converted.
+fn build_dlc_transactions(
+    request: &DlcTransactionRequest,
+    rust_local_params: &DlcPartyParams,
+    rust_remote_params: &DlcPartyParams,
+) -> Result<DlcTransactions, DLCError> {
+    validate_unique_funding_inputs(rust_local_params, rust_remote_params)?;
 
-    let payouts: Vec<DlcPayout> = outcomes
+    // Convert outcomes to payouts
+    let payouts: Vec<DlcPayout> = request
+        .outcomes
         .iter()
         .map(|outcome| DlcPayout {
             offer: Amount::from_sat(outcome.offer),
@@ -541,1458 +959,8378 @@ pub fn create_cets(
         })
         .collect();
 
-    let btc_txs = ddk_dlc::create_cets(
-        &fund_tx_input,
-        local_script,
-        local_serial_id,
-        remote_script,
-        remote_serial_id,
+    // Use rust-dlc library to create transactions
+    let dlc_txs = ddk_dlc::create_dlc_transactions(
+        rust_local_params,
+        rust_remote_params,
         &payouts,
-        lock_time,
-    );
+        request.refund_locktime,
+        request.fee_rate,
+        request.fund_lock_time,
+        request.cet_lock_time,
+        request.fund_output_serial_id,
+        request.contract_flags,
+    )
+    .map_err(DLCError::from)?;
 
-    Ok(btc_txs.iter().map(btc_tx_to_transaction).collect())
+    // Convert back to UniFFI types
+    Ok(rust_dlc_transactions_to_uniffi(dlc_txs))
 }
 
-/// Create a refund transaction
-pub fn create_refund_transaction(
-    local_final_script_pubkey: Vec<u8>,
-    remote_final_script_pubkey: Vec<u8>,
-    local_amount: u64,
-    remote_amount: u64,
-    lock_time: u32,
-    fund_tx_id: String,
-    fund_vout: u32,
-) -> Result<Transaction, DLCError> {
-    let txid = Txid::from_str(&fund_tx_id)
-        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
-
-    let local_output = BtcTxOut {
-        value: Amount::from_sat(local_amount),
-        script_pubkey: ScriptBuf::from(local_final_script_pubkey),
-    };
-
-    let remote_output = BtcTxOut {
-        value: Amount::from_sat(remote_amount),
-        script_pubkey: ScriptBuf::from(remote_final_script_pubkey),
-    };
-
-    let funding_input = TxIn {
-        previous_output: OutPoint {
-            txid,
-            vout: fund_vout,
-        },
-        script_sig: ScriptBuf::new(),
-        sequence: Sequence::ENABLE_LOCKTIME_NO_RBF,
-        witness: Witness::new(),
+/// Create the transactions of several contracts in one call, in request order.
+/// Fails as a whole if any contract fails, naming the index of the first one.
+pub fn create_dlc_transactions_batch(
+    requests: Vec<DlcTransactionRequest>,
+) -> Result<Vec<DlcTransactions>, DLCError> {
+    let annotate = |index: usize| {
+        move |e: DLCError| DLCError::InvalidArgument(format!("Contract {}: {}", index, e))
     };
 
-    let btc_tx =
-        ddk_dlc::create_refund_transaction(local_output, remote_output, funding_input, lock_time);
-
-    Ok(btc_tx_to_transaction(&btc_tx))
-}
+    // Validate and convert every request up front so a bad one fails the batch
+    // before any transactions are built
+    let rust_params = requests
+        .iter()
+        .enumerate()
+        .map(|(index, request)| {
+            validate_locktime_order(request.refund_locktime, request.cet_lock_time)
+                .and_then(|_| {
+                    let total_collateral =
+                        contract_total_collateral(&request.local_params, &request.remote_params)?;
+                    Ok((
+                        party_params_to_rust(
+                            &request.local_params,
+                            total_collateral,
+                            request.fee_rate,
+                        )?,
+                        party_params_to_rust(
+                            &request.remote_params,
+                            total_collateral,
+                            request.fee_rate,
+                        )?,
+                    ))
+                })
+                .map_err(annotate(index))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
 
-/// Check if a transaction output is dust
-pub fn is_dust_output(output: TxOutput) -> bool {
-    output.value < DUST_LIMIT
+    requests
+        .iter()
+        .zip(&rust_params)
+        .enumerate()
+        .map(|(index, (request, (local_params, remote_params)))| {
+            build_dlc_transactions(request, local_params, remote_params).map_err(annotate(index))
+        })
+        .collect()
 }
 
-/// Get change output and fees for a party
-pub fn get_change_output_and_fees(
-    params: PartyParams,
-    fee_rate: u64,
-) -> Result<ChangeOutputAndFees, DLCError> {
-    let rust_params = party_params_to_rust(&params)?;
-    let total_collateral = Amount::from_sat(params.collateral * 2); // Assume bilateral
-
-    let (change_output, fund_fee, cet_fee) = rust_params
-        .get_change_output_and_fees(total_collateral, fee_rate, Amount::ZERO)
-        .map_err(DLCError::from)?;
-
-    let uniffi_output = TxOutput {
-        value: change_output.value.to_sat(),
-        script_pubkey: change_output.script_pubkey.to_bytes(),
+/// Report which fields of two party params differ, to debug parties that
+/// disagree on a contract. Inputs are compared regardless of order.
+pub fn diff_party_params(a: PartyParams, b: PartyParams) -> PartyParamsDiff {
+    let inputs = |params: &PartyParams| {
+        let mut inputs = params
+            .inputs
+            .iter()
+            .map(|input| {
+                (
+                    input.txid.clone(),
+                    input.vout,
+                    input.script_sig.clone(),
+                    input.max_witness_length,
+                )
+            })
+            .collect::<Vec<_>>();
+        inputs.sort();
+        inputs
+    };
+    let dlc_inputs = |params: &PartyParams| {
+        let mut dlc_inputs = params
+            .dlc_inputs
+            .iter()
+            .map(|input| {
+                (
+                    input.fund_tx.raw_bytes.clone(),
+                    input.fund_vout,
+                    input.local_fund_pubkey.clone(),
+                    input.remote_fund_pubkey.clone(),
+                    input.fund_amount,
+                    input.max_witness_len,
+                    input.contract_id.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        dlc_inputs.sort();
+        dlc_inputs
+    };
+    let serial_ids = |params: &PartyParams| {
+        let mut input_serial_ids = params
+            .inputs
+            .iter()
+            .map(|input| (input.txid.clone(), input.vout, input.serial_id))
+            .chain(params.dlc_inputs.iter().map(|input| {
+                (
+                    input.fund_tx.raw_bytes.to_lower_hex_string(),
+                    input.fund_vout,
+                    input.input_serial_id,
+                )
+            }))
+            .collect::<Vec<_>>();
+        input_serial_ids.sort();
+        (
+            params.change_serial_id,
+            params.payout_serial_id,
+            input_serial_ids,
+        )
     };
 
-    Ok(ChangeOutputAndFees {
-        change_output: uniffi_output,
-        fund_fee: fund_fee.to_sat(),
-        cet_fee: cet_fee.to_sat(),
-    })
+    PartyParamsDiff {
+        fund_pubkey: a.fund_pubkey != b.fund_pubkey,
+        change_script_pubkey: a.change_script_pubkey != b.change_script_pubkey,
+        payout_script_pubkey: a.payout_script_pubkey != b.payout_script_pubkey,
+        input_amount: a.input_amount != b.input_amount,
+        collateral: a.collateral != b.collateral,
+        inputs: inputs(&a) != inputs(&b),
+        dlc_inputs: dlc_inputs(&a) != dlc_inputs(&b),
+        serial_ids: serial_ids(&a) != serial_ids(&b),
+    }
 }
 
-/// Get total input virtual size for fee calculation
-pub fn get_total_input_vsize(inputs: Vec<TxInputInfo>) -> u32 {
-    // Simplified calculation: P2WPKH inputs are ~148 vbytes each
-    inputs.len() as u32 * 148
+/// Pick a fund output serial id distinct from every input, change and payout
+/// serial id of both parties. The id is derived from those serial ids, so both
+/// parties compute the same value.
+pub fn safe_fund_output_serial_id(local_params: PartyParams, remote_params: PartyParams) -> u64 {
+    let used = [&local_params, &remote_params]
+        .into_iter()
+        .flat_map(|params| {
+            [params.change_serial_id, params.payout_serial_id]
+                .into_iter()
+                .chain(params.inputs.iter().map(|input| input.serial_id))
+                .chain(params.dlc_inputs.iter().map(|input| input.input_serial_id))
+        })
+        .collect::<HashSet<_>>();
+
+    let mut sorted_used = used.iter().copied().collect::<Vec<_>>();
+    sorted_used.sort_unstable();
+    let mut engine = sha256::Hash::engine();
+    for serial_id in sorted_used {
+        engine.input(&serial_id.to_be_bytes());
+    }
+    let hash = sha256::Hash::from_engine(engine).to_byte_array();
+
+    let mut serial_id = u64::from_be_bytes(hash[..8].try_into().expect("8 bytes"));
+    while used.contains(&serial_id) {
+        serial_id = serial_id.wrapping_add(1);
+    }
+    serial_id
 }
 
-/// Verify a fund transaction signature
-pub fn verify_fund_tx_signature(
+/// Rebuild the funding transaction expected from the agreed party params and
+/// compare it with the one provided, ignoring witnesses.
+///
+/// The fund lock time is taken from the provided transaction; the fee rate is
+/// required since change values depend on it.
+pub fn verify_funding_matches_params(
     fund_tx: Transaction,
-    signature: Vec<u8>,
-    pubkey: Vec<u8>,
-    txid: String,
-    vout: u32,
-    input_amount: u64,
+    local_params: PartyParams,
+    remote_params: PartyParams,
+    fee_rate: u64,
+    fund_output_serial_id: u64,
 ) -> Result<bool, DLCError> {
-    let btc_tx = transaction_to_btc_tx(&fund_tx)?;
-    let pk = PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
-    let input_txid = Txid::from_str(&txid)
-        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
+    let total_collateral = contract_total_collateral(&local_params, &remote_params)?;
+    let rust_local_params = party_params_to_rust(&local_params, total_collateral, fee_rate)?;
+    let rust_remote_params = party_params_to_rust(&remote_params, total_collateral, fee_rate)?;
 
-    // Find the input index
-    let input_index = btc_tx
-        .input
-        .iter()
-        .position(|input| {
-            input.previous_output.txid == input_txid && input.previous_output.vout == vout
-        })
-        .ok_or(DLCError::InvalidArgument(format!(
-            "Input index not found in {input_txid}"
-        )))?;
+    let (expected_fund_tx, _) = ddk_dlc::create_fund_transaction_with_fees(
+        &rust_local_params,
+        &rust_remote_params,
+        fee_rate,
+        fund_tx.lock_time,
+        fund_output_serial_id,
+        Amount::ZERO,
+    )
+    .map_err(DLCError::from)?;
 
-    // Create a simple P2WPKH script for verification
-    let wpkh = WPubkeyHash::hash(&pk.serialize());
-    let script = bitcoin::ScriptBuf::new_p2wpkh(&wpkh);
+    Ok(bitcoin::consensus::serialize(&expected_fund_tx) == witness_stripped_bytes(&fund_tx)?)
+}
 
-    // Parse signature
-    let sig = EcdsaSignature::from_der(&signature).map_err(|_| DLCError::InvalidSignature)?;
+/// Rebuild a contract's transactions with a rotated local fund pubkey,
+/// keeping the outcomes and all other parameters unchanged
+pub fn rekey_contract(
+    local_params: PartyParams,
+    new_fund_pubkey: Vec<u8>,
+    remote_params: PartyParams,
+    outcomes: Vec<Payout>,
+    refund_locktime: u32,
+    fee_rate: u64,
+    fund_lock_time: u32,
+    cet_lock_time: u32,
+    fund_output_serial_id: u64,
+    contract_flags: u8,
+) -> Result<DlcTransactions, DLCError> {
+    PublicKey::from_slice(&new_fund_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
 
-    let secp = Secp256k1::verification_only();
-    match ddk_dlc::verify_tx_input_sig(
-        &secp,
-        &sig,
-        &btc_tx,
-        input_index,
-        &script,
-        Amount::from_sat(input_amount),
-        &pk,
-    ) {
-        Ok(()) => Ok(true),
-        Err(_) => Ok(false),
-    }
+    let local_params = PartyParams {
+        fund_pubkey: new_fund_pubkey,
+        ..local_params
+    };
+
+    create_dlc_transactions(
+        outcomes,
+        local_params,
+        remote_params,
+        refund_locktime,
+        fee_rate,
+        fund_lock_time,
+        cet_lock_time,
+        fund_output_serial_id,
+        contract_flags,
+    )
 }
 
-// ============================================================================
-// SIGNING AND SIGNATURE FUNCTIONS (using rust-dlc library)
-// ============================================================================
+/// Create spliced DLC transactions. Locktimes are checked as in
+/// [`create_dlc_transactions`].
+pub fn create_spliced_dlc_transactions(
+    outcomes: Vec<Payout>,
+    local_params: PartyParams,
+    remote_params: PartyParams,
+    refund_locktime: u32,
+    fee_rate: u64,
+    fund_lock_time: u32,
+    cet_lock_time: u32,
+    fund_output_serial_id: u64,
+    contract_flags: u8,
+) -> Result<DlcTransactions, DLCError> {
+    validate_locktime_order(refund_locktime, cet_lock_time)?;
 
-/// Get raw signature for a fund transaction input
-pub fn get_raw_funding_transaction_input_signature(
-    funding_transaction: Transaction,
-    privkey: Vec<u8>,
-    prev_tx_id: String,
-    prev_tx_vout: u32,
-    value: u64,
-) -> Result<Vec<u8>, DLCError> {
-    let btc_tx = transaction_to_btc_tx(&funding_transaction)?;
-    let sk = SecretKey::from_slice(&privkey)
-        .map_err(|_| DLCError::InvalidArgument("Invalid private key".to_string()))?;
-    let prev_txid = Txid::from_str(&prev_tx_id)
-        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
+    // Convert UniFFI types to rust-dlc types
+    let total_collateral = contract_total_collateral(&local_params, &remote_params)?;
+    let rust_local_params = party_params_to_rust(&local_params, total_collateral, fee_rate)?;
+    let rust_remote_params = party_params_to_rust(&remote_params, total_collateral, fee_rate)?;
 
-    // Find the input index
-    let input_index = btc_tx
-        .input
+    // Convert outcomes to payouts
+    let payouts: Vec<DlcPayout> = outcomes
         .iter()
-        .position(|input| {
-            input.previous_output.txid == prev_txid && input.previous_output.vout == prev_tx_vout
+        .map(|outcome| DlcPayout {
+            offer: Amount::from_sat(outcome.offer),
+            accept: Amount::from_sat(outcome.accept),
         })
-        .ok_or(DLCError::InvalidArgument(format!(
-            "Input index not found in {prev_txid}"
-        )))?;
-
-    let secp = get_secp_context();
-    // Create P2WPKH script for signing
-    let pk = PublicKey::from_secret_key(secp, &sk);
-    let wpkh = WPubkeyHash::hash(&pk.serialize());
-    let script = bitcoin::ScriptBuf::new_p2wpkh(&wpkh);
+        .collect();
 
-    let sig = ddk_dlc::util::get_sig_for_tx_input(
-        secp,
-        &btc_tx,
-        input_index,
-        &script,
-        Amount::from_sat(value),
-        EcdsaSighashType::All,
-        &sk,
+    // Use rust-dlc library to create spliced transactions
+    let dlc_txs = ddk_dlc::create_spliced_dlc_transactions(
+        &rust_local_params,
+        &rust_remote_params,
+        &payouts,
+        refund_locktime,
+        fee_rate,
+        fund_lock_time,
+        cet_lock_time,
+        fund_output_serial_id,
+        contract_flags,
     )
     .map_err(DLCError::from)?;
 
-    Ok(sig)
+    // Convert back to UniFFI types
+    Ok(rust_dlc_transactions_to_uniffi(dlc_txs))
 }
 
-/// Sign a funding transaction input
-pub fn sign_fund_transaction_input(
-    fund_transaction: Transaction,
-    privkey: Vec<u8>,
-    prev_tx_id: String,
-    prev_tx_vout: u32,
-    value: u64,
+/// Create a single CET
+pub fn create_cet(
+    local_output: TxOutput,
+    local_payout_serial_id: u64,
+    remote_output: TxOutput,
+    remote_payout_serial_id: u64,
+    fund_tx_id: String,
+    fund_vout: u32,
+    lock_time: u32,
 ) -> Result<Transaction, DLCError> {
-    let mut btc_tx = transaction_to_btc_tx(&fund_transaction)?;
-    let sk = SecretKey::from_slice(&privkey)
-        .map_err(|_| DLCError::InvalidArgument("Invalid private key".to_string()))?;
-    let prev_txid = Txid::from_str(&prev_tx_id)
+    let txid = Txid::from_str(&fund_tx_id)
         .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
 
-    // Find the input index
-    let input_index = btc_tx
-        .input
-        .iter()
-        .position(|input| {
-            input.previous_output.txid == prev_txid && input.previous_output.vout == prev_tx_vout
-        })
-        .ok_or(DLCError::InvalidArgument(format!(
-            "Input index not found in {prev_txid}"
-        )))?;
+    let local_btc_output = BtcTxOut {
+        value: Amount::from_sat(local_output.value),
+        script_pubkey: ScriptBuf::from(local_output.script_pubkey),
+    };
 
-    let secp = Secp256k1::signing_only();
-    ddk_dlc::util::sign_p2wpkh_input(
-        &secp,
-        &sk,
-        &mut btc_tx,
-        input_index,
-        EcdsaSighashType::All,
-        Amount::from_sat(value),
-    )
-    .map_err(DLCError::from)?;
+    let remote_btc_output = BtcTxOut {
+        value: Amount::from_sat(remote_output.value),
+        script_pubkey: ScriptBuf::from(remote_output.script_pubkey),
+    };
+
+    let fund_tx_input = TxIn {
+        previous_output: OutPoint {
+            txid,
+            vout: fund_vout,
+        },
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ZERO,
+        witness: Witness::new(),
+    };
+
+    let btc_tx = ddk_dlc::create_cet(
+        local_btc_output,
+        local_payout_serial_id,
+        remote_btc_output,
+        remote_payout_serial_id,
+        &fund_tx_input,
+        lock_time,
+    );
 
     Ok(btc_tx_to_transaction(&btc_tx))
 }
 
-pub fn sign_multi_sig_input(
-    txn: Transaction,
-    dlc_input: DlcInputInfo,
-    local_privkey: Vec<u8>,
-    remote_signature: Vec<u8>,
+/// Same as [`create_cet`], first checking that the payouts plus the CET fee at
+/// `fee_rate`, as reserved by [`create_dlc_transactions`], are payable from
+/// `fund_output_value`. Returns `InsufficientFunds` otherwise.
+pub fn create_cet_checked(
+    local_output: TxOutput,
+    local_payout_serial_id: u64,
+    remote_output: TxOutput,
+    remote_payout_serial_id: u64,
+    fund_tx_id: String,
+    fund_vout: u32,
+    lock_time: u32,
+    fund_output_value: u64,
+    fee_rate: u64,
 ) -> Result<Transaction, DLCError> {
-    let secp = get_secp_context();
-    let btc_tx = transaction_to_btc_tx(&txn)?;
-    let sk = SecretKey::from_slice(&local_privkey)
-        .map_err(|_| DLCError::InvalidArgument("Invalid private key".to_string()))?;
+    let cet_fee =
+        compute_cet_fee(local_output.script_pubkey.len() as u32, fee_rate).saturating_add(
+            compute_cet_fee(remote_output.script_pubkey.len() as u32, fee_rate),
+        );
+    let required = local_output
+        .value
+        .checked_add(remote_output.value)
+        .and_then(|total| total.checked_add(cet_fee))
+        .ok_or(DLCError::InsufficientFunds)?;
+    if required > fund_output_value {
+        return Err(DLCError::InsufficientFunds);
+    }
 
-    let local_pk = PublicKey::from_slice(&dlc_input.local_fund_pubkey)
-        .map_err(|_| DLCError::InvalidPublicKey)?;
-    let remote_pk = PublicKey::from_slice(&dlc_input.remote_fund_pubkey)
-        .map_err(|_| DLCError::InvalidPublicKey)?;
+    create_cet(
+        local_output,
+        local_payout_serial_id,
+        remote_output,
+        remote_payout_serial_id,
+        fund_tx_id,
+        fund_vout,
+        lock_time,
+    )
+}
 
-    let dlc_input = dlc_input_info_to_rust(&dlc_input)?;
+/// Same as [`create_cet`], also reporting which payout outputs were dropped
+/// for being under the dust limit, e.g. the loser's output of a winner take
+/// all CET.
+pub fn create_cet_with_info(
+    local_output: TxOutput,
+    local_payout_serial_id: u64,
+    remote_output: TxOutput,
+    remote_payout_serial_id: u64,
+    fund_tx_id: String,
+    fund_vout: u32,
+    lock_time: u32,
+) -> Result<CetBuildResult, DLCError> {
+    let offer_output_dropped = is_dust_output(local_output.clone());
+    let accept_output_dropped = is_dust_output(remote_output.clone());
+    let transaction = create_cet(
+        local_output,
+        local_payout_serial_id,
+        remote_output,
+        remote_payout_serial_id,
+        fund_tx_id,
+        fund_vout,
+        lock_time,
+    )?;
 
-    let signature = ddk_dlc::dlc_input::create_dlc_funding_input_signature(
-        secp,
-        &btc_tx,
-        dlc_input.fund_vout as usize,
-        &dlc_input,
-        &sk,
-    )
-    .map_err(|_| DLCError::InvalidSignature)?;
+    Ok(CetBuildResult {
+        transaction,
+        offer_output_dropped,
+        accept_output_dropped,
+    })
+}
 
-    let (first, second) = if local_pk < remote_pk {
-        (local_pk, remote_pk)
-    } else {
-        (remote_pk, local_pk)
+/// Create multiple CETs
+pub fn create_cets(
+    fund_tx_id: String,
+    fund_vout: u32,
+    local_final_script_pubkey: Vec<u8>,
+    remote_final_script_pubkey: Vec<u8>,
+    outcomes: Vec<Payout>,
+    lock_time: u32,
+    local_serial_id: u64,
+    remote_serial_id: u64,
+) -> Result<Vec<Transaction>, DLCError> {
+    let txid = Txid::from_str(&fund_tx_id)
+        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
+
+    let fund_tx_input = TxIn {
+        previous_output: OutPoint {
+            txid,
+            vout: fund_vout,
+        },
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ZERO,
+        witness: Witness::new(),
     };
 
-    let witness = ddk_dlc::dlc_input::combine_dlc_input_signatures(
-        &dlc_input,
-        &signature,
-        &remote_signature,
-        &first,
-        &second,
+    let local_script = Script::from_bytes(&local_final_script_pubkey);
+    let remote_script = Script::from_bytes(&remote_final_script_pubkey);
+
+    let payouts: Vec<DlcPayout> = outcomes
+        .iter()
+        .map(|outcome| DlcPayout {
+            offer: Amount::from_sat(outcome.offer),
+            accept: Amount::from_sat(outcome.accept),
+        })
+        .collect();
+
+    let btc_txs = ddk_dlc::create_cets(
+        &fund_tx_input,
+        local_script,
+        local_serial_id,
+        remote_script,
+        remote_serial_id,
+        &payouts,
+        lock_time,
     );
 
-    let mut fund_psbt = Psbt::from_unsigned_tx(btc_tx).map_err(|_| DLCError::InvalidTransaction)?;
-    fund_psbt.inputs[dlc_input.fund_vout as usize].final_script_witness = Some(witness);
+    Ok(btc_txs.iter().map(btc_tx_to_transaction).collect())
+}
 
-    Ok(btc_tx_to_transaction(
-        &fund_psbt.extract_tx_unchecked_fee_rate(),
-    ))
+/// Create CETs after checking that every payout splits exactly
+/// `total_collateral`. CET fees are paid from the fund output on top of the
+/// collateral, so payouts must not have them deducted.
+pub fn create_cets_checked(
+    fund_tx_id: String,
+    fund_vout: u32,
+    local_final_script_pubkey: Vec<u8>,
+    remote_final_script_pubkey: Vec<u8>,
+    outcomes: Vec<Payout>,
+    total_collateral: u64,
+    lock_time: u32,
+    local_serial_id: u64,
+    remote_serial_id: u64,
+) -> Result<Vec<Transaction>, DLCError> {
+    for (i, outcome) in outcomes.iter().enumerate() {
+        if outcome.offer.checked_add(outcome.accept) != Some(total_collateral) {
+            return Err(DLCError::InvalidArgument(format!(
+                "Payout {}: offer ({}) + accept ({}) does not equal total collateral ({})",
+                i, outcome.offer, outcome.accept, total_collateral
+            )));
+        }
+    }
+
+    create_cets(
+        fund_tx_id,
+        fund_vout,
+        local_final_script_pubkey,
+        remote_final_script_pubkey,
+        outcomes,
+        lock_time,
+        local_serial_id,
+        remote_serial_id,
+    )
 }
 
-pub fn sign_cet(
-    cet: Transaction,
-    adaptor_signature: Vec<u8>,
-    oracle_signatures: Vec<Vec<u8>>,
-    funding_secret_key: Vec<u8>,
-    other_pubkey: Vec<u8>,
-    funding_script_pubkey: Vec<u8>,
-    fund_output_value: u64,
+/// Create a refund transaction
+pub fn create_refund_transaction(
+    local_final_script_pubkey: Vec<u8>,
+    remote_final_script_pubkey: Vec<u8>,
+    local_amount: u64,
+    remote_amount: u64,
+    lock_time: u32,
+    fund_tx_id: String,
+    fund_vout: u32,
 ) -> Result<Transaction, DLCError> {
-    let mut btc_tx = transaction_to_btc_tx(&cet)?;
-    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_signature)?;
-    let oracle_sigs = oracle_signatures
-        .iter()
-        .map(|sig| vec_to_schnorr_signature(sig.as_slice()))
-        .collect::<Result<Vec<_>, _>>()?;
-    let funding_sk = SecretKey::from_slice(&funding_secret_key)
-        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
-    let other_pk = PublicKey::from_slice(&other_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
-    let funding_pubkey =
-        PublicKey::from_slice(&funding_script_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
-    let dlc_redeem_script = ddk_dlc::make_funding_redeemscript(&funding_pubkey, &other_pk);
-    let secp = get_secp_context();
+    let txid = Txid::from_str(&fund_tx_id)
+        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
 
-    ddk_dlc::sign_cet(
-        secp,
-        &mut btc_tx,
-        &adaptor_sig,
-        &[oracle_sigs],
-        &funding_sk,
-        &other_pk,
-        dlc_redeem_script.as_script(),
-        Amount::from_sat(fund_output_value),
-    )
-    .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+    let local_output = BtcTxOut {
+        value: Amount::from_sat(local_amount),
+        script_pubkey: ScriptBuf::from(local_final_script_pubkey),
+    };
+
+    let remote_output = BtcTxOut {
+        value: Amount::from_sat(remote_amount),
+        script_pubkey: ScriptBuf::from(remote_final_script_pubkey),
+    };
+
+    let funding_input = TxIn {
+        previous_output: OutPoint {
+            txid,
+            vout: fund_vout,
+        },
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_LOCKTIME_NO_RBF,
+        witness: Witness::new(),
+    };
+
+    let btc_tx =
+        ddk_dlc::create_refund_transaction(local_output, remote_output, funding_input, lock_time);
 
     Ok(btc_tx_to_transaction(&btc_tx))
 }
 
-fn vec_to_schnorr_signature(signature: &[u8]) -> Result<SchnorrSignature, DLCError> {
-    let sig = SchnorrSignature::from_slice(signature).map_err(|_| DLCError::InvalidSignature)?;
-    Ok(sig)
+/// Hex encode a transaction, witnesses included
+pub fn transaction_to_hex(tx: Transaction) -> String {
+    tx.raw_bytes.to_lower_hex_string()
 }
 
-fn vec_to_ecdsa_adaptor_signature(signature: Vec<u8>) -> Result<EcdsaAdaptorSignature, DLCError> {
-    EcdsaAdaptorSignature::from_slice(&signature).map_err(|_| DLCError::InvalidSignature)
+/// Decode a hex encoded transaction
+pub fn transaction_from_hex(hex: String) -> Result<Transaction, DLCError> {
+    let bytes = Vec::<u8>::from_hex(&hex).map_err(|_| DLCError::SerializationError)?;
+    let btc_tx: BtcTransaction =
+        bitcoin::consensus::deserialize(&bytes).map_err(|_| DLCError::SerializationError)?;
+
+    Ok(btc_tx_to_transaction(&btc_tx))
 }
 
-fn signatures_to_secret(signatures: &[Vec<SchnorrSignature>]) -> Result<SecretKey, DLCError> {
-    let s_values = signatures
-        .iter()
-        .flatten()
-        .map(|x| match secp_utils::schnorrsig_decompose(x) {
-            Ok(v) => Ok(v.1),
-            Err(err) => Err(DLCError::Secp256k1Error(err.to_string())),
-        })
-        .collect::<Result<Vec<&[u8]>, DLCError>>()?;
+const PARTY_PARAMS_ENCODING_VERSION: u8 = 1;
 
-    if s_values.is_empty() {
-        return Err(DLCError::InvalidArgument(
-            "No signatures provided".to_string(),
-        ));
+fn write_length_prefixed(buf: &mut Vec<u8>, bytes: &[u8]) {
+    buf.extend((bytes.len() as u32).to_be_bytes());
+    buf.extend(bytes);
+}
+
+/// Cursor over the big-endian, length-prefixed encodings written with
+/// `write_length_prefixed`.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DLCError> {
+        if self.bytes.len() < len {
+            return Err(DLCError::SerializationError);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
     }
 
-    let secret = SecretKey::from_slice(s_values[0])
-        .map_err(|_| DLCError::InvalidArgument("Invalid signature".to_string()))?;
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DLCError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
 
-    let result = s_values.iter().skip(1).fold(secret, |accum, s| {
-        let sec = SecretKey::from_slice(s).unwrap();
-        accum.add_tweak(&Scalar::from(sec)).unwrap()
-    });
+    fn read_version(&mut self, expected: u8, what: &str) -> Result<(), DLCError> {
+        let version = self.read_u8()?;
+        if version != expected {
+            return Err(DLCError::InvalidArgument(format!(
+                "Unsupported {} version {}",
+                what, version
+            )));
+        }
+        Ok(())
+    }
 
-    Ok(result)
-}
+    fn read_u8(&mut self) -> Result<u8, DLCError> {
+        Ok(self.take(1)?[0])
+    }
 
-pub fn create_cet_adaptor_sigs_from_oracle_info(
-    cets: Vec<Transaction>,
-    oracle_info: Vec<OracleInfo>,
-    funding_secret_key: Vec<u8>,
-    funding_script_pubkey: Vec<u8>,
-    fund_output_value: u64,
-    msgs: Vec<Vec<Vec<Vec<u8>>>>,
-) -> Result<Vec<AdaptorSignature>, DLCError> {
-    let cets = cets
-        .iter()
-        .map(transaction_to_btc_tx)
-        .collect::<Result<Vec<_>, _>>()?;
-    let oracle_infos = oracle_info
-        .iter()
-        .map(|info| {
-            let public_key = XOnlyPublicKey::from_slice(&info.public_key)
-                .map_err(|_| DLCError::InvalidPublicKey)?;
-            let nonces = info
-                .nonces
-                .iter()
-                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
-            Ok(DlcOracleInfo { public_key, nonces })
-        })
-        .collect::<Result<Vec<_>, DLCError>>()
-        .map_err(|_| DLCError::InvalidArgument("Invalid oracle info".to_string()))?;
+    fn read_u32(&mut self) -> Result<u32, DLCError> {
+        Ok(u32::from_be_bytes(self.take_array()?))
+    }
 
-    let funding_sk = SecretKey::from_slice(&funding_secret_key)
-        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
-    let funding_script = Script::from_bytes(&funding_script_pubkey);
-    let msgs: Vec<Vec<Vec<Message>>> = msgs
-        .iter()
-        .map(|cet_msgs| {
-            // For each CET
-            cet_msgs
-                .iter()
-                .map(|outcome_msgs| {
-                    // For each outcome
-                    outcome_msgs
-                        .iter()
-                        .map(|msg_bytes| {
-                            // For each message (Vec<u8>)
-                            Message::from_digest_slice(msg_bytes).map_err(|_| {
-                                DLCError::InvalidArgument("Invalid message".to_string())
-                            })
-                        })
-                        .collect::<Result<Vec<_>, _>>()
-                })
-                .collect::<Result<Vec<_>, _>>()
+    fn read_u64(&mut self) -> Result<u64, DLCError> {
+        Ok(u64::from_be_bytes(self.take_array()?))
+    }
+
+    fn read_bytes(&mut self) -> Result<Vec<u8>, DLCError> {
+        let len = self.read_u32()? as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn read_string(&mut self) -> Result<String, DLCError> {
+        String::from_utf8(self.read_bytes()?).map_err(|_| DLCError::SerializationError)
+    }
+
+    fn read_pubkey(&mut self) -> Result<Vec<u8>, DLCError> {
+        let pubkey = self.read_bytes()?;
+        if pubkey.len() != 33 {
+            return Err(DLCError::InvalidPublicKey);
+        }
+        PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+        Ok(pubkey)
+    }
+
+    fn read_script(&mut self, name: &str) -> Result<Vec<u8>, DLCError> {
+        let script = self.read_bytes()?;
+        if script.is_empty() {
+            return Err(DLCError::InvalidArgument(format!("{} is empty", name)));
+        }
+        Ok(script)
+    }
+
+    fn read_tx(&mut self) -> Result<Transaction, DLCError> {
+        let tx: BtcTransaction = bitcoin::consensus::deserialize(&self.read_bytes()?)
+            .map_err(|_| DLCError::SerializationError)?;
+        Ok(btc_tx_to_transaction(&tx))
+    }
+
+    fn finish(self) -> Result<(), DLCError> {
+        if !self.bytes.is_empty() {
+            return Err(DLCError::SerializationError);
+        }
+        Ok(())
+    }
+}
+
+/// Encode party params in a deterministic, length-prefixed byte format so
+/// that peers exchanging them agree byte-for-byte regardless of SDK.
+pub fn party_params_serialize(params: PartyParams) -> Vec<u8> {
+    let mut out = vec![PARTY_PARAMS_ENCODING_VERSION];
+    write_length_prefixed(&mut out, &params.fund_pubkey);
+    match &params.change_script_pubkey {
+        Some(script) => {
+            out.push(1);
+            write_length_prefixed(&mut out, script);
+        }
+        None => out.push(0),
+    }
+    out.extend_from_slice(&params.change_serial_id.to_be_bytes());
+    write_length_prefixed(&mut out, &params.payout_script_pubkey);
+    out.extend_from_slice(&params.payout_serial_id.to_be_bytes());
+    out.extend_from_slice(&(params.inputs.len() as u32).to_be_bytes());
+    for input in &params.inputs {
+        write_length_prefixed(&mut out, input.txid.as_bytes());
+        out.extend_from_slice(&input.vout.to_be_bytes());
+        write_length_prefixed(&mut out, &input.script_sig);
+        out.extend_from_slice(&input.max_witness_length.to_be_bytes());
+        out.extend_from_slice(&input.serial_id.to_be_bytes());
+    }
+    out.extend_from_slice(&params.input_amount.to_be_bytes());
+    out.extend_from_slice(&params.collateral.to_be_bytes());
+    out.extend_from_slice(&(params.dlc_inputs.len() as u32).to_be_bytes());
+    for input in &params.dlc_inputs {
+        write_length_prefixed(&mut out, &input.fund_tx.raw_bytes);
+        out.extend_from_slice(&input.fund_vout.to_be_bytes());
+        write_length_prefixed(&mut out, &input.local_fund_pubkey);
+        write_length_prefixed(&mut out, &input.remote_fund_pubkey);
+        out.extend_from_slice(&input.fund_amount.to_be_bytes());
+        out.extend_from_slice(&input.max_witness_len.to_be_bytes());
+        out.extend_from_slice(&input.input_serial_id.to_be_bytes());
+        write_length_prefixed(&mut out, &input.contract_id);
+    }
+    out
+}
+
+/// Decode party params produced by `party_params_serialize`, validating
+/// public keys and scripts along the way.
+pub fn party_params_deserialize(bytes: Vec<u8>) -> Result<PartyParams, DLCError> {
+    let mut reader = ByteReader { bytes: &bytes };
+    reader.read_version(PARTY_PARAMS_ENCODING_VERSION, "party params")?;
+    let fund_pubkey = reader.read_pubkey()?;
+    let change_script_pubkey = match reader.read_u8()? {
+        0 => None,
+        1 => Some(reader.read_script("Change script pubkey")?),
+        _ => return Err(DLCError::SerializationError),
+    };
+    let change_serial_id = reader.read_u64()?;
+    let payout_script_pubkey = reader.read_script("Payout script pubkey")?;
+    let payout_serial_id = reader.read_u64()?;
+    let inputs = (0..reader.read_u32()?)
+        .map(|_| {
+            Ok(TxInputInfo {
+                txid: reader.read_string()?,
+                vout: reader.read_u32()?,
+                script_sig: reader.read_bytes()?,
+                max_witness_length: reader.read_u32()?,
+                serial_id: reader.read_u64()?,
+            })
         })
-        .collect::<Result<Vec<_>, _>>()?;
-    let secp = get_secp_context();
-    let adaptor_sigs = ddk_dlc::create_cet_adaptor_sigs_from_oracle_info(
-        secp,
-        &cets,
-        &oracle_infos,
-        &funding_sk,
-        funding_script,
-        Amount::from_sat(fund_output_value),
-        &msgs,
-    )
-    .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+        .collect::<Result<Vec<_>, DLCError>>()?;
+    let input_amount = reader.read_u64()?;
+    let collateral = reader.read_u64()?;
+    let dlc_inputs = (0..reader.read_u32()?)
+        .map(|_| {
+            Ok(DlcInputInfo {
+                fund_tx: reader.read_tx()?,
+                fund_vout: reader.read_u32()?,
+                local_fund_pubkey: reader.read_pubkey()?,
+                remote_fund_pubkey: reader.read_pubkey()?,
+                fund_amount: reader.read_u64()?,
+                max_witness_len: reader.read_u32()?,
+                input_serial_id: reader.read_u64()?,
+                contract_id: reader.read_bytes()?,
+            })
+        })
+        .collect::<Result<Vec<_>, DLCError>>()?;
+    reader.finish()?;
 
-    let adaptor_sigs = adaptor_sigs
-        .iter()
-        .map(|sig| AdaptorSignature {
-            signature: sig.as_ref().to_vec(),
-            proof: Vec::new(),
+    Ok(PartyParams {
+        fund_pubkey,
+        change_script_pubkey,
+        change_serial_id,
+        payout_script_pubkey,
+        payout_serial_id,
+        inputs,
+        input_amount,
+        collateral,
+        dlc_inputs,
+    })
+}
+
+/// Redeem script of a P2SH-wrapped input, `None` for a native segwit input.
+fn redeem_script(script_sig: &Script) -> Option<ScriptBuf> {
+    if script_sig.is_empty() {
+        return None;
+    }
+    // ddk-dlc writes the bare witness program, otherwise expect a single push
+    if script_sig.is_witness_program() {
+        return Some(script_sig.to_owned());
+    }
+    match script_sig.instructions().next() {
+        Some(Ok(instruction)) => instruction
+            .push_bytes()
+            .map(|bytes| ScriptBuf::from_bytes(bytes.as_bytes().to_vec())),
+        _ => None,
+    }
+}
+
+/// Wrap the fund transaction of a contract into a serialized PSBT for external
+/// signers. Script sigs and witnesses are cleared; a P2SH-wrapped input's
+/// redeem script is moved to the PSBT input. Prevouts are not known here, so
+/// `witness_utxo` is left for the signer to fill.
+pub fn dlc_transactions_to_fund_psbt(dlc_txs: DlcTransactions) -> Result<Vec<u8>, DLCError> {
+    let mut fund_tx = transaction_to_btc_tx(&dlc_txs.fund)?;
+    let redeem_scripts = fund_tx
+        .input
+        .iter_mut()
+        .map(|input| {
+            input.witness.clear();
+            redeem_script(&std::mem::take(&mut input.script_sig))
         })
         .collect::<Vec<_>>();
 
-    Ok(adaptor_sigs)
+    let mut psbt = Psbt::from_unsigned_tx(fund_tx).map_err(|_| DLCError::InvalidTransaction)?;
+    for (psbt_input, redeem_script) in psbt.inputs.iter_mut().zip(redeem_scripts) {
+        psbt_input.redeem_script = redeem_script;
+    }
+
+    Ok(psbt.serialize())
 }
 
-/// Create adaptor signatures from pre-computed adaptor points.
-pub fn create_cet_adaptor_sigs_from_points(
-    cets: Vec<Transaction>,
-    adaptor_points: Vec<Vec<u8>>,
-    funding_secret_key: Vec<u8>,
-    funding_script_pubkey: Vec<u8>,
-    fund_output_value: u64,
-) -> Result<Vec<AdaptorSignature>, DLCError> {
-    if cets.len() != adaptor_points.len() {
-        return Err(DLCError::InvalidArgument(format!(
-            "CETs length ({}) does not match adaptor points length ({})",
-            cets.len(),
-            adaptor_points.len()
-        )));
+/// Extract the signed transaction from a PSBT whose inputs all carry a final
+/// script witness, e.g. after collecting signatures from external signers.
+pub fn finalize_fund_psbt(psbt_bytes: Vec<u8>) -> Result<Transaction, DLCError> {
+    let psbt = Psbt::deserialize(&psbt_bytes).map_err(|_| DLCError::SerializationError)?;
+    if psbt.inputs.iter().any(|input| {
+        input
+            .final_script_witness
+            .as_ref()
+            .is_none_or(|w| w.is_empty())
+    }) {
+        return Err(DLCError::InvalidTransaction);
     }
 
-    let cets = cets
-        .iter()
-        .map(transaction_to_btc_tx)
-        .collect::<Result<Vec<_>, _>>()?;
+    Ok(btc_tx_to_transaction(&psbt.extract_tx_unchecked_fee_rate()))
+}
 
-    let adaptor_points = adaptor_points
-        .iter()
-        .map(|p| {
-            PublicKey::from_slice(p)
-                .map_err(|_| DLCError::InvalidArgument("Invalid adaptor point".to_string()))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+/// Get the txid of a transaction. Witnesses are excluded, so it is the same
+/// before and after the transaction is signed.
+pub fn get_transaction_txid(tx: Transaction) -> Result<String, DLCError> {
+    Ok(transaction_to_btc_tx(&tx)?.compute_txid().to_string())
+}
 
-    let funding_sk = SecretKey::from_slice(&funding_secret_key)
-        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
-    let funding_script = Script::from_bytes(&funding_script_pubkey);
+/// Get the witness txid of a transaction
+pub fn get_transaction_wtxid(tx: Transaction) -> Result<String, DLCError> {
+    Ok(transaction_to_btc_tx(&tx)?.compute_wtxid().to_string())
+}
 
-    let inputs: Vec<(&bitcoin::Transaction, &PublicKey)> =
-        cets.iter().zip(adaptor_points.iter()).collect();
+/// Check that the structured fields of a transaction agree with its raw bytes,
+/// which are authoritative. The first divergent field is reported.
+pub fn validate_transaction_consistency(tx: Transaction) -> Result<(), DLCError> {
+    let decoded = btc_tx_to_transaction(&transaction_to_btc_tx(&tx)?);
+    let mismatch = |field: String| {
+        Err(DLCError::InvalidArgument(format!(
+            "{} does not match raw bytes",
+            field
+        )))
+    };
 
-    let secp = get_secp_context();
-    let adaptor_sigs = ddk_dlc::create_cet_adaptor_sigs_from_points(
-        secp,
-        &inputs,
-        &funding_sk,
-        funding_script,
-        Amount::from_sat(fund_output_value),
-    )
-    .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+    if tx.version != decoded.version {
+        return mismatch(format!("Version {}", tx.version));
+    }
+    if tx.lock_time != decoded.lock_time {
+        return mismatch(format!("Lock time {}", tx.lock_time));
+    }
+    if tx.inputs.len() != decoded.inputs.len() {
+        return mismatch(format!("Input count {}", tx.inputs.len()));
+    }
+    if tx.outputs.len() != decoded.outputs.len() {
+        return mismatch(format!("Output count {}", tx.outputs.len()));
+    }
+    for (i, (input, raw)) in tx.inputs.iter().zip(&decoded.inputs).enumerate() {
+        if input.txid != raw.txid || input.vout != raw.vout {
+            return mismatch(format!(
+                "Input {} outpoint {}:{}",
+                i, input.txid, input.vout
+            ));
+        }
+    }
+    for (i, (output, raw)) in tx.outputs.iter().zip(&decoded.outputs).enumerate() {
+        if output.value != raw.value {
+            return mismatch(format!("Output {} value {}", i, output.value));
+        }
+    }
 
-    let adaptor_sigs = adaptor_sigs
-        .iter()
-        .map(|sig| AdaptorSignature {
-            signature: sig.as_ref().to_vec(),
-            proof: Vec::new(),
-        })
-        .collect::<Vec<_>>();
+    Ok(())
+}
 
-    Ok(adaptor_sigs)
+/// Check if a transaction output is dust
+pub fn is_dust_output(output: TxOutput) -> bool {
+    output.value < DUST_LIMIT
 }
 
-pub fn verify_cet_adaptor_sig_from_oracle_info(
-    adaptor_sig: AdaptorSignature,
-    cet: Transaction,
-    oracle_infos: Vec<OracleInfo>,
-    pubkey: Vec<u8>,
-    funding_script_pubkey: Vec<u8>,
-    total_collateral: u64,
-    msgs: Vec<Vec<Vec<u8>>>,
-) -> bool {
-    let secp = get_secp_context();
-    let Ok(btc_tx) = transaction_to_btc_tx(&cet) else {
-        return false;
+/// Check if a transaction output is dust at `fee_rate` (sat/vB), using the
+/// script-dependent threshold Bitcoin Core applies with `fee_rate` as the dust
+/// relay fee. Never lower than the flat limit of [`is_dust_output`].
+pub fn is_dust_output_for_feerate(output: TxOutput, fee_rate: u64) -> bool {
+    output.value < dust_threshold(&output.script_pubkey, fee_rate)
+}
+
+/// Get change output and fees for a party, given the counterparty's collateral
+pub fn get_change_output_and_fees(
+    params: PartyParams,
+    other_collateral: u64,
+    fee_rate: u64,
+    extra_fee: u64,
+) -> Result<ChangeOutputAndFees, DLCError> {
+    let rust_params = convert_party_params(&params)?;
+    let total_collateral = params
+        .collateral
+        .checked_add(other_collateral)
+        .map(Amount::from_sat)
+        .ok_or(DLCError::InvalidArgument(
+            "Total collateral overflows".to_string(),
+        ))?;
+
+    let (change_output, fund_fee, cet_fee) = rust_params
+        .get_change_output_and_fees(total_collateral, fee_rate, Amount::from_sat(extra_fee))
+        .map_err(DLCError::from)?;
+
+    let uniffi_output = TxOutput {
+        value: change_output.value.to_sat(),
+        script_pubkey: change_output.script_pubkey.to_bytes(),
     };
-    let Ok(adaptor_sig) = vec_to_ecdsa_adaptor_signature(adaptor_sig.signature) else {
-        return false;
+
+    Ok(ChangeOutputAndFees {
+        change_output: uniffi_output,
+        fund_fee: fund_fee.to_sat(),
+        cet_fee: cet_fee.to_sat(),
+    })
+}
+
+/// Signed change `input_amount` leaves a party once its collateral and fees at
+/// `fee_rate` are paid, negative when short.
+fn party_change(
+    params: &DlcPartyParams,
+    input_amount: u64,
+    total_collateral: Amount,
+    fee_rate: u64,
+) -> Result<i64, DLCError> {
+    // A party without collateral does not fund the contract and pays no fees
+    if params.collateral == Amount::ZERO {
+        return i64::try_from(input_amount)
+            .map_err(|_| DLCError::InvalidArgument("Change amount out of range".to_string()));
+    }
+
+    // Large enough to never be underfunded, so the fees can be derived
+    let funded_params = DlcPartyParams {
+        input_amount: Amount::MAX_MONEY,
+        ..params.clone()
     };
-    let Ok(oracle_infos) = oracle_infos
+    let (_, fund_fee, cet_fee) =
+        funded_params.get_change_output_and_fees(total_collateral, fee_rate, Amount::ZERO)?;
+    let required = params.collateral + fund_fee + cet_fee;
+
+    i64::try_from(input_amount as i128 - required.to_sat() as i128)
+        .map_err(|_| DLCError::InvalidArgument("Change amount out of range".to_string()))
+}
+
+/// Preview the change left by a (possibly partial) set of inputs, negative when
+/// the inputs do not cover collateral plus fees.
+///
+/// Fees are split as in a contract where the counterparty puts up the same
+/// `collateral`, i.e. a total collateral of twice `collateral`; the payout
+/// script is assumed to match the change script.
+pub fn preview_change(
+    inputs: Vec<TxInputInfo>,
+    input_amounts: Vec<u64>,
+    collateral: u64,
+    fee_rate: u64,
+    change_script: Vec<u8>,
+) -> Result<i64, DLCError> {
+    if inputs.len() != input_amounts.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "Inputs length ({}) does not match input amounts length ({})",
+            inputs.len(),
+            input_amounts.len()
+        )));
+    }
+
+    let input_total = input_amounts
         .iter()
-        .map(|info| {
-            let public_key = XOnlyPublicKey::from_slice(&info.public_key)?;
-            let nonces = info
-                .nonces
-                .iter()
-                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
-                .collect::<Result<Vec<_>, _>>()?;
-            Ok(DlcOracleInfo { public_key, nonces })
-        })
-        .collect::<Result<Vec<_>, ddk_dlc::Error>>()
-    else {
-        return false;
-    };
-    let Ok(pubkey) = PublicKey::from_slice(&pubkey) else {
-        return false;
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(DLCError::InvalidArgument(
+            "Input amounts overflow".to_string(),
+        ))?;
+
+    // Fees do not depend on the fund pubkey, any valid key will do
+    let params = DlcPartyParams {
+        fund_pubkey: placeholder_fund_pubkey(),
+        change_script_pubkey: ScriptBuf::from(change_script.clone()),
+        change_serial_id: 0,
+        payout_script_pubkey: ScriptBuf::from(change_script),
+        payout_serial_id: 0,
+        inputs: inputs
+            .iter()
+            .map(tx_input_info_to_rust)
+            .collect::<Result<Vec<_>, _>>()?,
+        dlc_inputs: vec![],
+        input_amount: Amount::from_sat(input_total),
+        collateral: Amount::from_sat(collateral),
     };
-    let funding_script = Script::from_bytes(&funding_script_pubkey);
-    let Ok(msgs) = msgs
+
+    party_change(
+        &params,
+        input_total,
+        Amount::from_sat(collateral.saturating_mul(2)),
+        fee_rate,
+    )
+}
+
+/// Change a party would get at each of `fee_rates`, negative where its inputs
+/// do not cover collateral plus fees.
+pub fn change_across_fee_rates(
+    params: PartyParams,
+    fee_rates: Vec<u64>,
+    remote_collateral: u64,
+) -> Result<Vec<i64>, DLCError> {
+    let total_collateral = params
+        .collateral
+        .checked_add(remote_collateral)
+        .map(Amount::from_sat)
+        .ok_or(DLCError::InvalidArgument(
+            "Total collateral overflows".to_string(),
+        ))?;
+    let rust_params = convert_party_params(&params)?;
+
+    fee_rates
         .into_iter()
-        .map(|msg| {
-            msg.iter()
-                .map(|m| Message::from_digest_slice(m).map_err(|_| DLCError::InvalidArgument))
-                .collect::<Result<Vec<_>, _>>()
+        .map(|fee_rate| {
+            party_change(
+                &rust_params,
+                params.input_amount,
+                total_collateral,
+                fee_rate,
+            )
         })
-        .collect::<Result<Vec<_>, _>>()
-    else {
-        return false;
-    };
-    let Ok(adaptor_point) = ddk_dlc::get_adaptor_point_from_oracle_info(secp, &oracle_infos, &msgs)
-    else {
-        return false;
-    };
-    let Ok(_) = ddk_dlc::verify_cet_adaptor_sig_from_point(
-        secp,
-        &adaptor_sig,
-        &btc_tx,
-        &adaptor_point,
-        &pubkey,
-        funding_script,
-        Amount::from_sat(total_collateral),
-    ) else {
-        return false;
+        .collect()
+}
+
+/// Smallest value worth paying to `script` at `fee_rate`: the dust limit, or the
+/// cost of creating and later spending the output if higher (as Bitcoin Core
+/// computes dust, with `fee_rate` as the relay fee).
+fn dust_threshold(script: &[u8], fee_rate: u64) -> u64 {
+    let output_size = 8 + bitcoin::VarInt(script.len() as u64).size() + script.len();
+    // Witness inputs are discounted: 32 + 4 + 1 + (107 / 4) + 4
+    let input_size = if Script::from_bytes(script).is_witness_program() {
+        67
+    } else {
+        148
     };
+    DUST_LIMIT.max(((output_size + input_size) as u64).saturating_mul(fee_rate))
+}
 
-    true
+/// Smallest total collateral for which every non-zero payout of every outcome
+/// is above the dust threshold of its script at `fee_rate`. Payouts are the
+/// collateral split by each outcome's ratio, rounded down.
+pub fn min_viable_collateral(
+    outcomes_ratios: Vec<PayoutRatio>,
+    fee_rate: u64,
+    local_script: Vec<u8>,
+    remote_script: Vec<u8>,
+) -> Result<u64, DLCError> {
+    if outcomes_ratios.is_empty() {
+        return Err(DLCError::InvalidArgument("No outcomes".to_string()));
+    }
+    let local_threshold = dust_threshold(&local_script, fee_rate);
+    let remote_threshold = dust_threshold(&remote_script, fee_rate);
+
+    let mut min_collateral = 0u64;
+    for ratio in &outcomes_ratios {
+        let total = ratio.offer as u64 + ratio.accept as u64;
+        if total == 0 {
+            return Err(DLCError::InvalidArgument(
+                "Outcome ratio pays neither party".to_string(),
+            ));
+        }
+        // floor(collateral * share / total) >= threshold
+        for (share, threshold) in [
+            (ratio.offer, local_threshold),
+            (ratio.accept, remote_threshold),
+        ] {
+            if share > 0 {
+                let needed = threshold.saturating_mul(total).div_ceil(share as u64);
+                min_collateral = min_collateral.max(needed);
+            }
+        }
+    }
+
+    Ok(min_collateral)
 }
 
-pub fn verify_cet_adaptor_sigs_from_oracle_info(
-    adaptor_sigs: Vec<AdaptorSignature>,
-    cets: Vec<Transaction>,
-    oracle_infos: Vec<OracleInfo>,
-    pubkey: Vec<u8>,
-    funding_script_pubkey: Vec<u8>,
-    total_collateral: u64,
-    msgs: Vec<Vec<Vec<Vec<u8>>>>,
-) -> bool {
-    cets.into_iter()
-        .zip(adaptor_sigs)
-        .enumerate()
-        .all(|(i, (cet, adaptor_sig))| {
-            verify_cet_adaptor_sig_from_oracle_info(
-                adaptor_sig,
-                cet,
-                oracle_infos.clone(),
-                pubkey.clone(),
-                funding_script_pubkey.clone(),
-                total_collateral,
-                msgs[i].clone(),
-            )
+/// Highest fee rate at which neither party's change output nor any non-zero
+/// payout falls below the dust limit. Parties without a change script only need
+/// to stay funded, since their leftover change is dropped.
+pub fn max_safe_fee_rate(
+    local_params: PartyParams,
+    remote_params: PartyParams,
+    outcomes: Vec<Payout>,
+) -> Result<u64, DLCError> {
+    // Payouts do not depend on the fee rate, CET fees come out of the fund output
+    if let Some(payout) = outcomes
+        .iter()
+        .flat_map(|payout| [payout.offer, payout.accept])
+        .find(|value| *value > 0 && *value < DUST_LIMIT)
+    {
+        return Err(DLCError::InvalidArgument(format!(
+            "Payout of {} sats is below the dust limit",
+            payout
+        )));
+    }
+
+    let total_collateral = local_params
+        .collateral
+        .checked_add(remote_params.collateral)
+        .map(Amount::from_sat)
+        .ok_or(DLCError::InvalidArgument(
+            "Total collateral overflows".to_string(),
+        ))?;
+    if total_collateral == Amount::ZERO {
+        return Err(DLCError::InvalidArgument(
+            "Contract has no collateral".to_string(),
+        ));
+    }
+
+    let parties = [
+        (
+            convert_party_params(&local_params)?,
+            local_params.change_script_pubkey.is_some(),
+        ),
+        (
+            convert_party_params(&remote_params)?,
+            remote_params.change_script_pubkey.is_some(),
+        ),
+    ];
+    let is_safe = |fee_rate: u64| {
+        parties.iter().all(|(params, has_change)| {
+            if params.collateral == Amount::ZERO {
+                return true;
+            }
+            match params.get_change_output_and_fees(total_collateral, fee_rate, Amount::ZERO) {
+                Ok((change_output, _, _)) => {
+                    !has_change || change_output.value.to_sat() >= DUST_LIMIT
+                }
+                Err(_) => false,
+            }
         })
+    };
+
+    if !is_safe(0) {
+        return Err(DLCError::InsufficientFunds);
+    }
+
+    // Fees grow with the fee rate and cost at least one sat per vbyte, so no
+    // funded party can stay above dust beyond its input amount
+    let (mut low, mut high) = (0, local_params.input_amount.max(remote_params.input_amount));
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if is_safe(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Ok(low)
 }
 
-/// Create CET adaptor signature from oracle info
-pub fn create_cet_adaptor_signature_from_oracle_info(
-    cet: Transaction,
-    oracle_info: OracleInfo,
-    funding_sk: Vec<u8>,
-    funding_script_pubkey: Vec<u8>,
-    total_collateral: u64,
-    msgs: Vec<Vec<u8>>,
-) -> Result<AdaptorSignature, DLCError> {
-    let btc_tx = transaction_to_btc_tx(&cet)?;
-    let sk = SecretKey::from_slice(&funding_sk)
-        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
-    let funding_script = Script::from_bytes(&funding_script_pubkey);
+/// Check that every input of a party, DLC inputs included, is in a set of known
+/// unspent outpoints formatted as `txid:vout`.
+pub fn verify_inputs_unspent(
+    params: PartyParams,
+    known_utxos: Vec<String>,
+) -> Result<bool, DLCError> {
+    let known_utxos = known_utxos
+        .iter()
+        .map(|utxo| OutPoint::from_str(utxo))
+        .collect::<Result<HashSet<_>, _>>()
+        .map_err(|_| DLCError::InvalidArgument("Invalid outpoint".to_string()))?;
+
+    let rust_params = convert_party_params(&params)?;
+    let mut outpoints = rust_params.inputs.iter().map(|input| input.outpoint).chain(
+        rust_params
+            .dlc_inputs
+            .iter()
+            .map(|input| OutPoint::new(input.fund_tx.compute_txid(), input.fund_vout)),
+    );
 
-    // Convert oracle info
-    let oracle_pk = XOnlyPublicKey::from_slice(&oracle_info.public_key)
-        .map_err(|_| DLCError::InvalidPublicKey)?;
-    let nonces: Result<Vec<_>, _> = oracle_info
-        .nonces
+    Ok(outpoints.all(|outpoint| known_utxos.contains(&outpoint)))
+}
+
+/// List the funding transaction inputs spending `owned_outpoints` ("txid:vout"),
+/// in input order, with `amounts[i]` the value of `owned_outpoints[i]`.
+pub fn funding_signing_plan(
+    fund_tx: Transaction,
+    owned_outpoints: Vec<String>,
+    amounts: Vec<u64>,
+) -> Result<Vec<SigningStep>, DLCError> {
+    if owned_outpoints.len() != amounts.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "Expected one amount per outpoint, got {} outpoints and {} amounts",
+            owned_outpoints.len(),
+            amounts.len()
+        )));
+    }
+
+    let btc_tx = transaction_to_btc_tx(&fund_tx)?;
+    let owned = owned_outpoints
         .iter()
-        .map(|n| XOnlyPublicKey::from_slice(n))
-        .collect();
-    let oracle_nonces = nonces.map_err(|_| DLCError::InvalidPublicKey)?;
+        .map(|outpoint| OutPoint::from_str(outpoint))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| DLCError::InvalidArgument("Invalid outpoint".to_string()))?;
 
-    let dlc_oracle_info = DlcOracleInfo {
-        public_key: oracle_pk,
-        nonces: oracle_nonces,
-    };
+    if let Some(missing) = owned.iter().find(|outpoint| {
+        !btc_tx
+            .input
+            .iter()
+            .any(|input| input.previous_output == **outpoint)
+    }) {
+        return Err(DLCError::InvalidArgument(format!(
+            "Outpoint {} is not spent by the funding transaction",
+            missing
+        )));
+    }
 
-    // Convert messages
-    let messages: Result<Vec<_>, _> = msgs
+    Ok(btc_tx
+        .input
         .iter()
-        .map(|msg| Message::from_digest_slice(msg))
-        .collect();
-    let msg_vec = messages.map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))?;
-    let nested_msgs = vec![msg_vec]; // Wrap in vector for single oracle
+        .enumerate()
+        .filter_map(|(input_index, input)| {
+            let owned_index = owned
+                .iter()
+                .position(|outpoint| *outpoint == input.previous_output)?;
+            // The spent script pubkey is not known here, so a native input can
+            // only be told apart from a wrapped one by its empty script_sig
+            let script_type = match redeem_script(&input.script_sig) {
+                None if input.script_sig.is_empty() => "witness",
+                Some(redeem_script) if redeem_script.is_p2wpkh() => "p2sh-p2wpkh",
+                Some(redeem_script) if redeem_script.is_p2wsh() => "p2sh-p2wsh",
+                _ => "p2sh",
+            };
+            Some(SigningStep {
+                input_index: input_index as u32,
+                script_type: script_type.to_string(),
+                amount: amounts[owned_index],
+            })
+        })
+        .collect())
+}
 
-    let secp = get_secp_context();
-    let adaptor_sig = ddk_dlc::create_cet_adaptor_sig_from_oracle_info(
-        secp,
-        &btc_tx,
-        &[dlc_oracle_info],
-        &sk,
-        funding_script,
-        Amount::from_sat(total_collateral),
-        &nested_msgs,
-    )
-    .map_err(DLCError::from)?;
+/// Script pubkey of a descriptor without wildcards. Extended keys in it must
+/// belong to `network`.
+fn descriptor_script_pubkey(descriptor: &str, network: Network) -> Result<Vec<u8>, DLCError> {
+    let descriptor = Descriptor::<DescriptorPublicKey>::from_str(descriptor)
+        .map_err(|e| DLCError::InvalidArgument(format!("Invalid descriptor: {}", e)))?;
+    if descriptor.has_wildcard() {
+        return Err(DLCError::InvalidArgument(
+            "Descriptor must not contain a wildcard".to_string(),
+        ));
+    }
 
-    Ok(AdaptorSignature {
-        signature: adaptor_sig.as_ref().to_vec(),
-        proof: Vec::new(), // EcdsaAdaptorSignature doesn't expose proof directly
+    let network_kind = bitcoin::NetworkKind::from(network);
+    let on_network = descriptor.for_each_key(|key| match key {
+        DescriptorPublicKey::Single(_) => true,
+        DescriptorPublicKey::XPub(xkey) => xkey.xkey.network == network_kind,
+        DescriptorPublicKey::MultiXPub(xkey) => xkey.xkey.network == network_kind,
+    });
+    if !on_network {
+        return Err(DLCError::InvalidNetwork);
+    }
+
+    let definite = descriptor
+        .at_derivation_index(0)
+        .map_err(|e| DLCError::InvalidArgument(format!("Invalid descriptor: {}", e)))?;
+    Ok(definite.script_pubkey().to_bytes())
+}
+
+/// Build party params whose change and payout scripts come from output
+/// descriptors. Serial ids are left at 0 for the caller to assign.
+pub fn party_params_from_descriptor(
+    fund_pubkey: Vec<u8>,
+    change_descriptor: String,
+    payout_descriptor: String,
+    inputs: Vec<TxInputInfo>,
+    input_amount: u64,
+    collateral: u64,
+    network: String,
+) -> Result<PartyParams, DLCError> {
+    PublicKey::from_slice(&fund_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let network = parse_network(&network)?;
+
+    Ok(PartyParams {
+        fund_pubkey,
+        change_script_pubkey: Some(descriptor_script_pubkey(&change_descriptor, network)?),
+        change_serial_id: 0,
+        payout_script_pubkey: descriptor_script_pubkey(&payout_descriptor, network)?,
+        payout_serial_id: 0,
+        inputs,
+        input_amount,
+        collateral,
+        dlc_inputs: vec![],
     })
 }
 
-pub fn create_cet_adaptor_points_from_oracle_info(
-    oracle_info: Vec<OracleInfo>,
-    msgs: Vec<Vec<Vec<Vec<u8>>>>,
-) -> Result<Vec<Vec<u8>>, DLCError> {
-    let oracle_infos = oracle_info
-        .iter()
-        .map(|info| {
-            let public_key = XOnlyPublicKey::from_slice(&info.public_key)
-                .map_err(|_| DLCError::InvalidPublicKey)?;
-            let nonces = info
-                .nonces
-                .iter()
-                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
-            Ok(DlcOracleInfo { public_key, nonces })
-        })
-        .collect::<Result<Vec<_>, DLCError>>()
-        .map_err(|_| DLCError::InvalidArgument("Invalid oracle info".to_string()))?;
+/// Check that a party's payout and change scripts are standard P2WPKH, P2WSH or
+/// P2TR scripts, which fee estimation assumes.
+pub fn validate_party_params(params: PartyParams) -> Result<(), DLCError> {
+    let scripts = [
+        ("payout_script_pubkey", Some(&params.payout_script_pubkey)),
+        ("change_script_pubkey", params.change_script_pubkey.as_ref()),
+    ];
+
+    for (field, script) in scripts {
+        let Some(script) = script else {
+            continue;
+        };
+        let script = Script::from_bytes(script);
+        if !(script.is_p2wpkh() || script.is_p2wsh() || script.is_p2tr()) {
+            return Err(DLCError::InvalidArgument(format!(
+                "{} must be a P2WPKH, P2WSH or P2TR script",
+                field
+            )));
+        }
+    }
 
-    let secp = get_secp_context();
-    let mut adaptor_points = Vec::new();
+    Ok(())
+}
 
-    // Process each CET's messages separately
-    for cet_msgs in msgs {
-        // Flatten from Vec<Vec<Vec<u8>>> to Vec<Vec<u8>>
-        let cet_msgs: Vec<Vec<Message>> = cet_msgs
+/// Public key standing in for a party's fund pubkey where only fees matter.
+fn placeholder_fund_pubkey() -> PublicKey {
+    let placeholder_sk = SecretKey::from_slice(&[1; 32]).expect("valid secret key");
+    PublicKey::from_secret_key(get_secp_context(), &placeholder_sk)
+}
+
+/// Fund and CET fees ddk-dlc charges a party putting up half of the collateral,
+/// spending inputs with the given witness lengths to a P2WPKH change output and
+/// a payout script of `payout_script_len` bytes. Saturates on overflow.
+fn party_fees(witness_lengths: Vec<usize>, payout_script_len: usize, fee_rate: u64) -> (u64, u64) {
+    let params = DlcPartyParams {
+        fund_pubkey: placeholder_fund_pubkey(),
+        change_script_pubkey: ScriptBuf::from(vec![0; P2WPKH_SCRIPT_PUBKEY_LEN]),
+        change_serial_id: 0,
+        payout_script_pubkey: ScriptBuf::from(vec![0; payout_script_len]),
+        payout_serial_id: 0,
+        inputs: witness_lengths
             .into_iter()
-            .map(|outcome_msgs| {
-                outcome_msgs
-                    .iter()
-                    .map(|m| {
-                        Message::from_digest_slice(m)
-                            .map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))
-                    })
-                    .collect::<Result<Vec<_>, _>>()
+            .map(|max_witness_len| DlcTxInputInfo {
+                outpoint: OutPoint::null(),
+                max_witness_len,
+                redeem_script: ScriptBuf::new(),
+                serial_id: 0,
             })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        // Get adaptor point for this CET
-        let adaptor_point =
-            ddk_dlc::get_adaptor_point_from_oracle_info(secp, &oracle_infos, &cet_msgs)
-                .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+            .collect(),
+        dlc_inputs: vec![],
+        input_amount: Amount::MAX,
+        collateral: Amount::from_sat(1),
+    };
 
-        // Convert the adaptor point to bytes
-        let adaptor_point_bytes = adaptor_point.serialize().to_vec();
-        adaptor_points.push(adaptor_point_bytes);
+    match params.get_change_output_and_fees(Amount::from_sat(2), fee_rate, Amount::ZERO) {
+        Ok((_, fund_fee, cet_fee)) => (fund_fee.to_sat(), cet_fee.to_sat()),
+        Err(_) => (u64::MAX, u64::MAX),
     }
+}
 
-    Ok(adaptor_points)
+/// Fee a single party pays towards a CET spending the P2WSH 2-of-2 funding output
+/// to a payout script of the given length.
+fn party_cet_fee(payout_script_len: usize, fee_rate: u64) -> u64 {
+    party_fees(vec![], payout_script_len, fee_rate).1
 }
 
-pub fn extract_ecdsa_signature_from_oracle_signatures(
-    oracle_signatures: Vec<Vec<u8>>,
-    adaptor_signature: Vec<u8>,
-) -> Result<Vec<u8>, DLCError> {
-    // Convert oracle signatures to Schnorr signatures
-    let oracle_sigs = oracle_signatures
+/// Fee a party pays towards the funding transaction for `input_count` P2WPKH
+/// inputs plus one input per entry of `extra_witness_lengths`, a P2WPKH change
+/// output and half of the base weight, as when both parties put up collateral.
+/// Computed by ddk-dlc, so it follows its fee model.
+pub fn compute_fund_fee(input_count: u32, extra_witness_lengths: Vec<u32>, fee_rate: u64) -> u64 {
+    let witness_lengths = std::iter::repeat_n(ddk_dlc::P2WPKH_WITNESS_SIZE, input_count as usize)
+        .chain(extra_witness_lengths.iter().map(|len| *len as usize))
+        .collect();
+
+    party_fees(witness_lengths, P2WPKH_SCRIPT_PUBKEY_LEN, fee_rate).0
+}
+
+/// Fee a party pays towards a CET or the refund transaction for a payout script
+/// of the given length, when both parties put up collateral.
+pub fn compute_cet_fee(payout_script_len: u32, fee_rate: u64) -> u64 {
+    party_cet_fee(payout_script_len as usize, fee_rate)
+}
+
+/// Reject a transaction whose effective fee rate (sat/vB) exceeds
+/// `max_fee_rate`, as a guard against fee calculation bugs. `input_amounts` are
+/// the values spent by each input, in order. Inputs that are not signed yet are
+/// counted with a P2WPKH witness.
+pub fn check_fee_sanity(
+    tx: Transaction,
+    input_amounts: Vec<u64>,
+    max_fee_rate: u64,
+) -> Result<(), DLCError> {
+    let btc_tx = transaction_to_btc_tx(&tx)?;
+    if input_amounts.len() != btc_tx.input.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "Expected {} input amounts, got {}",
+            btc_tx.input.len(),
+            input_amounts.len()
+        )));
+    }
+
+    let input_total = input_amounts
         .iter()
-        .map(|sig| vec_to_schnorr_signature(sig.as_slice()))
-        .collect::<Result<Vec<_>, _>>()?;
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(DLCError::InvalidArgument(
+            "Input amounts overflow".to_string(),
+        ))?;
+    let output_total = btc_tx
+        .output
+        .iter()
+        .try_fold(Amount::ZERO, |total, output| {
+            total.checked_add(output.value)
+        })
+        .ok_or(DLCError::InvalidTransaction)?
+        .to_sat();
+    let fee = input_total
+        .checked_sub(output_total)
+        .ok_or(DLCError::InvalidArgument(
+            "Outputs exceed inputs".to_string(),
+        ))?;
+
+    let unsigned_inputs = btc_tx.input.iter().filter(|input| input.witness.is_empty());
+    let mut weight =
+        btc_tx.weight().to_wu() as usize + unsigned_inputs.count() * ddk_dlc::P2WPKH_WITNESS_SIZE;
+    if btc_tx.input.iter().all(|input| input.witness.is_empty()) {
+        // Segwit marker and flag
+        weight += 2;
+    }
+    let vsize = weight.div_ceil(4) as u64;
 
-    // Extract the secret key from oracle signatures
-    let adaptor_secret = signatures_to_secret(&[oracle_sigs])?;
+    if fee > max_fee_rate.saturating_mul(vsize) {
+        return Err(DLCError::InvalidArgument(format!(
+            "Fee of {} sats for {} vbytes exceeds {} sat/vB",
+            fee, vsize, max_fee_rate
+        )));
+    }
 
-    // Convert adaptor signature to EcdsaAdaptorSignature
-    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_signature)?;
+    Ok(())
+}
 
-    // Decrypt the adaptor signature to get the final ECDSA signature
-    let ecdsa_sig = adaptor_sig
-        .decrypt(&adaptor_secret)
-        .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+/// Estimate the total CET fee when both payout outputs are P2TR and the funding
+/// spend is the P2WSH 2-of-2. P2TR script pubkeys are 12 bytes longer than
+/// P2WPKH ones, so this is slightly higher than the P2WPKH figure.
+pub fn estimate_cet_fee_taproot(fee_rate: u64) -> u64 {
+    party_cet_fee(P2TR_SCRIPT_PUBKEY_LEN, fee_rate).saturating_mul(2)
+}
 
-    // Return the DER-encoded signature
-    Ok(ecdsa_sig.serialize_der().to_vec())
+/// Get total input virtual size for fee calculation
+pub fn get_total_input_vsize(inputs: Vec<TxInputInfo>) -> u32 {
+    inputs
+        .iter()
+        .map(|input| {
+            // Outpoint (36) + sequence (4), scriptSig with its length prefix,
+            // and the witness at a quarter of its weight. `script_sig` holds the
+            // redeem script, pushed behind OP_0 as ddk-dlc builds the scriptSig.
+            let script_sig_len = match input.script_sig.len() {
+                0 => 0,
+                len => len + 2,
+            };
+            let base = 40 + bitcoin::VarInt(script_sig_len as u64).size() + script_sig_len;
+            base + (input.max_witness_length as usize).div_ceil(4)
+        })
+        .sum::<usize>() as u32
 }
 
-/// Get all the inputs that go into creating a CET adaptor signature.
-///
-/// This debug function is intentionally always available (not feature-gated)
-/// to enable debugging signature mismatches in production environments where
-/// rebuilding with debug features may not be feasible.
-///
-/// Use this to compare values with external signers (e.g., Fordefi) when
-/// debugging adaptor signature verification failures.
-///
-/// Returns:
-/// - `sighash`: The 32-byte BIP143 sighash message that gets signed
-/// - `adaptor_point`: The 33-byte compressed adaptor public key
-/// - `input_index`: Always 0 for CETs
-/// - `script_pubkey`: The funding script used for sighash calculation
-/// - `value`: The fund output value used for sighash calculation
-/// - `cet_txid`: The CET transaction ID
-/// - `cet_raw`: Raw serialized CET bytes
-pub fn get_cet_adaptor_signature_inputs(
-    cet: Transaction,
-    oracle_info: Vec<OracleInfo>,
-    funding_script_pubkey: Vec<u8>,
-    fund_output_value: u64,
-    msgs: Vec<Vec<Vec<u8>>>,
-) -> Result<CetAdaptorSignatureDebugInfo, DLCError> {
-    let btc_tx = transaction_to_btc_tx(&cet)?;
-    let funding_script = Script::from_bytes(&funding_script_pubkey);
+/// Estimate the network fee of the whole funding transaction: both parties'
+/// inputs, their change outputs and the P2WSH funding output, at `fee_rate`
+/// sat/vB. Inputs are sized as in [`get_total_input_vsize`], which rounds each
+/// witness up, so this can exceed the exact fee by a vbyte per input.
+pub fn estimate_fund_transaction_fee(
+    local_params: PartyParams,
+    remote_params: PartyParams,
+    fee_rate: u64,
+) -> Result<u64, DLCError> {
+    let parties = [&local_params, &remote_params];
+    let inputs = parties
+        .iter()
+        .flat_map(|params| params.inputs.iter().cloned())
+        .collect::<Vec<_>>();
+    let dlc_inputs = parties
+        .iter()
+        .flat_map(|params| params.dlc_inputs.iter())
+        .collect::<Vec<_>>();
+    let change_scripts = parties
+        .iter()
+        .filter_map(|params| params.change_script_pubkey.as_ref())
+        .collect::<Vec<_>>();
 
-    // Convert oracle info
-    let oracle_infos: Vec<DlcOracleInfo> = oracle_info
+    // DLC inputs spend a P2WSH 2-of-2 and have an empty script sig
+    let dlc_inputs_vsize = dlc_inputs
         .iter()
-        .map(|info| {
-            let public_key = XOnlyPublicKey::from_slice(&info.public_key)
-                .map_err(|_| DLCError::InvalidPublicKey)?;
-            let nonces = info
-                .nonces
-                .iter()
-                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
-            Ok(DlcOracleInfo { public_key, nonces })
-        })
-        .collect::<Result<Vec<_>, DLCError>>()?;
+        .map(|input| 41 + (input.max_witness_len as usize).div_ceil(4))
+        .sum::<usize>();
+    let change_outputs_size = change_scripts
+        .iter()
+        .map(|script| 8 + bitcoin::VarInt(script.len() as u64).size() + script.len())
+        .sum::<usize>();
+    // Value, script length and the 34 byte P2WSH script pubkey
+    let fund_output_size = 8 + 1 + 34;
+    // Version and lock time, input and output counts, and a vbyte for the
+    // segwit marker and flag
+    let input_count = inputs.len() + dlc_inputs.len();
+    let output_count = 1 + change_scripts.len();
+    let overhead = 8
+        + bitcoin::VarInt(input_count as u64).size()
+        + bitcoin::VarInt(output_count as u64).size()
+        + 1;
+
+    let vsize = overhead
+        + get_total_input_vsize(inputs) as usize
+        + dlc_inputs_vsize
+        + change_outputs_size
+        + fund_output_size;
+    (vsize as u64)
+        .checked_mul(fee_rate)
+        .ok_or(DLCError::InvalidArgument("Fee overflows".to_string()))
+}
 
-    // Convert messages
-    let cet_msgs: Vec<Vec<Message>> = msgs
-        .into_iter()
-        .map(|outcome_msgs| {
-            outcome_msgs
-                .iter()
-                .map(|m| {
-                    Message::from_digest_slice(m)
-                        .map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))
-                })
-                .collect::<Result<Vec<_>, _>>()
+/// Verify a fund transaction signature
+pub fn verify_fund_tx_signature(
+    fund_tx: Transaction,
+    signature: Vec<u8>,
+    pubkey: Vec<u8>,
+    txid: String,
+    vout: u32,
+    input_amount: u64,
+) -> Result<bool, DLCError> {
+    let btc_tx = transaction_to_btc_tx(&fund_tx)?;
+    let pk = PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let input_txid = Txid::from_str(&txid)
+        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
+
+    // Find the input index
+    let input_index = btc_tx
+        .input
+        .iter()
+        .position(|input| {
+            input.previous_output.txid == input_txid && input.previous_output.vout == vout
         })
-        .collect::<Result<Vec<_>, _>>()?;
+        .ok_or(DLCError::InvalidArgument(format!(
+            "Input index not found in {input_txid}"
+        )))?;
 
-    let secp = get_secp_context();
+    // Create a simple P2WPKH script for verification
+    let wpkh = WPubkeyHash::hash(&pk.serialize());
+    let script = bitcoin::ScriptBuf::new_p2wpkh(&wpkh);
 
-    // Get the adaptor point
-    let adaptor_point = ddk_dlc::get_adaptor_point_from_oracle_info(secp, &oracle_infos, &cet_msgs)
-        .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+    // Parse signature
+    let sig = EcdsaSignature::from_der(&signature).map_err(|_| DLCError::InvalidSignature)?;
 
-    // Get the sighash - this is the actual message being signed
-    let sig_hash = ddk_dlc::util::get_sig_hash_msg(
+    match ddk_dlc::verify_tx_input_sig(
+        get_verification_context(),
+        &sig,
         &btc_tx,
-        0, // input_index is always 0 for CETs
-        funding_script,
-        Amount::from_sat(fund_output_value),
-    )
-    .map_err(DLCError::from)?;
+        input_index,
+        &script,
+        Amount::from_sat(input_amount),
+        &pk,
+    ) {
+        Ok(()) => Ok(true),
+        Err(_) => Ok(false),
+    }
+}
 
-    Ok(CetAdaptorSignatureDebugInfo {
-        sighash: sig_hash.as_ref().to_vec(),
-        adaptor_point: adaptor_point.serialize().to_vec(),
-        input_index: 0,
-        script_pubkey: funding_script_pubkey,
-        value: fund_output_value,
-        cet_txid: btc_tx.compute_txid().to_string(),
-        cet_raw: cet.raw_bytes,
-    })
+/// Verify a fund transaction signature for a P2WSH input (e.g. a multisig),
+/// computing the sighash over the provided witness script
+pub fn verify_multisig_fund_input_signature(
+    fund_tx: Transaction,
+    signature: Vec<u8>,
+    pubkey: Vec<u8>,
+    witness_script: Vec<u8>,
+    txid: String,
+    vout: u32,
+    input_amount: u64,
+) -> Result<bool, DLCError> {
+    let btc_tx = transaction_to_btc_tx(&fund_tx)?;
+    let pk = PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let input_txid = Txid::from_str(&txid)
+        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
+
+    // Find the input index
+    let input_index = btc_tx
+        .input
+        .iter()
+        .position(|input| {
+            input.previous_output.txid == input_txid && input.previous_output.vout == vout
+        })
+        .ok_or(DLCError::InvalidArgument(format!(
+            "Input index not found in {input_txid}"
+        )))?;
+
+    // Parse signature
+    let sig = EcdsaSignature::from_der(&signature).map_err(|_| DLCError::InvalidSignature)?;
+
+    match ddk_dlc::verify_tx_input_sig(
+        get_verification_context(),
+        &sig,
+        &btc_tx,
+        input_index,
+        Script::from_bytes(&witness_script),
+        Amount::from_sat(input_amount),
+        &pk,
+    ) {
+        Ok(()) => Ok(true),
+        Err(_) => Ok(false),
+    }
 }
 
-/// Get the sighash for a CET - the actual 32-byte message that gets signed.
-///
-/// This debug function is intentionally always available (not feature-gated)
-/// to enable debugging sighash mismatches in production environments where
-/// rebuilding with debug features may not be feasible.
-///
-/// Use this to compare sighash values with external signers (e.g., Fordefi)
-/// when debugging signature verification failures.
-pub fn get_cet_sighash(
-    cet: Transaction,
+/// Check that a refund transaction pays each party its expected collateral,
+/// less at most `fee_tolerance`. A party with no output is treated as paid 0.
+pub fn verify_refund_payouts(
+    refund: Transaction,
+    local_script: Vec<u8>,
+    expected_local: u64,
+    remote_script: Vec<u8>,
+    expected_remote: u64,
+    fee_tolerance: u64,
+) -> Result<bool, DLCError> {
+    if local_script == remote_script {
+        return Err(DLCError::InvalidArgument(
+            "Local and remote refund scripts must differ".to_string(),
+        ));
+    }
+
+    let within_tolerance = |paid: u64, expected: u64| {
+        paid <= expected && paid >= expected.saturating_sub(fee_tolerance)
+    };
+
+    let local_paid = paid_to_script(&refund.outputs, &local_script)?;
+    let remote_paid = paid_to_script(&refund.outputs, &remote_script)?;
+
+    Ok(within_tolerance(local_paid, expected_local)
+        && within_tolerance(remote_paid, expected_remote))
+}
+
+/// Total value paid to `script` across `outputs`, failing on overflow.
+fn paid_to_script(outputs: &[TxOutput], script: &[u8]) -> Result<u64, DLCError> {
+    outputs
+        .iter()
+        .filter(|output| output.script_pubkey == script)
+        .try_fold(0u64, |total, output| total.checked_add(output.value))
+        .ok_or(DLCError::InvalidTransaction)
+}
+
+/// Verify a counterparty's signature on the 2-of-2 funding input of a refund
+/// transaction before countersigning
+pub fn verify_refund_transaction_signature(
+    refund_tx: Transaction,
+    signature: Vec<u8>,
+    pubkey: Vec<u8>,
     funding_script_pubkey: Vec<u8>,
     fund_output_value: u64,
-) -> Result<Vec<u8>, DLCError> {
-    let btc_tx = transaction_to_btc_tx(&cet)?;
-    let funding_script = Script::from_bytes(&funding_script_pubkey);
+) -> Result<bool, DLCError> {
+    let btc_tx = transaction_to_btc_tx(&refund_tx)?;
+    if btc_tx.input.len() != 1 {
+        return Err(DLCError::InvalidArgument(format!(
+            "Refund transaction must have exactly one input, found {}",
+            btc_tx.input.len()
+        )));
+    }
+    let pk = PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
 
-    let sig_hash = ddk_dlc::util::get_sig_hash_msg(
+    // Parse signature
+    let sig = EcdsaSignature::from_der(&signature).map_err(|_| DLCError::InvalidSignature)?;
+
+    match ddk_dlc::verify_tx_input_sig(
+        get_verification_context(),
+        &sig,
         &btc_tx,
-        0, // input_index is always 0 for CETs
-        funding_script,
+        0,
+        Script::from_bytes(&funding_script_pubkey),
         Amount::from_sat(fund_output_value),
+        &pk,
+    ) {
+        Ok(()) => Ok(true),
+        Err(_) => Ok(false),
+    }
+}
+
+// ============================================================================
+// SIGNING AND SIGNATURE FUNCTIONS (using rust-dlc library)
+// ============================================================================
+
+/// Get raw signature for a fund transaction input
+pub fn get_raw_funding_transaction_input_signature(
+    funding_transaction: Transaction,
+    privkey: Vec<u8>,
+    prev_tx_id: String,
+    prev_tx_vout: u32,
+    value: u64,
+) -> Result<Vec<u8>, DLCError> {
+    let privkey = Zeroizing::new(privkey);
+    let btc_tx = transaction_to_btc_tx(&funding_transaction)?;
+    let sk = SecretKeyGuard::from_slice(&privkey)
+        .map_err(|_| DLCError::InvalidArgument("Invalid private key".to_string()))?;
+    let prev_txid = Txid::from_str(&prev_tx_id)
+        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
+
+    // Find the input index
+    let input_index = btc_tx
+        .input
+        .iter()
+        .position(|input| {
+            input.previous_output.txid == prev_txid && input.previous_output.vout == prev_tx_vout
+        })
+        .ok_or(DLCError::InvalidArgument(format!(
+            "Input index not found in {prev_txid}"
+        )))?;
+
+    let secp = get_secp_context();
+    // Create P2WPKH script for signing
+    let pk = PublicKey::from_secret_key(secp, &sk);
+    let wpkh = WPubkeyHash::hash(&pk.serialize());
+    let script = bitcoin::ScriptBuf::new_p2wpkh(&wpkh);
+
+    let sig = ddk_dlc::util::get_sig_for_tx_input(
+        secp,
+        &btc_tx,
+        input_index,
+        &script,
+        Amount::from_sat(value),
+        EcdsaSighashType::All,
+        &sk,
     )
     .map_err(DLCError::from)?;
 
-    Ok(sig_hash.as_ref().to_vec())
+    Ok(sig)
 }
 
-pub fn convert_mnemonic_to_seed(
-    mnemonic: String,
-    passphrase: Option<String>,
-) -> Result<Vec<u8>, DLCError> {
-    let seed_mnemonic = Mnemonic::parse_in_normalized(Language::English, &mnemonic)
-        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidMnemonic))?;
-    let passphrase = passphrase.unwrap_or("".to_string());
-    let seed = seed_mnemonic.to_seed(&passphrase);
-    Ok(seed.to_vec())
-}
+/// Sign a funding transaction input
+pub fn sign_fund_transaction_input(
+    fund_transaction: Transaction,
+    privkey: Vec<u8>,
+    prev_tx_id: String,
+    prev_tx_vout: u32,
+    value: u64,
+) -> Result<Transaction, DLCError> {
+    let privkey = Zeroizing::new(privkey);
+    let mut btc_tx = transaction_to_btc_tx(&fund_transaction)?;
+    let sk = SecretKeyGuard::from_slice(&privkey)
+        .map_err(|_| DLCError::InvalidArgument("Invalid private key".to_string()))?;
+    let prev_txid = Txid::from_str(&prev_tx_id)
+        .map_err(|_| DLCError::InvalidArgument("Invalid transaction id".to_string()))?;
+
+    // Find the input index
+    let input_index = btc_tx
+        .input
+        .iter()
+        .position(|input| {
+            input.previous_output.txid == prev_txid && input.previous_output.vout == prev_tx_vout
+        })
+        .ok_or(DLCError::InvalidArgument(format!(
+            "Input index not found in {prev_txid}"
+        )))?;
+
+    ddk_dlc::util::sign_p2wpkh_input(
+        get_secp_context(),
+        &sk,
+        &mut btc_tx,
+        input_index,
+        EcdsaSighashType::All,
+        Amount::from_sat(value),
+    )
+    .map_err(DLCError::from)?;
+
+    Ok(btc_tx_to_transaction(&btc_tx))
+}
+
+/// Build a [`DlcInputInfo`] for splicing from the hex of a broadcast fund
+/// transaction, checking that `fund_vout` exists and holds `fund_amount`.
+pub fn dlc_input_info_from_fund_hex(
+    fund_tx_hex: String,
+    fund_vout: u32,
+    local_fund_pubkey: Vec<u8>,
+    remote_fund_pubkey: Vec<u8>,
+    fund_amount: u64,
+    max_witness_len: u32,
+    input_serial_id: u64,
+    contract_id: Vec<u8>,
+) -> Result<DlcInputInfo, DLCError> {
+    let fund_tx = transaction_from_hex(fund_tx_hex)?;
+    let output = fund_tx
+        .outputs
+        .get(fund_vout as usize)
+        .ok_or(DLCError::InvalidArgument(format!(
+            "Fund transaction has no output {}",
+            fund_vout
+        )))?;
+    if output.value != fund_amount {
+        return Err(DLCError::InvalidArgument(format!(
+            "Fund output {} holds {}, expected {}",
+            fund_vout, output.value, fund_amount
+        )));
+    }
+
+    let dlc_input = DlcInputInfo {
+        fund_tx,
+        fund_vout,
+        local_fund_pubkey,
+        remote_fund_pubkey,
+        fund_amount,
+        max_witness_len,
+        input_serial_id,
+        contract_id,
+    };
+    // Validate the pubkeys and contract id the same way signing will
+    dlc_input_info_to_rust(&dlc_input)?;
+
+    Ok(dlc_input)
+}
+
+/// Index of the input of `tx` spending the funding output of `dlc_input`.
+fn dlc_input_index(tx: &BtcTransaction, dlc_input: &RustDlcInputInfo) -> Result<usize, DLCError> {
+    let outpoint = OutPoint::new(dlc_input.fund_tx.compute_txid(), dlc_input.fund_vout);
+    tx.input
+        .iter()
+        .position(|input| input.previous_output == outpoint)
+        .ok_or(DLCError::InvalidArgument(format!(
+            "Transaction does not spend the DLC input {}",
+            outpoint
+        )))
+}
+
+/// Sighash of the input spending the DLC input, for signing the 2-of-2 with an
+/// external signer. The signature must be DER encoded with `SIGHASH_ALL` appended
+/// before being combined.
+pub fn dlc_input_sighash(txn: Transaction, dlc_input: DlcInputInfo) -> Result<Vec<u8>, DLCError> {
+    let btc_tx = transaction_to_btc_tx(&txn)?;
+    let dlc_input = dlc_input_info_to_rust(&dlc_input)?;
+    let input_index = dlc_input_index(&btc_tx, &dlc_input)?;
+
+    let funding_script = ddk_dlc::dlc_input::create_dlc_input_funding_script(&dlc_input);
+    let sighash = ddk_dlc::util::get_sig_hash_msg(
+        &btc_tx,
+        input_index,
+        &funding_script,
+        dlc_input.fund_amount,
+    )?;
+
+    Ok(sighash.as_ref().to_vec())
+}
+
+/// Sign the input spending the DLC input with the local funding key alone, for
+/// sending to the counterparty. The signature is DER encoded with
+/// `SIGHASH_ALL` appended, as [`sign_multi_sig_input`] expects.
+pub fn create_dlc_input_signature(
+    txn: Transaction,
+    dlc_input: DlcInputInfo,
+    local_privkey: Vec<u8>,
+) -> Result<Vec<u8>, DLCError> {
+    let local_privkey = Zeroizing::new(local_privkey);
+    let secp = get_secp_context();
+    let btc_tx = transaction_to_btc_tx(&txn)?;
+    let sk = SecretKeyGuard::from_slice(&local_privkey)
+        .map_err(|_| DLCError::InvalidArgument("Invalid private key".to_string()))?;
+    let dlc_input = dlc_input_info_to_rust(&dlc_input)?;
+    let input_index = dlc_input_index(&btc_tx, &dlc_input)?;
+
+    ddk_dlc::dlc_input::create_dlc_funding_input_signature(
+        secp,
+        &btc_tx,
+        input_index,
+        &dlc_input,
+        &sk,
+    )
+    .map_err(|_| DLCError::InvalidSignature)
+}
+
+pub fn sign_multi_sig_input(
+    txn: Transaction,
+    dlc_input: DlcInputInfo,
+    local_privkey: Vec<u8>,
+    remote_signature: Vec<u8>,
+) -> Result<Transaction, DLCError> {
+    let signature = create_dlc_input_signature(txn.clone(), dlc_input.clone(), local_privkey)?;
+    let btc_tx = transaction_to_btc_tx(&txn)?;
+
+    let local_pk = PublicKey::from_slice(&dlc_input.local_fund_pubkey)
+        .map_err(|_| DLCError::InvalidPublicKey)?;
+    let remote_pk = PublicKey::from_slice(&dlc_input.remote_fund_pubkey)
+        .map_err(|_| DLCError::InvalidPublicKey)?;
+
+    let dlc_input = dlc_input_info_to_rust(&dlc_input)?;
+
+    // rust-dlc orders the signatures by comparing the two pubkeys
+    let witness = ddk_dlc::dlc_input::combine_dlc_input_signatures(
+        &dlc_input,
+        &signature,
+        &remote_signature,
+        &local_pk,
+        &remote_pk,
+    );
+
+    let input_index = dlc_input_index(&btc_tx, &dlc_input)?;
+    let mut fund_psbt = Psbt::from_unsigned_tx(btc_tx).map_err(|_| DLCError::InvalidTransaction)?;
+    fund_psbt.inputs[input_index].final_script_witness = Some(witness);
+
+    Ok(btc_tx_to_transaction(
+        &fund_psbt.extract_tx_unchecked_fee_rate(),
+    ))
+}
+
+/// Verify a counterparty's signature for the input spending the DLC input, as made
+/// by [`create_dlc_input_signature`], before combining it. The 2-of-2 script
+/// orders the pubkeys itself, so either party's view of `dlc_input` works.
+pub fn verify_dlc_input_signature(
+    txn: Transaction,
+    dlc_input: DlcInputInfo,
+    signature: Vec<u8>,
+    signer_pubkey: Vec<u8>,
+) -> Result<bool, DLCError> {
+    if signer_pubkey != dlc_input.local_fund_pubkey && signer_pubkey != dlc_input.remote_fund_pubkey
+    {
+        return Err(DLCError::InvalidArgument(
+            "Signer pubkey is not a fund pubkey of the DLC input".to_string(),
+        ));
+    }
+    let btc_tx = transaction_to_btc_tx(&txn)?;
+    let dlc_input = dlc_input_info_to_rust(&dlc_input)?;
+    let input_index = dlc_input_index(&btc_tx, &dlc_input)?;
+    let pk = PublicKey::from_slice(&signer_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+
+    // DER signature followed by the sighash type, which must be SIGHASH_ALL
+    let (sighash_type, der) = signature.split_last().ok_or(DLCError::InvalidSignature)?;
+    let sig = EcdsaSignature::from_der(der).map_err(|_| DLCError::InvalidSignature)?;
+    if *sighash_type != EcdsaSighashType::All as u8 {
+        return Ok(false);
+    }
+
+    let funding_script = ddk_dlc::dlc_input::create_dlc_input_funding_script(&dlc_input);
+    Ok(ddk_dlc::verify_tx_input_sig(
+        get_verification_context(),
+        &sig,
+        &btc_tx,
+        input_index,
+        &funding_script,
+        dlc_input.fund_amount,
+        &pk,
+    )
+    .is_ok())
+}
+
+pub fn sign_cet(
+    cet: Transaction,
+    adaptor_signature: Vec<u8>,
+    oracle_signatures: Vec<Vec<u8>>,
+    funding_secret_key: Vec<u8>,
+    other_pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+) -> Result<Transaction, DLCError> {
+    let funding_secret_key = Zeroizing::new(funding_secret_key);
+    let mut btc_tx = transaction_to_btc_tx(&cet)?;
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_signature)?;
+    let oracle_sigs = oracle_signatures
+        .iter()
+        .map(|sig| vec_to_schnorr_signature(sig.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let funding_sk = SecretKeyGuard::from_slice(&funding_secret_key)
+        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
+    let other_pk = PublicKey::from_slice(&other_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let funding_pubkey =
+        PublicKey::from_slice(&funding_script_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let dlc_redeem_script = ddk_dlc::make_funding_redeemscript(&funding_pubkey, &other_pk);
+    let secp = get_secp_context();
+
+    ddk_dlc::sign_cet(
+        secp,
+        &mut btc_tx,
+        &adaptor_sig,
+        &[oracle_sigs],
+        &funding_sk,
+        &other_pk,
+        dlc_redeem_script.as_script(),
+        Amount::from_sat(fund_output_value),
+    )
+    .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    Ok(btc_tx_to_transaction(&btc_tx))
+}
+
+/// Like [`sign_cet`], for signers whose funding key never leaves an external
+/// device (e.g. MPC). `external_signature` is the DER signature (optionally with
+/// its sighash byte) of the key `funding_script_pubkey` over the CET. Both it and
+/// the decrypted counterparty signature are verified before the witness is built.
+pub fn sign_cet_with_external_signature(
+    cet: Transaction,
+    adaptor_signature: Vec<u8>,
+    oracle_signatures: Vec<Vec<u8>>,
+    external_signature: Vec<u8>,
+    other_pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+) -> Result<Transaction, DLCError> {
+    let mut btc_tx = transaction_to_btc_tx(&cet)?;
+    if btc_tx.input.is_empty() {
+        return Err(DLCError::InvalidTransaction);
+    }
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_signature)?;
+    let oracle_sigs = oracle_signatures
+        .iter()
+        .map(|sig| vec_to_schnorr_signature(sig.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let own_sig = match EcdsaSignature::from_der(&external_signature) {
+        Ok(sig) => sig,
+        Err(_) => match external_signature.split_last() {
+            Some((&sighash, der)) if sighash == EcdsaSighashType::All as u8 => {
+                EcdsaSignature::from_der(der).map_err(|_| DLCError::InvalidSignature)?
+            }
+            _ => return Err(DLCError::InvalidSignature),
+        },
+    };
+    let other_pk = PublicKey::from_slice(&other_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let own_pk =
+        PublicKey::from_slice(&funding_script_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let dlc_redeem_script = ddk_dlc::make_funding_redeemscript(&own_pk, &other_pk);
+    let fund_output_value = Amount::from_sat(fund_output_value);
+
+    let other_sig = adaptor_sig
+        .decrypt(&signatures_to_secret(&[oracle_sigs])?)
+        .map_err(|_| DLCError::InvalidSignature)?;
+    for (sig, pk) in [(&own_sig, &own_pk), (&other_sig, &other_pk)] {
+        ddk_dlc::verify_tx_input_sig(
+            get_verification_context(),
+            sig,
+            &btc_tx,
+            0,
+            &dlc_redeem_script,
+            fund_output_value,
+            pk,
+        )
+        .map_err(|_| DLCError::InvalidSignature)?;
+    }
+
+    let own_sig = ddk_dlc::util::finalize_sig(&own_sig, EcdsaSighashType::All);
+    let other_sig = ddk_dlc::util::finalize_sig(&other_sig, EcdsaSighashType::All);
+    let (first, second) = if own_pk < other_pk {
+        (own_sig, other_sig)
+    } else {
+        (other_sig, own_sig)
+    };
+    btc_tx.input[0].witness =
+        Witness::from_slice(&[Vec::new(), first, second, dlc_redeem_script.to_bytes()]);
+
+    Ok(btc_tx_to_transaction(&btc_tx))
+}
+
+/// Same as `sign_cet`, but first verifies the counterparty's adaptor signature
+/// against the oracle messages of this CET (`msgs[i]` for `oracle_infos[i]`),
+/// returning `InvalidSignature` instead of producing an invalid witness.
+pub fn sign_cet_checked(
+    cet: Transaction,
+    adaptor_signature: Vec<u8>,
+    oracle_signatures: Vec<Vec<u8>>,
+    funding_secret_key: Vec<u8>,
+    other_pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+    oracle_infos: Vec<OracleInfo>,
+    msgs: Vec<Vec<Vec<u8>>>,
+) -> Result<Transaction, DLCError> {
+    let other_pk = PublicKey::from_slice(&other_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let funding_pubkey =
+        PublicKey::from_slice(&funding_script_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let dlc_redeem_script = ddk_dlc::make_funding_redeemscript(&funding_pubkey, &other_pk);
+
+    let adaptor_sig = AdaptorSignature {
+        signature: adaptor_signature.clone(),
+        proof: Vec::new(),
+    };
+    if !verify_cet_adaptor_sig_from_oracle_info(
+        adaptor_sig,
+        cet.clone(),
+        oracle_infos,
+        other_pubkey.clone(),
+        dlc_redeem_script.to_bytes(),
+        fund_output_value,
+        msgs,
+    ) {
+        return Err(DLCError::InvalidSignature);
+    }
+
+    sign_cet(
+        cet,
+        adaptor_signature,
+        oracle_signatures,
+        funding_secret_key,
+        other_pubkey,
+        funding_script_pubkey,
+        fund_output_value,
+    )
+}
+
+/// Sign the funding input of a refund transaction, returning the DER encoded
+/// signature without a sighash byte. `funding_script_pubkey` is the 2-of-2
+/// funding witness script, which must be made of our key and `other_pubkey`.
+pub fn sign_refund_transaction(
+    refund_tx: Transaction,
+    funding_secret_key: Vec<u8>,
+    other_pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+) -> Result<Vec<u8>, DLCError> {
+    let funding_secret_key = Zeroizing::new(funding_secret_key);
+    let btc_tx = transaction_to_btc_tx(&refund_tx)?;
+    let funding_sk = SecretKeyGuard::from_slice(&funding_secret_key)
+        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
+    let other_pk = PublicKey::from_slice(&other_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let secp = get_secp_context();
+
+    let own_pk = PublicKey::from_secret_key(secp, &funding_sk);
+    let funding_script = ScriptBuf::from_bytes(funding_script_pubkey);
+    if ddk_dlc::make_funding_redeemscript(&own_pk, &other_pk) != funding_script {
+        return Err(DLCError::InvalidArgument(
+            "Funding script is not the 2-of-2 of the given keys".to_string(),
+        ));
+    }
+
+    let sig = ddk_dlc::util::get_raw_sig_for_tx_input(
+        secp,
+        &btc_tx,
+        0,
+        &funding_script,
+        Amount::from_sat(fund_output_value),
+        &funding_sk,
+    )?;
+
+    Ok(sig.serialize_der().to_vec())
+}
+
+/// Assemble the 2-of-2 witness of a refund transaction from both parties' DER
+/// signatures, ordering them by public key as the funding script does.
+pub fn finalize_refund_transaction(
+    refund_tx: Transaction,
+    local_signature: Vec<u8>,
+    remote_signature: Vec<u8>,
+    local_fund_pubkey: Vec<u8>,
+    remote_fund_pubkey: Vec<u8>,
+) -> Result<Transaction, DLCError> {
+    let mut btc_tx = transaction_to_btc_tx(&refund_tx)?;
+    let local_sig =
+        EcdsaSignature::from_der(&local_signature).map_err(|_| DLCError::InvalidSignature)?;
+    let remote_sig =
+        EcdsaSignature::from_der(&remote_signature).map_err(|_| DLCError::InvalidSignature)?;
+    let local_pk =
+        PublicKey::from_slice(&local_fund_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let remote_pk =
+        PublicKey::from_slice(&remote_fund_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+
+    let input = btc_tx
+        .input
+        .first_mut()
+        .ok_or(DLCError::InvalidTransaction)?;
+    let local_sig = ddk_dlc::util::finalize_sig(&local_sig, EcdsaSighashType::All);
+    let remote_sig = ddk_dlc::util::finalize_sig(&remote_sig, EcdsaSighashType::All);
+    let (first_sig, second_sig) = if local_pk < remote_pk {
+        (local_sig, remote_sig)
+    } else {
+        (remote_sig, local_sig)
+    };
+    input.witness = Witness::from_slice(&[
+        Vec::new(),
+        first_sig,
+        second_sig,
+        ddk_dlc::make_funding_redeemscript(&local_pk, &remote_pk).to_bytes(),
+    ]);
+
+    Ok(btc_tx_to_transaction(&btc_tx))
+}
+
+fn vec_to_schnorr_signature(signature: &[u8]) -> Result<SchnorrSignature, DLCError> {
+    let sig = SchnorrSignature::from_slice(signature).map_err(|_| DLCError::InvalidSignature)?;
+    Ok(sig)
+}
+
+/// Check that bytes have the length of a serialized ECDSA adaptor signature
+pub fn validate_adaptor_sig_bytes(bytes: Vec<u8>) -> Result<(), DLCError> {
+    if bytes.len() != ADAPTOR_SIGNATURE_SIZE {
+        return Err(DLCError::InvalidArgument(format!(
+            "adaptor signature must be {} bytes, got {}",
+            ADAPTOR_SIGNATURE_SIZE,
+            bytes.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Canonical 162-byte encoding of an adaptor signature, DLEQ proof included.
+pub fn adaptor_signature_serialize(sig: AdaptorSignature) -> Vec<u8> {
+    sig.signature
+}
+
+/// Parse the canonical 162-byte encoding of an adaptor signature.
+pub fn adaptor_signature_parse(bytes: Vec<u8>) -> Result<AdaptorSignature, DLCError> {
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(bytes)?;
+
+    Ok(AdaptorSignature {
+        signature: adaptor_sig.as_ref().to_vec(),
+        proof: Vec::new(),
+    })
+}
+
+/// Verify an adaptor signature over a 32-byte message digest, including its
+/// DLEQ proof binding it to `adaptor_point`, without building a CET. Malformed
+/// inputs are errors; a well-formed but invalid signature returns `false`.
+pub fn verify_adaptor_proof(
+    adaptor_sig: Vec<u8>,
+    adaptor_point: Vec<u8>,
+    message: Vec<u8>,
+    pubkey: Vec<u8>,
+) -> Result<bool, DLCError> {
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_sig)?;
+    let adaptor_point =
+        PublicKey::from_slice(&adaptor_point).map_err(|_| DLCError::InvalidPublicKey)?;
+    let message = Message::from_digest_slice(&message)
+        .map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))?;
+    let pubkey = PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+
+    Ok(adaptor_sig
+        .verify(
+            get_verification_context(),
+            &message,
+            &pubkey,
+            &adaptor_point,
+        )
+        .is_ok())
+}
+
+fn vec_to_ecdsa_adaptor_signature(signature: Vec<u8>) -> Result<EcdsaAdaptorSignature, DLCError> {
+    validate_adaptor_sig_bytes(signature.clone())?;
+    EcdsaAdaptorSignature::from_slice(&signature).map_err(|_| DLCError::InvalidSignature)
+}
+
+fn signatures_to_secret(signatures: &[Vec<SchnorrSignature>]) -> Result<SecretKey, DLCError> {
+    let s_values = signatures
+        .iter()
+        .flatten()
+        .map(|x| match secp_utils::schnorrsig_decompose(x) {
+            Ok(v) => Ok(v.1),
+            Err(err) => Err(DLCError::Secp256k1Error(err.to_string())),
+        })
+        .collect::<Result<Vec<&[u8]>, DLCError>>()?;
+
+    if s_values.is_empty() {
+        return Err(DLCError::InvalidArgument(
+            "No signatures provided".to_string(),
+        ));
+    }
+
+    let secret = SecretKey::from_slice(s_values[0])
+        .map_err(|_| DLCError::InvalidArgument("Invalid signature".to_string()))?;
+
+    s_values.iter().skip(1).try_fold(secret, |accum, s| {
+        let sec = SecretKey::from_slice(s)
+            .map_err(|_| DLCError::InvalidArgument("Invalid signature".to_string()))?;
+        accum
+            .add_tweak(&Scalar::from(sec))
+            .map_err(|e| DLCError::Secp256k1Error(e.to_string()))
+    })
+}
+
+pub fn create_cet_adaptor_sigs_from_oracle_info(
+    cets: Vec<Transaction>,
+    oracle_info: Vec<OracleInfo>,
+    funding_secret_key: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+    msgs: Vec<Vec<Vec<Vec<u8>>>>,
+) -> Result<Vec<AdaptorSignature>, DLCError> {
+    let funding_secret_key = Zeroizing::new(funding_secret_key);
+    let cets = cets
+        .iter()
+        .map(transaction_to_btc_tx)
+        .collect::<Result<Vec<_>, _>>()?;
+    let oracle_infos = oracle_info
+        .iter()
+        .map(|info| {
+            let public_key = XOnlyPublicKey::from_slice(&info.public_key)
+                .map_err(|_| DLCError::InvalidPublicKey)?;
+            let nonces = info
+                .nonces
+                .iter()
+                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
+            Ok(DlcOracleInfo { public_key, nonces })
+        })
+        .collect::<Result<Vec<_>, DLCError>>()
+        .map_err(|_| DLCError::InvalidArgument("Invalid oracle info".to_string()))?;
+
+    let funding_sk = SecretKeyGuard::from_slice(&funding_secret_key)
+        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
+    let funding_script = Script::from_bytes(&funding_script_pubkey);
+    let msgs: Vec<Vec<Vec<Message>>> = msgs
+        .iter()
+        .map(|cet_msgs| {
+            // For each CET
+            cet_msgs
+                .iter()
+                .map(|outcome_msgs| {
+                    // For each outcome
+                    outcome_msgs
+                        .iter()
+                        .map(|msg_bytes| {
+                            // For each message (Vec<u8>)
+                            Message::from_digest_slice(msg_bytes).map_err(|_| {
+                                DLCError::InvalidArgument("Invalid message".to_string())
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let secp = get_secp_context();
+    #[cfg(not(feature = "parallel"))]
+    let adaptor_sigs = ddk_dlc::create_cet_adaptor_sigs_from_oracle_info(
+        secp,
+        &cets,
+        &oracle_infos,
+        &funding_sk,
+        funding_script,
+        Amount::from_sat(fund_output_value),
+        &msgs,
+    );
+    // Same checks and ordering as the serial path, with each CET signed on the
+    // rayon pool. The shared context is `Sync`, so threads can borrow it.
+    #[cfg(feature = "parallel")]
+    let adaptor_sigs = {
+        use rayon::prelude::*;
+        if msgs.len() != cets.len() {
+            Err(ddk_dlc::Error::InvalidArgument(format!(
+                "Msgs length is not equal to cets length. msgs={} cets={}",
+                msgs.len(),
+                cets.len()
+            )))
+        } else {
+            cets.par_iter()
+                .zip(msgs.par_iter())
+                .map(|(cet, cet_msgs)| {
+                    ddk_dlc::create_cet_adaptor_sig_from_oracle_info(
+                        secp,
+                        cet,
+                        &oracle_infos,
+                        &funding_sk,
+                        funding_script,
+                        Amount::from_sat(fund_output_value),
+                        cet_msgs,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+        }
+    };
+    let adaptor_sigs = adaptor_sigs.map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    let adaptor_sigs = adaptor_sigs
+        .iter()
+        .map(|sig| AdaptorSignature {
+            signature: sig.as_ref().to_vec(),
+            proof: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(adaptor_sigs)
+}
+
+/// Adaptor signatures for an enumeration contract attested by a single oracle
+/// with a single nonce, where `outcomes[i]` is the outcome CET `i` pays out on.
+/// Outcomes are hashed with [`hash_outcome`].
+pub fn create_enumeration_adaptor_sigs(
+    cets: Vec<Transaction>,
+    oracle_info: OracleInfo,
+    funding_secret_key: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+    outcomes: Vec<String>,
+) -> Result<Vec<AdaptorSignature>, DLCError> {
+    if outcomes.len() != cets.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "Outcomes length ({}) does not match CETs length ({})",
+            outcomes.len(),
+            cets.len()
+        )));
+    }
+    if oracle_info.nonces.len() != 1 {
+        return Err(DLCError::InvalidArgument(format!(
+            "Enumeration oracle must announce exactly one nonce, found {}",
+            oracle_info.nonces.len()
+        )));
+    }
+
+    let msgs = outcomes
+        .into_iter()
+        .map(|outcome| vec![vec![hash_outcome(outcome)]])
+        .collect();
+    create_cet_adaptor_sigs_from_oracle_info(
+        cets,
+        vec![oracle_info],
+        funding_secret_key,
+        funding_script_pubkey,
+        fund_output_value,
+        msgs,
+    )
+}
+
+/// Create adaptor signatures from pre-computed adaptor points.
+pub fn create_cet_adaptor_sigs_from_points(
+    cets: Vec<Transaction>,
+    adaptor_points: Vec<Vec<u8>>,
+    funding_secret_key: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+) -> Result<Vec<AdaptorSignature>, DLCError> {
+    let funding_secret_key = Zeroizing::new(funding_secret_key);
+    if cets.len() != adaptor_points.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "CETs length ({}) does not match adaptor points length ({})",
+            cets.len(),
+            adaptor_points.len()
+        )));
+    }
+
+    let cets = cets
+        .iter()
+        .map(transaction_to_btc_tx)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let adaptor_points = adaptor_points
+        .iter()
+        .map(|p| {
+            PublicKey::from_slice(p)
+                .map_err(|_| DLCError::InvalidArgument("Invalid adaptor point".to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let funding_sk = SecretKeyGuard::from_slice(&funding_secret_key)
+        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
+    let funding_script = Script::from_bytes(&funding_script_pubkey);
+
+    let inputs: Vec<(&bitcoin::Transaction, &PublicKey)> =
+        cets.iter().zip(adaptor_points.iter()).collect();
+
+    let secp = get_secp_context();
+    let adaptor_sigs = ddk_dlc::create_cet_adaptor_sigs_from_points(
+        secp,
+        &inputs,
+        &funding_sk,
+        funding_script,
+        Amount::from_sat(fund_output_value),
+    )
+    .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    let adaptor_sigs = adaptor_sigs
+        .iter()
+        .map(|sig| AdaptorSignature {
+            signature: sig.as_ref().to_vec(),
+            proof: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(adaptor_sigs)
+}
+
+pub fn verify_cet_adaptor_sig_from_oracle_info(
+    adaptor_sig: AdaptorSignature,
+    cet: Transaction,
+    oracle_infos: Vec<OracleInfo>,
+    pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    total_collateral: u64,
+    msgs: Vec<Vec<Vec<u8>>>,
+) -> bool {
+    let secp = get_secp_context();
+    let Ok(btc_tx) = transaction_to_btc_tx(&cet) else {
+        return false;
+    };
+    let Ok(adaptor_sig) = vec_to_ecdsa_adaptor_signature(adaptor_sig.signature) else {
+        return false;
+    };
+    let Ok(oracle_infos) = oracle_infos
+        .iter()
+        .map(|info| {
+            let public_key = XOnlyPublicKey::from_slice(&info.public_key)?;
+            let nonces = info
+                .nonces
+                .iter()
+                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(DlcOracleInfo { public_key, nonces })
+        })
+        .collect::<Result<Vec<_>, ddk_dlc::Error>>()
+    else {
+        return false;
+    };
+    let Ok(pubkey) = PublicKey::from_slice(&pubkey) else {
+        return false;
+    };
+    let funding_script = Script::from_bytes(&funding_script_pubkey);
+    let Ok(msgs) = msgs
+        .into_iter()
+        .map(|msg| {
+            msg.iter()
+                .map(|m| Message::from_digest_slice(m).map_err(|_| DLCError::InvalidArgument))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return false;
+    };
+    let Ok(adaptor_point) = ddk_dlc::get_adaptor_point_from_oracle_info(secp, &oracle_infos, &msgs)
+    else {
+        return false;
+    };
+    let Ok(_) = ddk_dlc::verify_cet_adaptor_sig_from_point(
+        secp,
+        &adaptor_sig,
+        &btc_tx,
+        &adaptor_point,
+        &pubkey,
+        funding_script,
+        Amount::from_sat(total_collateral),
+    ) else {
+        return false;
+    };
+
+    true
+}
+
+/// Verify adaptor signatures against precomputed adaptor points, one result per
+/// CET. This skips recomputing points from oracle infos when re-verifying a
+/// stored contract. Malformed signatures, CETs or points verify as `false`.
+pub fn verify_cet_adaptor_sigs_with_points(
+    adaptor_sigs: Vec<Vec<u8>>,
+    cets: Vec<Transaction>,
+    adaptor_points: Vec<Vec<u8>>,
+    pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    total_collateral: u64,
+) -> Result<Vec<bool>, DLCError> {
+    if adaptor_sigs.len() != cets.len() || adaptor_points.len() != cets.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "Expected one adaptor signature and point per CET, got {} CETs, {} signatures and {} points",
+            cets.len(),
+            adaptor_sigs.len(),
+            adaptor_points.len()
+        )));
+    }
+    let pubkey = PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let funding_script = Script::from_bytes(&funding_script_pubkey);
+    let secp = get_secp_context();
+
+    Ok(adaptor_sigs
+        .into_iter()
+        .zip(cets.iter())
+        .zip(adaptor_points.iter())
+        .map(|((adaptor_sig, cet), adaptor_point)| {
+            let (Ok(adaptor_sig), Ok(btc_tx), Ok(adaptor_point)) = (
+                vec_to_ecdsa_adaptor_signature(adaptor_sig),
+                transaction_to_btc_tx(cet),
+                PublicKey::from_slice(adaptor_point),
+            ) else {
+                return false;
+            };
+            ddk_dlc::verify_cet_adaptor_sig_from_point(
+                secp,
+                &adaptor_sig,
+                &btc_tx,
+                &adaptor_point,
+                &pubkey,
+                funding_script,
+                Amount::from_sat(total_collateral),
+            )
+            .is_ok()
+        })
+        .collect())
+}
+
+/// Verify an adaptor signature against the exact CET bytes received.
+///
+/// The adaptor signature commits to the CET's BIP143 sighash, so verification
+/// inherently binds it to this CET: any change to the transaction (outputs,
+/// lock time, sequence, ...) invalidates the signature even when the adaptor
+/// point is unchanged.
+pub fn verify_adaptor_sig_binds_cet(
+    adaptor_sig: Vec<u8>,
+    cet: Transaction,
+    adaptor_point: Vec<u8>,
+    pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    total_collateral: u64,
+) -> bool {
+    let Ok(btc_tx) = transaction_to_btc_tx(&cet) else {
+        return false;
+    };
+    let Ok(adaptor_sig) = vec_to_ecdsa_adaptor_signature(adaptor_sig) else {
+        return false;
+    };
+    let Ok(adaptor_point) = PublicKey::from_slice(&adaptor_point) else {
+        return false;
+    };
+    let Ok(pubkey) = PublicKey::from_slice(&pubkey) else {
+        return false;
+    };
+
+    ddk_dlc::verify_cet_adaptor_sig_from_point(
+        get_secp_context(),
+        &adaptor_sig,
+        &btc_tx,
+        &adaptor_point,
+        &pubkey,
+        Script::from_bytes(&funding_script_pubkey),
+        Amount::from_sat(total_collateral),
+    )
+    .is_ok()
+}
+
+pub fn verify_cet_adaptor_sigs_from_oracle_info(
+    adaptor_sigs: Vec<AdaptorSignature>,
+    cets: Vec<Transaction>,
+    oracle_infos: Vec<OracleInfo>,
+    pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    total_collateral: u64,
+    msgs: Vec<Vec<Vec<Vec<u8>>>>,
+) -> bool {
+    verify_cet_adaptor_sigs_detailed(
+        adaptor_sigs,
+        cets,
+        oracle_infos,
+        pubkey,
+        funding_script_pubkey,
+        total_collateral,
+        msgs,
+    )
+    .is_ok_and(|results| results.into_iter().all(|valid| valid))
+}
+
+/// Verify adaptor signatures from oracle info, one result per CET, so a
+/// single bad signature among many can be located.
+pub fn verify_cet_adaptor_sigs_detailed(
+    adaptor_sigs: Vec<AdaptorSignature>,
+    cets: Vec<Transaction>,
+    oracle_infos: Vec<OracleInfo>,
+    pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    total_collateral: u64,
+    msgs: Vec<Vec<Vec<Vec<u8>>>>,
+) -> Result<Vec<bool>, DLCError> {
+    if adaptor_sigs.len() != cets.len() || msgs.len() != cets.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "Expected one adaptor signature and message set per CET, got {} CETs, {} signatures and {} message sets",
+            cets.len(),
+            adaptor_sigs.len(),
+            msgs.len()
+        )));
+    }
+
+    Ok(cets
+        .into_iter()
+        .zip(adaptor_sigs)
+        .zip(msgs)
+        .map(|((cet, adaptor_sig), cet_msgs)| {
+            verify_cet_adaptor_sig_from_oracle_info(
+                adaptor_sig,
+                cet,
+                oracle_infos.clone(),
+                pubkey.clone(),
+                funding_script_pubkey.clone(),
+                total_collateral,
+                cet_msgs,
+            )
+        })
+        .collect())
+}
+
+/// Create CET adaptor signature from oracle info
+pub fn create_cet_adaptor_signature_from_oracle_info(
+    cet: Transaction,
+    oracle_info: OracleInfo,
+    funding_sk: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    total_collateral: u64,
+    msgs: Vec<Vec<u8>>,
+) -> Result<AdaptorSignature, DLCError> {
+    let funding_sk = Zeroizing::new(funding_sk);
+    let btc_tx = transaction_to_btc_tx(&cet)?;
+    let sk = SecretKeyGuard::from_slice(&funding_sk)
+        .map_err(|_| DLCError::InvalidArgument("Invalid funding secret key".to_string()))?;
+    let funding_script = Script::from_bytes(&funding_script_pubkey);
+
+    // Convert oracle info
+    let oracle_pk = XOnlyPublicKey::from_slice(&oracle_info.public_key)
+        .map_err(|_| DLCError::InvalidPublicKey)?;
+    let nonces: Result<Vec<_>, _> = oracle_info
+        .nonces
+        .iter()
+        .map(|n| XOnlyPublicKey::from_slice(n))
+        .collect();
+    let oracle_nonces = nonces.map_err(|_| DLCError::InvalidPublicKey)?;
+
+    let dlc_oracle_info = DlcOracleInfo {
+        public_key: oracle_pk,
+        nonces: oracle_nonces,
+    };
+
+    // Convert messages
+    let messages: Result<Vec<_>, _> = msgs
+        .iter()
+        .map(|msg| Message::from_digest_slice(msg))
+        .collect();
+    let msg_vec = messages.map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))?;
+    let nested_msgs = vec![msg_vec]; // Wrap in vector for single oracle
+
+    let secp = get_secp_context();
+    let adaptor_sig = ddk_dlc::create_cet_adaptor_sig_from_oracle_info(
+        secp,
+        &btc_tx,
+        &[dlc_oracle_info],
+        &sk,
+        funding_script,
+        Amount::from_sat(total_collateral),
+        &nested_msgs,
+    )
+    .map_err(DLCError::from)?;
+
+    Ok(AdaptorSignature {
+        signature: adaptor_sig.as_ref().to_vec(),
+        proof: Vec::new(), // EcdsaAdaptorSignature doesn't expose proof directly
+    })
+}
+
+/// Create a CET adaptor signature attested by several oracles, where
+/// `msgs[i]` holds one message per nonce of `oracle_infos[i]`.
+pub fn create_cet_adaptor_signature_multi_oracle(
+    cet: Transaction,
+    oracle_infos: Vec<OracleInfo>,
+    funding_sk: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    total_collateral: u64,
+    msgs: Vec<Vec<Vec<u8>>>,
+) -> Result<AdaptorSignature, DLCError> {
+    if msgs.len() != oracle_infos.len() {
+        return Err(DLCError::InvalidArgument(format!(
+            "Expected {} message groups, one per oracle, got {}",
+            oracle_infos.len(),
+            msgs.len()
+        )));
+    }
+
+    let mut adaptor_sigs = create_cet_adaptor_sigs_from_oracle_info(
+        vec![cet],
+        oracle_infos,
+        funding_sk,
+        funding_script_pubkey,
+        total_collateral,
+        vec![msgs],
+    )?;
+
+    Ok(adaptor_sigs.remove(0))
+}
+
+/// Build the `[cet][oracle][nonce]` message matrix expected by the adaptor
+/// signature functions, with one empty slot per oracle nonce to be filled with
+/// the 32-byte outcome message hash.
+pub fn message_template(
+    oracle_infos: Vec<OracleInfo>,
+    cet_count: u32,
+) -> Result<Vec<Vec<Vec<Vec<u8>>>>, DLCError> {
+    if oracle_infos.is_empty() {
+        return Err(DLCError::InvalidArgument(
+            "No oracle info provided".to_string(),
+        ));
+    }
+    if oracle_infos.iter().any(|info| info.nonces.is_empty()) {
+        return Err(DLCError::InvalidArgument(
+            "Oracle info has no nonces".to_string(),
+        ));
+    }
+
+    let cet_template = oracle_infos
+        .iter()
+        .map(|info| vec![Vec::new(); info.nonces.len()])
+        .collect::<Vec<_>>();
+
+    Ok(vec![cet_template; cet_count as usize])
+}
+
+/// Compute one compressed adaptor point per CET, in the order of `msgs`.
+pub fn create_cet_adaptor_points_from_oracle_info(
+    oracle_info: Vec<OracleInfo>,
+    msgs: Vec<Vec<Vec<Vec<u8>>>>,
+) -> Result<Vec<Vec<u8>>, DLCError> {
+    let oracle_infos = oracle_info
+        .iter()
+        .map(|info| {
+            let public_key = XOnlyPublicKey::from_slice(&info.public_key)
+                .map_err(|_| DLCError::InvalidPublicKey)?;
+            let nonces = info
+                .nonces
+                .iter()
+                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
+            Ok(DlcOracleInfo { public_key, nonces })
+        })
+        .collect::<Result<Vec<_>, DLCError>>()
+        .map_err(|_| DLCError::InvalidArgument("Invalid oracle info".to_string()))?;
+
+    let secp = get_secp_context();
+    let mut adaptor_points = Vec::new();
+
+    // Process each CET's messages separately
+    for cet_msgs in msgs {
+        // Flatten from Vec<Vec<Vec<u8>>> to Vec<Vec<u8>>
+        let cet_msgs: Vec<Vec<Message>> = cet_msgs
+            .into_iter()
+            .map(|outcome_msgs| {
+                outcome_msgs
+                    .iter()
+                    .map(|m| {
+                        Message::from_digest_slice(m)
+                            .map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Get adaptor point for this CET
+        let adaptor_point =
+            ddk_dlc::get_adaptor_point_from_oracle_info(secp, &oracle_infos, &cet_msgs)
+                .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+        // Convert the adaptor point to bytes
+        let adaptor_point_bytes = adaptor_point.serialize().to_vec();
+        adaptor_points.push(adaptor_point_bytes);
+    }
+
+    Ok(adaptor_points)
+}
+
+/// sha256 of an outcome's UTF-8 bytes, the message an oracle signs for it.
+pub fn hash_outcome(outcome: String) -> Vec<u8> {
+    sha256::Hash::hash(outcome.as_bytes())
+        .to_byte_array()
+        .to_vec()
+}
+
+/// Hash each outcome with [`hash_outcome`], keeping their order.
+pub fn hash_outcomes(outcomes: Vec<String>) -> Vec<Vec<u8>> {
+    outcomes.into_iter().map(hash_outcome).collect()
+}
+
+/// Compute the point `s·G` that the oracle's signature over `message` with
+/// `nonce` will commit to, returned compressed (33 bytes).
+pub fn outcome_signature_point(
+    oracle_pubkey: Vec<u8>,
+    nonce: Vec<u8>,
+    message: Vec<u8>,
+) -> Result<Vec<u8>, DLCError> {
+    let oracle_pk =
+        XOnlyPublicKey::from_slice(&oracle_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let nonce = XOnlyPublicKey::from_slice(&nonce)
+        .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
+    let message = Message::from_digest_slice(&message)
+        .map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))?;
+
+    let point =
+        secp_utils::schnorrsig_compute_sig_point(get_secp_context(), &oracle_pk, &nonce, &message)
+            .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    Ok(point.serialize().to_vec())
+}
+
+/// Check that an oracle attestation uses the announced `nonce` as its R value
+/// and is a valid Schnorr signature over `message` by the oracle.
+pub fn verify_oracle_attestation(
+    oracle_pubkey: Vec<u8>,
+    nonce: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+) -> Result<bool, DLCError> {
+    let oracle_pk =
+        XOnlyPublicKey::from_slice(&oracle_pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let nonce = XOnlyPublicKey::from_slice(&nonce)
+        .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
+    let message = Message::from_digest_slice(&message)
+        .map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))?;
+    let signature =
+        SchnorrSignature::from_slice(&signature).map_err(|_| DLCError::InvalidSignature)?;
+
+    let Ok((sig_nonce, _)) = secp_utils::schnorrsig_decompose(&signature) else {
+        return Ok(false);
+    };
+    if sig_nonce != nonce {
+        return Ok(false);
+    }
+
+    Ok(get_verification_context()
+        .verify_schnorr(&signature, &message, &oracle_pk)
+        .is_ok())
+}
+
+/// Split a 64-byte Schnorr signature into its nonce (R, x-only) and s value.
+pub fn decompose_schnorr_signature(signature: Vec<u8>) -> Result<SchnorrParts, DLCError> {
+    let signature =
+        SchnorrSignature::from_slice(&signature).map_err(|_| DLCError::InvalidSignature)?;
+    let (nonce, s_value) =
+        secp_utils::schnorrsig_decompose(&signature).map_err(|_| DLCError::InvalidSignature)?;
+
+    Ok(SchnorrParts {
+        nonce: nonce.serialize().to_vec(),
+        s_value: s_value.to_vec(),
+    })
+}
+
+/// Sum the s values of oracle attestations into the 32-byte secret that
+/// decrypts adaptor signatures encrypted to their combined signature points.
+pub fn aggregate_oracle_s_values(signatures: Vec<Vec<u8>>) -> Result<Vec<u8>, DLCError> {
+    let signatures = signatures
+        .iter()
+        .map(|signature| {
+            SchnorrSignature::from_slice(signature).map_err(|_| DLCError::InvalidSignature)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(signatures_to_secret(&[signatures])?.secret_bytes().to_vec())
+}
+
+/// List, per CET, the oracle nonce points its messages commit to.
+///
+/// `msgs` follows the `[cet][oracle][nonce]` layout; a CET covering a digit
+/// prefix only depends on the first nonces of each oracle.
+pub fn cet_nonce_dependencies(
+    msgs: Vec<Vec<Vec<Vec<u8>>>>,
+    oracle_infos: Vec<OracleInfo>,
+) -> Result<Vec<Vec<Vec<u8>>>, DLCError> {
+    msgs.iter()
+        .enumerate()
+        .map(|(cet_index, cet_msgs)| {
+            if cet_msgs.len() > oracle_infos.len() {
+                return Err(DLCError::InvalidArgument(format!(
+                    "CET {cet_index} has messages for {} oracles but only {} were provided",
+                    cet_msgs.len(),
+                    oracle_infos.len()
+                )));
+            }
+
+            cet_msgs
+                .iter()
+                .zip(oracle_infos.iter())
+                .map(|(oracle_msgs, oracle_info)| {
+                    oracle_info
+                        .nonces
+                        .get(..oracle_msgs.len())
+                        .ok_or(DLCError::InvalidArgument(format!(
+                            "CET {cet_index} has {} messages but the oracle only has {} nonces",
+                            oracle_msgs.len(),
+                            oracle_info.nonces.len()
+                        )))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|nonces| nonces.concat())
+        })
+        .collect()
+}
+
+/// Check that a contract requiring `required` oracles was given exactly that many
+/// oracle infos, as a short list would silently yield a different adaptor point.
+pub fn validate_oracle_count(oracle_infos: Vec<OracleInfo>, required: u32) -> Result<(), DLCError> {
+    if required == 0 {
+        return Err(DLCError::InvalidArgument(
+            "Contract must require at least one oracle".to_string(),
+        ));
+    }
+    if oracle_infos.len() != required as usize {
+        return Err(DLCError::InvalidArgument(format!(
+            "Contract requires {} oracle infos, got {}",
+            required,
+            oracle_infos.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check that every CET adaptor point is a valid, distinct 33-byte compressed
+/// public key. Duplicate or invalid points indicate a contract construction bug.
+pub fn verify_adaptor_points_wellformed(adaptor_points: Vec<Vec<u8>>) -> Result<bool, DLCError> {
+    if adaptor_points.is_empty() {
+        return Err(DLCError::InvalidArgument(
+            "No adaptor points provided".to_string(),
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for point in &adaptor_points {
+        if point.len() != 33 || PublicKey::from_slice(point).is_err() || !seen.insert(point) {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+pub fn extract_ecdsa_signature_from_oracle_signatures(
+    oracle_signatures: Vec<Vec<u8>>,
+    adaptor_signature: Vec<u8>,
+) -> Result<Vec<u8>, DLCError> {
+    // Convert oracle signatures to Schnorr signatures
+    let oracle_sigs = oracle_signatures
+        .iter()
+        .map(|sig| vec_to_schnorr_signature(sig.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Extract the secret key from oracle signatures
+    let adaptor_secret = signatures_to_secret(&[oracle_sigs])?;
+
+    // Convert adaptor signature to EcdsaAdaptorSignature
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_signature)?;
+
+    // Decrypt the adaptor signature to get the final ECDSA signature
+    let ecdsa_sig = adaptor_sig
+        .decrypt(&adaptor_secret)
+        .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    // Return the DER-encoded signature
+    Ok(ecdsa_sig.serialize_der().to_vec())
+}
+
+/// Decrypt a CET adaptor signature with the sum of the oracle signatures'
+/// s-values, returning the DER encoded ECDSA signature. Oracle signatures are
+/// given in the order of the nonces the adaptor point was built from.
+pub fn decrypt_cet_adaptor_signature(
+    adaptor_signature: Vec<u8>,
+    oracle_signatures: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, DLCError> {
+    let oracle_sigs = oracle_signatures
+        .iter()
+        .map(|sig| vec_to_schnorr_signature(sig.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let adaptor_secret = signatures_to_secret(&[oracle_sigs])?;
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_signature)?;
+
+    let ecdsa_sig = adaptor_sig
+        .decrypt(&adaptor_secret)
+        .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    Ok(ecdsa_sig.serialize_der().to_vec())
+}
+
+/// Recover the adaptor secret (the oracle attestation scalar) from an adaptor
+/// signature and the decrypted signature published on-chain. The final
+/// signature may be 64-byte compact or DER encoded.
+pub fn recover_adaptor_secret(
+    adaptor_signature: Vec<u8>,
+    final_signature: Vec<u8>,
+    adaptor_point: Vec<u8>,
+) -> Result<Vec<u8>, DLCError> {
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_signature)?;
+    let signature = if final_signature.len() == 64 {
+        EcdsaSignature::from_compact(&final_signature)
+    } else {
+        EcdsaSignature::from_der(&final_signature)
+    }
+    .map_err(|_| DLCError::InvalidSignature)?;
+    let point = PublicKey::from_slice(&adaptor_point).map_err(|_| DLCError::InvalidPublicKey)?;
+
+    let secret = adaptor_sig
+        .recover(get_secp_context(), &signature, &point)
+        .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    Ok(secret.secret_bytes().to_vec())
+}
+
+/// Decrypt a CET adaptor signature with the secret recovered from the oracle
+/// signatures and verify the result against the CET before returning it.
+/// Returns `InvalidSignature` if the decrypted signature does not verify.
+pub fn decrypt_and_verify_cet_signature(
+    adaptor_sig: Vec<u8>,
+    oracle_signatures: Vec<Vec<u8>>,
+    cet: Transaction,
+    pubkey: Vec<u8>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+) -> Result<Vec<u8>, DLCError> {
+    let btc_tx = transaction_to_btc_tx(&cet)?;
+    let pk = PublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let oracle_sigs = oracle_signatures
+        .iter()
+        .map(|sig| vec_to_schnorr_signature(sig.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let adaptor_secret = signatures_to_secret(&[oracle_sigs])?;
+    let adaptor_sig = vec_to_ecdsa_adaptor_signature(adaptor_sig)?;
+
+    let ecdsa_sig = adaptor_sig
+        .decrypt(&adaptor_secret)
+        .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    ddk_dlc::verify_tx_input_sig(
+        get_verification_context(),
+        &ecdsa_sig,
+        &btc_tx,
+        0,
+        Script::from_bytes(&funding_script_pubkey),
+        Amount::from_sat(fund_output_value),
+        &pk,
+    )
+    .map_err(|_| DLCError::InvalidSignature)?;
+
+    Ok(ecdsa_sig.serialize_der().to_vec())
+}
+
+/// Get all the inputs that go into creating a CET adaptor signature.
+///
+/// This debug function is intentionally always available (not feature-gated)
+/// to enable debugging signature mismatches in production environments where
+/// rebuilding with debug features may not be feasible.
+///
+/// Use this to compare values with external signers (e.g., Fordefi) when
+/// debugging adaptor signature verification failures.
+///
+/// Returns:
+/// - `sighash`: The 32-byte BIP143 sighash message that gets signed
+/// - `adaptor_point`: The 33-byte compressed adaptor public key
+/// - `input_index`: Always 0 for CETs
+/// - `script_pubkey`: The funding script used for sighash calculation
+/// - `value`: The fund output value used for sighash calculation
+/// - `cet_txid`: The CET transaction ID
+/// - `cet_raw`: Raw serialized CET bytes
+pub fn get_cet_adaptor_signature_inputs(
+    cet: Transaction,
+    oracle_info: Vec<OracleInfo>,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+    msgs: Vec<Vec<Vec<u8>>>,
+) -> Result<CetAdaptorSignatureDebugInfo, DLCError> {
+    let btc_tx = transaction_to_btc_tx(&cet)?;
+    let funding_script = Script::from_bytes(&funding_script_pubkey);
+
+    // Convert oracle info
+    let oracle_infos: Vec<DlcOracleInfo> = oracle_info
+        .iter()
+        .map(|info| {
+            let public_key = XOnlyPublicKey::from_slice(&info.public_key)
+                .map_err(|_| DLCError::InvalidPublicKey)?;
+            let nonces = info
+                .nonces
+                .iter()
+                .map(|nonce| XOnlyPublicKey::from_slice(nonce))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| DLCError::InvalidArgument("Invalid nonce pubkey".to_string()))?;
+            Ok(DlcOracleInfo { public_key, nonces })
+        })
+        .collect::<Result<Vec<_>, DLCError>>()?;
+
+    // Convert messages
+    let cet_msgs: Vec<Vec<Message>> = msgs
+        .into_iter()
+        .map(|outcome_msgs| {
+            outcome_msgs
+                .iter()
+                .map(|m| {
+                    Message::from_digest_slice(m)
+                        .map_err(|_| DLCError::InvalidArgument("Invalid message".to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let secp = get_secp_context();
+
+    // Get the adaptor point
+    let adaptor_point = ddk_dlc::get_adaptor_point_from_oracle_info(secp, &oracle_infos, &cet_msgs)
+        .map_err(|e| DLCError::Secp256k1Error(e.to_string()))?;
+
+    // Get the sighash - this is the actual message being signed
+    let sig_hash = ddk_dlc::util::get_sig_hash_msg(
+        &btc_tx,
+        0, // input_index is always 0 for CETs
+        funding_script,
+        Amount::from_sat(fund_output_value),
+    )
+    .map_err(DLCError::from)?;
+
+    Ok(CetAdaptorSignatureDebugInfo {
+        sighash: sig_hash.as_ref().to_vec(),
+        adaptor_point: adaptor_point.serialize().to_vec(),
+        input_index: 0,
+        script_pubkey: funding_script_pubkey,
+        value: fund_output_value,
+        cet_txid: btc_tx.compute_txid().to_string(),
+        cet_raw: cet.raw_bytes,
+    })
+}
+
+/// Get the sighash for a CET - the actual 32-byte message that gets signed.
+///
+/// This debug function is intentionally always available (not feature-gated)
+/// to enable debugging sighash mismatches in production environments where
+/// rebuilding with debug features may not be feasible.
+///
+/// Use this to compare sighash values with external signers (e.g., Fordefi)
+/// when debugging signature verification failures.
+pub fn get_cet_sighash(
+    cet: Transaction,
+    funding_script_pubkey: Vec<u8>,
+    fund_output_value: u64,
+) -> Result<Vec<u8>, DLCError> {
+    let btc_tx = transaction_to_btc_tx(&cet)?;
+    let funding_script = Script::from_bytes(&funding_script_pubkey);
+
+    let sig_hash = ddk_dlc::util::get_sig_hash_msg(
+        &btc_tx,
+        0, // input_index is always 0 for CETs
+        funding_script,
+        Amount::from_sat(fund_output_value),
+    )
+    .map_err(DLCError::from)?;
+
+    Ok(sig_hash.as_ref().to_vec())
+}
+
+/// Compare the payouts of a CET against the refund transaction.
+///
+/// Outputs are matched on both value and script pubkey, so a CET that merely
+/// mirrors the refund reports `identical_payouts`.
+pub fn diff_cet_refund(cet: Transaction, refund: Transaction) -> CetRefundDiff {
+    let same_funding_outpoint = match (cet.inputs.first(), refund.inputs.first()) {
+        (Some(cet_input), Some(refund_input)) => {
+            cet_input.txid == refund_input.txid && cet_input.vout == refund_input.vout
+        }
+        _ => false,
+    };
+
+    let mut refund_only_outputs = refund.outputs;
+    let mut cet_only_outputs = Vec::new();
+    for output in cet.outputs {
+        match refund_only_outputs.iter().position(|refund_output| {
+            refund_output.value == output.value
+                && refund_output.script_pubkey == output.script_pubkey
+        }) {
+            Some(index) => {
+                refund_only_outputs.remove(index);
+            }
+            None => cet_only_outputs.push(output),
+        }
+    }
+
+    CetRefundDiff {
+        same_funding_outpoint,
+        identical_payouts: cet_only_outputs.is_empty() && refund_only_outputs.is_empty(),
+        cet_only_outputs,
+        refund_only_outputs,
+    }
+}
+
+/// Build a placeholder transaction with dummy outpoints, empty witnesses and
+/// zero-value P2WPKH outputs, useful as a fixture for witness sizing.
+pub fn dummy_transaction(input_count: u32, output_count: u32) -> Transaction {
+    let btc_tx = BtcTransaction {
+        version: bitcoin::transaction::Version::TWO,
+        lock_time: bitcoin::absolute::LockTime::ZERO,
+        input: (0..input_count)
+            .map(|vout| TxIn {
+                previous_output: OutPoint::new(Txid::all_zeros(), vout),
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ENABLE_LOCKTIME_NO_RBF,
+                witness: Witness::new(),
+            })
+            .collect(),
+        output: (0..output_count)
+            .map(|_| BtcTxOut {
+                value: Amount::ZERO,
+                script_pubkey: ScriptBuf::new_p2wpkh(&WPubkeyHash::all_zeros()),
+            })
+            .collect(),
+    };
+
+    btc_tx_to_transaction(&btc_tx)
+}
+
+/// Verify that the funding output spent by the refund transaction is the P2WSH
+/// of `funding_script_pubkey` (the 2-of-2 witness script).
+pub fn verify_funding_script_consistency(dlc_txs: DlcTransactions) -> Result<bool, DLCError> {
+    let fund_tx = transaction_to_btc_tx(&dlc_txs.fund)?;
+    let refund_tx = transaction_to_btc_tx(&dlc_txs.refund)?;
+    let fund_input = refund_tx.input.first().ok_or(DLCError::InvalidArgument(
+        "Refund transaction has no inputs".to_string(),
+    ))?;
+
+    if fund_input.previous_output.txid != fund_tx.compute_txid() {
+        return Ok(false);
+    }
+
+    let Some(fund_output) = fund_tx.output.get(fund_input.previous_output.vout as usize) else {
+        return Ok(false);
+    };
+
+    let funding_script = Script::from_bytes(&dlc_txs.funding_script_pubkey);
+    Ok(fund_output.script_pubkey == ScriptBuf::new_p2wsh(&funding_script.wscript_hash()))
+}
+
+/// Check that every CET of a contract spends the same funding outpoint and
+/// return it as `"<txid>:<vout>"`.
+pub fn validate_cets_share_funding(cets: Vec<Transaction>) -> Result<String, DLCError> {
+    let mut shared: Option<OutPoint> = None;
+    for (i, cet) in cets.iter().enumerate() {
+        let cet_tx = transaction_to_btc_tx(cet)?;
+        let [input] = cet_tx.input.as_slice() else {
+            return Err(DLCError::InvalidArgument(format!(
+                "CET {} has {} inputs, expected 1",
+                i,
+                cet_tx.input.len()
+            )));
+        };
+        match shared {
+            None => shared = Some(input.previous_output),
+            Some(outpoint) if outpoint != input.previous_output => {
+                return Err(DLCError::InvalidArgument(format!(
+                    "CET {} spends {} instead of {}",
+                    i, input.previous_output, outpoint
+                )));
+            }
+            Some(_) => {}
+        }
+    }
+
+    shared
+        .map(|outpoint| outpoint.to_string())
+        .ok_or(DLCError::InvalidArgument("No CETs provided".to_string()))
+}
+
+/// Get the value of the 2-of-2 funding output, found by its script rather than
+/// its index, which depends on the serial id ordering.
+pub fn get_fund_output_value(dlc_txs: DlcTransactions) -> Result<u64, DLCError> {
+    let vout = fund_output_vout(&dlc_txs)?;
+    Ok(dlc_txs.fund.outputs[vout as usize].value)
+}
+
+/// Index of the fund output locked by the P2WSH of `funding_script_pubkey`.
+fn fund_output_vout(dlc_txs: &DlcTransactions) -> Result<u32, DLCError> {
+    let funding_script = Script::from_bytes(&dlc_txs.funding_script_pubkey);
+    let fund_script_pubkey = ScriptBuf::new_p2wsh(&funding_script.wscript_hash());
+
+    dlc_txs
+        .fund
+        .outputs
+        .iter()
+        .position(|output| output.script_pubkey == fund_script_pubkey.as_bytes())
+        .map(|vout| vout as u32)
+        .ok_or(DLCError::InvalidArgument(
+            "Fund transaction has no funding output".to_string(),
+        ))
+}
+
+/// Build a new refund transaction for a contract, e.g. with a different
+/// locktime, spending the funding output found by `funding_script_pubkey`.
+pub fn rebuild_refund(
+    dlc_txs: DlcTransactions,
+    local_final_script_pubkey: Vec<u8>,
+    remote_final_script_pubkey: Vec<u8>,
+    local_amount: u64,
+    remote_amount: u64,
+    new_lock_time: u32,
+) -> Result<Transaction, DLCError> {
+    let fund_vout = fund_output_vout(&dlc_txs)?;
+    let fund_txid = get_transaction_txid(dlc_txs.fund)?;
+
+    create_refund_transaction(
+        local_final_script_pubkey,
+        remote_final_script_pubkey,
+        local_amount,
+        remote_amount,
+        new_lock_time,
+        fund_txid,
+        fund_vout,
+    )
+}
+
+/// Sanity check a contract's transactions before signing: every CET and the
+/// refund spend only the funding output locked by `funding_script_pubkey`,
+/// and none pays out more than it holds.
+pub fn validate_dlc_transactions(dlc_txs: DlcTransactions) -> Result<(), DLCError> {
+    let fund_txid = transaction_to_btc_tx(&dlc_txs.fund)?
+        .compute_txid()
+        .to_string();
+    let fund_vout = fund_output_vout(&dlc_txs)?;
+    let fund_value = dlc_txs.fund.outputs[fund_vout as usize].value;
+
+    let spenders = dlc_txs
+        .cets
+        .iter()
+        .enumerate()
+        .map(|(i, cet)| (format!("CET {}", i), cet))
+        .chain(std::iter::once(("Refund".to_string(), &dlc_txs.refund)));
+    for (name, tx) in spenders {
+        let [input] = tx.inputs.as_slice() else {
+            return Err(DLCError::InvalidArgument(format!(
+                "{} has {} inputs, expected only the funding input",
+                name,
+                tx.inputs.len()
+            )));
+        };
+        if input.txid != fund_txid || input.vout != fund_vout {
+            return Err(DLCError::InvalidArgument(format!(
+                "{} spends {}:{}, not the funding output {}:{}",
+                name, input.txid, input.vout, fund_txid, fund_vout
+            )));
+        }
+        let outputs_total = tx
+            .outputs
+            .iter()
+            .try_fold(0u64, |total, output| total.checked_add(output.value));
+        if outputs_total.is_none_or(|total| total > fund_value) {
+            return Err(DLCError::InvalidArgument(format!(
+                "{} outputs exceed the funding output value {}",
+                name, fund_value
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fee each CET pays: the funding output value minus the CET's outputs.
+pub fn cet_fees(cets: Vec<Transaction>, fund_output_value: u64) -> Result<Vec<u64>, DLCError> {
+    cets.iter()
+        .enumerate()
+        .map(|(i, cet)| {
+            let outputs_total = cet
+                .outputs
+                .iter()
+                .try_fold(0u64, |total, output| total.checked_add(output.value));
+            outputs_total
+                .and_then(|total| fund_output_value.checked_sub(total))
+                .ok_or(DLCError::InvalidArgument(format!(
+                    "CET {} outputs exceed the funding output value",
+                    i
+                )))
+        })
+        .collect()
+}
+
+/// Find the index of the outcome a CET settles. CET fees come out of the fund
+/// output, so each party's output carries its payout exactly; payouts below the
+/// dust limit have no output. The first matching outcome is returned.
+pub fn cet_to_outcome(
+    cet: Transaction,
+    outcomes: Vec<Payout>,
+    local_script: Vec<u8>,
+    remote_script: Vec<u8>,
+) -> Result<u32, DLCError> {
+    if local_script == remote_script {
+        return Err(DLCError::InvalidArgument(
+            "Local and remote payout scripts must differ".to_string(),
+        ));
+    }
+    let local_paid = paid_to_script(&cet.outputs, &local_script)?;
+    let remote_paid = paid_to_script(&cet.outputs, &remote_script)?;
+    let matches = |payout: u64, paid: u64| paid == payout || (payout < DUST_LIMIT && paid == 0);
+
+    outcomes
+        .iter()
+        .position(|payout| matches(payout.offer, local_paid) && matches(payout.accept, remote_paid))
+        .map(|index| index as u32)
+        .ok_or(DLCError::InvalidArgument(format!(
+            "No outcome pays {} and {} sats",
+            local_paid, remote_paid
+        )))
+}
+
+/// Find the index of the only CET paying `offer_amount` to `offer_script` and
+/// `accept_amount` to `accept_script`, whichever order its outputs are in.
+/// Amounts below the dust limit have no output.
+pub fn find_cet_by_payout(
+    cets: Vec<Transaction>,
+    offer_amount: u64,
+    accept_amount: u64,
+    offer_script: Vec<u8>,
+    accept_script: Vec<u8>,
+) -> Result<u32, DLCError> {
+    let mut expected = [(offer_amount, offer_script), (accept_amount, accept_script)]
+        .into_iter()
+        .filter(|(amount, _)| *amount >= DUST_LIMIT)
+        .collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    let mut matching = cets.iter().enumerate().filter(|(_, cet)| {
+        let mut outputs = cet
+            .outputs
+            .iter()
+            .map(|output| (output.value, output.script_pubkey.clone()))
+            .collect::<Vec<_>>();
+        outputs.sort_unstable();
+        outputs == expected
+    });
+    match (matching.next(), matching.next()) {
+        (Some((index, _)), None) => Ok(index as u32),
+        (None, _) => Err(DLCError::InvalidArgument(format!(
+            "No CET pays {} and {} sats",
+            offer_amount, accept_amount
+        ))),
+        (Some(_), Some(_)) => Err(DLCError::InvalidArgument(format!(
+            "More than one CET pays {} and {} sats",
+            offer_amount, accept_amount
+        ))),
+    }
+}
+
+/// List every public key a contract involves: both fund keys, the keys of the
+/// 2-of-2 funding script and the keys revealed by P2WPKH witnesses of the fund
+/// inputs. Payout keys are only known as hashes and cannot be listed. Returns
+/// compressed keys, sorted and deduplicated.
+pub fn contract_pubkeys(
+    dlc_txs: DlcTransactions,
+    local_fund_pubkey: Vec<u8>,
+    remote_fund_pubkey: Vec<u8>,
+) -> Vec<Vec<u8>> {
+    let funding_script = Script::from_bytes(&dlc_txs.funding_script_pubkey);
+    let script_keys = funding_script.instructions().filter_map(|instruction| {
+        instruction
+            .ok()?
+            .push_bytes()
+            .map(|b| b.as_bytes().to_vec())
+    });
+    let witness_keys = dlc_txs
+        .fund
+        .inputs
+        .iter()
+        .filter(|input| input.witness.len() == 2)
+        .map(|input| input.witness[1].clone());
+
+    let mut pubkeys = [local_fund_pubkey, remote_fund_pubkey]
+        .into_iter()
+        .chain(script_keys)
+        .chain(witness_keys)
+        .filter_map(|bytes| PublicKey::from_slice(&bytes).ok())
+        .map(|pubkey| pubkey.serialize().to_vec())
+        .collect::<Vec<_>>();
+    pubkeys.sort();
+    pubkeys.dedup();
+    pubkeys
+}
+
+/// Verify that the funding output locks both parties' collateral and that the
+/// refund pays exactly that collateral back. The funding output also carries
+/// the CET fee reserve both parties put up at `fee_rate`, and nothing more.
+pub fn verify_collateral_invariant(
+    dlc_txs: DlcTransactions,
+    offer_params: PartyParams,
+    accept_params: PartyParams,
+    fee_rate: u64,
+) -> Result<bool, DLCError> {
+    let total_collateral = contract_total_collateral(&offer_params, &accept_params)?;
+    let cet_fee = |params: &PartyParams, other: &PartyParams| {
+        get_change_output_and_fees(params.clone(), other.collateral, fee_rate, 0)
+            .map(|fees| fees.cet_fee)
+    };
+    let expected_fund_output_value = [
+        cet_fee(&offer_params, &accept_params)?,
+        cet_fee(&accept_params, &offer_params)?,
+    ]
+    .into_iter()
+    .try_fold(total_collateral, u64::checked_add)
+    .ok_or(DLCError::InvalidArgument("Collateral overflow".to_string()))?;
+
+    let fund_output_value = get_fund_output_value(dlc_txs.clone())?;
+
+    let refund_total = dlc_txs
+        .refund
+        .outputs
+        .iter()
+        .try_fold(0u64, |total, output| total.checked_add(output.value))
+        .ok_or(DLCError::InvalidTransaction)?;
+
+    Ok(fund_output_value == expected_fund_output_value && refund_total == total_collateral)
+}
+
+/// Estimate the cost of building a numeric contract before building it, so
+/// callers can warn about large contracts upfront.
+pub fn estimate_contract_cost(
+    base: u32,
+    nb_digits: u32,
+    nb_oracles: u32,
+) -> Result<ContractCost, DLCError> {
+    if base < 2 {
+        return Err(DLCError::InvalidArgument(
+            "Base must be at least 2".to_string(),
+        ));
+    }
+    if nb_digits == 0 || nb_oracles == 0 {
+        return Err(DLCError::InvalidArgument(
+            "Number of digits and oracles must be positive".to_string(),
+        ));
+    }
+
+    let overflow = || DLCError::InvalidArgument("Contract is too large to estimate".to_string());
+    let cet_count = (base as u64).checked_pow(nb_digits).ok_or_else(overflow)?;
+    let transaction_bytes = cet_count
+        .checked_mul(APPROX_CET_SIZE)
+        .ok_or_else(overflow)?;
+    let adaptor_signing_operations = cet_count
+        .checked_mul(nb_oracles as u64 * nb_digits as u64)
+        .ok_or_else(overflow)?;
+
+    Ok(ContractCost {
+        cet_count,
+        transaction_bytes,
+        adaptor_signing_operations,
+    })
+}
+
+/// Serialize a transaction without its witness data so that signed and unsigned
+/// copies of the same transaction compare equal.
+fn witness_stripped_bytes(tx: &Transaction) -> Result<Vec<u8>, DLCError> {
+    let mut btc_tx = transaction_to_btc_tx(tx)?;
+    btc_tx
+        .input
+        .iter_mut()
+        .for_each(|input| input.witness.clear());
+    Ok(bitcoin::consensus::serialize(&btc_tx))
+}
+
+/// Compare two sets of contract transactions, ignoring witnesses. CETs are
+/// compared in order since each one corresponds to an outcome. Transactions
+/// that fail to decode are never equal.
+pub fn dlc_transactions_equal(a: DlcTransactions, b: DlcTransactions) -> bool {
+    let same = |a: &Transaction, b: &Transaction| {
+        matches!(
+            (witness_stripped_bytes(a), witness_stripped_bytes(b)),
+            (Ok(a), Ok(b)) if a == b
+        )
+    };
+
+    a.funding_script_pubkey == b.funding_script_pubkey
+        && same(&a.fund, &b.fund)
+        && same(&a.refund, &b.refund)
+        && a.cets.len() == b.cets.len()
+        && a.cets.iter().zip(b.cets.iter()).all(|(a, b)| same(a, b))
+}
+
+/// Compare two transactions as multisets of inputs and outputs, so builds that
+/// only differ in serial-id ordering are equal. Witnesses are ignored since
+/// reordering inputs changes every signature.
+pub fn transactions_equivalent(a: Transaction, b: Transaction) -> bool {
+    let (Ok(a), Ok(b)) = (transaction_to_btc_tx(&a), transaction_to_btc_tx(&b)) else {
+        return false;
+    };
+    let sorted_inputs = |tx: &BtcTransaction| {
+        let mut inputs = tx
+            .input
+            .iter()
+            .map(|input| {
+                (
+                    input.previous_output,
+                    input.script_sig.clone(),
+                    input.sequence,
+                )
+            })
+            .collect::<Vec<_>>();
+        inputs.sort();
+        inputs
+    };
+    let sorted_outputs = |tx: &BtcTransaction| {
+        let mut outputs = tx.output.clone();
+        outputs.sort();
+        outputs
+    };
+
+    a.version == b.version
+        && a.lock_time == b.lock_time
+        && sorted_inputs(&a) == sorted_inputs(&b)
+        && sorted_outputs(&a) == sorted_outputs(&b)
+}
+
+/// Compute a stable 32-byte fingerprint of a contract for deduplication.
+///
+/// Transactions are hashed without witnesses, and CETs and oracle infos are
+/// sorted first, so equivalent contracts always produce the same fingerprint.
+pub fn contract_fingerprint(
+    dlc_txs: DlcTransactions,
+    oracle_infos: Vec<OracleInfo>,
+) -> Result<Vec<u8>, DLCError> {
+    let mut cets = dlc_txs
+        .cets
+        .iter()
+        .map(witness_stripped_bytes)
+        .collect::<Result<Vec<_>, _>>()?;
+    cets.sort();
+
+    let mut oracles = oracle_infos
+        .iter()
+        .map(|info| {
+            let mut bytes = info.public_key.clone();
+            info.nonces.iter().for_each(|nonce| bytes.extend(nonce));
+            bytes
+        })
+        .collect::<Vec<_>>();
+    oracles.sort();
+
+    let mut engine = sha256::Hash::engine();
+    let mut write = |bytes: &[u8]| {
+        engine.input(&(bytes.len() as u64).to_be_bytes());
+        engine.input(bytes);
+    };
+
+    write(&witness_stripped_bytes(&dlc_txs.fund)?);
+    write(&dlc_txs.funding_script_pubkey);
+    write(&(cets.len() as u64).to_be_bytes());
+    cets.iter().for_each(|cet| write(cet));
+    write(&witness_stripped_bytes(&dlc_txs.refund)?);
+    write(&(oracles.len() as u64).to_be_bytes());
+    oracles.iter().for_each(|oracle| write(oracle));
+
+    Ok(sha256::Hash::from_engine(engine).to_byte_array().to_vec())
+}
+
+/// Run the offer, accept and sign steps of a contract between two local parties:
+/// build the transactions, exchange and verify CET adaptor signatures, and
+/// countersign the refund. Funding inputs are left for each wallet to sign.
+/// Meant for tests and as a reference for the order of operations.
+///
+/// **Never use this with real keys.** It takes both parties' fund secret keys,
+/// which no real contract has in one place; counterparties must each run their
+/// own side of the flow.
+pub fn complete_dlc_flow(
+    offer_params: PartyParams,
+    accept_params: PartyParams,
+    outcomes: Vec<Payout>,
+    oracle_infos: Vec<OracleInfo>,
+    msgs: Vec<Vec<Vec<Vec<u8>>>>,
+    offer_fund_sk: Vec<u8>,
+    accept_fund_sk: Vec<u8>,
+    fee_rate: u64,
+    refund_locktime: u32,
+    fund_lock_time: u32,
+    cet_lock_time: u32,
+    fund_output_serial_id: u64,
+) -> Result<CompletedContract, DLCError> {
+    // Offer and accept
+    let dlc_txs = create_dlc_transactions(
+        outcomes,
+        offer_params.clone(),
+        accept_params.clone(),
+        refund_locktime,
+        fee_rate,
+        fund_lock_time,
+        cet_lock_time,
+        fund_output_serial_id,
+        0,
+    )?;
+    let fund_vout = dlc_txs
+        .refund
+        .inputs
+        .first()
+        .ok_or(DLCError::InvalidTransaction)?
+        .vout;
+    let fund_output_value = dlc_txs
+        .fund
+        .outputs
+        .get(fund_vout as usize)
+        .ok_or(DLCError::InvalidTransaction)?
+        .value;
+
+    // Each party signs the CETs and checks the other's signatures
+    let sign_cets = |funding_sk: &[u8], pubkey: &[u8]| {
+        let adaptor_sigs = create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            oracle_infos.clone(),
+            funding_sk.to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        )?;
+        if !verify_cet_adaptor_sigs_from_oracle_info(
+            adaptor_sigs.clone(),
+            dlc_txs.cets.clone(),
+            oracle_infos.clone(),
+            pubkey.to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        ) {
+            return Err(DLCError::InvalidSignature);
+        }
+        Ok(adaptor_sigs)
+    };
+    let accept_adaptor_signatures = sign_cets(&accept_fund_sk, &accept_params.fund_pubkey)?;
+    let offer_adaptor_signatures = sign_cets(&offer_fund_sk, &offer_params.fund_pubkey)?;
+
+    // Both parties sign the refund
+    let sign_refund = |funding_sk: &[u8], pubkey: &[u8], other_pubkey: &[u8]| {
+        let signature = sign_refund_transaction(
+            dlc_txs.refund.clone(),
+            funding_sk.to_vec(),
+            other_pubkey.to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )?;
+        if !verify_refund_transaction_signature(
+            dlc_txs.refund.clone(),
+            signature.clone(),
+            pubkey.to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )? {
+            return Err(DLCError::InvalidSignature);
+        }
+        Ok(signature)
+    };
+    let offer_refund_sig = sign_refund(
+        &offer_fund_sk,
+        &offer_params.fund_pubkey,
+        &accept_params.fund_pubkey,
+    )?;
+    let accept_refund_sig = sign_refund(
+        &accept_fund_sk,
+        &accept_params.fund_pubkey,
+        &offer_params.fund_pubkey,
+    )?;
+    let signed_refund = finalize_refund_transaction(
+        dlc_txs.refund.clone(),
+        offer_refund_sig,
+        accept_refund_sig,
+        offer_params.fund_pubkey,
+        accept_params.fund_pubkey,
+    )?;
+
+    Ok(CompletedContract {
+        dlc_transactions: dlc_txs,
+        fund_output_value,
+        offer_adaptor_signatures,
+        accept_adaptor_signatures,
+        signed_refund,
+    })
+}
+
+/// Version byte prefixed to serialized settlement state.
+pub const SETTLEMENT_STATE_VERSION: u8 = 1;
+
+fn write_settlement_tx(buf: &mut Vec<u8>, tx: &Transaction) -> Result<(), DLCError> {
+    // Reject undecodable transactions now rather than when resuming
+    transaction_to_btc_tx(tx).map_err(|_| DLCError::InvalidTransaction)?;
+    write_length_prefixed(buf, &tx.raw_bytes);
+    Ok(())
+}
+
+/// Serialize the state needed to settle a contract later into a versioned blob.
+pub fn serialize_settlement_state(
+    dlc_txs: DlcTransactions,
+    adaptor_sigs: Vec<AdaptorSignature>,
+    oracle_infos: Vec<OracleInfo>,
+    contract_id: Vec<u8>,
+) -> Result<Vec<u8>, DLCError> {
+    if contract_id.len() != 32 {
+        return Err(DLCError::InvalidArgument(
+            "Contract id must be 32 bytes".to_string(),
+        ));
+    }
+
+    let mut buf = vec![SETTLEMENT_STATE_VERSION];
+    buf.extend(&contract_id);
+
+    write_settlement_tx(&mut buf, &dlc_txs.fund)?;
+    write_length_prefixed(&mut buf, &dlc_txs.funding_script_pubkey);
+    buf.extend((dlc_txs.cets.len() as u32).to_be_bytes());
+    for cet in &dlc_txs.cets {
+        write_settlement_tx(&mut buf, cet)?;
+    }
+    write_settlement_tx(&mut buf, &dlc_txs.refund)?;
+
+    buf.extend((adaptor_sigs.len() as u32).to_be_bytes());
+    for sig in &adaptor_sigs {
+        write_length_prefixed(&mut buf, &sig.signature);
+        write_length_prefixed(&mut buf, &sig.proof);
+    }
+
+    buf.extend((oracle_infos.len() as u32).to_be_bytes());
+    for info in &oracle_infos {
+        write_length_prefixed(&mut buf, &info.public_key);
+        buf.extend((info.nonces.len() as u32).to_be_bytes());
+        info.nonces
+            .iter()
+            .for_each(|nonce| write_length_prefixed(&mut buf, nonce));
+    }
+
+    Ok(buf)
+}
+
+/// Restore settlement state written by `serialize_settlement_state`.
+pub fn deserialize_settlement_state(bytes: Vec<u8>) -> Result<SettlementState, DLCError> {
+    let mut reader = ByteReader { bytes: &bytes };
+
+    reader.read_version(SETTLEMENT_STATE_VERSION, "settlement state")?;
+    let contract_id = reader.take(32)?.to_vec();
+
+    let fund = reader.read_tx()?;
+    let funding_script_pubkey = reader.read_bytes()?;
+    let cet_count = reader.read_u32()?;
+    let cets = (0..cet_count)
+        .map(|_| reader.read_tx())
+        .collect::<Result<Vec<_>, _>>()?;
+    let refund = reader.read_tx()?;
+
+    let sig_count = reader.read_u32()?;
+    let adaptor_signatures = (0..sig_count)
+        .map(|_| {
+            Ok(AdaptorSignature {
+                signature: reader.read_bytes()?,
+                proof: reader.read_bytes()?,
+            })
+        })
+        .collect::<Result<Vec<_>, DLCError>>()?;
+
+    let oracle_count = reader.read_u32()?;
+    let oracle_infos = (0..oracle_count)
+        .map(|_| {
+            let public_key = reader.read_bytes()?;
+            let nonce_count = reader.read_u32()?;
+            let nonces = (0..nonce_count)
+                .map(|_| reader.read_bytes())
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(OracleInfo { public_key, nonces })
+        })
+        .collect::<Result<Vec<_>, DLCError>>()?;
+
+    reader.finish()?;
+
+    Ok(SettlementState {
+        dlc_transactions: DlcTransactions {
+            fund,
+            cets,
+            refund,
+            funding_script_pubkey,
+        },
+        adaptor_signatures,
+        oracle_infos,
+        contract_id,
+    })
+}
+
+pub fn convert_mnemonic_to_seed(
+    mnemonic: String,
+    passphrase: Option<String>,
+) -> Result<Vec<u8>, DLCError> {
+    let seed_mnemonic = Mnemonic::parse_in_normalized(Language::English, &mnemonic)
+        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidMnemonic))?;
+    let passphrase = passphrase.unwrap_or("".to_string());
+    let seed = seed_mnemonic.to_seed(&passphrase);
+    Ok(seed.to_vec())
+}
+
+/// Check an English mnemonic, naming the first unknown word or reporting a bad
+/// checksum or word count as `InvalidArgument`, so wallets can tell the user
+/// what to fix.
+pub fn validate_mnemonic(mnemonic: String) -> Result<(), DLCError> {
+    match Mnemonic::parse_in_normalized(Language::English, &mnemonic) {
+        Ok(_) => Ok(()),
+        Err(bip39::Error::UnknownWord(index)) => {
+            let word = mnemonic.split_whitespace().nth(index).unwrap_or_default();
+            Err(DLCError::InvalidArgument(format!(
+                "Unknown mnemonic word {} at position {}",
+                word,
+                index + 1
+            )))
+        }
+        Err(bip39::Error::InvalidChecksum) => Err(DLCError::InvalidArgument(
+            "Invalid mnemonic checksum".to_string(),
+        )),
+        Err(e @ bip39::Error::BadWordCount(_)) => Err(DLCError::InvalidArgument(e.to_string())),
+        Err(_) => Err(DLCError::KeyError(ExtendedKey::InvalidMnemonic)),
+    }
+}
+
+/// Parse a network name, case-insensitively. Accepts the bitcoin crate names
+/// plus the common "mainnet" and "testnet3" aliases.
+pub fn parse_network(network: &str) -> Result<Network, DLCError> {
+    match network.to_ascii_lowercase().as_str() {
+        "bitcoin" | "mainnet" => Ok(Network::Bitcoin),
+        "testnet" | "testnet3" => Ok(Network::Testnet),
+        "testnet4" => Ok(Network::Testnet4),
+        "signet" => Ok(Network::Signet),
+        "regtest" => Ok(Network::Regtest),
+        _ => Err(DLCError::InvalidNetwork),
+    }
+}
+
+/// Whether [`parse_network`] accepts `network`.
+pub fn is_valid_network(network: String) -> bool {
+    parse_network(&network).is_ok()
+}
+
+/// Create master extended private key from 64-byte seed
+/// Returns 78-byte encoded xpriv
+pub fn create_extkey_from_seed(seed: Vec<u8>, network: String) -> Result<Vec<u8>, DLCError> {
+    let seed = Zeroizing::new(seed);
+    if seed.len() != 64 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    }
+    let network = parse_network(&network)?;
+    let xpriv = Xpriv::new_master(network, &seed)
+        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+    Ok(xpriv.encode().to_vec())
+}
+
+/// Derive child extended private key from parent extended key
+/// Input: 78-byte encoded xpriv, Output: 78-byte encoded xpriv
+pub fn create_extkey_from_parent_path(extkey: Vec<u8>, path: String) -> Result<Vec<u8>, DLCError> {
+    let extkey = Zeroizing::new(extkey);
+    if extkey.len() != 78 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    }
+
+    let secp = get_secp_context();
+    let xpriv =
+        Xpriv::decode(&extkey).map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+
+    let derivation_path = path
+        .into_derivation_path()
+        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidDerivationPath))?;
+
+    let derived_xpriv = xpriv
+        .derive_priv(secp, &derivation_path)
+        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+
+    Ok(derived_xpriv.encode().to_vec())
+}
+
+/// Extract public key from extended key (private or public)
+/// Input: 78-byte encoded xpriv/xpub, Output: 33-byte compressed public key
+pub fn get_pubkey_from_extkey(extkey: Vec<u8>, network: String) -> Result<Vec<u8>, DLCError> {
+    if extkey.len() != 78 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    }
+
+    let secp = get_secp_context();
+    let network = bitcoin::NetworkKind::from(parse_network(&network)?);
+
+    // Try as xpriv first, then as xpub
+    let xpub = if let Ok(xpriv) = Xpriv::decode(&extkey) {
+        Xpub::from_priv(secp, &xpriv)
+    } else if let Ok(xpub) = Xpub::decode(&extkey) {
+        xpub
+    } else {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    };
+
+    if xpub.network != network {
+        return Err(DLCError::InvalidNetwork);
+    }
+
+    Ok(xpub.public_key.serialize().to_vec())
+}
+
+/// Network of an encoded xpriv/xpub, read from its version bytes. Extended keys
+/// only distinguish mainnet from test networks, so this is "bitcoin" or "testnet".
+pub fn get_network_from_extkey(extkey: Vec<u8>) -> Result<String, DLCError> {
+    if extkey.len() != 78 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    }
+
+    let network = if let Ok(xpriv) = Xpriv::decode(&extkey) {
+        xpriv.network
+    } else if let Ok(xpub) = Xpub::decode(&extkey) {
+        xpub.network
+    } else {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    };
+
+    Ok(match network {
+        bitcoin::NetworkKind::Main => "bitcoin",
+        bitcoin::NetworkKind::Test => "testnet",
+    }
+    .to_string())
+}
+
+/// 4-byte BIP32 fingerprint of an encoded xpriv/xpub: the first 4 bytes of the
+/// hash160 of its public key, as used in key origins.
+pub fn get_extkey_fingerprint(extkey: Vec<u8>) -> Result<Vec<u8>, DLCError> {
+    if extkey.len() != 78 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    }
+
+    let fingerprint = if let Ok(xpriv) = Xpriv::decode(&extkey) {
+        xpriv.fingerprint(get_secp_context())
+    } else if let Ok(xpub) = Xpub::decode(&extkey) {
+        xpub.fingerprint()
+    } else {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    };
+
+    Ok(fingerprint.as_bytes().to_vec())
+}
+
+/// Derive the public key at `path` from a 64-byte seed in one call
+/// Output: 33-byte compressed public key
+pub fn derive_pubkey_from_seed(
+    seed: Vec<u8>,
+    network: String,
+    path: String,
+) -> Result<Vec<u8>, DLCError> {
+    let master_xpriv = create_extkey_from_seed(seed, network.clone())?;
+    let derived_xpriv = create_extkey_from_parent_path(master_xpriv, path)?;
+    get_pubkey_from_extkey(derived_xpriv, network)
+}
+
+/// Derive the private key at `path` from a 64-byte seed in one call
+/// Output: 32-byte secret key
+pub fn derive_privkey_from_seed(
+    seed: Vec<u8>,
+    network: String,
+    path: String,
+) -> Result<Vec<u8>, DLCError> {
+    let master_xpriv = Zeroizing::new(create_extkey_from_seed(seed, network)?);
+    let derived_xpriv =
+        Zeroizing::new(create_extkey_from_parent_path(master_xpriv.to_vec(), path)?);
+    let mut xpriv =
+        Xpriv::decode(&derived_xpriv).map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+    let secret = xpriv.private_key.secret_bytes().to_vec();
+    xpriv.private_key.non_secure_erase();
+    Ok(secret)
+}
+
+/// Encode a compressed public key as a "p2wpkh", "p2tr" (key path only) or
+/// "p2sh-p2wpkh" address for the given network
+pub fn pubkey_to_address(
+    pubkey: Vec<u8>,
+    network: String,
+    address_type: String,
+) -> Result<String, DLCError> {
+    let pubkey =
+        CompressedPublicKey::from_slice(&pubkey).map_err(|_| DLCError::InvalidPublicKey)?;
+    let network = parse_network(&network)?;
+
+    let address = match address_type.as_str() {
+        "p2wpkh" => Address::p2wpkh(&pubkey, network),
+        "p2tr" => Address::p2tr(get_secp_context(), pubkey.0.into(), None, network),
+        "p2sh-p2wpkh" => Address::p2shwpkh(&pubkey, network),
+        other => {
+            return Err(DLCError::InvalidArgument(format!(
+                "Unknown address type {}, expected p2wpkh, p2tr or p2sh-p2wpkh",
+                other
+            )))
+        }
+    };
+
+    Ok(address.to_string())
+}
+
+/// Parse an address for the given network and return its script pubkey, for
+/// use as a payout or change script
+pub fn address_to_script_pubkey(address: String, network: String) -> Result<Vec<u8>, DLCError> {
+    let network = parse_network(&network)?;
+    let address = Address::from_str(&address)
+        .map_err(|_| DLCError::InvalidArgument(format!("Invalid address {}", address)))?
+        .require_network(network)
+        .map_err(|_| DLCError::InvalidNetwork)?;
+
+    Ok(address.script_pubkey().to_bytes())
+}
+
+/// DEPRECATED: Use create_extkey_from_seed + create_extkey_from_parent_path instead
+/// This function handles both seeds (64 bytes) and xprivs (78 bytes) which is confusing
+#[deprecated(
+    since = "0.4.0",
+    note = "Use create_extkey_from_seed + create_extkey_from_parent_path"
+)]
+pub fn create_xpriv_from_parent_path(
+    seed_or_xpriv: Vec<u8>,
+    base_derivation_path: String,
+    network: String,
+    path: String,
+) -> Result<Vec<u8>, DLCError> {
+    let master_xpriv = if seed_or_xpriv.len() == 64 {
+        // This is a seed, create master xpriv
+        create_extkey_from_seed(seed_or_xpriv, network.clone())?
+    } else if seed_or_xpriv.len() == 78 {
+        // This is already an xpriv
+        seed_or_xpriv
+    } else {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    };
+
+    // Derive base path from master
+    let base_xpriv =
+        create_extkey_from_parent_path(master_xpriv, base_derivation_path.replace("m/", ""))?;
+
+    // Derive final path from base
+    create_extkey_from_parent_path(base_xpriv, path)
+}
+
+/// Convert extended private key to extended public key
+/// Input: 78-byte encoded xpriv, Output: 78-byte encoded xpub
+pub fn get_xpub_from_xpriv(xpriv: Vec<u8>, network: String) -> Result<Vec<u8>, DLCError> {
+    if xpriv.len() != 78 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    }
+
+    let secp = get_secp_context();
+    let _network = parse_network(&network)?;
+
+    let xpriv = Xpriv::decode(&xpriv).map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+
+    let xpub = Xpub::from_priv(secp, &xpriv);
+    Ok(xpub.encode().to_vec())
+}
+
+/// Check that an extended private key and extended public key are a pair
+/// Input: 78-byte encoded xpriv and 78-byte encoded xpub
+pub fn extkeys_match(xpriv: Vec<u8>, xpub: Vec<u8>) -> Result<bool, DLCError> {
+    if xpriv.len() != 78 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    }
+    if xpub.len() != 78 {
+        return Err(DLCError::KeyError(ExtendedKey::InvalidXpub));
+    }
+
+    let xpriv = Xpriv::decode(&xpriv).map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+    let xpub = Xpub::decode(&xpub).map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpub))?;
+
+    Ok(Xpub::from_priv(get_secp_context(), &xpriv) == xpub)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::bip32::DerivationPath;
+    use bitcoin::{locktime::absolute::LockTime, Address, CompressedPublicKey};
+    use ddk_dlc::secp_utils;
+    use secp256k1_zkp::{
+        rand::{thread_rng, RngCore},
+        Keypair, Scalar,
+    };
+    use std::str::FromStr;
+
+    /// Create test keys similar to rust-dlc tests
+    fn create_test_keys() -> (SecretKey, PublicKey, SecretKey, PublicKey) {
+        let secp = Secp256k1::new();
+        let offer_sk =
+            SecretKey::from_str("0000000000000000000000000000000000000000000000000000000000000001")
+                .unwrap();
+        let offer_pk = PublicKey::from_secret_key(&secp, &offer_sk);
+        let accept_sk =
+            SecretKey::from_str("0000000000000000000000000000000000000000000000000000000000000002")
+                .unwrap();
+        let accept_pk = PublicKey::from_secret_key(&secp, &accept_sk);
+        (offer_sk, offer_pk, accept_sk, accept_pk)
+    }
+
+    /// Create realistic party params for testing
+    fn create_test_party_params(
+        input_amount: u64,
+        collateral: u64,
+        fund_pubkey: Vec<u8>,
+        serial_id: u64,
+    ) -> PartyParams {
+        let mut rng = thread_rng();
+
+        // Create a realistic P2WPKH script
+        let mut random_hash = [0u8; 20];
+        rng.fill_bytes(&mut random_hash);
+        let mut change_script = vec![0x00, 0x14]; // OP_0 + 20 bytes (P2WPKH)
+        change_script.extend_from_slice(&random_hash);
+
+        rng.fill_bytes(&mut random_hash);
+        let mut payout_script = vec![0x00, 0x14]; // OP_0 + 20 bytes (P2WPKH)
+        payout_script.extend_from_slice(&random_hash);
+
+        PartyParams {
+            fund_pubkey,
+            change_script_pubkey: Some(change_script),
+            change_serial_id: serial_id + 1,
+            payout_script_pubkey: payout_script,
+            payout_serial_id: serial_id + 2,
+            inputs: vec![TxInputInfo {
+                txid: "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"
+                    .to_string(),
+                vout: serial_id as u32,
+                script_sig: vec![],
+                max_witness_length: 108,
+                serial_id,
+            }],
+            input_amount,
+            collateral,
+            dlc_inputs: vec![],
+        }
+    }
+
+    #[test]
+    fn mnemonic_to_seed_test() {
+        let mnemonic = Mnemonic::generate(24).unwrap();
+        let rust_seed = mnemonic.to_seed_normalized("").to_vec();
+        let ffi_seed = convert_mnemonic_to_seed(mnemonic.to_string(), None).unwrap();
+        assert_eq!(rust_seed, ffi_seed);
+    }
+
+    #[test]
+    fn xpriv_to_xpub_test() {
+        let mnemonic = Mnemonic::generate(24).unwrap();
+        let rust_xpriv =
+            Xpriv::new_master(Network::Bitcoin, &mnemonic.to_seed_normalized("")).unwrap();
+        let ffi_xpriv = create_extkey_from_seed(
+            mnemonic.to_seed_normalized("").to_vec(),
+            "bitcoin".to_string(),
+        )
+        .unwrap();
+        let rust_xpub = Xpub::from_priv(get_secp_context(), &rust_xpriv);
+        let ffi_xpub = get_xpub_from_xpriv(ffi_xpriv, "bitcoin".to_string()).unwrap();
+        assert_eq!(rust_xpub.encode().to_vec(), ffi_xpub);
+    }
+
+    #[test]
+    fn extkeys_match_test() {
+        let seed = Mnemonic::generate(24).unwrap().to_seed_normalized("");
+        let xpriv = create_extkey_from_seed(seed.to_vec(), "bitcoin".to_string()).unwrap();
+        let xpub = get_xpub_from_xpriv(xpriv.clone(), "bitcoin".to_string()).unwrap();
+        assert!(extkeys_match(xpriv.clone(), xpub.clone()).unwrap());
+
+        let other_seed = Mnemonic::generate(24).unwrap().to_seed_normalized("");
+        let other_xpriv =
+            create_extkey_from_seed(other_seed.to_vec(), "bitcoin".to_string()).unwrap();
+        let other_xpub = get_xpub_from_xpriv(other_xpriv, "bitcoin".to_string()).unwrap();
+        assert!(!extkeys_match(xpriv.clone(), other_xpub).unwrap());
+
+        // An xpriv passed as the xpub is rejected
+        assert!(matches!(
+            extkeys_match(xpriv.clone(), xpriv),
+            Err(DLCError::KeyError(ExtendedKey::InvalidXpub))
+        ));
+    }
+
+    #[test]
+    fn xpriv_to_path() {
+        let base_derivation_path = "84'/0'/0'";
+        let app_path = "0/1";
+        let network = "bitcoin";
+        let secp = get_secp_context();
+
+        let mnemonic = Mnemonic::generate(24).unwrap();
+        let rust_xpriv =
+            Xpriv::new_master(Network::Bitcoin, &mnemonic.to_seed_normalized("")).unwrap();
+        let rust_path =
+            DerivationPath::from_str(&format!("{}/{}", base_derivation_path, app_path)).unwrap();
+        let rust_xpriv = rust_xpriv.derive_priv(secp, &rust_path).unwrap();
+
+        let ffi_xpriv_bytes = convert_mnemonic_to_seed(mnemonic.to_string(), None).unwrap();
+        let ffi_xpub = create_xpriv_from_parent_path(
+            ffi_xpriv_bytes,
+            base_derivation_path.to_string(),
+            network.to_string(),
+            app_path.to_string(),
+        )
+        .unwrap();
+        assert_eq!(rust_xpriv.encode().to_vec(), ffi_xpub);
+    }
+
+    #[test]
+    fn test_create_fund_tx_locking_script_matches_rust_dlc() {
+        let (_offer_sk, offer_pk, _accept_sk, accept_pk) = create_test_keys();
+
+        // Test our wrapper
+        let wrapper_result = create_fund_tx_locking_script(
+            offer_pk.serialize().to_vec(),
+            accept_pk.serialize().to_vec(),
+        )
+        .unwrap();
+
+        // Compare with direct rust-dlc call
+        let direct_result = ddk_dlc::make_funding_redeemscript(&offer_pk, &accept_pk);
+
+        assert_eq!(wrapper_result, direct_result.to_bytes());
+    }
+
+    #[test]
+    fn test_get_change_output_and_fees_asymmetric_collateral() {
+        let (_offer_sk, offer_pk, _accept_sk, _accept_pk) = create_test_keys();
+        let params = create_test_party_params(
+            150_000_000,
+            100_000_000, // offer collateral
+            offer_pk.serialize().to_vec(),
+            1,
+        );
+        let rust_params = party_params_to_rust(&params, params.collateral + 50_000_000, 4).unwrap();
+
+        let result = get_change_output_and_fees(params.clone(), 50_000_000, 4, 1_000).unwrap();
+        let direct_result = rust_params
+            .get_change_output_and_fees(Amount::from_sat(150_000_000), 4, Amount::from_sat(1_000))
+            .unwrap();
+        assert_eq!(result.fund_fee, direct_result.1.to_sat());
+        assert_eq!(result.cet_fee, direct_result.2.to_sat());
+        assert_eq!(result.change_output.value, direct_result.0.value.to_sat());
+
+        // A sole funder pays the full base weight, unlike the bilateral assumption
+        let sole_funder = get_change_output_and_fees(params.clone(), 0, 4, 0).unwrap();
+        let bilateral = get_change_output_and_fees(params, 100_000_000, 4, 0).unwrap();
+        assert!(sole_funder.fund_fee > bilateral.fund_fee);
+        assert!(sole_funder.cet_fee > bilateral.cet_fee);
+    }
+
+    #[test]
+    fn test_get_change_output_and_fees_wrapper() {
+        let (_offer_sk, offer_pk, _accept_sk, _accept_pk) = create_test_keys();
+
+        let params = create_test_party_params(
+            150_000_000, // 1.5 BTC input
+            100_000_000, // 1 BTC collateral
+            offer_pk.serialize().to_vec(),
+            1,
+        );
+
+        let result = get_change_output_and_fees(params.clone(), params.collateral, 4, 0);
+        assert!(result.is_ok());
+
+        let change_and_fees = result.unwrap();
+
+        // Verify we get reasonable values
+        assert!(change_and_fees.fund_fee > 0);
+        assert!(change_and_fees.cet_fee > 0);
+        assert!(change_and_fees.change_output.value > 0);
+
+        // Compare with direct rust-dlc call
+        let rust_params = party_params_to_rust(&params, params.collateral * 2, 4).unwrap();
+        let total_collateral = Amount::from_sat(params.collateral * 2);
+        let direct_result = rust_params
+            .get_change_output_and_fees(total_collateral, 4, Amount::ZERO)
+            .unwrap();
+
+        assert_eq!(change_and_fees.fund_fee, direct_result.1.to_sat());
+        assert_eq!(change_and_fees.cet_fee, direct_result.2.to_sat());
+        assert_eq!(
+            change_and_fees.change_output.value,
+            direct_result.0.value.to_sat()
+        );
+    }
+
+    #[test]
+    fn test_create_dlc_transactions_wrapper() {
+        let (_offer_sk, offer_pk, _accept_sk, accept_pk) = create_test_keys();
+
+        let offer_params = create_test_party_params(
+            1_000_000_000, // 10 BTC input
+            100_000_000,   // 1 BTC collateral
+            offer_pk.serialize().to_vec(),
+            1,
+        );
+
+        let accept_params = create_test_party_params(
+            1_000_000_000, // 10 BTC input
+            100_000_000,   // 1 BTC collateral
+            accept_pk.serialize().to_vec(),
+            2,
+        );
+
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000, // 2 BTC to offer
+                accept: 0,          // 0 BTC to accept
+            },
+            Payout {
+                offer: 0,            // 0 BTC to offer
+                accept: 200_000_000, // 2 BTC to accept
+            },
+        ];
+
+        let result = create_dlc_transactions(
+            outcomes,
+            offer_params,
+            accept_params,
+            100, // refund locktime
+            4,   // fee rate
+            10,  // fund lock time
+            10,  // cet lock time
+            0,   // fund output serial id
+            0,   // contract flags
+        );
+
+        assert!(result.is_ok());
+        let dlc_txs = result.unwrap();
+
+        // Verify structure
+        assert_eq!(dlc_txs.fund.lock_time, 10);
+        assert_eq!(dlc_txs.refund.lock_time, 100);
+        assert_eq!(dlc_txs.cets.len(), 2);
+        assert!(dlc_txs.cets.iter().all(|cet| cet.lock_time == 10));
+
+        // Verify funding transaction has correct structure
+        assert_eq!(dlc_txs.fund.inputs.len(), 2); // Two parties contributing
+        assert!(!dlc_txs.fund.outputs.is_empty()); // At least funding output
+
+        // Verify CETs have correct structure
+        for cet in &dlc_txs.cets {
+            assert_eq!(cet.inputs.len(), 1); // Single funding input
+            assert!(!cet.outputs.is_empty()); // At least one output (dust may be filtered)
+        }
+
+        // Verify refund transaction
+        assert_eq!(dlc_txs.refund.inputs.len(), 1); // Single funding input
+        assert!(dlc_txs.refund.outputs.len() >= 2); // At least two refund outputs
+    }
+
+    #[test]
+    fn test_create_cet_wrapper() {
+        let local_output = TxOutput {
+            value: 100_000_000, // 1 BTC
+            script_pubkey: vec![
+                0x00, 0x14, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+                0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14,
+            ],
+        };
+
+        let remote_output = TxOutput {
+            value: 100_000_000, // 1 BTC
+            script_pubkey: vec![
+                0x00, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
+                0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28,
+            ],
+        };
+
+        let result = create_cet(
+            local_output,
+            1,
+            remote_output,
+            2,
+            "0000000000000000000000000000000000000000000000000000000000000000".to_string(),
+            0,
+            10,
+        );
+
+        assert!(result.is_ok());
+        let cet = result.unwrap();
+
+        assert_eq!(cet.lock_time, 10);
+        assert_eq!(cet.inputs.len(), 1);
+        assert_eq!(cet.outputs.len(), 2);
+        assert_eq!(cet.outputs[0].value, 100_000_000);
+        assert_eq!(cet.outputs[1].value, 100_000_000);
+    }
+
+    #[test]
+    fn test_create_refund_transaction_wrapper() {
+        let local_script = vec![
+            0x00, 0x14, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+            0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14,
+        ];
+        let remote_script = vec![
+            0x00, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
+            0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28,
+        ];
+
+        let result = create_refund_transaction(
+            local_script,
+            remote_script,
+            100_000_000, // 1 BTC to local
+            100_000_000, // 1 BTC to remote
+            144,         // locktime (1 day in blocks)
+            "0000000000000000000000000000000000000000000000000000000000000000".to_string(),
+            0,
+        );
+
+        assert!(result.is_ok());
+        let refund_tx = result.unwrap();
+
+        assert_eq!(refund_tx.lock_time, 144);
+        assert_eq!(refund_tx.inputs.len(), 1);
+        assert_eq!(refund_tx.outputs.len(), 2);
+        assert_eq!(refund_tx.outputs[0].value, 100_000_000);
+        assert_eq!(refund_tx.outputs[1].value, 100_000_000);
+    }
+
+    #[test]
+    fn test_is_dust_output() {
+        let dust_output = TxOutput {
+            value: 500, // Below dust limit
+            script_pubkey: vec![],
+        };
+
+        let non_dust_output = TxOutput {
+            value: 5000, // Above dust limit
+            script_pubkey: vec![],
+        };
+
+        assert!(is_dust_output(dust_output));
+        assert!(!is_dust_output(non_dust_output));
+    }
+
+    #[test]
+    fn test_conversion_functions() {
+        let (_offer_sk, offer_pk, _accept_sk, _accept_pk) = create_test_keys();
+
+        // Test party params conversion
+        let params =
+            create_test_party_params(100_000_000, 50_000_000, offer_pk.serialize().to_vec(), 1);
+
+        let rust_params = party_params_to_rust(&params, 100_000_000, 4).unwrap();
+        assert_eq!(rust_params.fund_pubkey, offer_pk);
+        assert_eq!(rust_params.input_amount, Amount::from_sat(100_000_000));
+        assert_eq!(rust_params.collateral, Amount::from_sat(50_000_000));
+
+        // Test TX input conversion
+        let tx_input = TxInputInfo {
+            txid: "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456".to_string(),
+            vout: 0,
+            script_sig: vec![],
+            max_witness_length: 108,
+            serial_id: 1,
+        };
+
+        let rust_input = tx_input_info_to_rust(&tx_input).unwrap();
+        assert_eq!(rust_input.serial_id, 1);
+        assert_eq!(rust_input.max_witness_len, 108);
+        assert_eq!(rust_input.outpoint.vout, 0);
+    }
+
+    #[test]
+    fn test_transaction_bidirectional_conversion() {
+        // Create a test Bitcoin transaction
+        let btc_tx = BtcTransaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: LockTime::from_consensus(144),
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    txid: Txid::from_str(
+                        "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456",
+                    )
+                    .unwrap(),
+                    vout: 0,
+                },
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ZERO,
+                witness: Witness::new(),
+            }],
+            output: vec![BtcTxOut {
+                value: Amount::from_sat(100_000_000),
+                script_pubkey: ScriptBuf::from(vec![0x00, 0x14]),
+            }],
+        };
+
+        // Convert to UniFFI format and back
+        let uniffi_tx = btc_tx_to_transaction(&btc_tx);
+        let converted_back = transaction_to_btc_tx(&uniffi_tx).unwrap();
+
+        // Verify they're equivalent
+        assert_eq!(btc_tx.version, converted_back.version);
+        assert_eq!(btc_tx.lock_time, converted_back.lock_time);
+        assert_eq!(btc_tx.input.len(), converted_back.input.len());
+        assert_eq!(btc_tx.output.len(), converted_back.output.len());
+        assert_eq!(
+            btc_tx.input[0].previous_output,
+            converted_back.input[0].previous_output
+        );
+        assert_eq!(btc_tx.output[0].value, converted_back.output[0].value);
+    }
+
+    #[test]
+    fn test_error_handling_invalid_keys() {
+        // Test invalid public key
+        let result = create_fund_tx_locking_script(
+            vec![0u8; 20], // Invalid key length
+            vec![1u8; 33],
+        );
+        assert!(matches!(result, Err(DLCError::InvalidPublicKey)));
+
+        // Test invalid txid
+        let result = create_cet(
+            TxOutput {
+                value: 1000,
+                script_pubkey: vec![],
+            },
+            1,
+            TxOutput {
+                value: 1000,
+                script_pubkey: vec![],
+            },
+            2,
+            "invalid_txid".to_string(),
+            0,
+            0,
+        );
+        assert!(matches!(result, Err(DLCError::InvalidArgument(_))));
+    }
+
+    fn get_p2wpkh_script_pubkey(secp: &Secp256k1<All>) -> ScriptBuf {
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let sk = bitcoin::PrivateKey {
+            inner: SecretKey::new(&mut rng),
+            network: Network::Testnet.into(),
+            compressed: true,
+        };
+        let pk = CompressedPublicKey::from_private_key(secp, &sk).unwrap();
+        Address::p2wpkh(&pk, Network::Testnet).script_pubkey()
+    }
+
+    fn get_party_params(
+        input_amount: u64,
+        collateral: u64,
+        serial_id: Option<u64>,
+    ) -> (PartyParams, SecretKey) {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let fund_privkey = SecretKey::new(&mut rng);
+        let serial_id = serial_id.unwrap_or(1);
+        let mut input_txid = [0u8; 32];
+        rng.fill_bytes(&mut input_txid);
+        (
+            PartyParams {
+                fund_pubkey: PublicKey::from_secret_key(&secp, &fund_privkey)
+                    .serialize()
+                    .to_vec(),
+                change_script_pubkey: Some(get_p2wpkh_script_pubkey(&secp).into_bytes()),
+                change_serial_id: serial_id,
+                payout_script_pubkey: get_p2wpkh_script_pubkey(&secp).into_bytes(),
+                payout_serial_id: serial_id,
+                input_amount,
+                collateral,
+                inputs: vec![TxInputInfo {
+                    txid: Txid::from_byte_array(input_txid).to_string(),
+                    vout: 0,
+                    max_witness_length: 108,
+                    script_sig: vec![],
+                    serial_id,
+                }],
+                dlc_inputs: vec![],
+            },
+            fund_privkey,
+        )
+    }
+
+    /// Create an oracle with `nb_nonces` nonces, returning its info, keypair and
+    /// nonce secrets.
+    fn create_test_oracle(nb_nonces: usize) -> (OracleInfo, Keypair, Vec<[u8; 32]>) {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let oracle_kp = Keypair::new(&secp, &mut rng);
+        let sk_nonces = (0..nb_nonces)
+            .map(|_| {
+                let mut sk_nonce = [0u8; 32];
+                rng.fill_bytes(&mut sk_nonce);
+                sk_nonce
+            })
+            .collect::<Vec<_>>();
+        let nonces = sk_nonces
+            .iter()
+            .map(|sk_nonce| {
+                let nonce_kp = Keypair::from_seckey_slice(&secp, sk_nonce).unwrap();
+                XOnlyPublicKey::from_keypair(&nonce_kp)
+                    .0
+                    .serialize()
+                    .to_vec()
+            })
+            .collect();
+
+        (
+            OracleInfo {
+                public_key: oracle_kp.x_only_public_key().0.serialize().to_vec(),
+                nonces,
+            },
+            oracle_kp,
+            sk_nonces,
+        )
+    }
+
+    fn payouts_test() -> Vec<Payout> {
+        vec![
+            Payout {
+                offer: 100000000,
+                accept: 100000000,
+            },
+            Payout {
+                offer: 100000000,
+                accept: 100000000,
+            },
+            Payout {
+                offer: 100000000,
+                accept: 100000000,
+            },
+        ]
+    }
+
+    /// Both parties' params and keys, and the transactions of a contract over
+    /// [`payouts_test`] at 4 sat/vB.
+    struct TestContract {
+        offer_params: PartyParams,
+        offer_fund_sk: SecretKey,
+        accept_params: PartyParams,
+        accept_fund_sk: SecretKey,
+        dlc_txs: DlcTransactions,
+        fund_vout: u32,
+        fund_output_value: u64,
+    }
+
+    fn build_test_contract() -> TestContract {
+        let (offer_params, offer_fund_sk) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, accept_fund_sk) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_params.clone(),
+            accept_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+        let fund_vout = dlc_txs.refund.inputs[0].vout;
+        let fund_output_value = dlc_txs.fund.outputs[fund_vout as usize].value;
+
+        TestContract {
+            offer_params,
+            offer_fund_sk,
+            accept_params,
+            accept_fund_sk,
+            dlc_txs,
+            fund_vout,
+            fund_output_value,
+        }
+    }
+
+    /// Transaction with `input_count` inputs whose input at `input_index` spends
+    /// the funding output of `dlc_input`.
+    fn dlc_input_spend(
+        dlc_input: &DlcInputInfo,
+        input_index: u32,
+        input_count: u32,
+    ) -> Transaction {
+        let mut tx = transaction_to_btc_tx(&dummy_transaction(input_count, 1)).unwrap();
+        let fund_txid = transaction_to_btc_tx(&dlc_input.fund_tx)
+            .unwrap()
+            .compute_txid();
+        tx.input[input_index as usize].previous_output =
+            OutPoint::new(fund_txid, dlc_input.fund_vout);
+        btc_tx_to_transaction(&tx)
+    }
+
+    fn signatures_to_secret(signatures: &[Vec<SchnorrSignature>]) -> SecretKey {
+        let s_values = signatures
+            .iter()
+            .flatten()
+            .map(|x| secp_utils::schnorrsig_decompose(x).unwrap().1)
+            .collect::<Vec<_>>();
+        let secret = SecretKey::from_slice(s_values[0]).unwrap();
+
+        s_values.iter().skip(1).fold(secret, |accum, s| {
+            let sec = SecretKey::from_slice(s).unwrap();
+            accum.add_tweak(&Scalar::from(sec)).unwrap()
+        })
+    }
+
+    /// Verify a signature for a given transaction input.
+    fn verify_tx_input_sig(
+        signature: Vec<u8>,
+        tx: Transaction,
+        input_index: usize,
+        script_pubkey: Vec<u8>,
+        value: u64,
+        pk: Vec<u8>,
+    ) -> Result<(), DLCError> {
+        let secp = get_secp_context();
+        let btc_txn = transaction_to_btc_tx(&tx)?;
+        let script = ScriptBuf::from_bytes(script_pubkey);
+        let sig = EcdsaSignature::from_der(&signature).map_err(|_| DLCError::InvalidSignature)?;
+        let pk = PublicKey::from_slice(&pk).map_err(|_| DLCError::InvalidPublicKey)?;
+        ddk_dlc::verify_tx_input_sig(
+            secp,
+            &sig,
+            &btc_txn,
+            input_index,
+            &script,
+            Amount::from_sat(value),
+            &pk,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn create_cet_adaptor_sig_single_oracle_three_outcomes() {
+        // Arrange
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let (offer_party_params, offer_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_party_params, _accept_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, None);
+
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_party_params.clone(),
+            accept_party_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let cets = dlc_txs.cets;
+        const NB_ORACLES: usize = 1; // 1 oracle
+        const NB_OUTCOMES: usize = 3; // 3 outcomes (enumeration)
+        const NB_DIGITS: usize = 1; // 1 nonce for enumeration contract
+
+        let mut oracle_infos: Vec<OracleInfo> = Vec::with_capacity(NB_ORACLES);
+        let mut oracle_sks: Vec<Keypair> = Vec::with_capacity(NB_ORACLES);
+        let mut oracle_sk_nonce: Vec<Vec<[u8; 32]>> = Vec::with_capacity(NB_ORACLES);
+        let mut oracle_sigs: Vec<Vec<SchnorrSignature>> = Vec::with_capacity(NB_ORACLES);
+
+        // Messages: 3 outcomes × 1 oracle × 1 message per outcome
+        let messages: Vec<Vec<Vec<_>>> = (0..NB_OUTCOMES)
+            .map(|outcome_idx| {
+                vec![
+                    // Single oracle
+                    vec![
+                        // Single message for this outcome
+                        {
+                            let message = &[outcome_idx as u8]; // Different message per outcome
+                            let hash = sha256::Hash::hash(message).to_byte_array();
+                            hash.to_vec()
+                        },
+                    ],
+                ]
+            })
+            .collect();
+
+        // Setup single oracle with single nonce
+        for i in 0..NB_ORACLES {
+            // Runs once
+            let oracle_kp = Keypair::new(&secp, &mut rng);
+            let oracle_pubkey = oracle_kp.x_only_public_key().0;
+            let mut nonces: Vec<XOnlyPublicKey> = Vec::with_capacity(NB_DIGITS);
+            let mut sk_nonces: Vec<[u8; 32]> = Vec::with_capacity(NB_DIGITS);
+            oracle_sigs.push(Vec::with_capacity(NB_DIGITS));
+
+            // Single nonce for enumeration
+            let mut sk_nonce = [0u8; 32];
+            rng.fill_bytes(&mut sk_nonce);
+            let oracle_r_kp = Keypair::from_seckey_slice(&secp, &sk_nonce).unwrap();
+            let nonce = XOnlyPublicKey::from_keypair(&oracle_r_kp).0;
+
+            // Sign the first outcome's message with the single nonce
+            let sig = secp_utils::schnorrsig_sign_with_nonce(
+                &secp,
+                &Message::from_digest_slice(&messages[0][0][0]).unwrap(), // First outcome, first oracle, first message
+                &oracle_kp,
+                &sk_nonce,
+            );
+
+            oracle_sigs[i].push(sig);
+            nonces.push(nonce);
+            sk_nonces.push(sk_nonce);
+
+            oracle_infos.push(OracleInfo {
+                public_key: oracle_pubkey.serialize().to_vec(),
+                nonces: nonces.iter().map(|n| n.serialize().to_vec()).collect(), // Just 1 nonce
+            });
+            oracle_sk_nonce.push(sk_nonces);
+            oracle_sks.push(oracle_kp);
+        }
+        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
+            &PublicKey::from_slice(&offer_party_params.fund_pubkey.clone()).unwrap(),
+            &PublicKey::from_slice(&accept_party_params.fund_pubkey.clone()).unwrap(),
+        );
+        let fund_output_value = dlc_txs.fund.outputs[0].value;
+
+        // Act
+        let cet_sigs = create_cet_adaptor_sigs_from_oracle_info(
+            cets.clone(), // Use only first 3 CETs
+            oracle_infos.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            funding_script_pubkey.clone().into_bytes(),
+            fund_output_value,
+            messages.clone(),
+        )
+        .unwrap();
+
+        let oracle_signatures = oracle_sigs
+            .iter()
+            .map(|s| s.iter().map(|s| s.serialize().to_vec()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let sign_res = sign_cet(
+            cets[0].clone(),
+            cet_sigs[0].signature.clone(),
+            oracle_signatures[0].clone(),
+            _accept_fund_sk.secret_bytes().to_vec(),
+            offer_party_params.fund_pubkey.clone(),
+            accept_party_params.fund_pubkey.clone(),
+            fund_output_value,
+        );
+
+        assert!(sign_res.is_ok());
+
+        let adaptor_secret = signatures_to_secret(&oracle_sigs);
+        let signature = vec_to_ecdsa_adaptor_signature(cet_sigs[0].signature.clone()).unwrap();
+        let adapted_sig = signature.decrypt(&adaptor_secret).unwrap();
+
+        let batch_verify = verify_cet_adaptor_sigs_from_oracle_info(
+            cet_sigs.clone(),
+            cets.clone(),
+            oracle_infos.clone(),
+            offer_party_params.fund_pubkey.clone(),
+            funding_script_pubkey.clone().into_bytes(),
+            fund_output_value,
+            messages.clone(),
+        );
+
+        assert!(batch_verify);
+
+        // Assert
+        assert_eq!(cet_sigs.len(), 3, "Should have 3 CET signatures");
+        assert!(cet_sigs
+            .iter()
+            .enumerate()
+            .all(|(i, x)| verify_cet_adaptor_sig_from_oracle_info(
+                x.clone(),
+                cets[i].clone(),
+                oracle_infos.clone(),
+                offer_party_params.fund_pubkey.clone(),
+                funding_script_pubkey.clone().into_bytes(),
+                fund_output_value,
+                messages[i].clone(),
+            )));
+        sign_res.expect("Error signing CET");
+        verify_tx_input_sig(
+            adapted_sig.serialize_der().to_vec(),
+            cets[0].clone(),
+            0,
+            funding_script_pubkey.clone().into_bytes(),
+            fund_output_value,
+            offer_party_params.fund_pubkey.clone(),
+        )
+        .expect("Invalid decrypted adaptor signature");
+    }
+
+    #[test]
+    fn test_extract_ecdsa_signature_from_oracle_signatures() {
+        // Setup test data (similar to the main test)
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let (offer_party_params, offer_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_party_params, _accept_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, None);
+
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_party_params.clone(),
+            accept_party_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let cets = dlc_txs.cets;
+        const NB_ORACLES: usize = 1; // 1 oracle
+        const NB_OUTCOMES: usize = 3; // 3 outcomes (enumeration)
+        const NB_DIGITS: usize = 1; // 1 nonce for enumeration contract
+
+        let mut oracle_infos: Vec<OracleInfo> = Vec::with_capacity(NB_ORACLES);
+        let mut oracle_sks: Vec<Keypair> = Vec::with_capacity(NB_ORACLES);
+        let mut oracle_sk_nonce: Vec<Vec<[u8; 32]>> = Vec::with_capacity(NB_ORACLES);
+        let mut oracle_sigs: Vec<Vec<SchnorrSignature>> = Vec::with_capacity(NB_ORACLES);
+
+        // Messages: 3 outcomes × 1 oracle × 1 message per outcome
+        let messages: Vec<Vec<Vec<_>>> = (0..NB_OUTCOMES)
+            .map(|outcome_idx| {
+                vec![
+                    // Single oracle
+                    vec![
+                        // Single message for this outcome
+                        {
+                            let message = &[outcome_idx as u8]; // Different message per outcome
+                            let hash = sha256::Hash::hash(message).to_byte_array();
+                            hash.to_vec()
+                        },
+                    ],
+                ]
+            })
+            .collect();
+
+        // Setup single oracle with single nonce
+        for i in 0..NB_ORACLES {
+            // Runs once
+            let oracle_kp = Keypair::new(&secp, &mut rng);
+            let oracle_pubkey = oracle_kp.x_only_public_key().0;
+            let mut nonces: Vec<XOnlyPublicKey> = Vec::with_capacity(NB_DIGITS);
+            let mut sk_nonces: Vec<[u8; 32]> = Vec::with_capacity(NB_DIGITS);
+            oracle_sigs.push(Vec::with_capacity(NB_DIGITS));
+
+            // Single nonce for enumeration
+            let mut sk_nonce = [0u8; 32];
+            rng.fill_bytes(&mut sk_nonce);
+            let oracle_r_kp = Keypair::from_seckey_slice(&secp, &sk_nonce).unwrap();
+            let nonce = XOnlyPublicKey::from_keypair(&oracle_r_kp).0;
+
+            // Sign the first outcome's message with the single nonce
+            let sig = secp_utils::schnorrsig_sign_with_nonce(
+                &secp,
+                &Message::from_digest_slice(&messages[0][0][0]).unwrap(), // First outcome, first oracle, first message
+                &oracle_kp,
+                &sk_nonce,
+            );
+
+            oracle_sigs[i].push(sig);
+            nonces.push(nonce);
+            sk_nonces.push(sk_nonce);
+
+            oracle_infos.push(OracleInfo {
+                public_key: oracle_pubkey.serialize().to_vec(),
+                nonces: nonces.iter().map(|n| n.serialize().to_vec()).collect(), // Just 1 nonce
+            });
+            oracle_sk_nonce.push(sk_nonces);
+            oracle_sks.push(oracle_kp);
+        }
+
+        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
+            &PublicKey::from_slice(&offer_party_params.fund_pubkey.clone()).unwrap(),
+            &PublicKey::from_slice(&accept_party_params.fund_pubkey.clone()).unwrap(),
+        );
+        let fund_output_value = dlc_txs.fund.outputs[0].value;
+
+        // Create adaptor signatures
+        let cet_sigs = create_cet_adaptor_sigs_from_oracle_info(
+            cets.clone(),
+            oracle_infos.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            funding_script_pubkey.clone().into_bytes(),
+            fund_output_value,
+            messages.clone(),
+        )
+        .unwrap();
+
+        // Convert oracle signatures to the format expected by our function
+        let oracle_signatures = oracle_sigs
+            .iter()
+            .map(|s| s.iter().map(|s| s.serialize().to_vec()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // Test our new function
+        let result = extract_ecdsa_signature_from_oracle_signatures(
+            oracle_signatures[0].clone(),
+            cet_sigs[0].signature.clone(),
+        );
+
+        assert!(result.is_ok(), "Function should succeed");
+
+        let ecdsa_sig_bytes = result.unwrap();
+        assert!(
+            !ecdsa_sig_bytes.is_empty(),
+            "Should return non-empty signature"
+        );
+
+        // Verify the signature is valid DER format
+        let ecdsa_sig = EcdsaSignature::from_der(&ecdsa_sig_bytes);
+        assert!(ecdsa_sig.is_ok(), "Should be valid DER signature");
+    }
+
+    #[test]
+    fn test_get_cet_sighash() {
+        // Setup: Create DLC transactions to get a valid CET
+        let (offer_party_params, _offer_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_party_params, _accept_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, Some(2));
+
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_party_params.clone(),
+            accept_party_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let cet = dlc_txs.cets[0].clone();
+        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
+            &PublicKey::from_slice(&offer_party_params.fund_pubkey).unwrap(),
+            &PublicKey::from_slice(&accept_party_params.fund_pubkey).unwrap(),
+        );
+        let fund_output_value = dlc_txs.fund.outputs[0].value;
+
+        // Act: Get the sighash
+        let result = get_cet_sighash(
+            cet.clone(),
+            funding_script_pubkey.clone().into_bytes(),
+            fund_output_value,
+        );
+
+        // Assert
+        assert!(result.is_ok(), "get_cet_sighash should succeed");
+        let sighash = result.unwrap();
+        assert_eq!(sighash.len(), 32, "Sighash should be 32 bytes");
+
+        // Verify against direct ddk-dlc call
+        let btc_tx = transaction_to_btc_tx(&cet).unwrap();
+        let direct_sighash = ddk_dlc::util::get_sig_hash_msg(
+            &btc_tx,
+            0,
+            Script::from_bytes(&funding_script_pubkey.clone().into_bytes()),
+            Amount::from_sat(fund_output_value),
+        )
+        .unwrap();
+
+        assert_eq!(
+            sighash,
+            direct_sighash.as_ref().to_vec(),
+            "Sighash should match direct ddk-dlc calculation"
+        );
+    }
+
+    #[test]
+    fn test_get_cet_adaptor_signature_inputs() {
+        // Setup: Create DLC transactions and oracle info
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let (offer_party_params, _offer_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_party_params, _accept_fund_sk) =
+            get_party_params(1_000_000_000, 100_000_000, Some(2));
+
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_party_params.clone(),
+            accept_party_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let cet = dlc_txs.cets[0].clone();
+        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
+            &PublicKey::from_slice(&offer_party_params.fund_pubkey).unwrap(),
+            &PublicKey::from_slice(&accept_party_params.fund_pubkey).unwrap(),
+        );
+        let fund_output_value = dlc_txs.fund.outputs[0].value;
+
+        // Create oracle info (single oracle, single nonce for enumeration)
+        let oracle_kp = Keypair::new(&secp, &mut rng);
+        let oracle_pubkey = oracle_kp.x_only_public_key().0;
+        let mut sk_nonce = [0u8; 32];
+        rng.fill_bytes(&mut sk_nonce);
+        let oracle_r_kp = Keypair::from_seckey_slice(&secp, &sk_nonce).unwrap();
+        let nonce = XOnlyPublicKey::from_keypair(&oracle_r_kp).0;
+
+        let oracle_info = vec![OracleInfo {
+            public_key: oracle_pubkey.serialize().to_vec(),
+            nonces: vec![nonce.serialize().to_vec()],
+        }];
+
+        // Create message (first outcome)
+        let message = &[0u8];
+        let hash = sha256::Hash::hash(message).to_byte_array();
+        let msgs = vec![vec![hash.to_vec()]]; // Single oracle, single message
+
+        // Act: Get debug info
+        let result = get_cet_adaptor_signature_inputs(
+            cet.clone(),
+            oracle_info.clone(),
+            funding_script_pubkey.clone().into_bytes(),
+            fund_output_value,
+            msgs.clone(),
+        );
+
+        // Assert
+        assert!(
+            result.is_ok(),
+            "get_cet_adaptor_signature_inputs should succeed"
+        );
+        let debug_info = result.unwrap();
+
+        // Verify sighash
+        assert_eq!(debug_info.sighash.len(), 32, "Sighash should be 32 bytes");
+        let expected_sighash = get_cet_sighash(
+            cet.clone(),
+            funding_script_pubkey.clone().into_bytes(),
+            fund_output_value,
+        )
+        .unwrap();
+        assert_eq!(
+            debug_info.sighash, expected_sighash,
+            "Sighash should match get_cet_sighash result"
+        );
+
+        // Verify adaptor point
+        assert_eq!(
+            debug_info.adaptor_point.len(),
+            33,
+            "Adaptor point should be 33 bytes (compressed pubkey)"
+        );
+
+        // Verify input index is always 0 for CETs
+        assert_eq!(
+            debug_info.input_index, 0,
+            "Input index should always be 0 for CETs"
+        );
+
+        // Verify script_pubkey matches what we passed in
+        assert_eq!(
+            debug_info.script_pubkey,
+            funding_script_pubkey.clone().into_bytes(),
+            "Script pubkey should match input"
+        );
+
+        // Verify value matches
+        assert_eq!(
+            debug_info.value, fund_output_value,
+            "Value should match input"
+        );
+
+        // Verify cet_txid is valid
+        let btc_tx = transaction_to_btc_tx(&cet).unwrap();
+        assert_eq!(
+            debug_info.cet_txid,
+            btc_tx.compute_txid().to_string(),
+            "CET txid should match"
+        );
+
+        // Verify cet_raw matches input
+        assert_eq!(
+            debug_info.cet_raw, cet.raw_bytes,
+            "CET raw bytes should match input"
+        );
+    }
+
+    #[test]
+    fn test_get_cet_sighash_invalid_transaction() {
+        // Create an invalid transaction (empty raw_bytes)
+        let invalid_tx = Transaction {
+            version: 2,
+            lock_time: 0,
+            inputs: vec![],
+            outputs: vec![],
+            raw_bytes: vec![0x00], // Invalid serialization
+        };
+
+        let result = get_cet_sighash(invalid_tx, vec![0x00, 0x14], 100_000);
+
+        assert!(
+            result.is_err(),
+            "Should fail with invalid transaction bytes"
+        );
+    }
+
+    #[test]
+    fn test_get_cet_adaptor_signature_inputs_invalid_oracle_pubkey() {
+        // Setup valid CET
+        let (offer_party_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_party_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_party_params.clone(),
+            accept_party_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let cet = dlc_txs.cets[0].clone();
+        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
+            &PublicKey::from_slice(&offer_party_params.fund_pubkey).unwrap(),
+            &PublicKey::from_slice(&accept_party_params.fund_pubkey).unwrap(),
+        );
+
+        // Invalid oracle info (wrong pubkey length)
+        let invalid_oracle_info = vec![OracleInfo {
+            public_key: vec![0x00; 20], // Invalid: should be 32 bytes for x-only
+            nonces: vec![vec![0x00; 32]],
+        }];
+
+        let msgs = vec![vec![vec![0u8; 32]]];
+
+        let result = get_cet_adaptor_signature_inputs(
+            cet,
+            invalid_oracle_info,
+            funding_script_pubkey.into_bytes(),
+            100_000,
+            msgs,
+        );
+
+        assert!(
+            result.is_err(),
+            "Should fail with invalid oracle public key"
+        );
+    }
+
+    #[test]
+    fn test_create_dlc_transactions_rejects_duplicate_inputs() {
+        let (_offer_sk, offer_pk, _accept_sk, accept_pk) = create_test_keys();
+
+        let offer_params =
+            create_test_party_params(1_000_000_000, 100_000_000, offer_pk.serialize().to_vec(), 1);
+        let mut accept_params = create_test_party_params(
+            1_000_000_000,
+            100_000_000,
+            accept_pk.serialize().to_vec(),
+            2,
+        );
+        // Accept party lists the same outpoint as the offer party
+        accept_params.inputs[0].txid = offer_params.inputs[0].txid.clone();
+        accept_params.inputs[0].vout = offer_params.inputs[0].vout;
+
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 0,
+                accept: 200_000_000,
+            },
+        ];
+
+        let result =
+            create_dlc_transactions(outcomes, offer_params, accept_params, 100, 4, 10, 10, 0, 0);
+
+        assert!(matches!(
+            result,
+            Err(DLCError::InvalidArgument(msg)) if msg == "duplicate funding input"
+        ));
+    }
+
+    #[test]
+    fn test_contract_fingerprint_is_order_independent() {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 0,
+                accept: 200_000_000,
+            },
+            Payout {
+                offer: 100_000_000,
+                accept: 100_000_000,
+            },
+        ];
+        let dlc_txs =
+            create_dlc_transactions(outcomes, offer_params, accept_params, 100, 4, 10, 10, 0, 0)
+                .unwrap();
+
+        let oracle_infos = (0..2)
+            .map(|_| OracleInfo {
+                public_key: Keypair::new(&secp, &mut rng)
+                    .x_only_public_key()
+                    .0
+                    .serialize()
+                    .to_vec(),
+                nonces: vec![Keypair::new(&secp, &mut rng)
+                    .x_only_public_key()
+                    .0
+                    .serialize()
+                    .to_vec()],
+            })
+            .collect::<Vec<_>>();
+
+        let fingerprint = contract_fingerprint(dlc_txs.clone(), oracle_infos.clone()).unwrap();
+        assert_eq!(fingerprint.len(), 32);
+
+        // Same contract with CETs and oracles reordered and a signed funding tx
+        let mut reordered = dlc_txs.clone();
+        reordered.cets.reverse();
+        reordered.fund =
+            add_signature_to_transaction(reordered.fund, vec![1; 71], vec![2; 33], 0).unwrap();
+        let mut reordered_oracles = oracle_infos.clone();
+        reordered_oracles.reverse();
+        assert_eq!(
+            fingerprint,
+            contract_fingerprint(reordered, reordered_oracles).unwrap()
+        );
+
+        // Dropping a CET changes the contract
+        let mut different = dlc_txs.clone();
+        different.cets.pop();
+        assert_ne!(
+            fingerprint,
+            contract_fingerprint(different, oracle_infos.clone()).unwrap()
+        );
+
+        // An undecodable CET is an error rather than hashed as raw bytes
+        let mut corrupt = dlc_txs;
+        corrupt.cets[0].raw_bytes = vec![0xff; 4];
+        assert!(matches!(
+            contract_fingerprint(corrupt, oracle_infos),
+            Err(DLCError::SerializationError)
+        ));
+    }
+
+    #[test]
+    fn test_verify_funding_script_consistency() {
+        let TestContract { dlc_txs, .. } = build_test_contract();
+
+        assert!(verify_funding_script_consistency(dlc_txs.clone()).unwrap());
+
+        // Passing the output scriptPubKey instead of the witness script is inconsistent
+        let fund_tx = transaction_to_btc_tx(&dlc_txs.fund).unwrap();
+        let fund_vout = dlc_txs.refund.inputs[0].vout as usize;
+        let mut inconsistent = dlc_txs;
+        inconsistent.funding_script_pubkey = fund_tx.output[fund_vout].script_pubkey.to_bytes();
+        assert!(!verify_funding_script_consistency(inconsistent).unwrap());
+    }
+
+    #[test]
+    fn test_dummy_transaction_round_trip() {
+        let dummy = dummy_transaction(2, 3);
+        assert_eq!(dummy.inputs.len(), 2);
+        assert_eq!(dummy.outputs.len(), 3);
+        assert!(dummy.inputs.iter().all(|input| input.witness.is_empty()));
+
+        let btc_tx = transaction_to_btc_tx(&dummy).unwrap();
+        assert_eq!(btc_tx.input.len(), 2);
+        assert_eq!(btc_tx.output.len(), 3);
+        assert_eq!(btc_tx_to_transaction(&btc_tx).raw_bytes, dummy.raw_bytes);
+    }
+
+    #[test]
+    fn test_diff_cet_refund() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 100_000_000,
+                accept: 100_000_000,
+            },
+        ];
+        let dlc_txs = create_dlc_transactions(
+            outcomes,
+            offer_params.clone(),
+            accept_params,
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let winner_take_all = diff_cet_refund(dlc_txs.cets[0].clone(), dlc_txs.refund.clone());
+        assert!(winner_take_all.same_funding_outpoint);
+        assert!(!winner_take_all.identical_payouts);
+        assert_eq!(winner_take_all.cet_only_outputs.len(), 1);
+        assert_eq!(winner_take_all.cet_only_outputs[0].value, 200_000_000);
+        assert_eq!(
+            winner_take_all.cet_only_outputs[0].script_pubkey,
+            offer_params.payout_script_pubkey
+        );
+        assert_eq!(winner_take_all.refund_only_outputs.len(), 2);
+
+        let split = diff_cet_refund(dlc_txs.cets[1].clone(), dlc_txs.refund);
+        assert!(split.identical_payouts);
+        assert!(split.cet_only_outputs.is_empty());
+        assert!(split.refund_only_outputs.is_empty());
+    }
+
+    #[test]
+    fn test_create_dlc_transactions_without_change() {
+        let fee_rate = 4;
+        let (mut offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        offer_params.change_script_pubkey = None;
+
+        // Fund the offer party exactly with collateral plus fees
+        let (change_output, _, _) = convert_party_params(&offer_params)
+            .unwrap()
+            .get_change_output_and_fees(Amount::from_sat(200_000_000), fee_rate, Amount::ZERO)
+            .unwrap();
+        let mut exact_offer_params = offer_params.clone();
+        exact_offer_params.input_amount -= change_output.value.to_sat();
+
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            exact_offer_params,
+            accept_params.clone(),
+            100,
+            fee_rate,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        // Only the funding output and the accept party's change remain
+        assert_eq!(dlc_txs.fund.outputs.len(), 2);
+        assert!(dlc_txs
+            .fund
+            .outputs
+            .iter()
+            .all(|output| !output.script_pubkey.is_empty()));
+
+        // Leftover change without a change script would be burnt to fees
+        let result = create_dlc_transactions(
+            payouts_test(),
+            offer_params,
+            accept_params,
+            100,
+            fee_rate,
+            10,
+            10,
+            0,
+            0,
+        );
+        assert!(matches!(result, Err(DLCError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_create_spliced_dlc_transactions_validates_params() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+
+        // Splice the previous contract's funding output into a new one
+        let fund_vout = dlc_txs.refund.inputs[0].vout;
+        let mut spliced_offer_params = offer_params.clone();
+        spliced_offer_params.dlc_inputs = vec![DlcInputInfo {
+            fund_tx: dlc_txs.fund.clone(),
+            fund_vout,
+            local_fund_pubkey: offer_params.fund_pubkey.clone(),
+            remote_fund_pubkey: accept_params.fund_pubkey.clone(),
+            fund_amount: dlc_txs.fund.outputs[fund_vout as usize].value,
+            max_witness_len: 220,
+            input_serial_id: 3,
+            contract_id: vec![0; 32],
+        }];
+        spliced_offer_params.input_amount += dlc_txs.fund.outputs[fund_vout as usize].value;
+        let (spliced_accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(4));
+
+        assert!(create_spliced_dlc_transactions(
+            payouts_test(),
+            spliced_offer_params.clone(),
+            spliced_accept_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .is_ok());
+
+        // The refund must unlock after the CETs, as for a fresh contract
+        let result = create_spliced_dlc_transactions(
+            payouts_test(),
+            spliced_offer_params.clone(),
+            spliced_accept_params.clone(),
+            10,
+            4,
+            10,
+            10,
+            0,
+            0,
+        );
+        assert!(matches!(result, Err(DLCError::InvalidArgument(_))));
+
+        // Without a change script the leftover would go to an empty script
+        spliced_offer_params.change_script_pubkey = None;
+        let result = create_spliced_dlc_transactions(
+            payouts_test(),
+            spliced_offer_params,
+            spliced_accept_params,
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        );
+        assert!(matches!(result, Err(DLCError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_adaptor_sig_does_not_verify_for_modified_cet() {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let (offer_params, offer_fund_sk) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 0,
+                accept: 200_000_000,
+            },
+        ];
+        let dlc_txs = create_dlc_transactions(
+            outcomes,
+            offer_params.clone(),
+            accept_params,
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+        let fund_output_value = dlc_txs.fund.outputs[dlc_txs.refund.inputs[0].vout as usize].value;
+        let adaptor_point = PublicKey::from_secret_key(&secp, &SecretKey::new(&mut rng))
+            .serialize()
+            .to_vec();
+
+        let cet = dlc_txs.cets[0].clone();
+        let adaptor_sig = create_cet_adaptor_sigs_from_points(
+            vec![cet.clone()],
+            vec![adaptor_point.clone()],
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )
+        .unwrap()
+        .remove(0)
+        .signature;
+
+        assert!(verify_adaptor_sig_binds_cet(
+            adaptor_sig.clone(),
+            cet.clone(),
+            adaptor_point.clone(),
+            offer_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        ));
+
+        // Redirect part of the payout: same adaptor point, different CET
+        let mut modified = transaction_to_btc_tx(&cet).unwrap();
+        modified.output[0].value -= Amount::from_sat(10_000);
+        let modified = btc_tx_to_transaction(&modified);
+
+        assert!(!verify_adaptor_sig_binds_cet(
+            adaptor_sig,
+            modified,
+            adaptor_point,
+            offer_params.fund_pubkey,
+            dlc_txs.funding_script_pubkey,
+            fund_output_value,
+        ));
+    }
+
+    #[test]
+    fn test_message_template_fill_and_sign() {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let TestContract {
+            offer_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+
+        let nb_nonces = [1, 3];
+        let oracle_infos = nb_nonces
+            .iter()
+            .map(|nb| OracleInfo {
+                public_key: Keypair::new(&secp, &mut rng)
+                    .x_only_public_key()
+                    .0
+                    .serialize()
+                    .to_vec(),
+                nonces: (0..*nb)
+                    .map(|_| {
+                        Keypair::new(&secp, &mut rng)
+                            .x_only_public_key()
+                            .0
+                            .serialize()
+                            .to_vec()
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut msgs = message_template(oracle_infos.clone(), dlc_txs.cets.len() as u32).unwrap();
+        assert_eq!(msgs.len(), dlc_txs.cets.len());
+        for (cet_index, cet_msgs) in msgs.iter_mut().enumerate() {
+            assert_eq!(cet_msgs.len(), oracle_infos.len());
+            for (oracle_index, oracle_msgs) in cet_msgs.iter_mut().enumerate() {
+                assert_eq!(oracle_msgs.len(), nb_nonces[oracle_index]);
+                for (nonce_index, slot) in oracle_msgs.iter_mut().enumerate() {
+                    let message = format!("{cet_index}-{oracle_index}-{nonce_index}");
+                    *slot = sha256::Hash::hash(message.as_bytes())
+                        .to_byte_array()
+                        .to_vec();
+                }
+            }
+        }
+
+        let adaptor_sigs = create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            oracle_infos,
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey,
+            fund_output_value,
+            msgs,
+        )
+        .unwrap();
+        assert_eq!(adaptor_sigs.len(), dlc_txs.cets.len());
+
+        assert!(message_template(vec![], 1).is_err());
+    }
+
+    #[test]
+    fn test_estimate_contract_cost_base_two_twenty_digits() {
+        let cost = estimate_contract_cost(2, 20, 1).unwrap();
+        assert_eq!(cost.cet_count, 1_048_576);
+        assert_eq!(cost.transaction_bytes, 1_048_576 * APPROX_CET_SIZE);
+        assert_eq!(cost.adaptor_signing_operations, 1_048_576 * 20);
+
+        let multi_oracle = estimate_contract_cost(2, 20, 3).unwrap();
+        assert_eq!(
+            multi_oracle.adaptor_signing_operations,
+            cost.adaptor_signing_operations * 3
+        );
+
+        assert!(estimate_contract_cost(1, 20, 1).is_err());
+        assert!(estimate_contract_cost(10, 30, 1).is_err());
+    }
+
+    #[test]
+    fn test_rekey_contract() {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 50_000_000,
+                accept: 150_000_000,
+            },
+        ];
+        let original = create_dlc_transactions(
+            outcomes.clone(),
+            offer_params.clone(),
+            accept_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let new_fund_pubkey = PublicKey::from_secret_key(&secp, &SecretKey::new(&mut rng))
+            .serialize()
+            .to_vec();
+        let rekeyed = rekey_contract(
+            offer_params,
+            new_fund_pubkey.clone(),
+            accept_params.clone(),
+            outcomes,
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        let expected_script =
+            create_fund_tx_locking_script(new_fund_pubkey, accept_params.fund_pubkey.clone())
+                .unwrap();
+        assert_eq!(rekeyed.funding_script_pubkey, expected_script);
+        assert_ne!(
+            rekeyed.funding_script_pubkey,
+            original.funding_script_pubkey
+        );
+        assert!(verify_funding_script_consistency(rekeyed.clone()).unwrap());
+
+        assert_eq!(rekeyed.cets.len(), original.cets.len());
+        for (rekeyed_cet, original_cet) in rekeyed.cets.iter().zip(original.cets.iter()) {
+            let rekeyed_payouts = rekeyed_cet
+                .outputs
+                .iter()
+                .map(|output| (output.value, output.script_pubkey.clone()))
+                .collect::<Vec<_>>();
+            let original_payouts = original_cet
+                .outputs
+                .iter()
+                .map(|output| (output.value, output.script_pubkey.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(rekeyed_payouts, original_payouts);
+        }
+
+        let result = rekey_contract(
+            accept_params.clone(),
+            vec![0; 33],
+            accept_params,
+            payouts_test(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        );
+        assert!(matches!(result, Err(DLCError::InvalidPublicKey)));
+    }
+
+    #[test]
+    fn test_verify_adaptor_points_wellformed() {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let points = (0..3)
+            .map(|_| PublicKey::from_secret_key(&secp, &SecretKey::new(&mut rng)))
+            .collect::<Vec<_>>();
+        let compressed = points
+            .iter()
+            .map(|point| point.serialize().to_vec())
+            .collect::<Vec<_>>();
+        assert!(verify_adaptor_points_wellformed(compressed.clone()).unwrap());
+
+        let mut duplicated = compressed.clone();
+        duplicated.push(compressed[1].clone());
+        assert!(!verify_adaptor_points_wellformed(duplicated).unwrap());
+
+        let mut invalid = compressed.clone();
+        invalid[0][0] = 0x05;
+        assert!(!verify_adaptor_points_wellformed(invalid).unwrap());
+
+        let mut uncompressed = compressed;
+        uncompressed[2] = points[2].serialize_uncompressed().to_vec();
+        assert!(!verify_adaptor_points_wellformed(uncompressed).unwrap());
+
+        assert!(verify_adaptor_points_wellformed(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_estimate_cet_fee_taproot() {
+        let secp = Secp256k1::new();
+        let mut rng = secp256k1_zkp::rand::thread_rng();
+        let fee_rate = 4;
+        let (xonly, _) = Keypair::new(&secp, &mut rng).x_only_public_key();
+        let p2tr_script = ScriptBuf::new_p2tr(&secp, xonly, None);
+        assert_eq!(p2tr_script.len(), P2TR_SCRIPT_PUBKEY_LEN);
+
+        // Matches the per-party CET fee rust-dlc computes for a P2TR payout
+        let (mut params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        params.payout_script_pubkey = p2tr_script.into_bytes();
+        let taproot_party_fee =
+            get_change_output_and_fees(params.clone(), 100_000_000, fee_rate, 0)
+                .unwrap()
+                .cet_fee;
+        assert_eq!(estimate_cet_fee_taproot(fee_rate), taproot_party_fee * 2);
+
+        // P2TR outputs are larger than P2WPKH ones, so the fee is higher
+        params.payout_script_pubkey = get_p2wpkh_script_pubkey(&secp).into_bytes();
+        let p2wpkh_party_fee = get_change_output_and_fees(params, 100_000_000, fee_rate, 0)
+            .unwrap()
+            .cet_fee;
+        assert!(estimate_cet_fee_taproot(fee_rate) > p2wpkh_party_fee * 2);
+    }
+
+    #[test]
+    fn test_verify_funding_matches_params() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(600_000_000, 100_000_000, Some(2));
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_params.clone(),
+            accept_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+
+        assert!(verify_funding_matches_params(
+            dlc_txs.fund.clone(),
+            offer_params.clone(),
+            accept_params.clone(),
+            4,
+            0,
+        )
+        .unwrap());
+
+        // Swap the two change amounts
+        let mut tampered = transaction_to_btc_tx(&dlc_txs.fund).unwrap();
+        let change_indexes = tampered
+            .output
+            .iter()
+            .enumerate()
+            .filter(|(_, output)| !output.script_pubkey.is_p2wsh())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        assert_eq!(change_indexes.len(), 2);
+        let first_value = tampered.output[change_indexes[0]].value;
+        tampered.output[change_indexes[0]].value = tampered.output[change_indexes[1]].value;
+        tampered.output[change_indexes[1]].value = first_value;
+
+        assert!(!verify_funding_matches_params(
+            btc_tx_to_transaction(&tampered),
+            offer_params,
+            accept_params,
+            4,
+            0,
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_decrypt_and_verify_cet_signature() {
+        let secp = Secp256k1::new();
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(1);
+        let msgs = (0..dlc_txs.cets.len())
+            .map(|outcome| {
+                vec![vec![sha256::Hash::hash(&[outcome as u8])
+                    .to_byte_array()
+                    .to_vec()]]
+            })
+            .collect::<Vec<_>>();
+
+        let adaptor_sigs = create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            vec![oracle_info],
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        )
+        .unwrap();
+
+        // Oracle attests to the first outcome
+        let oracle_sig = secp_utils::schnorrsig_sign_with_nonce(
+            &secp,
+            &Message::from_digest_slice(&msgs[0][0][0]).unwrap(),
+            &oracle_kp,
+            &sk_nonces[0],
+        );
+        let oracle_signatures = vec![oracle_sig.serialize().to_vec()];
+
+        let signature = decrypt_and_verify_cet_signature(
+            adaptor_sigs[0].signature.clone(),
+            oracle_signatures.clone(),
+            dlc_txs.cets[0].clone(),
+            offer_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )
+        .unwrap();
+        verify_tx_input_sig(
+            signature,
+            dlc_txs.cets[0].clone(),
+            0,
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            offer_params.fund_pubkey.clone(),
+        )
+        .unwrap();
+
+        // The attestation does not unlock another outcome's adaptor signature:
+        // it decrypts, but the result does not verify
+        let result = decrypt_and_verify_cet_signature(
+            adaptor_sigs[1].signature.clone(),
+            oracle_signatures,
+            dlc_txs.cets[0].clone(),
+            offer_params.fund_pubkey,
+            dlc_txs.funding_script_pubkey,
+            fund_output_value,
+        );
+        assert!(matches!(result, Err(DLCError::InvalidSignature)));
+    }
+
+    #[test]
+    fn test_cet_nonce_dependencies_two_digits() {
+        let (oracle_info, _, _) = create_test_oracle(2);
+        let msgs = message_template(vec![oracle_info.clone()], 4).unwrap();
+
+        let dependencies = cet_nonce_dependencies(msgs, vec![oracle_info.clone()]).unwrap();
+        assert_eq!(dependencies.len(), 4);
+        for cet_nonces in &dependencies {
+            assert_eq!(cet_nonces.len(), 2);
+            assert_eq!(cet_nonces, &oracle_info.nonces);
+        }
+
+        // A CET covering a one-digit prefix only depends on the first nonce
+        let prefix_msgs = vec![vec![vec![vec![0u8; 32]]]];
+        let dependencies = cet_nonce_dependencies(prefix_msgs, vec![oracle_info.clone()]).unwrap();
+        assert_eq!(dependencies[0], vec![oracle_info.nonces[0].clone()]);
+
+        // More messages than nonces is rejected
+        let too_many = vec![vec![vec![vec![0u8; 32]; 3]]];
+        assert!(cet_nonce_dependencies(too_many, vec![oracle_info]).is_err());
+    }
+
+    #[test]
+    fn test_dlc_transactions_equal() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 0,
+                accept: 200_000_000,
+            },
+        ];
+        let build = |outcomes: Vec<Payout>, fee_rate: u64| {
+            create_dlc_transactions(
+                outcomes,
+                offer_params.clone(),
+                accept_params.clone(),
+                100,
+                fee_rate,
+                10,
+                10,
+                0,
+                0,
+            )
+            .unwrap()
+        };
+
+        let local_build = build(outcomes.clone(), 4);
+        let mut remote_build = build(outcomes.clone(), 4);
+        remote_build.fund =
+            add_signature_to_transaction(remote_build.fund, vec![1; 71], vec![2; 33], 0).unwrap();
+        assert!(dlc_transactions_equal(local_build.clone(), remote_build));
+
+        // Different fee rate changes the funding transaction
+        assert!(!dlc_transactions_equal(
+            local_build.clone(),
+            build(outcomes.clone(), 5)
+        ));
+
+        // Swapped outcomes produce the same CET set in a different order
+        let mut reversed = outcomes;
+        reversed.reverse();
+        assert!(!dlc_transactions_equal(
+            local_build.clone(),
+            build(reversed, 4)
+        ));
+
+        // Undecodable transactions are never equal, even to identical bytes
+        let mut corrupt = local_build;
+        corrupt.refund.raw_bytes = vec![0xff; 4];
+        assert!(!dlc_transactions_equal(corrupt.clone(), corrupt));
+    }
+
+    #[test]
+    fn test_create_cet_checked_rejects_overspend() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            fund_vout,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+        let fee_rate = 4;
+        let fund_tx_id = dlc_txs.refund.inputs[0].txid.clone();
+        let build = |local: u64, remote: u64| {
+            create_cet_checked(
+                TxOutput {
+                    value: local,
+                    script_pubkey: offer_params.payout_script_pubkey.clone(),
+                },
+                1,
+                TxOutput {
+                    value: remote,
+                    script_pubkey: accept_params.payout_script_pubkey.clone(),
+                },
+                2,
+                fund_tx_id.clone(),
+                fund_vout,
+                10,
+                fund_output_value,
+                fee_rate,
+            )
+        };
+
+        // The contract's own payouts leave the fee it reserved
+        let cet = build(150_000_000, 50_000_000).unwrap();
+        assert_eq!(cet.outputs.len(), 2);
+
+        // Payouts taking the whole fund output leave nothing for the CET fee
+        let cet_fee = fund_output_value - 200_000_000;
+        assert!(matches!(
+            build(150_000_000, 50_000_000 + cet_fee),
+            Err(DLCError::InsufficientFunds)
+        ));
+        assert!(matches!(
+            build(150_000_000, 50_000_001),
+            Err(DLCError::InsufficientFunds)
+        ));
+    }
+
+    #[test]
+    fn test_verify_multisig_fund_input_signature() {
+        let secp = Secp256k1::new();
+        let (offer_sk, offer_pk, _accept_sk, accept_pk) = create_test_keys();
+        let witness_script = ddk_dlc::make_funding_redeemscript(&offer_pk, &accept_pk);
+        let fund_tx = dummy_transaction(2, 1);
+        let input = fund_tx.inputs[1].clone();
+        let input_amount = 50_000_000;
+
+        let signature = ddk_dlc::util::get_raw_sig_for_tx_input(
+            &secp,
+            &transaction_to_btc_tx(&fund_tx).unwrap(),
+            1,
+            &witness_script,
+            Amount::from_sat(input_amount),
+            &offer_sk,
+        )
+        .unwrap()
+        .serialize_der()
+        .to_vec();
+
+        assert!(verify_multisig_fund_input_signature(
+            fund_tx.clone(),
+            signature.clone(),
+            offer_pk.serialize().to_vec(),
+            witness_script.to_bytes(),
+            input.txid.clone(),
+            input.vout,
+            input_amount,
+        )
+        .unwrap());
+
+        // Signature does not verify for the other multisig key
+        assert!(!verify_multisig_fund_input_signature(
+            fund_tx.clone(),
+            signature.clone(),
+            accept_pk.serialize().to_vec(),
+            witness_script.to_bytes(),
+            input.txid.clone(),
+            input.vout,
+            input_amount,
+        )
+        .unwrap());
+
+        // Nor against the P2WPKH script assumed by verify_fund_tx_signature
+        let p2wpkh = ScriptBuf::new_p2wpkh(&WPubkeyHash::hash(&offer_pk.serialize()));
+        assert!(!verify_multisig_fund_input_signature(
+            fund_tx,
+            signature,
+            offer_pk.serialize().to_vec(),
+            p2wpkh.to_bytes(),
+            input.txid,
+            input.vout,
+            input_amount,
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_preview_change() {
+        let secp = Secp256k1::new();
+        let (params, _) = get_party_params(0, 100_000_000, None);
+        let change_script = get_p2wpkh_script_pubkey(&secp).into_bytes();
+        let mut second_input = params.inputs[0].clone();
+        second_input.vout = 1;
+        second_input.serial_id = 5;
+        let inputs = vec![params.inputs[0].clone(), second_input];
+
+        // First input alone does not cover the collateral
+        let underfunded = preview_change(
+            inputs[..1].to_vec(),
+            vec![60_000_000],
+            100_000_000,
+            4,
+            change_script.clone(),
+        )
+        .unwrap();
+        assert!(underfunded < -40_000_000);
+
+        let funded = preview_change(
+            inputs.clone(),
+            vec![60_000_000, 60_000_000],
+            100_000_000,
+            4,
+            change_script.clone(),
+        )
+        .unwrap();
+        assert!(funded > 0 && funded < 20_000_000);
+
+        // Matches the change computed for equivalent party params
+        let mut full_params = params;
+        full_params.inputs = inputs.clone();
+        full_params.input_amount = 120_000_000;
+        full_params.change_script_pubkey = Some(change_script.clone());
+        full_params.payout_script_pubkey = change_script.clone();
+        let expected = get_change_output_and_fees(full_params, 100_000_000, 4, 0).unwrap();
+        assert_eq!(funded as u64, expected.change_output.value);
+
+        // Without collateral the inputs are all change
+        assert_eq!(
+            preview_change(
+                inputs.clone(),
+                vec![60_000_000, 60_000_000],
+                0,
+                4,
+                change_script.clone()
+            )
+            .unwrap(),
+            120_000_000
+        );
+
+        assert!(preview_change(inputs, vec![1], 100_000_000, 4, change_script).is_err());
+    }
+
+    #[test]
+    fn test_get_total_input_vsize_per_input() {
+        let p2wpkh = TxInputInfo {
+            txid: "0000000000000000000000000000000000000000000000000000000000000001".to_string(),
+            vout: 0,
+            script_sig: vec![],
+            max_witness_length: P2WPKH_WITNESS_SIZE as u32,
+            serial_id: 1,
+        };
+        // 40 + 1 + ceil(107 / 4)
+        assert_eq!(get_total_input_vsize(vec![p2wpkh.clone()]), 68);
+        assert_ne!(get_total_input_vsize(vec![p2wpkh.clone()]), 148);
+
+        let multisig = TxInputInfo {
+            max_witness_length: 220,
+            serial_id: 2,
+            ..p2wpkh.clone()
+        };
+        assert_eq!(get_total_input_vsize(vec![multisig.clone()]), 96);
+        assert_eq!(get_total_input_vsize(vec![p2wpkh.clone(), multisig]), 164);
+
+        // P2SH-P2WPKH carries its 20 byte redeem script in the scriptSig
+        let nested = TxInputInfo {
+            script_sig: vec![0; 20],
+            ..p2wpkh
+        };
+        // 40 + 1 + (2 + 20) + ceil(107 / 4)
+        assert_eq!(get_total_input_vsize(vec![nested]), 90);
+        assert_eq!(get_total_input_vsize(vec![]), 0);
+    }
+
+    fn settlement_state_fixture() -> (DlcTransactions, Vec<AdaptorSignature>, Vec<OracleInfo>) {
+        let TestContract {
+            offer_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+
+        let (oracle_info, _, _) = create_test_oracle(1);
+        let msgs = (0..dlc_txs.cets.len())
+            .map(|outcome| {
+                vec![vec![sha256::Hash::hash(&[outcome as u8])
+                    .to_byte_array()
+                    .to_vec()]]
+            })
+            .collect::<Vec<_>>();
+        let adaptor_sigs = create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs,
+        )
+        .unwrap();
+
+        (dlc_txs, adaptor_sigs, vec![oracle_info])
+    }
+
+    #[test]
+    fn test_settlement_state_round_trip() {
+        let (dlc_txs, adaptor_sigs, oracle_infos) = settlement_state_fixture();
+        let contract_id = vec![7; 32];
+
+        let bytes = serialize_settlement_state(
+            dlc_txs.clone(),
+            adaptor_sigs.clone(),
+            oracle_infos.clone(),
+            contract_id.clone(),
+        )
+        .unwrap();
+        assert_eq!(bytes[0], SETTLEMENT_STATE_VERSION);
+
+        let state = deserialize_settlement_state(bytes.clone()).unwrap();
+        assert_eq!(state.contract_id, contract_id);
+        assert!(dlc_transactions_equal(
+            state.dlc_transactions.clone(),
+            dlc_txs.clone()
+        ));
+        assert_eq!(
+            state.dlc_transactions.fund.raw_bytes,
+            dlc_txs.fund.raw_bytes
+        );
+        assert_eq!(
+            state.dlc_transactions.funding_script_pubkey,
+            dlc_txs.funding_script_pubkey
+        );
+        assert_eq!(state.adaptor_signatures.len(), adaptor_sigs.len());
+        for (restored, original) in state.adaptor_signatures.iter().zip(&adaptor_sigs) {
+            assert_eq!(restored.signature, original.signature);
+            assert_eq!(restored.proof, original.proof);
+        }
+        assert_eq!(state.oracle_infos[0].public_key, oracle_infos[0].public_key);
+        assert_eq!(state.oracle_infos[0].nonces, oracle_infos[0].nonces);
+
+        // Truncated and padded blobs are rejected
+        assert!(matches!(
+            deserialize_settlement_state(bytes[..bytes.len() - 1].to_vec()),
+            Err(DLCError::SerializationError)
+        ));
+        let mut padded = bytes;
+        padded.push(0);
+        assert!(matches!(
+            deserialize_settlement_state(padded),
+            Err(DLCError::SerializationError)
+        ));
+
+        assert!(matches!(
+            serialize_settlement_state(dlc_txs, adaptor_sigs, oracle_infos, vec![7; 31]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_settlement_state_rejects_unknown_version() {
+        let (dlc_txs, adaptor_sigs, oracle_infos) = settlement_state_fixture();
+        let mut bytes =
+            serialize_settlement_state(dlc_txs, adaptor_sigs, oracle_infos, vec![7; 32]).unwrap();
+        bytes[0] = SETTLEMENT_STATE_VERSION + 1;
+
+        assert!(matches!(
+            deserialize_settlement_state(bytes),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            deserialize_settlement_state(vec![]),
+            Err(DLCError::SerializationError)
+        ));
+    }
+
+    #[test]
+    fn test_create_cet_adaptor_points_one_per_cet() {
+        let (oracle_info, _, _) = create_test_oracle(1);
+        let msgs = (0..3u8)
+            .map(|outcome| {
+                vec![vec![sha256::Hash::hash(&[outcome])
+                    .to_byte_array()
+                    .to_vec()]]
+            })
+            .collect::<Vec<_>>();
+
+        let points = create_cet_adaptor_points_from_oracle_info(vec![oracle_info], msgs).unwrap();
+
+        assert_eq!(points.len(), 3);
+        assert!(points.iter().all(|point| point.len() == 33));
+        assert_ne!(points[0], points[1]);
+        assert_ne!(points[1], points[2]);
+        assert_ne!(points[0], points[2]);
+    }
+
+    #[test]
+    fn test_max_safe_fee_rate() {
+        let (local_params, _) = get_party_params(100_100_000, 100_000_000, None);
+        let (remote_params, _) = get_party_params(100_050_000, 100_000_000, Some(2));
+        let outcomes = payouts_test();
+
+        let fee_rate = max_safe_fee_rate(
+            local_params.clone(),
+            remote_params.clone(),
+            outcomes.clone(),
+        )
+        .unwrap();
+        assert!(fee_rate > 0);
+
+        let change = |params: &PartyParams, fee_rate: u64| {
+            get_change_output_and_fees(params.clone(), 100_000_000, fee_rate, 0)
+                .map(|result| result.change_output.value)
+        };
+        assert!(change(&local_params, fee_rate).unwrap() >= DUST_LIMIT);
+        assert!(change(&remote_params, fee_rate).unwrap() >= DUST_LIMIT);
+        // The remote party has less spare input, so it hits dust first
+        assert!(change(&remote_params, fee_rate + 1).unwrap() < DUST_LIMIT);
+
+        let dlc_txs = create_dlc_transactions(
+            outcomes.clone(),
+            local_params.clone(),
+            remote_params.clone(),
+            100,
+            fee_rate,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+        assert!(dlc_txs
+            .fund
+            .outputs
+            .iter()
+            .all(|output| output.value >= DUST_LIMIT));
+        assert_eq!(dlc_txs.fund.outputs.len(), 3);
+
+        let dlc_txs = create_dlc_transactions(
+            outcomes.clone(),
+            local_params.clone(),
+            remote_params.clone(),
+            100,
+            fee_rate + 1,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+        // The remote change became dust and was dropped
+        assert_eq!(dlc_txs.fund.outputs.len(), 2);
+
+        let dust_outcomes = vec![Payout {
+            offer: 199_999_500,
+            accept: 500,
+        }];
+        assert!(matches!(
+            max_safe_fee_rate(local_params, remote_params, dust_outcomes),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_sign_and_finalize_refund_transaction() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            accept_params,
+            accept_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+
+        let offer_sig = sign_refund_transaction(
+            dlc_txs.refund.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            accept_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )
+        .unwrap();
+        let accept_sig = sign_refund_transaction(
+            dlc_txs.refund.clone(),
+            accept_fund_sk.secret_bytes().to_vec(),
+            offer_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )
+        .unwrap();
+
+        let signed = finalize_refund_transaction(
+            dlc_txs.refund.clone(),
+            offer_sig.clone(),
+            accept_sig.clone(),
+            offer_params.fund_pubkey.clone(),
+            accept_params.fund_pubkey.clone(),
+        )
+        .unwrap();
+
+        let witness = &signed.inputs[0].witness;
+        assert_eq!(witness.len(), 4);
+        assert!(witness[0].is_empty());
+        assert_eq!(witness[3], dlc_txs.funding_script_pubkey);
+
+        // Signatures sit in the same order as the keys in the funding script
+        let (first, second) = if offer_params.fund_pubkey < accept_params.fund_pubkey {
+            (
+                (&offer_sig, &offer_params.fund_pubkey),
+                (&accept_sig, &accept_params.fund_pubkey),
+            )
+        } else {
+            (
+                (&accept_sig, &accept_params.fund_pubkey),
+                (&offer_sig, &offer_params.fund_pubkey),
+            )
+        };
+        for (witness_sig, (sig, pubkey)) in [(&witness[1], first), (&witness[2], second)] {
+            assert_eq!(&witness_sig[..witness_sig.len() - 1], sig.as_slice());
+            assert_eq!(
+                *witness_sig.last().unwrap(),
+                EcdsaSighashType::All.to_u32() as u8
+            );
+            verify_tx_input_sig(
+                sig.clone(),
+                signed.clone(),
+                0,
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+                pubkey.clone(),
+            )
+            .unwrap();
+        }
+
+        // Signing against a script that does not include our key is refused
+        assert!(matches!(
+            sign_refund_transaction(
+                dlc_txs.refund,
+                offer_fund_sk.secret_bytes().to_vec(),
+                offer_params.fund_pubkey.clone(),
+                dlc_txs.funding_script_pubkey,
+                fund_output_value,
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_inputs_unspent() {
+        let (mut params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let second_input = TxInputInfo {
+            txid: "0000000000000000000000000000000000000000000000000000000000000002".to_string(),
+            vout: 1,
+            ..params.inputs[0].clone()
+        };
+        params.inputs.push(second_input);
+
+        let outpoints = params
+            .inputs
+            .iter()
+            .map(|input| format!("{}:{}", input.txid, input.vout))
+            .collect::<Vec<_>>();
+        let unrelated =
+            "0000000000000000000000000000000000000000000000000000000000000003:0".to_string();
+
+        let mut known = outpoints.clone();
+        known.push(unrelated.clone());
+        assert!(verify_inputs_unspent(params.clone(), known).unwrap());
+
+        // The second input was spent
+        let known = vec![outpoints[0].clone(), unrelated];
+        assert!(!verify_inputs_unspent(params.clone(), known).unwrap());
+
+        assert!(matches!(
+            verify_inputs_unspent(params, vec!["not an outpoint".to_string()]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_refund_transaction_signature() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            accept_params,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+
+        let signature = sign_refund_transaction(
+            dlc_txs.refund.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            accept_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )
+        .unwrap();
+
+        let verify = |pubkey: &[u8], signature: &[u8]| {
+            verify_refund_transaction_signature(
+                dlc_txs.refund.clone(),
+                signature.to_vec(),
+                pubkey.to_vec(),
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+            )
+        };
+        assert!(verify(&offer_params.fund_pubkey, &signature).unwrap());
+        assert!(!verify(&accept_params.fund_pubkey, &signature).unwrap());
+        assert!(matches!(
+            verify(&offer_params.fund_pubkey, &[0x30; 10]),
+            Err(DLCError::InvalidSignature)
+        ));
+
+        // A refund spending more than the funding output is rejected
+        let mut btc_refund = transaction_to_btc_tx(&dlc_txs.refund).unwrap();
+        btc_refund.input.push(btc_refund.input[0].clone());
+        assert!(matches!(
+            verify_refund_transaction_signature(
+                btc_tx_to_transaction(&btc_refund),
+                signature,
+                offer_params.fund_pubkey,
+                dlc_txs.funding_script_pubkey,
+                fund_output_value,
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_dlc_input_sighash() {
+        let secp = get_secp_context();
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            accept_params,
+            accept_fund_sk,
+            dlc_txs,
+            fund_vout,
+            ..
+        } = build_test_contract();
+        let dlc_input = DlcInputInfo {
+            fund_tx: dlc_txs.fund.clone(),
+            fund_vout,
+            local_fund_pubkey: offer_params.fund_pubkey.clone(),
+            remote_fund_pubkey: accept_params.fund_pubkey.clone(),
+            fund_amount: dlc_txs.fund.outputs[fund_vout as usize].value,
+            max_witness_len: 220,
+            input_serial_id: 1,
+            contract_id: vec![0; 32],
+        };
+        // The DLC input does not have to sit at index fund_vout
+        let input_index = fund_vout + 1;
+        let txn = dlc_input_spend(&dlc_input, input_index, 3);
+
+        let sighash = dlc_input_sighash(txn.clone(), dlc_input.clone()).unwrap();
+        assert_eq!(sighash.len(), 32);
+
+        // An external signer signing the sighash matches rust-dlc's own signature
+        let external_sig = secp.sign_ecdsa_low_r(
+            &Message::from_digest_slice(&sighash).unwrap(),
+            &offer_fund_sk,
+        );
+        let external_sig = ddk_dlc::util::finalize_sig(&external_sig, EcdsaSighashType::All);
+        let rust_dlc_input = dlc_input_info_to_rust(&dlc_input).unwrap();
+        let btc_tx = transaction_to_btc_tx(&txn).unwrap();
+        let expected = ddk_dlc::dlc_input::create_dlc_funding_input_signature(
+            secp,
+            &btc_tx,
+            input_index as usize,
+            &rust_dlc_input,
+            &offer_fund_sk,
+        )
+        .unwrap();
+        assert_eq!(external_sig, expected);
+        ddk_dlc::dlc_input::verify_dlc_funding_input_signature(
+            secp,
+            &btc_tx,
+            input_index as usize,
+            &rust_dlc_input,
+            external_sig.clone(),
+            &PublicKey::from_secret_key(secp, &offer_fund_sk),
+        )
+        .unwrap();
+
+        // The counterparty combines it with their own signature
+        let signed = sign_multi_sig_input(
+            txn,
+            dlc_input.clone(),
+            accept_fund_sk.secret_bytes().to_vec(),
+            external_sig.clone(),
+        )
+        .unwrap();
+        let witness = &signed.inputs[input_index as usize].witness;
+        assert_eq!(witness.len(), 4);
+        assert!(witness.contains(&external_sig));
+
+        assert!(matches!(
+            dlc_input_sighash(dummy_transaction(3, 1), dlc_input),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_transaction_hex_round_trip() {
+        let TestContract { dlc_txs, .. } = build_test_contract();
+        let fund = add_signature_to_transaction(dlc_txs.fund, vec![1; 71], vec![2; 33], 0).unwrap();
+
+        let hex = transaction_to_hex(fund.clone());
+        assert_eq!(hex.len(), fund.raw_bytes.len() * 2);
+
+        let decoded = transaction_from_hex(hex.clone()).unwrap();
+        assert_eq!(decoded.raw_bytes, fund.raw_bytes);
+        assert_eq!(decoded.inputs[0].witness, fund.inputs[0].witness);
+        assert_eq!(transaction_to_hex(decoded), hex);
+
+        assert!(matches!(
+            transaction_from_hex("zz".to_string()),
+            Err(DLCError::SerializationError)
+        ));
+        assert!(matches!(
+            transaction_from_hex(hex[..hex.len() - 2].to_string()),
+            Err(DLCError::SerializationError)
+        ));
+    }
+
+    #[test]
+    fn test_party_params_serialization_round_trip() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            fund_vout,
+            ..
+        } = build_test_contract();
+        let mut params = offer_params.clone();
+        params.dlc_inputs = vec![DlcInputInfo {
+            fund_tx: dlc_txs.fund.clone(),
+            fund_vout,
+            local_fund_pubkey: offer_params.fund_pubkey.clone(),
+            remote_fund_pubkey: accept_params.fund_pubkey.clone(),
+            fund_amount: dlc_txs.fund.outputs[fund_vout as usize].value,
+            max_witness_len: 220,
+            input_serial_id: 3,
+            contract_id: vec![7; 32],
+        }];
+
+        let bytes = party_params_serialize(params.clone());
+        let decoded = party_params_deserialize(bytes.clone()).unwrap();
+        let diff = diff_party_params(params.clone(), decoded.clone());
+        assert!(
+            !(diff.fund_pubkey
+                || diff.change_script_pubkey
+                || diff.payout_script_pubkey
+                || diff.input_amount
+                || diff.collateral
+                || diff.inputs
+                || diff.dlc_inputs
+                || diff.serial_ids)
+        );
+        assert_eq!(
+            decoded.dlc_inputs[0].fund_tx.raw_bytes,
+            dlc_txs.fund.raw_bytes
+        );
+        assert_eq!(party_params_serialize(decoded), bytes);
+
+        assert!(matches!(
+            party_params_deserialize(bytes[..bytes.len() - 1].to_vec()),
+            Err(DLCError::SerializationError)
+        ));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            party_params_deserialize(trailing),
+            Err(DLCError::SerializationError)
+        ));
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] += 1;
+        assert!(matches!(
+            party_params_deserialize(unknown_version),
+            Err(DLCError::InvalidArgument(_))
+        ));
+
+        let mut bad_pubkey = params.clone();
+        bad_pubkey.fund_pubkey.pop();
+        assert!(matches!(
+            party_params_deserialize(party_params_serialize(bad_pubkey)),
+            Err(DLCError::InvalidPublicKey)
+        ));
+        let mut no_payout = params;
+        no_payout.payout_script_pubkey.clear();
+        assert!(matches!(
+            party_params_deserialize(party_params_serialize(no_payout)),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_adaptor_sig_bytes() {
+        let result = validate_adaptor_sig_bytes(vec![0; 100]);
+        assert!(
+            matches!(result, Err(DLCError::InvalidArgument(ref msg)) if msg == "adaptor signature must be 162 bytes, got 100")
+        );
+        assert!(validate_adaptor_sig_bytes(vec![0; 162]).is_ok());
+
+        // Parsing surfaces the length instead of a bare invalid signature
+        assert!(matches!(
+            vec_to_ecdsa_adaptor_signature(vec![0; 100]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_transaction_txid() {
+        let genesis_coinbase =
+            bitcoin::constants::genesis_block(Network::Bitcoin).txdata[0].clone();
+        let tx = btc_tx_to_transaction(&genesis_coinbase);
+        assert_eq!(
+            get_transaction_txid(tx.clone()).unwrap(),
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        );
+        // Without witnesses both ids are the same
+        assert_eq!(
+            get_transaction_wtxid(tx).unwrap(),
+            get_transaction_txid(btc_tx_to_transaction(&genesis_coinbase)).unwrap()
+        );
+
+        let TestContract { dlc_txs, .. } = build_test_contract();
+        let fund = add_signature_to_transaction(dlc_txs.fund, vec![1; 71], vec![2; 33], 0).unwrap();
+        let btc_fund = transaction_to_btc_tx(&fund).unwrap();
+        assert_eq!(
+            get_transaction_txid(fund.clone()).unwrap(),
+            btc_fund.compute_txid().to_string()
+        );
+        assert_eq!(
+            get_transaction_wtxid(fund.clone()).unwrap(),
+            btc_fund.compute_wtxid().to_string()
+        );
+        assert_ne!(
+            get_transaction_txid(fund.clone()).unwrap(),
+            get_transaction_wtxid(fund.clone()).unwrap()
+        );
+        // CETs spend the fund output by txid
+        assert_eq!(
+            get_transaction_txid(fund).unwrap(),
+            dlc_txs.cets[0].inputs[0].txid
+        );
+
+        let malformed = Transaction {
+            raw_bytes: vec![1, 2, 3],
+            ..btc_tx_to_transaction(&genesis_coinbase)
+        };
+        assert!(matches!(
+            get_transaction_txid(malformed.clone()),
+            Err(DLCError::SerializationError)
+        ));
+        assert!(matches!(
+            get_transaction_wtxid(malformed),
+            Err(DLCError::SerializationError)
+        ));
+    }
+
+    #[test]
+    fn test_validate_party_params() {
+        let secp = Secp256k1::new();
+        let (mut params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        validate_party_params(params.clone()).unwrap();
+
+        let (_, offer_pk, _, _) = create_test_keys();
+        let (xonly, _) = offer_pk.x_only_public_key();
+        params.payout_script_pubkey = ScriptBuf::new_p2tr(&secp, xonly, None).into_bytes();
+        validate_party_params(params.clone()).unwrap();
+
+        // Omitting the change script is allowed
+        params.change_script_pubkey = None;
+        validate_party_params(params.clone()).unwrap();
+
+        let bare_multisig = bitcoin::blockdata::script::Builder::new()
+            .push_int(1)
+            .push_key(&bitcoin::PublicKey::new(offer_pk))
+            .push_int(1)
+            .push_opcode(bitcoin::opcodes::all::OP_CHECKMULTISIG)
+            .into_script();
+        params.change_script_pubkey = Some(bare_multisig.to_bytes());
+        assert!(matches!(
+            validate_party_params(params.clone()),
+            Err(DLCError::InvalidArgument(msg)) if msg.starts_with("change_script_pubkey")
+        ));
+
+        params.change_script_pubkey = None;
+        params.payout_script_pubkey = bare_multisig.into_bytes();
+        assert!(matches!(
+            validate_party_params(params),
+            Err(DLCError::InvalidArgument(msg)) if msg.starts_with("payout_script_pubkey")
+        ));
+    }
+
+    #[test]
+    fn test_complete_dlc_flow_settles() {
+        let secp = Secp256k1::new();
+        let (offer_params, offer_fund_sk) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, accept_fund_sk) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 0,
+                accept: 200_000_000,
+            },
+            Payout {
+                offer: 100_000_000,
+                accept: 100_000_000,
+            },
+        ];
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(1);
+        let msgs = (0..outcomes.len())
+            .map(|outcome| {
+                vec![vec![sha256::Hash::hash(&[outcome as u8])
+                    .to_byte_array()
+                    .to_vec()]]
+            })
+            .collect::<Vec<_>>();
+
+        let contract = complete_dlc_flow(
+            offer_params.clone(),
+            accept_params.clone(),
+            outcomes,
+            vec![oracle_info],
+            msgs.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            accept_fund_sk.secret_bytes().to_vec(),
+            4,
+            100,
+            10,
+            10,
+            0,
+        )
+        .unwrap();
+        let dlc_txs = &contract.dlc_transactions;
+        assert_eq!(contract.offer_adaptor_signatures.len(), dlc_txs.cets.len());
+        assert_eq!(contract.accept_adaptor_signatures.len(), dlc_txs.cets.len());
+        assert_eq!(contract.signed_refund.inputs[0].witness.len(), 4);
+
+        // The oracle attests to the accept party winning, who then settles
+        let outcome = 1;
+        let oracle_sig = secp_utils::schnorrsig_sign_with_nonce(
+            &secp,
+            &Message::from_digest_slice(&msgs[outcome][0][0]).unwrap(),
+            &oracle_kp,
+            &sk_nonces[0],
+        );
+        let cet = sign_cet(
+            dlc_txs.cets[outcome].clone(),
+            contract.offer_adaptor_signatures[outcome].signature.clone(),
+            vec![oracle_sig.serialize().to_vec()],
+            accept_fund_sk.secret_bytes().to_vec(),
+            offer_params.fund_pubkey.clone(),
+            accept_params.fund_pubkey.clone(),
+            contract.fund_output_value,
+        )
+        .unwrap();
+        assert_eq!(
+            cet.outputs
+                .iter()
+                .find(|output| output.script_pubkey == accept_params.payout_script_pubkey)
+                .unwrap()
+                .value,
+            200_000_000
+        );
+
+        let witness = &cet.inputs[0].witness;
+        assert_eq!(witness.len(), 4);
+        let mut pubkeys = [
+            offer_params.fund_pubkey.clone(),
+            accept_params.fund_pubkey.clone(),
+        ];
+        pubkeys.sort();
+        for (sig, pubkey) in witness[1..3].iter().zip(pubkeys) {
+            verify_tx_input_sig(
+                sig[..sig.len() - 1].to_vec(),
+                cet.clone(),
+                0,
+                dlc_txs.funding_script_pubkey.clone(),
+                contract.fund_output_value,
+                pubkey,
+            )
+            .unwrap();
+        }
+
+        // Mismatched keys are caught before anything is returned
+        assert!(complete_dlc_flow(
+            offer_params,
+            accept_params,
+            payouts_test(),
+            vec![create_test_oracle(1).0],
+            msgs,
+            accept_fund_sk.secret_bytes().to_vec(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            4,
+            100,
+            10,
+            10,
+            0,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_compute_fees_match_change_output_and_fees() {
+        let (mut params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        assert_eq!(
+            params.inputs[0].max_witness_length as usize,
+            ddk_dlc::P2WPKH_WITNESS_SIZE
+        );
+
+        for fee_rate in [1, 4, 37] {
+            let fees =
+                get_change_output_and_fees(params.clone(), 100_000_000, fee_rate, 0).unwrap();
+            assert_eq!(compute_fund_fee(1, vec![], fee_rate), fees.fund_fee);
+            assert_eq!(
+                compute_cet_fee(params.payout_script_pubkey.len() as u32, fee_rate),
+                fees.cet_fee
+            );
+        }
+
+        // A second, larger multisig input
+        let multisig_input = TxInputInfo {
+            txid: "0000000000000000000000000000000000000000000000000000000000000002".to_string(),
+            max_witness_length: 220,
+            ..params.inputs[0].clone()
+        };
+        params.inputs.push(multisig_input);
+        let fees = get_change_output_and_fees(params.clone(), 100_000_000, 4, 0).unwrap();
+        assert_eq!(compute_fund_fee(1, vec![220], 4), fees.fund_fee);
+        assert!(compute_fund_fee(1, vec![220], 4) > compute_fund_fee(2, vec![], 4));
+
+        // P2TR payouts cost more than P2WPKH ones
+        assert!(
+            compute_cet_fee(P2TR_SCRIPT_PUBKEY_LEN as u32, 4)
+                > compute_cet_fee(P2WPKH_SCRIPT_PUBKEY_LEN as u32, 4)
+        );
 
-/// Create master extended private key from 64-byte seed
-/// Returns 78-byte encoded xpriv
-pub fn create_extkey_from_seed(seed: Vec<u8>, network: String) -> Result<Vec<u8>, DLCError> {
-    if seed.len() != 64 {
-        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+        // The fund output of a built contract reserves both parties' CET fees
+        let TestContract {
+            offer_params,
+            accept_params,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+        assert_eq!(
+            fund_output_value - 200_000_000,
+            compute_cet_fee(offer_params.payout_script_pubkey.len() as u32, 4)
+                + compute_cet_fee(accept_params.payout_script_pubkey.len() as u32, 4)
+        );
     }
-    let network = Network::from_str(&network).map_err(|_| DLCError::InvalidNetwork)?;
-    let xpriv = Xpriv::new_master(network, &seed)
-        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
-    Ok(xpriv.encode().to_vec())
-}
 
-/// Derive child extended private key from parent extended key
-/// Input: 78-byte encoded xpriv, Output: 78-byte encoded xpriv
-pub fn create_extkey_from_parent_path(extkey: Vec<u8>, path: String) -> Result<Vec<u8>, DLCError> {
-    if extkey.len() != 78 {
-        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
-    }
+    #[test]
+    fn test_validate_oracle_count() {
+        let oracle_infos = (0..3).map(|_| create_test_oracle(1).0).collect::<Vec<_>>();
+        validate_oracle_count(oracle_infos.clone(), 3).unwrap();
 
-    let secp = get_secp_context();
-    let xpriv =
-        Xpriv::decode(&extkey).map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+        let result = validate_oracle_count(oracle_infos[..2].to_vec(), 3);
+        assert!(
+            matches!(result, Err(DLCError::InvalidArgument(ref msg)) if msg == "Contract requires 3 oracle infos, got 2")
+        );
+        assert!(matches!(
+            validate_oracle_count(vec![], 0),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
 
-    let derivation_path = path
-        .into_derivation_path()
-        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidDerivationPath))?;
+    #[test]
+    fn test_adaptor_signature_serialize_parse() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+        let (oracle_info, _, _) = create_test_oracle(1);
+        let msgs = vec![sha256::Hash::hash(&[0]).to_byte_array().to_vec()];
 
-    let derived_xpriv = xpriv
-        .derive_priv(secp, &derivation_path)
-        .map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+        let adaptor_sig = create_cet_adaptor_signature_from_oracle_info(
+            dlc_txs.cets[0].clone(),
+            oracle_info.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        )
+        .unwrap();
 
-    Ok(derived_xpriv.encode().to_vec())
-}
+        let bytes = adaptor_signature_serialize(adaptor_sig.clone());
+        assert_eq!(bytes.len(), ADAPTOR_SIGNATURE_SIZE);
+
+        let parsed = adaptor_signature_parse(bytes.clone()).unwrap();
+        assert_eq!(parsed.signature, adaptor_sig.signature);
+        assert_eq!(adaptor_signature_serialize(parsed.clone()), bytes);
+        assert!(verify_cet_adaptor_sig_from_oracle_info(
+            parsed,
+            dlc_txs.cets[0].clone(),
+            vec![oracle_info],
+            offer_params.fund_pubkey,
+            dlc_txs.funding_script_pubkey,
+            fund_output_value,
+            vec![msgs],
+        ));
 
-/// Extract public key from extended key (private or public)
-/// Input: 78-byte encoded xpriv/xpub, Output: 33-byte compressed public key
-pub fn get_pubkey_from_extkey(extkey: Vec<u8>, network: String) -> Result<Vec<u8>, DLCError> {
-    if extkey.len() != 78 {
-        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+        assert!(matches!(
+            adaptor_signature_parse(bytes[..100].to_vec()),
+            Err(DLCError::InvalidArgument(_))
+        ));
     }
 
-    let secp = get_secp_context();
-    let _network = Network::from_str(&network).map_err(|_| DLCError::InvalidNetwork)?;
+    #[test]
+    fn test_get_transaction_txid_ignores_witnesses() {
+        let TestContract { dlc_txs, .. } = build_test_contract();
+        let unsigned = dlc_txs.fund;
+        let signed =
+            add_signature_to_transaction(unsigned.clone(), vec![1; 71], vec![2; 33], 0).unwrap();
+        assert_ne!(signed.raw_bytes, unsigned.raw_bytes);
 
-    // Try as xpriv first
-    if let Ok(xpriv) = Xpriv::decode(&extkey) {
-        let xpub = Xpub::from_priv(secp, &xpriv);
-        return Ok(xpub.public_key.serialize().to_vec());
+        assert_eq!(
+            get_transaction_txid(signed.clone()).unwrap(),
+            get_transaction_txid(unsigned.clone()).unwrap()
+        );
+        assert_ne!(
+            get_transaction_wtxid(signed).unwrap(),
+            get_transaction_wtxid(unsigned).unwrap()
+        );
     }
 
-    // Try as xpub
-    if let Ok(xpub) = Xpub::decode(&extkey) {
-        return Ok(xpub.public_key.serialize().to_vec());
+    #[test]
+    fn test_decrypt_cet_adaptor_signature() {
+        let secp = Secp256k1::new();
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+
+        // Single nonce and two nonces (e.g. two digits of a numeric outcome)
+        for nb_nonces in [1, 2] {
+            let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(nb_nonces);
+            let msgs = (0..nb_nonces)
+                .map(|i| sha256::Hash::hash(&[i as u8]).to_byte_array().to_vec())
+                .collect::<Vec<_>>();
+            let adaptor_sig = create_cet_adaptor_signature_from_oracle_info(
+                dlc_txs.cets[0].clone(),
+                oracle_info,
+                offer_fund_sk.secret_bytes().to_vec(),
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+                msgs.clone(),
+            )
+            .unwrap();
+            let oracle_signatures = msgs
+                .iter()
+                .zip(&sk_nonces)
+                .map(|(msg, sk_nonce)| {
+                    secp_utils::schnorrsig_sign_with_nonce(
+                        &secp,
+                        &Message::from_digest_slice(msg).unwrap(),
+                        &oracle_kp,
+                        sk_nonce,
+                    )
+                    .serialize()
+                    .to_vec()
+                })
+                .collect::<Vec<_>>();
+
+            let signature =
+                decrypt_cet_adaptor_signature(adaptor_sig.signature.clone(), oracle_signatures)
+                    .unwrap();
+            verify_tx_input_sig(
+                signature,
+                dlc_txs.cets[0].clone(),
+                0,
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+                offer_params.fund_pubkey.clone(),
+            )
+            .unwrap();
+
+            assert!(matches!(
+                decrypt_cet_adaptor_signature(adaptor_sig.signature, vec![]),
+                Err(DLCError::InvalidArgument(_))
+            ));
+        }
     }
 
-    Err(DLCError::KeyError(ExtendedKey::InvalidXpriv))
-}
+    #[test]
+    fn test_create_dlc_transactions_batch() {
+        let requests = (0..10u64)
+            .map(|i| {
+                let (local_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+                let (remote_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+                DlcTransactionRequest {
+                    outcomes: payouts_test(),
+                    local_params,
+                    remote_params,
+                    refund_locktime: 100,
+                    fee_rate: 4 + i,
+                    fund_lock_time: 10,
+                    cet_lock_time: 10,
+                    fund_output_serial_id: 0,
+                    contract_flags: 0,
+                }
+            })
+            .collect::<Vec<_>>();
 
-/// DEPRECATED: Use create_extkey_from_seed + create_extkey_from_parent_path instead
-/// This function handles both seeds (64 bytes) and xprivs (78 bytes) which is confusing
-#[deprecated(
-    since = "0.4.0",
-    note = "Use create_extkey_from_seed + create_extkey_from_parent_path"
-)]
-pub fn create_xpriv_from_parent_path(
-    seed_or_xpriv: Vec<u8>,
-    base_derivation_path: String,
-    network: String,
-    path: String,
-) -> Result<Vec<u8>, DLCError> {
-    let master_xpriv = if seed_or_xpriv.len() == 64 {
-        // This is a seed, create master xpriv
-        create_extkey_from_seed(seed_or_xpriv, network.clone())?
-    } else if seed_or_xpriv.len() == 78 {
-        // This is already an xpriv
-        seed_or_xpriv
-    } else {
-        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
-    };
+        let batch = create_dlc_transactions_batch(requests.clone()).unwrap();
+        assert_eq!(batch.len(), 10);
+        for (dlc_txs, request) in batch.iter().zip(&requests) {
+            let expected = create_dlc_transactions(
+                request.outcomes.clone(),
+                request.local_params.clone(),
+                request.remote_params.clone(),
+                request.refund_locktime,
+                request.fee_rate,
+                request.fund_lock_time,
+                request.cet_lock_time,
+                request.fund_output_serial_id,
+                request.contract_flags,
+            )
+            .unwrap();
+            assert!(dlc_transactions_equal(dlc_txs.clone(), expected));
+        }
 
-    // Derive base path from master
-    let base_xpriv =
-        create_extkey_from_parent_path(master_xpriv, base_derivation_path.replace("m/", ""))?;
+        // Underfunding the second contract fails the whole batch
+        let mut underfunded = requests.clone();
+        underfunded[1].local_params.input_amount = 1_000;
+        let result = create_dlc_transactions_batch(underfunded);
+        assert!(
+            matches!(result, Err(DLCError::InvalidArgument(ref msg)) if msg.starts_with("Contract 1:"))
+        );
 
-    // Derive final path from base
-    create_extkey_from_parent_path(base_xpriv, path)
-}
+        // So do invalid params, before any contract is built
+        let mut invalid = requests;
+        invalid[7].remote_params.fund_pubkey = vec![2; 10];
+        let result = create_dlc_transactions_batch(invalid);
+        assert!(
+            matches!(result, Err(DLCError::InvalidArgument(ref msg)) if msg.starts_with("Contract 7:"))
+        );
+    }
 
-/// Convert extended private key to extended public key
-/// Input: 78-byte encoded xpriv, Output: 78-byte encoded xpub
-pub fn get_xpub_from_xpriv(xpriv: Vec<u8>, network: String) -> Result<Vec<u8>, DLCError> {
-    if xpriv.len() != 78 {
-        return Err(DLCError::KeyError(ExtendedKey::InvalidXpriv));
+    #[test]
+    fn test_recover_adaptor_secret() {
+        let secp = Secp256k1::new();
+        let (signing_sk, _, _, _) = create_test_keys();
+        let adaptor_secret = SecretKey::from_slice(&[7u8; 32]).unwrap();
+        let adaptor_point = PublicKey::from_secret_key(&secp, &adaptor_secret);
+        let msg = Message::from_digest_slice(&[3u8; 32]).unwrap();
+        let adaptor_sig = EcdsaAdaptorSignature::encrypt(&secp, &msg, &signing_sk, &adaptor_point);
+        let final_sig = adaptor_sig.decrypt(&adaptor_secret).unwrap();
+
+        for encoded in [
+            final_sig.serialize_der().to_vec(),
+            final_sig.serialize_compact().to_vec(),
+        ] {
+            let recovered = recover_adaptor_secret(
+                adaptor_sig.as_ref().to_vec(),
+                encoded,
+                adaptor_point.serialize().to_vec(),
+            )
+            .unwrap();
+            assert_eq!(recovered, adaptor_secret.secret_bytes().to_vec());
+        }
+
+        // A signature that is not the decryption of the adaptor signature
+        let other_sig = secp.sign_ecdsa(&msg, &signing_sk);
+        assert!(recover_adaptor_secret(
+            adaptor_sig.as_ref().to_vec(),
+            other_sig.serialize_der().to_vec(),
+            adaptor_point.serialize().to_vec(),
+        )
+        .is_err());
     }
 
-    let secp = get_secp_context();
-    let _network = Network::from_str(&network).map_err(|_| DLCError::InvalidNetwork)?;
+    #[test]
+    fn test_create_cet_adaptor_signature_multi_oracle() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+
+        let oracle_infos = vec![create_test_oracle(2).0, create_test_oracle(2).0];
+        let msgs = (0..2u8)
+            .map(|oracle| {
+                (0..2u8)
+                    .map(|nonce| {
+                        sha256::Hash::hash(&[oracle, nonce])
+                            .to_byte_array()
+                            .to_vec()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
 
-    let xpriv = Xpriv::decode(&xpriv).map_err(|_| DLCError::KeyError(ExtendedKey::InvalidXpriv))?;
+        let adaptor_sig = create_cet_adaptor_signature_multi_oracle(
+            dlc_txs.cets[0].clone(),
+            oracle_infos.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        )
+        .unwrap();
+        assert!(verify_cet_adaptor_sig_from_oracle_info(
+            adaptor_sig,
+            dlc_txs.cets[0].clone(),
+            oracle_infos.clone(),
+            offer_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        ));
 
-    let xpub = Xpub::from_priv(secp, &xpriv);
-    Ok(xpub.encode().to_vec())
-}
+        // One message group missing
+        let result = create_cet_adaptor_signature_multi_oracle(
+            dlc_txs.cets[0].clone(),
+            oracle_infos,
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs[..1].to_vec(),
+        );
+        assert!(matches!(result, Err(DLCError::InvalidArgument(_))));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use bitcoin::bip32::DerivationPath;
-    use bitcoin::{hashes::sha256, locktime::absolute::LockTime, Address, CompressedPublicKey};
-    use ddk_dlc::secp_utils;
-    use secp256k1_zkp::{
-        rand::{thread_rng, RngCore},
-        Keypair, Scalar,
-    };
-    use std::str::FromStr;
+    #[test]
+    fn test_verify_refund_payouts() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let verify = |refund: Transaction, fee_tolerance: u64| {
+            verify_refund_payouts(
+                refund,
+                offer_params.payout_script_pubkey.clone(),
+                offer_params.collateral,
+                accept_params.payout_script_pubkey.clone(),
+                accept_params.collateral,
+                fee_tolerance,
+            )
+            .unwrap()
+        };
 
-    /// Create test keys similar to rust-dlc tests
-    fn create_test_keys() -> (SecretKey, PublicKey, SecretKey, PublicKey) {
-        let secp = Secp256k1::new();
-        let offer_sk =
-            SecretKey::from_str("0000000000000000000000000000000000000000000000000000000000000001")
-                .unwrap();
-        let offer_pk = PublicKey::from_secret_key(&secp, &offer_sk);
-        let accept_sk =
-            SecretKey::from_str("0000000000000000000000000000000000000000000000000000000000000002")
-                .unwrap();
-        let accept_pk = PublicKey::from_secret_key(&secp, &accept_sk);
-        (offer_sk, offer_pk, accept_sk, accept_pk)
+        assert!(verify(dlc_txs.refund.clone(), 0));
+
+        // Short-changing a party is only accepted within the tolerance
+        let mut tampered = dlc_txs.refund.clone();
+        let local_output = tampered
+            .outputs
+            .iter_mut()
+            .find(|output| output.script_pubkey == offer_params.payout_script_pubkey)
+            .unwrap();
+        local_output.value -= 500;
+        assert!(!verify(tampered.clone(), 499));
+        assert!(verify(tampered, 500));
+
+        // Overpaying a party is never accepted
+        let mut tampered = dlc_txs.refund.clone();
+        tampered.outputs[0].value += 1;
+        assert!(!verify(tampered, 1_000));
+
+        // Outputs whose sum overflows are rejected rather than wrapped
+        let mut tampered = dlc_txs.refund.clone();
+        let mut duplicate = tampered.outputs[0].clone();
+        duplicate.value = u64::MAX;
+        tampered.outputs.push(duplicate);
+        let result = verify_refund_payouts(
+            tampered,
+            offer_params.payout_script_pubkey.clone(),
+            offer_params.collateral,
+            accept_params.payout_script_pubkey.clone(),
+            accept_params.collateral,
+            0,
+        );
+        assert!(matches!(result, Err(DLCError::InvalidTransaction)));
     }
 
-    /// Create realistic party params for testing
-    fn create_test_party_params(
-        input_amount: u64,
-        collateral: u64,
-        fund_pubkey: Vec<u8>,
-        serial_id: u64,
-    ) -> PartyParams {
-        let mut rng = thread_rng();
+    #[test]
+    fn test_funding_signing_plan() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let outpoint = |input: &TxInputInfo| format!("{}:{}", input.txid, input.vout);
+        let owned = offer_params.inputs.iter().map(outpoint).collect::<Vec<_>>();
+
+        let plan =
+            funding_signing_plan(dlc_txs.fund.clone(), owned.clone(), vec![1_000_000_000]).unwrap();
+        assert_eq!(plan.len(), owned.len());
+        let step = &plan[0];
+        let fund_input = &dlc_txs.fund.inputs[step.input_index as usize];
+        assert_eq!(format!("{}:{}", fund_input.txid, fund_input.vout), owned[0]);
+        assert_eq!(step.script_type, "witness");
+        assert_eq!(step.amount, 1_000_000_000);
+
+        // Wrapped inputs are classified by their redeem script
+        let wrapped = |redeem_script: ScriptBuf| {
+            let mut fund = transaction_to_btc_tx(&dlc_txs.fund).unwrap();
+            // Single push of the redeem script
+            fund.input[step.input_index as usize].script_sig = ScriptBuf::from_bytes(
+                [vec![redeem_script.len() as u8], redeem_script.to_bytes()].concat(),
+            );
+            funding_signing_plan(
+                btc_tx_to_transaction(&fund),
+                owned.clone(),
+                vec![1_000_000_000],
+            )
+            .unwrap()[0]
+                .script_type
+                .clone()
+        };
+        assert_eq!(
+            wrapped(ScriptBuf::new_p2wpkh(&WPubkeyHash::all_zeros())),
+            "p2sh-p2wpkh"
+        );
+        assert_eq!(
+            wrapped(ScriptBuf::new_p2wsh(&bitcoin::WScriptHash::all_zeros())),
+            "p2sh-p2wsh"
+        );
+        assert_eq!(wrapped(ScriptBuf::from_bytes(vec![0x51])), "p2sh");
 
-        // Create a realistic P2WPKH script
-        let mut random_hash = [0u8; 20];
-        rng.fill_bytes(&mut random_hash);
-        let mut change_script = vec![0x00, 0x14]; // OP_0 + 20 bytes (P2WPKH)
-        change_script.extend_from_slice(&random_hash);
+        // Every input is planned once when a single party owns them all
+        let all = owned
+            .iter()
+            .cloned()
+            .chain(accept_params.inputs.iter().map(outpoint))
+            .collect::<Vec<_>>();
+        let plan = funding_signing_plan(dlc_txs.fund.clone(), all, vec![1_000_000_000; 2]).unwrap();
+        let indexes = plan.iter().map(|step| step.input_index).collect::<Vec<_>>();
+        assert_eq!(
+            indexes,
+            (0..dlc_txs.fund.inputs.len() as u32).collect::<Vec<_>>()
+        );
 
-        rng.fill_bytes(&mut random_hash);
-        let mut payout_script = vec![0x00, 0x14]; // OP_0 + 20 bytes (P2WPKH)
-        payout_script.extend_from_slice(&random_hash);
+        // Outpoints the transaction does not spend and missing amounts are rejected
+        let foreign = format!("{}:7", "11".repeat(32));
+        assert!(funding_signing_plan(dlc_txs.fund.clone(), vec![foreign], vec![1]).is_err());
+        assert!(funding_signing_plan(dlc_txs.fund, owned, vec![]).is_err());
+    }
 
-        PartyParams {
-            fund_pubkey,
-            change_script_pubkey: change_script,
-            change_serial_id: serial_id + 1,
-            payout_script_pubkey: payout_script,
-            payout_serial_id: serial_id + 2,
-            inputs: vec![TxInputInfo {
-                txid: "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"
-                    .to_string(),
-                vout: serial_id as u32,
-                script_sig: vec![],
-                max_witness_length: 108,
-                serial_id,
-            }],
-            input_amount,
-            collateral,
-            dlc_inputs: vec![],
-        }
+    #[test]
+    fn test_sign_cet_checked() {
+        let secp = Secp256k1::new();
+        let (offer_params, offer_fund_sk) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, accept_fund_sk) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(1);
+        let msgs = (0..payouts_test().len())
+            .map(|outcome| {
+                vec![vec![sha256::Hash::hash(&[outcome as u8])
+                    .to_byte_array()
+                    .to_vec()]]
+            })
+            .collect::<Vec<_>>();
+        let contract = complete_dlc_flow(
+            offer_params.clone(),
+            accept_params.clone(),
+            payouts_test(),
+            vec![oracle_info.clone()],
+            msgs.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            accept_fund_sk.secret_bytes().to_vec(),
+            4,
+            100,
+            10,
+            10,
+            0,
+        )
+        .unwrap();
+        let dlc_txs = &contract.dlc_transactions;
+
+        let outcome = 1;
+        let oracle_sig = secp_utils::schnorrsig_sign_with_nonce(
+            &secp,
+            &Message::from_digest_slice(&msgs[outcome][0][0]).unwrap(),
+            &oracle_kp,
+            &sk_nonces[0],
+        );
+        let sign = |adaptor_signature: Vec<u8>| {
+            sign_cet(
+                dlc_txs.cets[outcome].clone(),
+                adaptor_signature,
+                vec![oracle_sig.serialize().to_vec()],
+                accept_fund_sk.secret_bytes().to_vec(),
+                offer_params.fund_pubkey.clone(),
+                accept_params.fund_pubkey.clone(),
+                contract.fund_output_value,
+            )
+        };
+        let sign_checked = |adaptor_signature: Vec<u8>| {
+            sign_cet_checked(
+                dlc_txs.cets[outcome].clone(),
+                adaptor_signature,
+                vec![oracle_sig.serialize().to_vec()],
+                accept_fund_sk.secret_bytes().to_vec(),
+                offer_params.fund_pubkey.clone(),
+                accept_params.fund_pubkey.clone(),
+                contract.fund_output_value,
+                vec![oracle_info.clone()],
+                msgs[outcome].clone(),
+            )
+        };
+
+        let good_sig = contract.offer_adaptor_signatures[outcome].signature.clone();
+        let unchecked = sign(good_sig.clone()).unwrap();
+        let checked = sign_checked(good_sig).unwrap();
+        assert_eq!(checked.raw_bytes, unchecked.raw_bytes);
+
+        // The adaptor signature of another CET yields an invalid witness unchecked
+        let tampered = contract.offer_adaptor_signatures[0].signature.clone();
+        let cet = sign(tampered.clone()).unwrap();
+        let offer_sig = cet.inputs[0].witness.iter().find(|sig| {
+            verify_tx_input_sig(
+                sig[..sig.len().saturating_sub(1)].to_vec(),
+                cet.clone(),
+                0,
+                dlc_txs.funding_script_pubkey.clone(),
+                contract.fund_output_value,
+                offer_params.fund_pubkey.clone(),
+            )
+            .is_ok()
+        });
+        assert!(offer_sig.is_none());
+        assert!(matches!(
+            sign_checked(tampered),
+            Err(DLCError::InvalidSignature)
+        ));
     }
 
     #[test]
-    fn mnemonic_to_seed_test() {
-        let mnemonic = Mnemonic::generate(24).unwrap();
-        let rust_seed = mnemonic.to_seed_normalized("").to_vec();
-        let ffi_seed = convert_mnemonic_to_seed(mnemonic.to_string(), None).unwrap();
-        assert_eq!(rust_seed, ffi_seed);
+    fn test_verify_collateral_invariant() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let fee_rate = 4;
+        let verify = |dlc_txs: DlcTransactions| {
+            verify_collateral_invariant(
+                dlc_txs,
+                offer_params.clone(),
+                accept_params.clone(),
+                fee_rate,
+            )
+            .unwrap()
+        };
+        assert!(verify(dlc_txs.clone()));
+
+        // Funding output short by the fund fee of both parties
+        let fund_fee = |params: &PartyParams, other_collateral: u64| {
+            get_change_output_and_fees(params.clone(), other_collateral, fee_rate, 0)
+                .unwrap()
+                .fund_fee
+        };
+        let fund_fee = fund_fee(&offer_params, accept_params.collateral)
+            + fund_fee(&accept_params, offer_params.collateral);
+        let mut short = dlc_txs.clone();
+        let fund_vout = short.refund.inputs[0].vout as usize;
+        short.fund.outputs[fund_vout].value -= fund_fee;
+        assert!(!verify(short));
+
+        // Funding output holding more than the collateral and CET fee reserve
+        let mut over_funded = dlc_txs.clone();
+        over_funded.fund.outputs[fund_vout].value += 1;
+        assert!(!verify(over_funded));
+
+        // A refund that does not give back the collateral
+        let mut short_refund = dlc_txs;
+        short_refund.refund.outputs[0].value -= 1;
+        assert!(!verify(short_refund));
     }
 
     #[test]
-    fn xpriv_to_xpub_test() {
-        let mnemonic = Mnemonic::generate(24).unwrap();
-        let rust_xpriv =
-            Xpriv::new_master(Network::Bitcoin, &mnemonic.to_seed_normalized("").to_vec()).unwrap();
-        let ffi_xpriv = create_extkey_from_seed(
-            mnemonic.to_seed_normalized("").to_vec(),
-            "bitcoin".to_string(),
+    fn test_outcome_signature_point() {
+        let secp = Secp256k1::new();
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(1);
+        let msg = sha256::Hash::hash(b"outcome").to_byte_array().to_vec();
+
+        let point = outcome_signature_point(
+            oracle_info.public_key.clone(),
+            oracle_info.nonces[0].clone(),
+            msg.clone(),
         )
         .unwrap();
-        let rust_xpub = Xpub::from_priv(get_secp_context(), &rust_xpriv);
-        let ffi_xpub = get_xpub_from_xpriv(ffi_xpriv, "bitcoin".to_string()).unwrap();
-        assert_eq!(rust_xpub.encode().to_vec(), ffi_xpub);
-    }
-
-    #[test]
-    fn xpriv_to_path() {
-        let base_derivation_path = "84'/0'/0'";
-        let app_path = "0/1";
-        let network = "bitcoin";
-        let secp = get_secp_context();
 
-        let mnemonic = Mnemonic::generate(24).unwrap();
-        let rust_xpriv =
-            Xpriv::new_master(Network::Bitcoin, &mnemonic.to_seed_normalized("")).unwrap();
-        let rust_path =
-            DerivationPath::from_str(&format!("{}/{}", base_derivation_path, app_path)).unwrap();
-        let rust_xpriv = rust_xpriv.derive_priv(&secp, &rust_path).unwrap();
+        let attestation = secp_utils::schnorrsig_sign_with_nonce(
+            &secp,
+            &Message::from_digest_slice(&msg).unwrap(),
+            &oracle_kp,
+            &sk_nonces[0],
+        );
+        let (_, s_value) = secp_utils::schnorrsig_decompose(&attestation).unwrap();
+        let s = SecretKey::from_slice(s_value).unwrap();
+        assert_eq!(
+            point,
+            PublicKey::from_secret_key(&secp, &s).serialize().to_vec()
+        );
 
-        let ffi_xpriv_bytes = convert_mnemonic_to_seed(mnemonic.to_string(), None).unwrap();
-        let ffi_xpub = create_xpriv_from_parent_path(
-            ffi_xpriv_bytes,
-            base_derivation_path.to_string(),
-            network.to_string(),
-            app_path.to_string(),
+        // A different message commits to a different point
+        let other = outcome_signature_point(
+            oracle_info.public_key,
+            oracle_info.nonces[0].clone(),
+            sha256::Hash::hash(b"other").to_byte_array().to_vec(),
         )
         .unwrap();
-        assert_eq!(rust_xpriv.encode().to_vec(), ffi_xpub);
+        assert_ne!(point, other);
     }
 
     #[test]
-    fn test_create_fund_tx_locking_script_matches_rust_dlc() {
-        let (_offer_sk, offer_pk, _accept_sk, accept_pk) = create_test_keys();
+    fn test_validate_mnemonic() {
+        let valid = format!("{} about", "abandon ".repeat(11).trim_end());
+        assert!(validate_mnemonic(valid).is_ok());
 
-        // Test our wrapper
-        let wrapper_result = create_fund_tx_locking_script(
-            offer_pk.serialize().to_vec(),
-            accept_pk.serialize().to_vec(),
-        )
-        .unwrap();
+        let typo = format!("{} abandn about", "abandon ".repeat(10).trim_end());
+        let Err(DLCError::InvalidArgument(msg)) = validate_mnemonic(typo) else {
+            panic!("expected an unknown word error");
+        };
+        assert!(msg.contains("abandn") && msg.contains("position 11"));
 
-        // Compare with direct rust-dlc call
-        let direct_result = ddk_dlc::make_funding_redeemscript(&offer_pk, &accept_pk);
+        // Every word is in the wordlist but the last one breaks the checksum
+        let bad_checksum = "abandon ".repeat(12).trim_end().to_string();
+        let Err(DLCError::InvalidArgument(msg)) = validate_mnemonic(bad_checksum) else {
+            panic!("expected a checksum error");
+        };
+        assert!(msg.contains("checksum"));
 
-        assert_eq!(wrapper_result, direct_result.to_bytes());
+        assert!(matches!(
+            validate_mnemonic("abandon about".to_string()),
+            Err(DLCError::InvalidArgument(_))
+        ));
     }
 
     #[test]
-    fn test_get_change_output_and_fees_wrapper() {
-        let (_offer_sk, offer_pk, _accept_sk, _accept_pk) = create_test_keys();
+    fn test_transactions_equivalent() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let build = |offer_serial_id: u64, fund_output_serial_id: u64| {
+            let mut offer_params = offer_params.clone();
+            offer_params.inputs[0].serial_id = offer_serial_id;
+            offer_params.change_serial_id = offer_serial_id;
+            create_dlc_transactions(
+                payouts_test(),
+                offer_params,
+                accept_params.clone(),
+                100,
+                4,
+                10,
+                10,
+                fund_output_serial_id,
+                0,
+            )
+            .unwrap()
+            .fund
+        };
 
-        let params = create_test_party_params(
-            150_000_000, // 1.5 BTC input
-            100_000_000, // 1 BTC collateral
-            offer_pk.serialize().to_vec(),
-            1,
-        );
+        // Same inputs and outputs, serialized in a different order
+        let fund = build(0, 0);
+        let reordered = build(10, 5);
+        assert_ne!(fund.raw_bytes, reordered.raw_bytes);
+        assert!(transactions_equivalent(fund.clone(), reordered));
 
-        let result = get_change_output_and_fees(params.clone(), 4);
-        assert!(result.is_ok());
+        // Changing an output amount breaks equivalence
+        let mut tx = transaction_to_btc_tx(&fund).unwrap();
+        tx.output[0].value += Amount::from_sat(1);
+        assert!(!transactions_equivalent(fund, btc_tx_to_transaction(&tx)));
+    }
 
-        let change_and_fees = result.unwrap();
+    #[test]
+    fn test_derive_pubkey_from_seed() {
+        let seed = Mnemonic::generate(24)
+            .unwrap()
+            .to_seed_normalized("")
+            .to_vec();
+        let path = "m/84'/0'/0'/0/0".to_string();
 
-        // Verify we get reasonable values
-        assert!(change_and_fees.fund_fee > 0);
-        assert!(change_and_fees.cet_fee > 0);
-        assert!(change_and_fees.change_output.value > 0);
+        let master = create_extkey_from_seed(seed.clone(), "bitcoin".to_string()).unwrap();
+        let child = create_extkey_from_parent_path(master, path.clone()).unwrap();
+        let expected = get_pubkey_from_extkey(child, "bitcoin".to_string()).unwrap();
 
-        // Compare with direct rust-dlc call
-        let rust_params = party_params_to_rust(&params).unwrap();
-        let total_collateral = Amount::from_sat(params.collateral * 2);
-        let direct_result = rust_params
-            .get_change_output_and_fees(total_collateral, 4, Amount::ZERO)
-            .unwrap();
+        let pubkey = derive_pubkey_from_seed(seed.clone(), "bitcoin".to_string(), path).unwrap();
+        assert_eq!(pubkey.len(), 33);
+        assert_eq!(pubkey, expected);
 
-        assert_eq!(change_and_fees.fund_fee, direct_result.1.to_sat());
-        assert_eq!(change_and_fees.cet_fee, direct_result.2.to_sat());
-        assert_eq!(
-            change_and_fees.change_output.value,
-            direct_result.0.value.to_sat()
+        assert!(
+            derive_pubkey_from_seed(seed[..32].to_vec(), "bitcoin".to_string(), "m/0".into())
+                .is_err()
         );
+        assert!(matches!(
+            derive_pubkey_from_seed(seed, "bitcoin".to_string(), "m/x".to_string()),
+            Err(DLCError::KeyError(ExtendedKey::InvalidDerivationPath))
+        ));
     }
 
     #[test]
-    fn test_create_dlc_transactions_wrapper() {
-        let (_offer_sk, offer_pk, _accept_sk, accept_pk) = create_test_keys();
+    fn test_safe_fund_output_serial_id() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let used = [&offer_params, &accept_params]
+            .into_iter()
+            .flat_map(|params| {
+                [params.change_serial_id, params.payout_serial_id]
+                    .into_iter()
+                    .chain(params.inputs.iter().map(|input| input.serial_id))
+            })
+            .collect::<Vec<_>>();
 
-        let offer_params = create_test_party_params(
-            1_000_000_000, // 10 BTC input
-            100_000_000,   // 1 BTC collateral
-            offer_pk.serialize().to_vec(),
-            1,
+        let serial_id = safe_fund_output_serial_id(offer_params.clone(), accept_params.clone());
+        assert!(!used.contains(&serial_id));
+        // Both parties derive the same id whatever side they are on
+        assert_eq!(
+            serial_id,
+            safe_fund_output_serial_id(accept_params.clone(), offer_params.clone())
         );
 
-        let accept_params = create_test_party_params(
-            1_000_000_000, // 10 BTC input
-            100_000_000,   // 1 BTC collateral
-            accept_pk.serialize().to_vec(),
-            2,
+        // Still distinct when a party already uses the derived id
+        let mut colliding = offer_params.clone();
+        colliding.payout_serial_id = serial_id;
+        let other = safe_fund_output_serial_id(colliding.clone(), accept_params.clone());
+        assert_ne!(other, serial_id);
+        assert!(!used.contains(&other));
+
+        assert!(create_dlc_transactions(
+            payouts_test(),
+            offer_params,
+            accept_params,
+            100,
+            4,
+            10,
+            10,
+            serial_id,
+            0,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_derive_privkey_from_seed() {
+        let secp = Secp256k1::new();
+        let seed = Mnemonic::generate(24)
+            .unwrap()
+            .to_seed_normalized("")
+            .to_vec();
+        let path = "m/84'/0'/0'/0/0".to_string();
+
+        let privkey =
+            derive_privkey_from_seed(seed.clone(), "bitcoin".to_string(), path.clone()).unwrap();
+        assert_eq!(privkey.len(), 32);
+        let pubkey = PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&privkey).unwrap());
+        assert_eq!(
+            pubkey.serialize().to_vec(),
+            derive_pubkey_from_seed(seed.clone(), "bitcoin".to_string(), path).unwrap()
         );
 
-        let outcomes = vec![
-            Payout {
-                offer: 200_000_000, // 2 BTC to offer
-                accept: 0,          // 0 BTC to accept
-            },
-            Payout {
-                offer: 0,            // 0 BTC to offer
-                accept: 200_000_000, // 2 BTC to accept
-            },
-        ];
+        assert!(
+            derive_privkey_from_seed(seed[..32].to_vec(), "bitcoin".to_string(), "m/0".into())
+                .is_err()
+        );
+        assert!(matches!(
+            derive_privkey_from_seed(seed, "bitcoin".to_string(), "m/x".to_string()),
+            Err(DLCError::KeyError(ExtendedKey::InvalidDerivationPath))
+        ));
+    }
 
-        let result = create_dlc_transactions(
-            outcomes,
+    #[test]
+    fn test_contract_pubkeys() {
+        let TestContract {
             offer_params,
+            offer_fund_sk: offer_sk,
             accept_params,
-            100, // refund locktime
-            4,   // fee rate
-            10,  // fund lock time
-            10,  // cet lock time
-            0,   // fund output serial id
-            0,   // contract flags
+            dlc_txs,
+            ..
+        } = build_test_contract();
+
+        let pubkeys = contract_pubkeys(
+            dlc_txs.clone(),
+            offer_params.fund_pubkey.clone(),
+            accept_params.fund_pubkey.clone(),
         );
+        assert_eq!(pubkeys.len(), 2);
+        assert!(pubkeys.contains(&offer_params.fund_pubkey));
+        assert!(pubkeys.contains(&accept_params.fund_pubkey));
+
+        // Signing a fund input reveals the key spending it
+        let input = &offer_params.inputs[0];
+        let signed_fund = sign_fund_transaction_input(
+            dlc_txs.fund.clone(),
+            offer_sk.secret_bytes().to_vec(),
+            input.txid.clone(),
+            input.vout,
+            offer_params.input_amount,
+        )
+        .unwrap();
+        let input_pubkey = PublicKey::from_secret_key(&Secp256k1::new(), &offer_sk)
+            .serialize()
+            .to_vec();
+        let signed_txs = DlcTransactions {
+            fund: signed_fund,
+            ..dlc_txs
+        };
+        let pubkeys = contract_pubkeys(
+            signed_txs,
+            offer_params.fund_pubkey.clone(),
+            accept_params.fund_pubkey.clone(),
+        );
+        assert!(pubkeys.contains(&input_pubkey));
+    }
 
-        assert!(result.is_ok());
-        let dlc_txs = result.unwrap();
-
-        // Verify structure
-        assert_eq!(dlc_txs.fund.lock_time, 10);
-        assert_eq!(dlc_txs.refund.lock_time, 100);
-        assert_eq!(dlc_txs.cets.len(), 2);
-        assert!(dlc_txs.cets.iter().all(|cet| cet.lock_time == 10));
+    #[test]
+    fn test_pubkey_to_address() {
+        let (_, pubkey, _, _) = create_test_keys();
+        let pubkey = pubkey.serialize().to_vec();
+        let address = |address_type: &str| {
+            pubkey_to_address(
+                pubkey.clone(),
+                "testnet".to_string(),
+                address_type.to_string(),
+            )
+        };
 
-        // Verify funding transaction has correct structure
-        assert_eq!(dlc_txs.fund.inputs.len(), 2); // Two parties contributing
-        assert!(dlc_txs.fund.outputs.len() >= 1); // At least funding output
+        let p2wpkh = address("p2wpkh").unwrap();
+        assert!(p2wpkh.starts_with("tb1q"));
+        let compressed = CompressedPublicKey::from_slice(&pubkey).unwrap();
+        let parsed = Address::from_str(&p2wpkh).unwrap().assume_checked();
+        assert_eq!(
+            parsed.script_pubkey(),
+            ScriptBuf::new_p2wpkh(&compressed.wpubkey_hash())
+        );
 
-        // Verify CETs have correct structure
-        for cet in &dlc_txs.cets {
-            assert_eq!(cet.inputs.len(), 1); // Single funding input
-            assert!(cet.outputs.len() >= 1); // At least one output (dust may be filtered)
-        }
+        assert!(address("p2tr").unwrap().starts_with("tb1p"));
+        assert!(address("p2sh-p2wpkh").unwrap().starts_with('2'));
 
-        // Verify refund transaction
-        assert_eq!(dlc_txs.refund.inputs.len(), 1); // Single funding input
-        assert!(dlc_txs.refund.outputs.len() >= 2); // At least two refund outputs
+        assert!(matches!(
+            address("p2pkh"),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            pubkey_to_address(pubkey.clone(), "moonnet".to_string(), "p2wpkh".to_string()),
+            Err(DLCError::InvalidNetwork)
+        ));
+        assert!(matches!(
+            pubkey_to_address(
+                pubkey[..32].to_vec(),
+                "testnet".to_string(),
+                "p2wpkh".to_string()
+            ),
+            Err(DLCError::InvalidPublicKey)
+        ));
     }
 
     #[test]
-    fn test_create_cet_wrapper() {
-        let local_output = TxOutput {
-            value: 100_000_000, // 1 BTC
-            script_pubkey: vec![
-                0x00, 0x14, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
-                0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14,
-            ],
+    fn test_verify_cet_adaptor_sigs_with_points() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            dlc_txs,
+            fund_output_value,
+            ..
+        } = build_test_contract();
+        let (oracle_info, _, _) = create_test_oracle(1);
+        let msgs = (0..dlc_txs.cets.len())
+            .map(|outcome| {
+                vec![vec![sha256::Hash::hash(&[outcome as u8])
+                    .to_byte_array()
+                    .to_vec()]]
+            })
+            .collect::<Vec<_>>();
+        let adaptor_sigs = create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        )
+        .unwrap();
+        let adaptor_points =
+            create_cet_adaptor_points_from_oracle_info(vec![oracle_info.clone()], msgs.clone())
+                .unwrap();
+        let verify_with_points = |sigs: &[AdaptorSignature]| {
+            verify_cet_adaptor_sigs_with_points(
+                sigs.iter().map(|sig| sig.signature.clone()).collect(),
+                dlc_txs.cets.clone(),
+                adaptor_points.clone(),
+                offer_params.fund_pubkey.clone(),
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+            )
+            .unwrap()
         };
-
-        let remote_output = TxOutput {
-            value: 100_000_000, // 1 BTC
-            script_pubkey: vec![
-                0x00, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
-                0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28,
-            ],
+        let verify_with_oracle_info = |sigs: &[AdaptorSignature]| {
+            verify_cet_adaptor_sigs_from_oracle_info(
+                sigs.to_vec(),
+                dlc_txs.cets.clone(),
+                vec![oracle_info.clone()],
+                offer_params.fund_pubkey.clone(),
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+                msgs.clone(),
+            )
         };
 
-        let result = create_cet(
-            local_output,
-            1,
-            remote_output,
-            2,
-            "0000000000000000000000000000000000000000000000000000000000000000".to_string(),
-            0,
-            10,
+        assert_eq!(
+            verify_with_points(&adaptor_sigs),
+            vec![true; dlc_txs.cets.len()]
         );
+        assert!(verify_with_oracle_info(&adaptor_sigs));
+
+        // Swapping two signatures fails both ways, and only for those CETs
+        let mut swapped = adaptor_sigs.clone();
+        swapped.swap(0, 1);
+        assert_eq!(verify_with_points(&swapped), vec![false, false, true]);
+        assert!(!verify_with_oracle_info(&swapped));
+
+        assert!(verify_cet_adaptor_sigs_with_points(
+            vec![],
+            dlc_txs.cets.clone(),
+            adaptor_points,
+            offer_params.fund_pubkey,
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+        )
+        .is_err());
+    }
 
-        assert!(result.is_ok());
-        let cet = result.unwrap();
+    #[test]
+    fn test_address_to_script_pubkey() {
+        let (_, pubkey, _, _) = create_test_keys();
+        let compressed = CompressedPublicKey::from_slice(&pubkey.serialize()).unwrap();
+
+        for address_type in ["p2wpkh", "p2tr", "p2sh-p2wpkh"] {
+            let address = pubkey_to_address(
+                pubkey.serialize().to_vec(),
+                "testnet".to_string(),
+                address_type.to_string(),
+            )
+            .unwrap();
+            let script = address_to_script_pubkey(address, "testnet".to_string()).unwrap();
+            let expected = match address_type {
+                "p2wpkh" => ScriptBuf::new_p2wpkh(&compressed.wpubkey_hash()),
+                "p2tr" => ScriptBuf::new_p2tr(get_secp_context(), compressed.0.into(), None),
+                _ => ScriptBuf::new_p2sh(
+                    &ScriptBuf::new_p2wpkh(&compressed.wpubkey_hash()).script_hash(),
+                ),
+            };
+            assert_eq!(script, expected.to_bytes());
+        }
 
-        assert_eq!(cet.lock_time, 10);
-        assert_eq!(cet.inputs.len(), 1);
-        assert_eq!(cet.outputs.len(), 2);
-        assert_eq!(cet.outputs[0].value, 100_000_000);
-        assert_eq!(cet.outputs[1].value, 100_000_000);
+        let address = pubkey_to_address(
+            pubkey.serialize().to_vec(),
+            "bitcoin".into(),
+            "p2wpkh".into(),
+        )
+        .unwrap();
+        assert!(matches!(
+            address_to_script_pubkey(address, "testnet".to_string()),
+            Err(DLCError::InvalidNetwork)
+        ));
+        assert!(matches!(
+            address_to_script_pubkey("not an address".to_string(), "testnet".to_string()),
+            Err(DLCError::InvalidArgument(_))
+        ));
     }
 
     #[test]
-    fn test_create_refund_transaction_wrapper() {
-        let local_script = vec![
-            0x00, 0x14, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
-            0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14,
-        ];
-        let remote_script = vec![
-            0x00, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
-            0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28,
-        ];
-
-        let result = create_refund_transaction(
-            local_script,
-            remote_script,
-            100_000_000, // 1 BTC to local
-            100_000_000, // 1 BTC to remote
-            144,         // locktime (1 day in blocks)
-            "0000000000000000000000000000000000000000000000000000000000000000".to_string(),
+    fn test_dlc_transactions_to_fund_psbt() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (mut accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        // Nested segwit input, its redeem script goes to the PSBT input
+        let redeem_script = ScriptBuf::new_p2wpkh(&WPubkeyHash::all_zeros());
+        accept_params.inputs[0].script_sig = WPubkeyHash::all_zeros().to_byte_array().to_vec();
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_params,
+            accept_params,
+            100,
+            4,
+            10,
+            10,
             0,
-        );
+            0,
+        )
+        .unwrap();
 
-        assert!(result.is_ok());
-        let refund_tx = result.unwrap();
+        let psbt_bytes = dlc_transactions_to_fund_psbt(dlc_txs.clone()).unwrap();
+        let psbt = Psbt::deserialize(&psbt_bytes).unwrap();
+        let mut expected = transaction_to_btc_tx(&dlc_txs.fund).unwrap();
+        let nested_index = expected
+            .input
+            .iter()
+            .position(|input| !input.script_sig.is_empty())
+            .unwrap();
+        expected.input[nested_index].script_sig = ScriptBuf::new();
 
-        assert_eq!(refund_tx.lock_time, 144);
-        assert_eq!(refund_tx.inputs.len(), 1);
-        assert_eq!(refund_tx.outputs.len(), 2);
-        assert_eq!(refund_tx.outputs[0].value, 100_000_000);
-        assert_eq!(refund_tx.outputs[1].value, 100_000_000);
+        assert_eq!(psbt.unsigned_tx, expected);
+        assert_eq!(psbt.inputs[nested_index].redeem_script, Some(redeem_script));
+        assert!(psbt
+            .inputs
+            .iter()
+            .enumerate()
+            .all(|(i, input)| i == nested_index || input.redeem_script.is_none()));
     }
 
     #[test]
-    fn test_is_dust_output() {
-        let dust_output = TxOutput {
-            value: 500, // Below dust limit
-            script_pubkey: vec![],
-        };
+    fn test_check_fee_sanity() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let fee_rate = 4;
+        let input_amounts = vec![offer_params.input_amount, accept_params.input_amount];
+        assert!(
+            check_fee_sanity(dlc_txs.fund.clone(), input_amounts.clone(), 2 * fee_rate).is_ok()
+        );
 
-        let non_dust_output = TxOutput {
-            value: 5000, // Above dust limit
-            script_pubkey: vec![],
-        };
+        // Pay 10x the fee by shrinking a change output
+        let mut tx = transaction_to_btc_tx(&dlc_txs.fund).unwrap();
+        let fee = input_amounts.iter().sum::<u64>()
+            - tx.output
+                .iter()
+                .map(|output| output.value.to_sat())
+                .sum::<u64>();
+        let change_vout = tx
+            .output
+            .iter()
+            .position(|output| {
+                output.script_pubkey.as_bytes()
+                    == offer_params.change_script_pubkey.as_deref().unwrap()
+            })
+            .unwrap();
+        tx.output[change_vout].value -= Amount::from_sat(9 * fee);
+        let overpaying = btc_tx_to_transaction(&tx);
+        assert!(matches!(
+            check_fee_sanity(overpaying.clone(), input_amounts.clone(), 2 * fee_rate),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(check_fee_sanity(overpaying, input_amounts, 10 * fee_rate).is_ok());
 
-        assert!(is_dust_output(dust_output));
-        assert!(!is_dust_output(non_dust_output));
+        assert!(check_fee_sanity(dlc_txs.fund, vec![1], fee_rate).is_err());
     }
 
     #[test]
-    fn test_conversion_functions() {
-        let (_offer_sk, offer_pk, _accept_sk, _accept_pk) = create_test_keys();
-
-        // Test party params conversion
-        let params =
-            create_test_party_params(100_000_000, 50_000_000, offer_pk.serialize().to_vec(), 1);
-
-        let rust_params = party_params_to_rust(&params).unwrap();
-        assert_eq!(rust_params.fund_pubkey, offer_pk);
-        assert_eq!(rust_params.input_amount, Amount::from_sat(100_000_000));
-        assert_eq!(rust_params.collateral, Amount::from_sat(50_000_000));
+    fn test_diff_party_params() {
+        let (params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let mut other = params.clone();
+        other.collateral += 1;
 
-        // Test TX input conversion
-        let tx_input = TxInputInfo {
-            txid: "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456".to_string(),
-            vout: 0,
-            script_sig: vec![],
-            max_witness_length: 108,
-            serial_id: 1,
-        };
+        let diff = diff_party_params(params.clone(), other);
+        assert!(diff.collateral);
+        assert!(
+            !(diff.fund_pubkey
+                || diff.change_script_pubkey
+                || diff.payout_script_pubkey
+                || diff.input_amount
+                || diff.inputs
+                || diff.dlc_inputs
+                || diff.serial_ids)
+        );
 
-        let rust_input = tx_input_info_to_rust(&tx_input).unwrap();
-        assert_eq!(rust_input.serial_id, 1);
-        assert_eq!(rust_input.max_witness_len, 108);
-        assert_eq!(rust_input.outpoint.vout, 0);
+        let mut other = params.clone();
+        other.inputs[0].serial_id += 1;
+        let diff = diff_party_params(params, other);
+        assert!(diff.serial_ids && !diff.inputs && !diff.collateral);
     }
 
     #[test]
-    fn test_transaction_bidirectional_conversion() {
-        // Create a test Bitcoin transaction
-        let btc_tx = BtcTransaction {
-            version: bitcoin::transaction::Version::TWO,
-            lock_time: LockTime::from_consensus(144),
-            input: vec![TxIn {
-                previous_output: OutPoint {
-                    txid: Txid::from_str(
-                        "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456",
-                    )
-                    .unwrap(),
-                    vout: 0,
-                },
-                script_sig: ScriptBuf::new(),
-                sequence: Sequence::ZERO,
-                witness: Witness::new(),
-            }],
-            output: vec![BtcTxOut {
-                value: Amount::from_sat(100_000_000),
-                script_pubkey: ScriptBuf::from(vec![0x00, 0x14]),
-            }],
-        };
+    fn test_finalize_fund_psbt() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk: offer_sk,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let mut psbt =
+            Psbt::deserialize(&dlc_transactions_to_fund_psbt(dlc_txs.clone()).unwrap()).unwrap();
+        assert!(matches!(
+            finalize_fund_psbt(psbt.serialize()),
+            Err(DLCError::InvalidTransaction)
+        ));
 
-        // Convert to UniFFI format and back
-        let uniffi_tx = btc_tx_to_transaction(&btc_tx);
-        let converted_back = transaction_to_btc_tx(&uniffi_tx).unwrap();
+        // Each signer fills the final witness of its own input
+        let pubkey = PublicKey::from_secret_key(&Secp256k1::new(), &offer_sk);
+        let inputs = offer_params.inputs.iter().chain(&accept_params.inputs);
+        for input in inputs.clone() {
+            let signed = sign_fund_transaction_input(
+                dlc_txs.fund.clone(),
+                offer_sk.secret_bytes().to_vec(),
+                input.txid.clone(),
+                input.vout,
+                1_000_000_000,
+            )
+            .unwrap();
+            let index = psbt
+                .unsigned_tx
+                .input
+                .iter()
+                .position(|tx_in| {
+                    tx_in.previous_output.to_string() == format!("{}:{}", input.txid, input.vout)
+                })
+                .unwrap();
+            psbt.inputs[index].final_script_witness =
+                Some(Witness::from_slice(&signed.inputs[index].witness));
+        }
 
-        // Verify they're equivalent
-        assert_eq!(btc_tx.version, converted_back.version);
-        assert_eq!(btc_tx.lock_time, converted_back.lock_time);
-        assert_eq!(btc_tx.input.len(), converted_back.input.len());
-        assert_eq!(btc_tx.output.len(), converted_back.output.len());
+        let fund = finalize_fund_psbt(psbt.serialize()).unwrap();
         assert_eq!(
-            btc_tx.input[0].previous_output,
-            converted_back.input[0].previous_output
+            get_transaction_txid(fund.clone()).unwrap(),
+            get_transaction_txid(dlc_txs.fund).unwrap()
         );
-        assert_eq!(btc_tx.output[0].value, converted_back.output[0].value);
+        for input in inputs {
+            let index = fund
+                .inputs
+                .iter()
+                .position(|tx_in| tx_in.txid == input.txid && tx_in.vout == input.vout)
+                .unwrap();
+            let sig = &fund.inputs[index].witness[0];
+            // P2WPKH inputs are signed over the P2PKH script code of the key
+            verify_tx_input_sig(
+                sig[..sig.len() - 1].to_vec(),
+                fund.clone(),
+                index,
+                ScriptBuf::new_p2pkh(&bitcoin::PublicKey::new(pubkey).pubkey_hash()).to_bytes(),
+                1_000_000_000,
+                pubkey.serialize().to_vec(),
+            )
+            .unwrap();
+        }
     }
 
     #[test]
-    fn test_error_handling_invalid_keys() {
-        // Test invalid public key
-        let result = create_fund_tx_locking_script(
-            vec![0u8; 20], // Invalid key length
-            vec![1u8; 33],
+    fn test_verify_adaptor_proof() {
+        let secp = Secp256k1::new();
+        let (signing_sk, signing_pk, _, _) = create_test_keys();
+        let adaptor_point =
+            PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&[7u8; 32]).unwrap());
+        let msg = [3u8; 32];
+        let adaptor_sig = EcdsaAdaptorSignature::encrypt(
+            &secp,
+            &Message::from_digest_slice(&msg).unwrap(),
+            &signing_sk,
+            &adaptor_point,
         );
-        assert!(matches!(result, Err(DLCError::InvalidPublicKey)));
+        let verify = |sig: Vec<u8>| {
+            verify_adaptor_proof(
+                sig,
+                adaptor_point.serialize().to_vec(),
+                msg.to_vec(),
+                signing_pk.serialize().to_vec(),
+            )
+        };
 
-        // Test invalid txid
-        let result = create_cet(
-            TxOutput {
-                value: 1000,
-                script_pubkey: vec![],
-            },
-            1,
-            TxOutput {
-                value: 1000,
-                script_pubkey: vec![],
-            },
-            2,
-            "invalid_txid".to_string(),
-            0,
-            0,
-        );
-        assert!(matches!(result, Err(DLCError::InvalidArgument(_))));
-    }
+        let sig_bytes = adaptor_sig.as_ref().to_vec();
+        assert!(verify(sig_bytes.clone()).unwrap());
 
-    fn get_p2wpkh_script_pubkey(secp: &Secp256k1<All>) -> ScriptBuf {
-        let mut rng = secp256k1_zkp::rand::thread_rng();
-        let sk = bitcoin::PrivateKey {
-            inner: SecretKey::new(&mut rng),
-            network: Network::Testnet.into(),
-            compressed: true,
-        };
-        let pk = CompressedPublicKey::from_private_key(secp, &sk).unwrap();
-        Address::p2wpkh(&pk, Network::Testnet).script_pubkey()
+        // Flip a byte of the DLEQ proof, which follows the 98-byte signature part
+        let mut corrupted = sig_bytes;
+        corrupted[ADAPTOR_SIGNATURE_SIZE - 1] ^= 0x01;
+        assert!(!verify(corrupted).unwrap());
+
+        assert!(verify(vec![0; 10]).is_err());
     }
 
-    fn get_party_params(
-        input_amount: u64,
-        collateral: u64,
-        serial_id: Option<u64>,
-    ) -> (PartyParams, SecretKey) {
-        let secp = Secp256k1::new();
-        let mut rng = secp256k1_zkp::rand::thread_rng();
-        let fund_privkey = SecretKey::new(&mut rng);
-        let serial_id = serial_id.unwrap_or(1);
-        (
-            PartyParams {
-                fund_pubkey: PublicKey::from_secret_key(&secp, &fund_privkey)
-                    .serialize()
-                    .to_vec(),
-                change_script_pubkey: get_p2wpkh_script_pubkey(&secp).into_bytes(),
-                change_serial_id: serial_id,
-                payout_script_pubkey: get_p2wpkh_script_pubkey(&secp).into_bytes(),
-                payout_serial_id: serial_id,
-                input_amount,
-                collateral,
-                inputs: vec![TxInputInfo {
-                    txid: "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"
-                        .to_string(),
-                    vout: 0,
-                    max_witness_length: 108,
-                    script_sig: vec![],
-                    serial_id,
-                }],
-                dlc_inputs: vec![],
-            },
-            fund_privkey,
+    #[test]
+    fn test_get_fund_output_value() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+
+        // Change outputs use serial ids 1 and 2, so the fund output moves from
+        // the first to the last position
+        for (fund_output_serial_id, fund_vout) in [(0, 0), (10, 2)] {
+            let dlc_txs = create_dlc_transactions(
+                payouts_test(),
+                offer_params.clone(),
+                accept_params.clone(),
+                100,
+                4,
+                10,
+                10,
+                fund_output_serial_id,
+                0,
+            )
+            .unwrap();
+            assert_eq!(dlc_txs.refund.inputs[0].vout, fund_vout);
+            assert_eq!(
+                get_fund_output_value(dlc_txs.clone()).unwrap(),
+                dlc_txs.fund.outputs[fund_vout as usize].value
+            );
+        }
+
+        let dlc_txs = create_dlc_transactions(
+            payouts_test(),
+            offer_params,
+            accept_params,
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
         )
+        .unwrap();
+        let without_fund_output = DlcTransactions {
+            funding_script_pubkey: vec![0x51],
+            ..dlc_txs
+        };
+        assert!(get_fund_output_value(without_fund_output).is_err());
     }
 
-    fn payouts_test() -> Vec<Payout> {
-        vec![
-            Payout {
-                offer: 100000000,
-                accept: 100000000,
-            },
-            Payout {
-                offer: 100000000,
-                accept: 100000000,
+    #[test]
+    fn test_min_viable_collateral() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let local_script = offer_params.payout_script_pubkey;
+        let remote_script = accept_params.payout_script_pubkey;
+        let payouts = |collateral: u64, ratios: &[PayoutRatio]| {
+            ratios
+                .iter()
+                .flat_map(|ratio| {
+                    let total = (ratio.offer + ratio.accept) as u64;
+                    [
+                        collateral * ratio.offer as u64 / total,
+                        collateral * ratio.accept as u64 / total,
+                    ]
+                })
+                .filter(|value| *value > 0)
+                .collect::<Vec<_>>()
+        };
+
+        // Winner takes all: only the dust limit matters at a low fee rate
+        let winner_take_all = vec![
+            PayoutRatio {
+                offer: 1,
+                accept: 0,
             },
-            Payout {
-                offer: 100000000,
-                accept: 100000000,
+            PayoutRatio {
+                offer: 0,
+                accept: 1,
             },
-        ]
-    }
-
-    fn signatures_to_secret(signatures: &[Vec<SchnorrSignature>]) -> SecretKey {
-        let s_values = signatures
-            .iter()
-            .flatten()
-            .map(|x| secp_utils::schnorrsig_decompose(x).unwrap().1)
-            .collect::<Vec<_>>();
-        let secret = SecretKey::from_slice(s_values[0]).unwrap();
-
-        s_values.iter().skip(1).fold(secret, |accum, s| {
-            let sec = SecretKey::from_slice(s).unwrap();
-            accum.add_tweak(&Scalar::from(sec)).unwrap()
-        })
-    }
-
-    /// Verify a signature for a given transaction input.
-    fn verify_tx_input_sig(
-        signature: Vec<u8>,
-        tx: Transaction,
-        input_index: usize,
-        script_pubkey: Vec<u8>,
-        value: u64,
-        pk: Vec<u8>,
-    ) -> Result<(), DLCError> {
-        let secp = get_secp_context();
-        let btc_txn = transaction_to_btc_tx(&tx)?;
-        let script = ScriptBuf::from_bytes(script_pubkey);
-        let sig = EcdsaSignature::from_der(&signature).map_err(|_| DLCError::InvalidSignature)?;
-        let pk = PublicKey::from_slice(&pk).map_err(|_| DLCError::InvalidPublicKey)?;
-        ddk_dlc::verify_tx_input_sig(
-            secp,
-            &sig,
-            &btc_txn,
-            input_index,
-            &script,
-            Amount::from_sat(value),
-            &pk,
-        )?;
-        Ok(())
+        ];
+        let collateral = min_viable_collateral(
+            winner_take_all.clone(),
+            1,
+            local_script.clone(),
+            remote_script.clone(),
+        )
+        .unwrap();
+        assert_eq!(collateral, DUST_LIMIT);
+        assert!(payouts(collateral, &winner_take_all).iter().all(|value| {
+            !is_dust_output(TxOutput {
+                value: *value,
+                script_pubkey: local_script.clone(),
+            })
+        }));
+
+        // Splitting 1:3 needs four times the threshold for the smaller share,
+        // which at 100 sat/vB is the cost of spending a P2WPKH output
+        let split = vec![PayoutRatio {
+            offer: 1,
+            accept: 3,
+        }];
+        let collateral = min_viable_collateral(
+            split.clone(),
+            100,
+            local_script.clone(),
+            remote_script.clone(),
+        )
+        .unwrap();
+        let threshold = (8 + 1 + 22 + 67) * 100;
+        assert_eq!(collateral, 4 * threshold);
+        assert!(payouts(collateral, &split)
+            .iter()
+            .all(|value| *value >= threshold));
+        assert!(payouts(collateral - 1, &split)
+            .iter()
+            .any(|value| *value < threshold));
+
+        assert!(
+            min_viable_collateral(vec![], 1, local_script.clone(), remote_script.clone()).is_err()
+        );
+        assert!(min_viable_collateral(
+            vec![PayoutRatio {
+                offer: 0,
+                accept: 0
+            }],
+            1,
+            local_script,
+            remote_script
+        )
+        .is_err());
     }
 
     #[test]
-    fn create_cet_adaptor_sig_single_oracle_three_outcomes() {
-        // Arrange
-        let secp = Secp256k1::new();
-        let mut rng = secp256k1_zkp::rand::thread_rng();
-        let (offer_party_params, offer_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, None);
-        let (accept_party_params, _accept_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, None);
-
+    fn test_cet_to_outcome() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let outcomes = vec![
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 0,
+                accept: 200_000_000,
+            },
+            Payout {
+                offer: 150_000_000,
+                accept: 50_000_000,
+            },
+        ];
         let dlc_txs = create_dlc_transactions(
-            payouts_test(),
-            offer_party_params.clone(),
-            accept_party_params.clone(),
+            outcomes.clone(),
+            offer_params.clone(),
+            accept_params.clone(),
             100,
             4,
             10,
@@ -2002,282 +9340,771 @@ mod tests {
         )
         .unwrap();
 
-        let cets = dlc_txs.cets;
-        const NB_ORACLES: usize = 1; // 1 oracle
-        const NB_OUTCOMES: usize = 3; // 3 outcomes (enumeration)
-        const NB_DIGITS: usize = 1; // 1 nonce for enumeration contract
+        for (index, cet) in dlc_txs.cets.iter().enumerate() {
+            let outcome = cet_to_outcome(
+                cet.clone(),
+                outcomes.clone(),
+                offer_params.payout_script_pubkey.clone(),
+                accept_params.payout_script_pubkey.clone(),
+            )
+            .unwrap();
+            assert_eq!(outcome, index as u32);
+        }
 
-        let mut oracle_infos: Vec<OracleInfo> = Vec::with_capacity(NB_ORACLES);
-        let mut oracle_sks: Vec<Keypair> = Vec::with_capacity(NB_ORACLES);
-        let mut oracle_sk_nonce: Vec<Vec<[u8; 32]>> = Vec::with_capacity(NB_ORACLES);
-        let mut oracle_sigs: Vec<Vec<SchnorrSignature>> = Vec::with_capacity(NB_ORACLES);
+        // The refund pays 1:1, which no outcome does
+        assert!(matches!(
+            cet_to_outcome(
+                dlc_txs.refund,
+                outcomes.clone(),
+                offer_params.payout_script_pubkey.clone(),
+                accept_params.payout_script_pubkey.clone(),
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
 
-        // Messages: 3 outcomes × 1 oracle × 1 message per outcome
-        let messages: Vec<Vec<Vec<_>>> = (0..NB_OUTCOMES)
-            .map(|outcome_idx| {
-                vec![
-                    // Single oracle
-                    vec![
-                        // Single message for this outcome
-                        {
-                            let message = &[outcome_idx as u8]; // Different message per outcome
-                            let hash = sha256::Hash::hash(message).to_byte_array();
-                            hash.to_vec()
-                        },
-                    ],
-                ]
-            })
-            .collect();
+        // Outputs whose sum overflows are rejected rather than wrapped
+        let mut overflowing = dlc_txs.cets[0].clone();
+        let mut duplicate = overflowing.outputs[0].clone();
+        duplicate.value = u64::MAX;
+        overflowing.outputs.push(duplicate);
+        assert!(matches!(
+            cet_to_outcome(
+                overflowing,
+                outcomes,
+                offer_params.payout_script_pubkey,
+                accept_params.payout_script_pubkey,
+            ),
+            Err(DLCError::InvalidTransaction)
+        ));
+    }
 
-        // Setup single oracle with single nonce
-        for i in 0..NB_ORACLES {
-            // Runs once
-            let oracle_kp = Keypair::new(&secp, &mut rng);
-            let oracle_pubkey = oracle_kp.x_only_public_key().0;
-            let mut nonces: Vec<XOnlyPublicKey> = Vec::with_capacity(NB_DIGITS);
-            let mut sk_nonces: Vec<[u8; 32]> = Vec::with_capacity(NB_DIGITS);
-            oracle_sigs.push(Vec::with_capacity(NB_DIGITS));
+    #[test]
+    fn test_validate_cets_share_funding() {
+        let TestContract { dlc_txs, .. } = build_test_contract();
 
-            // Single nonce for enumeration
-            let mut sk_nonce = [0u8; 32];
-            rng.fill_bytes(&mut sk_nonce);
-            let oracle_r_kp = Keypair::from_seckey_slice(&secp, &sk_nonce).unwrap();
-            let nonce = XOnlyPublicKey::from_keypair(&oracle_r_kp).0;
+        let fund_txid = transaction_to_btc_tx(&dlc_txs.fund).unwrap().compute_txid();
+        let refund_input = &transaction_to_btc_tx(&dlc_txs.refund).unwrap().input[0];
+        let shared = validate_cets_share_funding(dlc_txs.cets.clone()).unwrap();
+        assert_eq!(
+            shared,
+            format!("{}:{}", fund_txid, refund_input.previous_output.vout)
+        );
 
-            // Sign the first outcome's message with the single nonce
-            let sig = secp_utils::schnorrsig_sign_with_nonce(
-                &secp,
-                &Message::from_digest_slice(&messages[0][0][0]).unwrap(), // First outcome, first oracle, first message
-                &oracle_kp,
-                &sk_nonce,
-            );
+        let mut diverging = transaction_to_btc_tx(&dlc_txs.cets[1]).unwrap();
+        diverging.input[0].previous_output.vout += 1;
+        let mut cets = dlc_txs.cets;
+        cets[1] = btc_tx_to_transaction(&diverging);
+        assert!(matches!(
+            validate_cets_share_funding(cets),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(validate_cets_share_funding(vec![]).is_err());
+    }
 
-            oracle_sigs[i].push(sig);
-            nonces.push(nonce);
-            sk_nonces.push(sk_nonce);
+    #[test]
+    fn test_add_witnesses_to_transaction() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk: offer_sk,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        assert_eq!(dlc_txs.fund.inputs.len(), 2);
+
+        let pubkey = PublicKey::from_secret_key(&Secp256k1::new(), &offer_sk);
+        let inputs = offer_params.inputs.iter().chain(&accept_params.inputs);
+        let witnesses = inputs
+            .map(|input| {
+                let signed = sign_fund_transaction_input(
+                    dlc_txs.fund.clone(),
+                    offer_sk.secret_bytes().to_vec(),
+                    input.txid.clone(),
+                    input.vout,
+                    1_000_000_000,
+                )
+                .unwrap();
+                let index = signed
+                    .inputs
+                    .iter()
+                    .position(|tx_in| tx_in.txid == input.txid && tx_in.vout == input.vout)
+                    .unwrap();
+                WitnessEntry {
+                    input_index: index as u32,
+                    items: signed.inputs[index].witness.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
 
-            oracle_infos.push(OracleInfo {
-                public_key: oracle_pubkey.serialize().to_vec(),
-                nonces: nonces.iter().map(|n| n.serialize().to_vec()).collect(), // Just 1 nonce
-            });
-            oracle_sk_nonce.push(sk_nonces);
-            oracle_sks.push(oracle_kp);
+        let fund = add_witnesses_to_transaction(dlc_txs.fund.clone(), witnesses.clone()).unwrap();
+        for entry in &witnesses {
+            let sig = &fund.inputs[entry.input_index as usize].witness[0];
+            verify_tx_input_sig(
+                sig[..sig.len() - 1].to_vec(),
+                fund.clone(),
+                entry.input_index as usize,
+                ScriptBuf::new_p2pkh(&bitcoin::PublicKey::new(pubkey).pubkey_hash()).to_bytes(),
+                1_000_000_000,
+                pubkey.serialize().to_vec(),
+            )
+            .unwrap();
         }
-        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
-            &PublicKey::from_slice(&offer_party_params.fund_pubkey.clone()).unwrap(),
-            &PublicKey::from_slice(&accept_party_params.fund_pubkey.clone()).unwrap(),
+
+        let out_of_range = WitnessEntry {
+            input_index: 2,
+            items: vec![vec![1]],
+        };
+        assert!(matches!(
+            add_witnesses_to_transaction(dlc_txs.fund, vec![out_of_range]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_party_params_from_descriptor() {
+        let (_, offer_pk, _, accept_pk) = create_test_keys();
+        let change_descriptor = format!("wpkh({})", offer_pk);
+        let payout_descriptor = format!("wpkh({})", accept_pk);
+
+        let params = party_params_from_descriptor(
+            offer_pk.serialize().to_vec(),
+            change_descriptor.clone(),
+            payout_descriptor,
+            vec![],
+            1_000_000_000,
+            100_000_000,
+            "regtest".to_string(),
+        )
+        .unwrap();
+        let expected_change = ScriptBuf::new_p2wpkh(&CompressedPublicKey(offer_pk).wpubkey_hash());
+        let expected_payout = ScriptBuf::new_p2wpkh(&CompressedPublicKey(accept_pk).wpubkey_hash());
+        assert_eq!(
+            params.change_script_pubkey,
+            Some(expected_change.to_bytes())
         );
-        let fund_output_value = dlc_txs.fund.outputs[0].value;
+        assert_eq!(params.payout_script_pubkey, expected_payout.to_bytes());
+        assert!(validate_party_params(params).is_ok());
+
+        // Extended keys must match the network and be fully derived
+        let xpriv = Xpriv::new_master(Network::Regtest, &[7; 32]).unwrap();
+        let tpub = Xpub::from_priv(&Secp256k1::new(), &xpriv);
+        for (descriptor, network) in [
+            (format!("wpkh({}/0/1)", tpub), "bitcoin"),
+            (format!("wpkh({}/0/*)", tpub), "regtest"),
+            ("wpkh(nope)".to_string(), "regtest"),
+        ] {
+            assert!(party_params_from_descriptor(
+                offer_pk.serialize().to_vec(),
+                change_descriptor.clone(),
+                descriptor,
+                vec![],
+                1_000_000_000,
+                100_000_000,
+                network.to_string(),
+            )
+            .is_err());
+        }
+        assert!(party_params_from_descriptor(
+            offer_pk.serialize().to_vec(),
+            change_descriptor,
+            format!("wpkh({}/0/1)", tpub),
+            vec![],
+            1_000_000_000,
+            100_000_000,
+            "regtest".to_string(),
+        )
+        .is_ok());
+    }
 
-        // Act
-        let cet_sigs = create_cet_adaptor_sigs_from_oracle_info(
-            cets.clone(), // Use only first 3 CETs
-            oracle_infos.clone(),
+    #[test]
+    fn test_hash_outcome() {
+        let expected = sha256::Hash::hash(b"1").to_byte_array().to_vec();
+        assert_eq!(hash_outcome("1".to_string()), expected);
+        assert_eq!(
+            hash_outcomes(vec!["1".to_string(), "2".to_string()]),
+            vec![expected, sha256::Hash::hash(b"2").to_byte_array().to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_shared_secp_contexts() {
+        let context_address = || get_verification_context() as *const _ as usize;
+        let from_thread = std::thread::spawn(context_address).join().unwrap();
+        assert_eq!(context_address(), from_thread);
+
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let fund_output_value = get_fund_output_value(dlc_txs.clone()).unwrap();
+        let signature = sign_refund_transaction(
+            dlc_txs.refund.clone(),
             offer_fund_sk.secret_bytes().to_vec(),
-            funding_script_pubkey.clone().into_bytes(),
+            accept_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
             fund_output_value,
-            messages.clone(),
         )
         .unwrap();
 
-        let oracle_signatures = oracle_sigs
-            .iter()
-            .map(|s| s.iter().map(|s| s.serialize().to_vec()).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+        // The cached context agrees with a freshly built one
+        for pubkey in [&offer_params.fund_pubkey, &accept_params.fund_pubkey] {
+            let fresh = ddk_dlc::verify_tx_input_sig(
+                &Secp256k1::verification_only(),
+                &EcdsaSignature::from_der(&signature).unwrap(),
+                &transaction_to_btc_tx(&dlc_txs.refund).unwrap(),
+                0,
+                Script::from_bytes(&dlc_txs.funding_script_pubkey),
+                Amount::from_sat(fund_output_value),
+                &PublicKey::from_slice(pubkey).unwrap(),
+            )
+            .is_ok();
+            let cached = verify_refund_transaction_signature(
+                dlc_txs.refund.clone(),
+                signature.clone(),
+                pubkey.clone(),
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+            )
+            .unwrap();
+            assert_eq!(cached, fresh);
+            assert_eq!(cached, pubkey == &offer_params.fund_pubkey);
+        }
+    }
 
-        let sign_res = sign_cet(
-            cets[0].clone(),
-            cet_sigs[0].signature.clone(),
-            oracle_signatures[0].clone(),
-            _accept_fund_sk.secret_bytes().to_vec(),
-            offer_party_params.fund_pubkey.clone(),
-            accept_party_params.fund_pubkey.clone(),
-            fund_output_value,
-        );
+    #[test]
+    fn test_verify_oracle_attestation() {
+        let secp = Secp256k1::new();
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(2);
+        let msg = sha256::Hash::hash(b"outcome").to_byte_array().to_vec();
+        let attestation = secp_utils::schnorrsig_sign_with_nonce(
+            &secp,
+            &Message::from_digest_slice(&msg).unwrap(),
+            &oracle_kp,
+            &sk_nonces[0],
+        )
+        .as_ref()
+        .to_vec();
+
+        let verify = |nonce: &[u8], message: &[u8]| {
+            verify_oracle_attestation(
+                oracle_info.public_key.clone(),
+                nonce.to_vec(),
+                message.to_vec(),
+                attestation.clone(),
+            )
+            .unwrap()
+        };
+        assert!(verify(&oracle_info.nonces[0], &msg));
+        assert!(!verify(&oracle_info.nonces[1], &msg));
+        assert!(!verify(
+            &oracle_info.nonces[0],
+            sha256::Hash::hash(b"other").as_byte_array()
+        ));
+    }
 
-        assert!(sign_res.is_ok());
+    #[test]
+    fn test_change_across_fee_rates() {
+        let (params, _) = get_party_params(100_050_000, 100_000_000, None);
+        let fee_rates = vec![1, 10, 100, 1_000];
+        let changes =
+            change_across_fee_rates(params.clone(), fee_rates.clone(), 100_000_000).unwrap();
+
+        assert_eq!(changes.len(), fee_rates.len());
+        assert!(changes.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(changes[0] > 0);
+        assert!(changes[3] < 0);
+
+        // Matches the change output wherever the inputs cover the fees
+        let change_output =
+            get_change_output_and_fees(params.clone(), 100_000_000, fee_rates[1], 0).unwrap();
+        assert_eq!(changes[1], change_output.change_output.value as i64);
+
+        // Without collateral the inputs are left untouched at any fee rate
+        let params = PartyParams {
+            collateral: 0,
+            ..params
+        };
+        let changes = change_across_fee_rates(params, fee_rates, 100_000_000).unwrap();
+        assert!(changes.iter().all(|change| *change == 100_050_000));
+    }
 
-        let adaptor_secret = signatures_to_secret(&oracle_sigs);
-        let signature = vec_to_ecdsa_adaptor_signature(cet_sigs[0].signature.clone()).unwrap();
-        let adapted_sig = signature.decrypt(&adaptor_secret).unwrap();
+    #[test]
+    fn test_decompose_schnorr_signature() {
+        let secp = Secp256k1::new();
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(1);
+        let msg = Message::from_digest(sha256::Hash::hash(b"outcome").to_byte_array());
+        let signature =
+            secp_utils::schnorrsig_sign_with_nonce(&secp, &msg, &oracle_kp, &sk_nonces[0])
+                .as_ref()
+                .to_vec();
+
+        let parts = decompose_schnorr_signature(signature.clone()).unwrap();
+        assert_eq!(parts.nonce, oracle_info.nonces[0]);
+        assert_eq!([parts.nonce, parts.s_value].concat(), signature);
+
+        // R is not a valid x coordinate
+        let mut invalid = signature;
+        invalid[..32].copy_from_slice(&[0xff; 32]);
+        assert!(matches!(
+            decompose_schnorr_signature(invalid),
+            Err(DLCError::InvalidSignature)
+        ));
+        assert!(matches!(
+            decompose_schnorr_signature(vec![1; 63]),
+            Err(DLCError::InvalidSignature)
+        ));
+    }
 
-        let batch_verify = verify_cet_adaptor_sigs_from_oracle_info(
-            cet_sigs.clone(),
-            cets.clone(),
-            oracle_infos.clone(),
-            offer_party_params.fund_pubkey.clone(),
-            funding_script_pubkey.clone().into_bytes(),
-            fund_output_value,
-            messages.clone(),
-        );
+    #[test]
+    fn test_validate_transaction_consistency() {
+        let tx = dummy_transaction(2, 2);
+        assert!(validate_transaction_consistency(tx.clone()).is_ok());
+
+        let mut mismatched = tx.clone();
+        mismatched.outputs[1].value += 1;
+        let Err(DLCError::InvalidArgument(message)) = validate_transaction_consistency(mismatched)
+        else {
+            panic!("Mismatched output value should be rejected");
+        };
+        assert!(message.starts_with("Output 1 value"));
 
-        assert!(batch_verify);
+        let mut mismatched = tx;
+        mismatched.lock_time += 1;
+        assert!(validate_transaction_consistency(mismatched).is_err());
+    }
 
-        // Assert
-        assert_eq!(cet_sigs.len(), 3, "Should have 3 CET signatures");
-        assert!(cet_sigs
+    #[test]
+    fn test_aggregate_oracle_s_values() {
+        let secp = Secp256k1::new();
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(3);
+        let digits = ["1", "0", "1"];
+        let signatures = digits
             .iter()
-            .enumerate()
-            .all(|(i, x)| verify_cet_adaptor_sig_from_oracle_info(
-                x.clone(),
-                cets[i].clone(),
-                oracle_infos.clone(),
-                offer_party_params.fund_pubkey.clone(),
-                funding_script_pubkey.clone().into_bytes(),
-                fund_output_value,
-                messages[i].clone(),
-            )));
-        sign_res.expect("Error signing CET");
-        verify_tx_input_sig(
-            adapted_sig.serialize_der().to_vec(),
-            cets[0].clone(),
-            0,
-            funding_script_pubkey.clone().into_bytes(),
-            fund_output_value,
-            offer_party_params.fund_pubkey.clone(),
-        )
-        .expect("Invalid decrypted adaptor signature");
+            .zip(&sk_nonces)
+            .map(|(digit, sk_nonce)| {
+                let msg =
+                    Message::from_digest(sha256::Hash::hash(digit.as_bytes()).to_byte_array());
+                secp_utils::schnorrsig_sign_with_nonce(&secp, &msg, &oracle_kp, sk_nonce)
+                    .as_ref()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+
+        // Encrypt an adaptor signature to the sum of the three signature points
+        let points = digits
+            .iter()
+            .zip(&oracle_info.nonces)
+            .map(|(digit, nonce)| {
+                let point = outcome_signature_point(
+                    oracle_info.public_key.clone(),
+                    nonce.clone(),
+                    hash_outcome(digit.to_string()),
+                )
+                .unwrap();
+                PublicKey::from_slice(&point).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let adaptor_point = PublicKey::combine_keys(&points.iter().collect::<Vec<_>>()).unwrap();
+        let (signing_sk, signing_pk, _, _) = create_test_keys();
+        let msg = Message::from_digest([3; 32]);
+        let adaptor_sig = EcdsaAdaptorSignature::encrypt(&secp, &msg, &signing_sk, &adaptor_point);
+
+        let secret = aggregate_oracle_s_values(signatures.clone()).unwrap();
+        let decrypted = adaptor_sig
+            .decrypt(&SecretKey::from_slice(&secret).unwrap())
+            .unwrap();
+        assert!(secp.verify_ecdsa(&msg, &decrypted, &signing_pk).is_ok());
+
+        // Missing one attestation does not decrypt
+        let partial = aggregate_oracle_s_values(signatures[..2].to_vec()).unwrap();
+        let decrypted = adaptor_sig
+            .decrypt(&SecretKey::from_slice(&partial).unwrap())
+            .unwrap();
+        assert!(secp.verify_ecdsa(&msg, &decrypted, &signing_pk).is_err());
+
+        assert!(matches!(
+            aggregate_oracle_s_values(vec![]),
+            Err(DLCError::InvalidArgument(_))
+        ));
     }
 
     #[test]
-    fn test_extract_ecdsa_signature_from_oracle_signatures() {
-        // Setup test data (similar to the main test)
+    fn test_sign_cet_with_external_signature() {
         let secp = Secp256k1::new();
-        let mut rng = secp256k1_zkp::rand::thread_rng();
-        let (offer_party_params, offer_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, None);
-        let (accept_party_params, _accept_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, None);
-
-        let dlc_txs = create_dlc_transactions(
+        let (offer_params, offer_fund_sk) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, accept_fund_sk) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let (oracle_info, oracle_kp, sk_nonces) = create_test_oracle(1);
+        let msgs = (0..payouts_test().len())
+            .map(|outcome| vec![vec![hash_outcome(outcome.to_string())]])
+            .collect::<Vec<_>>();
+        let contract = complete_dlc_flow(
+            offer_params.clone(),
+            accept_params.clone(),
             payouts_test(),
-            offer_party_params.clone(),
-            accept_party_params.clone(),
-            100,
+            vec![oracle_info],
+            msgs.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            accept_fund_sk.secret_bytes().to_vec(),
             4,
+            100,
             10,
             10,
             0,
+        )
+        .unwrap();
+
+        let outcome = 2;
+        let cet = contract.dlc_transactions.cets[outcome].clone();
+        let oracle_sig = secp_utils::schnorrsig_sign_with_nonce(
+            &secp,
+            &Message::from_digest_slice(&msgs[outcome][0][0]).unwrap(),
+            &oracle_kp,
+            &sk_nonces[0],
+        )
+        .serialize()
+        .to_vec();
+        let adaptor_signature = contract.offer_adaptor_signatures[outcome].signature.clone();
+        let signed = sign_cet(
+            cet.clone(),
+            adaptor_signature.clone(),
+            vec![oracle_sig.clone()],
+            accept_fund_sk.secret_bytes().to_vec(),
+            offer_params.fund_pubkey.clone(),
+            accept_params.fund_pubkey.clone(),
+            contract.fund_output_value,
+        )
+        .unwrap();
+
+        // The external signer produces the accept party's signature
+        let accept_pk = PublicKey::from_slice(&accept_params.fund_pubkey).unwrap();
+        let offer_pk = PublicKey::from_slice(&offer_params.fund_pubkey).unwrap();
+        let external_signature = ddk_dlc::util::get_sig_for_tx_input(
+            &secp,
+            &transaction_to_btc_tx(&cet).unwrap(),
+            0,
+            &ddk_dlc::make_funding_redeemscript(&accept_pk, &offer_pk),
+            Amount::from_sat(contract.fund_output_value),
+            EcdsaSighashType::All,
+            &accept_fund_sk,
+        )
+        .unwrap();
+        let sign_external = |external_signature: Vec<u8>| {
+            sign_cet_with_external_signature(
+                cet.clone(),
+                adaptor_signature.clone(),
+                vec![oracle_sig.clone()],
+                external_signature,
+                offer_params.fund_pubkey.clone(),
+                accept_params.fund_pubkey.clone(),
+                contract.fund_output_value,
+            )
+        };
+
+        let external = sign_external(external_signature.clone()).unwrap();
+        assert_eq!(external.raw_bytes, signed.raw_bytes);
+        // The sighash byte is optional
+        let der = external_signature[..external_signature.len() - 1].to_vec();
+        assert_eq!(sign_external(der).unwrap().raw_bytes, signed.raw_bytes);
+
+        // A signature by the wrong key is rejected
+        let wrong_key_signature = ddk_dlc::util::get_sig_for_tx_input(
+            &secp,
+            &transaction_to_btc_tx(&cet).unwrap(),
             0,
+            &ddk_dlc::make_funding_redeemscript(&accept_pk, &offer_pk),
+            Amount::from_sat(contract.fund_output_value),
+            EcdsaSighashType::All,
+            &offer_fund_sk,
         )
         .unwrap();
+        assert!(matches!(
+            sign_external(wrong_key_signature),
+            Err(DLCError::InvalidSignature)
+        ));
+    }
 
-        let cets = dlc_txs.cets;
-        const NB_ORACLES: usize = 1; // 1 oracle
-        const NB_OUTCOMES: usize = 3; // 3 outcomes (enumeration)
-        const NB_DIGITS: usize = 1; // 1 nonce for enumeration contract
+    #[test]
+    fn test_create_enumeration_adaptor_sigs() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let fund_output_value = get_fund_output_value(dlc_txs.clone()).unwrap();
+        let (oracle_info, _, _) = create_test_oracle(1);
+        let outcomes = vec!["win".to_string(), "lose".to_string(), "draw".to_string()];
+
+        let sigs = create_enumeration_adaptor_sigs(
+            dlc_txs.cets.clone(),
+            oracle_info.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            outcomes.clone(),
+        )
+        .unwrap();
+        let msgs = outcomes
+            .iter()
+            .map(|outcome| vec![vec![hash_outcome(outcome.clone())]])
+            .collect::<Vec<_>>();
+        assert!(verify_cet_adaptor_sigs_from_oracle_info(
+            sigs,
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_params.fund_pubkey,
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs,
+        ));
 
-        let mut oracle_infos: Vec<OracleInfo> = Vec::with_capacity(NB_ORACLES);
-        let mut oracle_sks: Vec<Keypair> = Vec::with_capacity(NB_ORACLES);
-        let mut oracle_sk_nonce: Vec<Vec<[u8; 32]>> = Vec::with_capacity(NB_ORACLES);
-        let mut oracle_sigs: Vec<Vec<SchnorrSignature>> = Vec::with_capacity(NB_ORACLES);
+        assert!(matches!(
+            create_enumeration_adaptor_sigs(
+                dlc_txs.cets,
+                oracle_info,
+                offer_fund_sk.secret_bytes().to_vec(),
+                dlc_txs.funding_script_pubkey,
+                fund_output_value,
+                outcomes[..2].to_vec(),
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
 
-        // Messages: 3 outcomes × 1 oracle × 1 message per outcome
-        let messages: Vec<Vec<Vec<_>>> = (0..NB_OUTCOMES)
-            .map(|outcome_idx| {
-                vec![
-                    // Single oracle
-                    vec![
-                        // Single message for this outcome
-                        {
-                            let message = &[outcome_idx as u8]; // Different message per outcome
-                            let hash = sha256::Hash::hash(message).to_byte_array();
-                            hash.to_vec()
-                        },
-                    ],
-                ]
-            })
-            .collect();
+    #[test]
+    fn test_cet_fees() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let fee_rate = 4;
+        let fund_output_value = get_fund_output_value(dlc_txs.clone()).unwrap();
+
+        // Each party pays its share of the CET fee into the funding output
+        let cet_fee = [
+            (&offer_params, &accept_params),
+            (&accept_params, &offer_params),
+        ]
+        .iter()
+        .map(|(params, other)| {
+            get_change_output_and_fees((*params).clone(), other.collateral, fee_rate, 0)
+                .unwrap()
+                .cet_fee
+        })
+        .sum::<u64>();
+        let fees = cet_fees(dlc_txs.cets.clone(), fund_output_value).unwrap();
+        assert_eq!(fees, vec![cet_fee; dlc_txs.cets.len()]);
 
-        // Setup single oracle with single nonce
-        for i in 0..NB_ORACLES {
-            // Runs once
-            let oracle_kp = Keypair::new(&secp, &mut rng);
-            let oracle_pubkey = oracle_kp.x_only_public_key().0;
-            let mut nonces: Vec<XOnlyPublicKey> = Vec::with_capacity(NB_DIGITS);
-            let mut sk_nonces: Vec<[u8; 32]> = Vec::with_capacity(NB_DIGITS);
-            oracle_sigs.push(Vec::with_capacity(NB_DIGITS));
+        assert!(matches!(
+            cet_fees(dlc_txs.cets, cet_fee),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
 
-            // Single nonce for enumeration
-            let mut sk_nonce = [0u8; 32];
-            rng.fill_bytes(&mut sk_nonce);
-            let oracle_r_kp = Keypair::from_seckey_slice(&secp, &sk_nonce).unwrap();
-            let nonce = XOnlyPublicKey::from_keypair(&oracle_r_kp).0;
+    #[test]
+    fn test_parse_network() {
+        for (names, expected) in [
+            (
+                &["bitcoin", "mainnet", "Mainnet", "BITCOIN"][..],
+                Network::Bitcoin,
+            ),
+            (&["testnet", "testnet3", "TestNet"][..], Network::Testnet),
+            (&["testnet4"][..], Network::Testnet4),
+            (&["signet", "Signet"][..], Network::Signet),
+            (&["regtest", "REGTEST"][..], Network::Regtest),
+        ] {
+            for name in names {
+                assert_eq!(parse_network(name).unwrap(), expected);
+                assert!(is_valid_network(name.to_string()));
+            }
+        }
+        assert!(matches!(
+            parse_network("main"),
+            Err(DLCError::InvalidNetwork)
+        ));
+        assert!(!is_valid_network(String::new()));
 
-            // Sign the first outcome's message with the single nonce
-            let sig = secp_utils::schnorrsig_sign_with_nonce(
-                &secp,
-                &Message::from_digest_slice(&messages[0][0][0]).unwrap(), // First outcome, first oracle, first message
-                &oracle_kp,
-                &sk_nonce,
-            );
+        // Aliases are accepted wherever a network is taken
+        let seed = [1u8; 64].to_vec();
+        assert_eq!(
+            create_extkey_from_seed(seed.clone(), "mainnet".to_string()).unwrap(),
+            create_extkey_from_seed(seed, "bitcoin".to_string()).unwrap()
+        );
+    }
 
-            oracle_sigs[i].push(sig);
-            nonces.push(nonce);
-            sk_nonces.push(sk_nonce);
+    #[test]
+    fn test_get_network_from_extkey() {
+        let seed = [7u8; 64].to_vec();
+        let xpriv = create_extkey_from_seed(seed.clone(), "bitcoin".to_string()).unwrap();
+        assert_eq!(get_network_from_extkey(xpriv.clone()).unwrap(), "bitcoin");
+        let xpub = get_xpub_from_xpriv(xpriv, "bitcoin".to_string()).unwrap();
+        assert_eq!(get_network_from_extkey(xpub).unwrap(), "bitcoin");
+
+        let tpriv = create_extkey_from_seed(seed, "regtest".to_string()).unwrap();
+        assert_eq!(get_network_from_extkey(tpriv).unwrap(), "testnet");
+
+        assert!(get_network_from_extkey(vec![0; 78]).is_err());
+        assert!(get_network_from_extkey(vec![0; 10]).is_err());
+    }
 
-            oracle_infos.push(OracleInfo {
-                public_key: oracle_pubkey.serialize().to_vec(),
-                nonces: nonces.iter().map(|n| n.serialize().to_vec()).collect(), // Just 1 nonce
-            });
-            oracle_sk_nonce.push(sk_nonces);
-            oracle_sks.push(oracle_kp);
+    #[test]
+    fn test_get_pubkey_from_extkey_network() {
+        let tpriv = create_extkey_from_seed([9u8; 64].to_vec(), "testnet".to_string()).unwrap();
+        let tpub = get_xpub_from_xpriv(tpriv.clone(), "testnet".to_string()).unwrap();
+        let expected = Xpub::decode(&tpub).unwrap().public_key.serialize().to_vec();
+
+        for extkey in [tpriv, tpub] {
+            for network in ["testnet", "signet", "regtest"] {
+                assert_eq!(
+                    get_pubkey_from_extkey(extkey.clone(), network.to_string()).unwrap(),
+                    expected
+                );
+            }
+            assert!(matches!(
+                get_pubkey_from_extkey(extkey, "bitcoin".to_string()),
+                Err(DLCError::InvalidNetwork)
+            ));
         }
+    }
 
-        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
-            &PublicKey::from_slice(&offer_party_params.fund_pubkey.clone()).unwrap(),
-            &PublicKey::from_slice(&accept_party_params.fund_pubkey.clone()).unwrap(),
+    #[test]
+    fn test_get_extkey_fingerprint() {
+        // BIP32 test vector 1
+        let seed = Vec::<u8>::from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let xpriv = Xpriv::new_master(Network::Bitcoin, &seed).unwrap();
+        let xpub = Xpub::from_priv(&Secp256k1::new(), &xpriv);
+
+        let expected = xpub.fingerprint().as_bytes().to_vec();
+        assert_eq!(expected, Vec::<u8>::from_hex("3442193e").unwrap());
+        assert_eq!(
+            get_extkey_fingerprint(xpriv.encode().to_vec()).unwrap(),
+            expected
         );
-        let fund_output_value = dlc_txs.fund.outputs[0].value;
+        assert_eq!(
+            get_extkey_fingerprint(xpub.encode().to_vec()).unwrap(),
+            expected
+        );
+        assert!(get_extkey_fingerprint(vec![0; 78]).is_err());
+    }
 
-        // Create adaptor signatures
-        let cet_sigs = create_cet_adaptor_sigs_from_oracle_info(
-            cets.clone(),
-            oracle_infos.clone(),
-            offer_fund_sk.secret_bytes().to_vec(),
-            funding_script_pubkey.clone().into_bytes(),
-            fund_output_value,
-            messages.clone(),
-        )
-        .unwrap();
+    #[test]
+    fn test_create_dlc_transactions_locktime_order() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let create = |refund_locktime: u32, cet_lock_time: u32, checked: bool| {
+            let create_fn = if checked {
+                create_dlc_transactions
+            } else {
+                create_dlc_transactions_unchecked
+            };
+            create_fn(
+                payouts_test(),
+                offer_params.clone(),
+                accept_params.clone(),
+                refund_locktime,
+                4,
+                10,
+                cet_lock_time,
+                0,
+                0,
+            )
+        };
 
-        // Convert oracle signatures to the format expected by our function
-        let oracle_signatures = oracle_sigs
-            .iter()
-            .map(|s| s.iter().map(|s| s.serialize().to_vec()).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+        assert!(create(101, 100, true).is_ok());
+        assert!(create(1_700_000_001, 1_700_000_000, true).is_ok());
+        for refund_locktime in [99, 100] {
+            assert!(matches!(
+                create(refund_locktime, 100, true),
+                Err(DLCError::InvalidArgument(_))
+            ));
+        }
+        // A timestamp is not after a block height, whatever their values
+        for (refund_locktime, cet_lock_time) in [(1_700_000_000, 100), (100, 1_700_000_000)] {
+            assert!(matches!(
+                create(refund_locktime, cet_lock_time, true),
+                Err(DLCError::InvalidArgument(_))
+            ));
+        }
 
-        // Test our new function
-        let result = extract_ecdsa_signature_from_oracle_signatures(
-            oracle_signatures[0].clone(),
-            cet_sigs[0].signature.clone(),
+        let unchecked = create(99, 100, false).unwrap();
+        let refund = transaction_to_btc_tx(&unchecked.refund).unwrap();
+        assert_eq!(refund.lock_time.to_consensus_u32(), 99);
+    }
+
+    #[test]
+    fn test_rebuild_refund() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+
+        let refund = rebuild_refund(
+            dlc_txs.clone(),
+            offer_params.payout_script_pubkey.clone(),
+            accept_params.payout_script_pubkey.clone(),
+            offer_params.collateral,
+            accept_params.collateral,
+            200,
+        )
+        .unwrap();
+        let original = transaction_to_btc_tx(&dlc_txs.refund).unwrap();
+        let rebuilt = transaction_to_btc_tx(&refund).unwrap();
+        assert_eq!(
+            rebuilt.input[0].previous_output,
+            original.input[0].previous_output
         );
+        assert_eq!(rebuilt.input[0].sequence, original.input[0].sequence);
+        assert_eq!(rebuilt.output, original.output);
+        assert_eq!(refund.lock_time, 200);
+
+        let mut no_fund_output = dlc_txs;
+        no_fund_output.funding_script_pubkey = vec![0x51];
+        assert!(rebuild_refund(
+            no_fund_output,
+            offer_params.payout_script_pubkey,
+            accept_params.payout_script_pubkey,
+            offer_params.collateral,
+            accept_params.collateral,
+            200,
+        )
+        .is_err());
+    }
 
-        assert!(result.is_ok(), "Function should succeed");
+    #[test]
+    fn test_secret_key_guard_zeroizes() {
+        fn assert_zeroize<T: Zeroize>() {}
+        assert_zeroize::<SecretKeyGuard>();
+        assert!(std::mem::needs_drop::<SecretKeyGuard>());
 
-        let ecdsa_sig_bytes = result.unwrap();
-        assert!(
-            !ecdsa_sig_bytes.is_empty(),
-            "Should return non-empty signature"
-        );
+        let (sk, pk, _, _) = create_test_keys();
+        let mut guard = SecretKeyGuard::from_slice(&sk.secret_bytes()).unwrap();
+        assert_eq!(PublicKey::from_secret_key(&Secp256k1::new(), &guard), pk);
 
-        // Verify the signature is valid DER format
-        let ecdsa_sig = EcdsaSignature::from_der(&ecdsa_sig_bytes);
-        assert!(ecdsa_sig.is_ok(), "Should be valid DER signature");
+        guard.zeroize();
+        assert_ne!(guard.secret_bytes(), sk.secret_bytes());
     }
 
     #[test]
-    fn test_get_cet_sighash() {
-        // Setup: Create DLC transactions to get a valid CET
-        let (offer_party_params, _offer_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, None);
-        let (accept_party_params, _accept_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, Some(2));
-
+    fn test_create_cet_adaptor_sigs_matches_per_cet() {
+        let (offer_params, offer_fund_sk) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let payouts = (0..200u64)
+            .map(|i| Payout {
+                offer: i * 1_000_000,
+                accept: 200_000_000 - i * 1_000_000,
+            })
+            .collect::<Vec<_>>();
         let dlc_txs = create_dlc_transactions(
-            payouts_test(),
-            offer_party_params.clone(),
-            accept_party_params.clone(),
+            payouts,
+            offer_params.clone(),
+            accept_params,
             100,
             4,
             10,
@@ -2286,57 +10113,243 @@ mod tests {
             0,
         )
         .unwrap();
+        assert_eq!(dlc_txs.cets.len(), 200);
+        let fund_output_value = get_fund_output_value(dlc_txs.clone()).unwrap();
+        let (oracle_info, _, _) = create_test_oracle(1);
+        let msgs = (0..200)
+            .map(|i| vec![vec![hash_outcome(format!("outcome-{i}"))]])
+            .collect::<Vec<_>>();
 
-        let cet = dlc_txs.cets[0].clone();
-        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
-            &PublicKey::from_slice(&offer_party_params.fund_pubkey).unwrap(),
-            &PublicKey::from_slice(&accept_party_params.fund_pubkey).unwrap(),
-        );
-        let fund_output_value = dlc_txs.fund.outputs[0].value;
-
-        // Act: Get the sighash
-        let result = get_cet_sighash(
-            cet.clone(),
-            funding_script_pubkey.clone().into_bytes(),
+        // With `--features parallel` this exercises the rayon path.
+        let sigs = create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
             fund_output_value,
-        );
+            msgs.clone(),
+        )
+        .unwrap();
+        assert_eq!(sigs.len(), 200);
+        // Adaptor signatures use a random nonce, so compare by verification:
+        // every signature must sit at its CET's index and no other.
+        assert!(verify_cet_adaptor_sigs_from_oracle_info(
+            sigs.clone(),
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        ));
+        let mut shifted = sigs;
+        shifted.rotate_left(1);
+        for (i, sig) in shifted.into_iter().enumerate() {
+            assert!(!verify_cet_adaptor_sig_from_oracle_info(
+                sig,
+                dlc_txs.cets[i].clone(),
+                vec![oracle_info.clone()],
+                offer_params.fund_pubkey.clone(),
+                dlc_txs.funding_script_pubkey.clone(),
+                fund_output_value,
+                msgs[i].clone(),
+            ));
+        }
 
-        // Assert
-        assert!(result.is_ok(), "get_cet_sighash should succeed");
-        let sighash = result.unwrap();
-        assert_eq!(sighash.len(), 32, "Sighash should be 32 bytes");
+        assert!(create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            vec![oracle_info],
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey,
+            fund_output_value,
+            msgs[..199].to_vec(),
+        )
+        .is_err());
+    }
 
-        // Verify against direct ddk-dlc call
-        let btc_tx = transaction_to_btc_tx(&cet).unwrap();
-        let direct_sighash = ddk_dlc::util::get_sig_hash_msg(
-            &btc_tx,
-            0,
-            Script::from_bytes(&funding_script_pubkey.clone().into_bytes()),
-            Amount::from_sat(fund_output_value),
+    #[test]
+    fn test_verify_cet_adaptor_sigs_detailed() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let fund_output_value = get_fund_output_value(dlc_txs.clone()).unwrap();
+        let (oracle_info, _, _) = create_test_oracle(1);
+        let msgs = ["a", "b", "c"]
+            .iter()
+            .map(|outcome| vec![vec![hash_outcome(outcome.to_string())]])
+            .collect::<Vec<_>>();
+        let mut sigs = create_cet_adaptor_sigs_from_oracle_info(
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_fund_sk.secret_bytes().to_vec(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        )
+        .unwrap();
+        // The CETs are identical, so CET 0's signature only fails at index 2
+        // because it was made for a different outcome.
+        sigs[2] = sigs[0].clone();
+
+        let results = verify_cet_adaptor_sigs_detailed(
+            sigs.clone(),
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
         )
         .unwrap();
+        assert_eq!(results, vec![true, true, false]);
+        assert!(!verify_cet_adaptor_sigs_from_oracle_info(
+            sigs.clone(),
+            dlc_txs.cets.clone(),
+            vec![oracle_info.clone()],
+            offer_params.fund_pubkey.clone(),
+            dlc_txs.funding_script_pubkey.clone(),
+            fund_output_value,
+            msgs.clone(),
+        ));
+
+        assert!(matches!(
+            verify_cet_adaptor_sigs_detailed(
+                sigs[..2].to_vec(),
+                dlc_txs.cets,
+                vec![oracle_info],
+                offer_params.fund_pubkey,
+                dlc_txs.funding_script_pubkey,
+                fund_output_value,
+                msgs,
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_cets_checked() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            ..
+        } = build_test_contract();
+        let fund_input = &dlc_txs.cets[0].inputs[0];
+        let create = |outcomes: Vec<Payout>| {
+            create_cets_checked(
+                fund_input.txid.clone(),
+                fund_input.vout,
+                offer_params.payout_script_pubkey.clone(),
+                accept_params.payout_script_pubkey.clone(),
+                outcomes,
+                200_000_000,
+                10,
+                offer_params.payout_serial_id,
+                accept_params.payout_serial_id,
+            )
+        };
+
+        let cets = create(payouts_test()).unwrap();
+        assert_eq!(cets.len(), dlc_txs.cets.len());
+        for (cet, expected) in cets.iter().zip(dlc_txs.cets.iter()) {
+            assert_eq!(cet.lock_time, expected.lock_time);
+            assert_eq!(cet.outputs.len(), expected.outputs.len());
+            for (output, expected) in cet.outputs.iter().zip(expected.outputs.iter()) {
+                assert_eq!(output.value, expected.value);
+                assert_eq!(output.script_pubkey, expected.script_pubkey);
+            }
+        }
+
+        let mut outcomes = payouts_test();
+        outcomes[1].accept -= 1_000;
+        match create(outcomes) {
+            Err(DLCError::InvalidArgument(msg)) => assert!(msg.starts_with("Payout 1:")),
+            _ => panic!("expected unbalanced payout to be rejected"),
+        }
+
+        let mut outcomes = payouts_test();
+        outcomes[2].offer = u64::MAX;
+        assert!(create(outcomes).is_err());
+    }
+
+    #[test]
+    fn test_create_dlc_transactions_with_fee_output() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(3));
+        let fee_output = TxOutput {
+            value: 2_000_000,
+            script_pubkey: Vec::from_hex("00141111111111111111111111111111111111111111").unwrap(),
+        };
+        let outcomes = vec![
+            Payout {
+                offer: 198_000_000,
+                accept: 0,
+            },
+            Payout {
+                offer: 99_000_000,
+                accept: 99_000_000,
+            },
+            Payout {
+                offer: 0,
+                accept: 198_000_000,
+            },
+        ];
+        let create_at = |outcomes: Vec<Payout>, fee_output: Option<TxOutput>, fee_rate: u64| {
+            create_dlc_transactions_with_fee_output(
+                outcomes,
+                offer_params.clone(),
+                accept_params.clone(),
+                100,
+                fee_rate,
+                10,
+                10,
+                0,
+                0,
+                fee_output,
+                2,
+            )
+        };
+        let create = |outcomes: Vec<Payout>, fee_output: Option<TxOutput>| {
+            create_at(outcomes, fee_output, 4)
+        };
 
+        let dlc_txs = create(outcomes.clone(), Some(fee_output.clone())).unwrap();
+        let fund_output_value = get_fund_output_value(dlc_txs.clone()).unwrap();
+        let cet_fee = fund_output_value - 200_000_000;
+        for (cet, outcome) in dlc_txs.cets.iter().zip(outcomes.iter()) {
+            let total: u64 = cet.outputs.iter().map(|output| output.value).sum();
+            assert_eq!(total, fund_output_value - cet_fee);
+            assert!(cet
+                .outputs
+                .iter()
+                .any(|output| output.value == fee_output.value
+                    && output.script_pubkey == fee_output.script_pubkey));
+            if outcome.offer > 0 && outcome.accept > 0 {
+                assert_eq!(cet.outputs.len(), 3);
+            }
+        }
+        // The middle CET orders outputs by serial id: offer, fee, accept.
+        let middle = &dlc_txs.cets[1];
         assert_eq!(
-            sighash,
-            direct_sighash.as_ref().to_vec(),
-            "Sighash should match direct ddk-dlc calculation"
+            middle.outputs[0].script_pubkey,
+            offer_params.payout_script_pubkey
         );
-    }
-
-    #[test]
-    fn test_get_cet_adaptor_signature_inputs() {
-        // Setup: Create DLC transactions and oracle info
-        let secp = Secp256k1::new();
-        let mut rng = secp256k1_zkp::rand::thread_rng();
-        let (offer_party_params, _offer_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, None);
-        let (accept_party_params, _accept_fund_sk) =
-            get_party_params(1_000_000_000, 100_000_000, Some(2));
+        assert_eq!(middle.outputs[1].script_pubkey, fee_output.script_pubkey);
+        assert_eq!(
+            middle.outputs[2].script_pubkey,
+            accept_params.payout_script_pubkey
+        );
+        assert!(validate_cets_share_funding(dlc_txs.cets.clone()).is_ok());
+        assert_eq!(dlc_txs.cets[0].inputs[0].sequence, 0xfffffffe);
 
-        let dlc_txs = create_dlc_transactions(
+        let unchanged = create(payouts_test(), None).unwrap();
+        let expected = create_dlc_transactions(
             payouts_test(),
-            offer_party_params.clone(),
-            accept_party_params.clone(),
+            offer_params.clone(),
+            accept_params.clone(),
             100,
             4,
             10,
@@ -2345,131 +10358,443 @@ mod tests {
             0,
         )
         .unwrap();
+        assert!(dlc_transactions_equal(unchanged, expected));
 
-        let cet = dlc_txs.cets[0].clone();
-        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
-            &PublicKey::from_slice(&offer_party_params.fund_pubkey).unwrap(),
-            &PublicKey::from_slice(&accept_party_params.fund_pubkey).unwrap(),
-        );
-        let fund_output_value = dlc_txs.fund.outputs[0].value;
+        // Payouts that still sum to the full collateral leave no room for the fee.
+        match create(payouts_test(), Some(fee_output.clone())) {
+            Err(DLCError::InvalidArgument(msg)) => assert!(msg.starts_with("Payout 0:")),
+            _ => panic!("expected payouts ignoring the fee to be rejected"),
+        }
 
-        // Create oracle info (single oracle, single nonce for enumeration)
-        let oracle_kp = Keypair::new(&secp, &mut rng);
-        let oracle_pubkey = oracle_kp.x_only_public_key().0;
-        let mut sk_nonce = [0u8; 32];
-        rng.fill_bytes(&mut sk_nonce);
-        let oracle_r_kp = Keypair::from_seckey_slice(&secp, &sk_nonce).unwrap();
-        let nonce = XOnlyPublicKey::from_keypair(&oracle_r_kp).0;
+        // Every CET pays at least the fee rate once signed, three outputs included
+        for fee_rate in [1, 4, 25] {
+            let dlc_txs = create_at(outcomes.clone(), Some(fee_output.clone()), fee_rate).unwrap();
+            let fund_output_value = get_fund_output_value(dlc_txs.clone()).unwrap();
+            for cet in &dlc_txs.cets {
+                let fee = fund_output_value - cet.outputs.iter().map(|o| o.value).sum::<u64>();
+                // Segwit marker and flag plus the 2-of-2 multisig witness
+                let weight = transaction_to_btc_tx(cet).unwrap().weight().to_wu() + 2 + 220;
+                assert!(fee >= weight.div_ceil(4) * fee_rate);
+            }
+        }
 
-        let oracle_info = vec![OracleInfo {
-            public_key: oracle_pubkey.serialize().to_vec(),
-            nonces: vec![nonce.serialize().to_vec()],
-        }];
+        // A fee output that would be dropped from the CETs or is unspendable
+        for fee_output in [
+            TxOutput {
+                value: DUST_LIMIT - 1,
+                ..fee_output.clone()
+            },
+            TxOutput {
+                script_pubkey: vec![],
+                ..fee_output
+            },
+        ] {
+            match create(outcomes.clone(), Some(fee_output)) {
+                Err(DLCError::InvalidArgument(msg)) => assert!(msg.starts_with("Fee output")),
+                _ => panic!("expected the fee output to be rejected"),
+            }
+        }
+    }
 
-        // Create message (first outcome)
-        let message = &[0u8];
-        let hash = sha256::Hash::hash(message).to_byte_array();
-        let msgs = vec![vec![hash.to_vec()]]; // Single oracle, single message
+    #[test]
+    fn test_validate_dlc_transactions() {
+        let TestContract { dlc_txs, .. } = build_test_contract();
+        assert!(validate_dlc_transactions(dlc_txs.clone()).is_ok());
+
+        let mut tampered = dlc_txs.clone();
+        let mut cet = transaction_to_btc_tx(&tampered.cets[1]).unwrap();
+        cet.input[0].previous_output.vout += 1;
+        tampered.cets[1] = btc_tx_to_transaction(&cet);
+        match validate_dlc_transactions(tampered) {
+            Err(DLCError::InvalidArgument(msg)) => assert!(msg.starts_with("CET 1 spends")),
+            _ => panic!("expected a CET with the wrong outpoint to be rejected"),
+        }
 
-        // Act: Get debug info
-        let result = get_cet_adaptor_signature_inputs(
-            cet.clone(),
-            oracle_info.clone(),
-            funding_script_pubkey.clone().into_bytes(),
-            fund_output_value,
-            msgs.clone(),
-        );
+        let mut tampered = dlc_txs.clone();
+        let mut refund = transaction_to_btc_tx(&tampered.refund).unwrap();
+        refund.output[0].value = Amount::from_sat(300_000_000);
+        tampered.refund = btc_tx_to_transaction(&refund);
+        match validate_dlc_transactions(tampered) {
+            Err(DLCError::InvalidArgument(msg)) => assert!(msg.starts_with("Refund outputs")),
+            _ => panic!("expected an overpaying refund to be rejected"),
+        }
 
-        // Assert
-        assert!(
-            result.is_ok(),
-            "get_cet_adaptor_signature_inputs should succeed"
-        );
-        let debug_info = result.unwrap();
+        let mut tampered = dlc_txs;
+        tampered.funding_script_pubkey = vec![0x51];
+        assert!(validate_dlc_transactions(tampered).is_err());
+    }
 
-        // Verify sighash
-        assert_eq!(debug_info.sighash.len(), 32, "Sighash should be 32 bytes");
-        let expected_sighash = get_cet_sighash(
-            cet.clone(),
-            funding_script_pubkey.clone().into_bytes(),
-            fund_output_value,
+    #[test]
+    fn test_create_dlc_input_signature() {
+        let secp = get_secp_context();
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            accept_params,
+            accept_fund_sk,
+            dlc_txs,
+            fund_vout,
+            ..
+        } = build_test_contract();
+        let offer_input = DlcInputInfo {
+            fund_tx: dlc_txs.fund.clone(),
+            fund_vout,
+            local_fund_pubkey: offer_params.fund_pubkey.clone(),
+            remote_fund_pubkey: accept_params.fund_pubkey.clone(),
+            fund_amount: dlc_txs.fund.outputs[fund_vout as usize].value,
+            max_witness_len: 220,
+            input_serial_id: 1,
+            contract_id: vec![0; 32],
+        };
+        let accept_input = DlcInputInfo {
+            local_fund_pubkey: accept_params.fund_pubkey.clone(),
+            remote_fund_pubkey: offer_params.fund_pubkey.clone(),
+            ..offer_input.clone()
+        };
+        let input_index = fund_vout + 1;
+        let txn = dlc_input_spend(&offer_input, input_index, 3);
+
+        // Each party produces only its half and sends it over
+        let offer_sig = create_dlc_input_signature(
+            txn.clone(),
+            offer_input.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
         )
         .unwrap();
-        assert_eq!(
-            debug_info.sighash, expected_sighash,
-            "Sighash should match get_cet_sighash result"
-        );
-
-        // Verify adaptor point
-        assert_eq!(
-            debug_info.adaptor_point.len(),
-            33,
-            "Adaptor point should be 33 bytes (compressed pubkey)"
-        );
+        let accept_sig = create_dlc_input_signature(
+            txn.clone(),
+            accept_input.clone(),
+            accept_fund_sk.secret_bytes().to_vec(),
+        )
+        .unwrap();
+        assert_eq!(offer_sig.last(), Some(&(EcdsaSighashType::All as u8)));
 
-        // Verify input index is always 0 for CETs
+        // Either party combining yields the same spend, signatures in pubkey order
+        let signed_by_offer = sign_multi_sig_input(
+            txn.clone(),
+            offer_input.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+            accept_sig.clone(),
+        )
+        .unwrap();
+        let signed_by_accept = sign_multi_sig_input(
+            txn.clone(),
+            accept_input,
+            accept_fund_sk.secret_bytes().to_vec(),
+            offer_sig.clone(),
+        )
+        .unwrap();
+        assert_eq!(signed_by_offer.raw_bytes, signed_by_accept.raw_bytes);
+
+        let witness = &signed_by_offer.inputs[input_index as usize].witness;
+        let rust_input = dlc_input_info_to_rust(&offer_input).unwrap();
+        let btc_tx = transaction_to_btc_tx(&txn).unwrap();
+        let mut pubkeys = [
+            PublicKey::from_slice(&offer_params.fund_pubkey).unwrap(),
+            PublicKey::from_slice(&accept_params.fund_pubkey).unwrap(),
+        ];
+        pubkeys.sort();
+        assert!(witness[0].is_empty());
+        for (sig, pubkey) in witness[1..3].iter().zip(pubkeys.iter()) {
+            ddk_dlc::dlc_input::verify_dlc_funding_input_signature(
+                secp,
+                &btc_tx,
+                input_index as usize,
+                &rust_input,
+                sig.clone(),
+                pubkey,
+            )
+            .unwrap();
+        }
         assert_eq!(
-            debug_info.input_index, 0,
-            "Input index should always be 0 for CETs"
+            witness[3],
+            ddk_dlc::dlc_input::create_dlc_input_funding_script(&rust_input).to_bytes()
         );
 
-        // Verify script_pubkey matches what we passed in
-        assert_eq!(
-            debug_info.script_pubkey,
-            funding_script_pubkey.clone().into_bytes(),
-            "Script pubkey should match input"
-        );
+        assert!(matches!(
+            create_dlc_input_signature(txn, offer_input.clone(), vec![0; 31]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        // A transaction that does not spend the DLC input
+        assert!(matches!(
+            create_dlc_input_signature(
+                dummy_transaction(3, 1),
+                offer_input,
+                offer_fund_sk.secret_bytes().to_vec()
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
+    }
 
-        // Verify value matches
-        assert_eq!(
-            debug_info.value, fund_output_value,
-            "Value should match input"
-        );
+    #[test]
+    fn test_verify_dlc_input_signature() {
+        let TestContract {
+            offer_params,
+            offer_fund_sk,
+            accept_params,
+            accept_fund_sk,
+            dlc_txs,
+            fund_vout,
+            ..
+        } = build_test_contract();
+        let offer_input = DlcInputInfo {
+            fund_tx: dlc_txs.fund.clone(),
+            fund_vout,
+            local_fund_pubkey: offer_params.fund_pubkey.clone(),
+            remote_fund_pubkey: accept_params.fund_pubkey.clone(),
+            fund_amount: dlc_txs.fund.outputs[fund_vout as usize].value,
+            max_witness_len: 220,
+            input_serial_id: 1,
+            contract_id: vec![0; 32],
+        };
+        let accept_input = DlcInputInfo {
+            local_fund_pubkey: accept_params.fund_pubkey.clone(),
+            remote_fund_pubkey: offer_params.fund_pubkey.clone(),
+            ..offer_input.clone()
+        };
+        // The DLC input does not have to sit at index fund_vout
+        let txn = dlc_input_spend(&offer_input, fund_vout + 2, fund_vout + 3);
+        let offer_sig = create_dlc_input_signature(
+            txn.clone(),
+            offer_input.clone(),
+            offer_fund_sk.secret_bytes().to_vec(),
+        )
+        .unwrap();
+        let accept_sig = create_dlc_input_signature(
+            txn.clone(),
+            accept_input.clone(),
+            accept_fund_sk.secret_bytes().to_vec(),
+        )
+        .unwrap();
 
-        // Verify cet_txid is valid
-        let btc_tx = transaction_to_btc_tx(&cet).unwrap();
-        assert_eq!(
-            debug_info.cet_txid,
-            btc_tx.compute_txid().to_string(),
-            "CET txid should match"
-        );
+        // The accepting party checks the offer's signature from its own view
+        assert!(verify_dlc_input_signature(
+            txn.clone(),
+            accept_input.clone(),
+            offer_sig.clone(),
+            offer_params.fund_pubkey.clone(),
+        )
+        .unwrap());
+        assert!(verify_dlc_input_signature(
+            txn.clone(),
+            offer_input.clone(),
+            accept_sig.clone(),
+            accept_params.fund_pubkey.clone(),
+        )
+        .unwrap());
+
+        // Signatures attributed to the wrong key do not verify
+        assert!(!verify_dlc_input_signature(
+            txn.clone(),
+            accept_input.clone(),
+            offer_sig.clone(),
+            accept_params.fund_pubkey.clone(),
+        )
+        .unwrap());
+        assert!(!verify_dlc_input_signature(
+            txn.clone(),
+            offer_input.clone(),
+            accept_sig,
+            offer_params.fund_pubkey.clone(),
+        )
+        .unwrap());
+
+        let mut other_sighash = offer_sig.clone();
+        *other_sighash.last_mut().unwrap() = EcdsaSighashType::None as u8;
+        assert!(!verify_dlc_input_signature(
+            txn.clone(),
+            accept_input.clone(),
+            other_sighash,
+            offer_params.fund_pubkey.clone(),
+        )
+        .unwrap());
+
+        let (_, _, _, stranger_pk) = create_test_keys();
+        assert!(matches!(
+            verify_dlc_input_signature(
+                txn.clone(),
+                accept_input.clone(),
+                offer_sig.clone(),
+                stranger_pk.serialize().to_vec(),
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            verify_dlc_input_signature(
+                txn.clone(),
+                accept_input.clone(),
+                Vec::new(),
+                offer_params.fund_pubkey.clone()
+            ),
+            Err(DLCError::InvalidSignature)
+        ));
 
-        // Verify cet_raw matches input
-        assert_eq!(
-            debug_info.cet_raw, cet.raw_bytes,
-            "CET raw bytes should match input"
-        );
+        // The signature commits to the input index, so it fails elsewhere
+        let moved = dlc_input_spend(&offer_input, fund_vout, fund_vout + 3);
+        assert!(!verify_dlc_input_signature(
+            moved,
+            accept_input.clone(),
+            offer_sig.clone(),
+            offer_params.fund_pubkey.clone(),
+        )
+        .unwrap());
+        assert!(matches!(
+            verify_dlc_input_signature(
+                dummy_transaction(3, 1),
+                accept_input,
+                offer_sig,
+                offer_params.fund_pubkey
+            ),
+            Err(DLCError::InvalidArgument(_))
+        ));
     }
 
     #[test]
-    fn test_get_cet_sighash_invalid_transaction() {
-        // Create an invalid transaction (empty raw_bytes)
-        let invalid_tx = Transaction {
-            version: 2,
-            lock_time: 0,
-            inputs: vec![],
-            outputs: vec![],
-            raw_bytes: vec![0x00], // Invalid serialization
+    fn test_dlc_input_info_from_fund_hex() {
+        let TestContract {
+            offer_params,
+            accept_params,
+            dlc_txs,
+            fund_vout,
+            ..
+        } = build_test_contract();
+        let fund_amount = dlc_txs.fund.outputs[fund_vout as usize].value;
+        let fund_hex = transaction_to_hex(dlc_txs.fund.clone());
+        let from_hex = |fund_vout: u32, fund_amount: u64, contract_id: Vec<u8>| {
+            dlc_input_info_from_fund_hex(
+                fund_hex.clone(),
+                fund_vout,
+                offer_params.fund_pubkey.clone(),
+                accept_params.fund_pubkey.clone(),
+                fund_amount,
+                220,
+                1,
+                contract_id,
+            )
         };
 
-        let result = get_cet_sighash(invalid_tx, vec![0x00, 0x14], 100_000);
+        let dlc_input = from_hex(fund_vout, fund_amount, vec![7; 32]).unwrap();
+        assert_eq!(dlc_input.fund_tx.raw_bytes, dlc_txs.fund.raw_bytes);
+        assert_eq!(dlc_input.fund_vout, fund_vout);
+        assert_eq!(dlc_input.fund_amount, fund_amount);
+        assert_eq!(dlc_input.contract_id, vec![7; 32]);
+        assert!(dlc_input_sighash(dlc_input_spend(&dlc_input, 0, 1), dlc_input).is_ok());
 
-        assert!(
-            result.is_err(),
-            "Should fail with invalid transaction bytes"
-        );
+        assert!(matches!(
+            from_hex(fund_vout, fund_amount - 1, vec![7; 32]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        let output_count = dlc_txs.fund.outputs.len() as u32;
+        assert!(matches!(
+            from_hex(output_count, fund_amount, vec![7; 32]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            from_hex(fund_vout, fund_amount, vec![7; 31]),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            dlc_input_info_from_fund_hex(
+                "zz".to_string(),
+                fund_vout,
+                offer_params.fund_pubkey,
+                accept_params.fund_pubkey,
+                fund_amount,
+                220,
+                1,
+                vec![7; 32],
+            ),
+            Err(DLCError::SerializationError)
+        ));
     }
 
     #[test]
-    fn test_get_cet_adaptor_signature_inputs_invalid_oracle_pubkey() {
-        // Setup valid CET
-        let (offer_party_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
-        let (accept_party_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+    fn test_estimate_fund_transaction_fee() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        let fee_rate = 7;
+        // Fee implied by the size of the fund transaction once every input
+        // carries its maximum witness
+        let actual_fee = |offer_params: &PartyParams, accept_params: &PartyParams| {
+            let dlc_txs = create_dlc_transactions(
+                payouts_test(),
+                offer_params.clone(),
+                accept_params.clone(),
+                100,
+                fee_rate,
+                10,
+                10,
+                0,
+                0,
+            )
+            .unwrap();
+            let fund = transaction_to_btc_tx(&dlc_txs.fund).unwrap();
+            let witness_weight = offer_params
+                .inputs
+                .iter()
+                .chain(accept_params.inputs.iter())
+                .map(|input| input.max_witness_length as u64)
+                .sum::<u64>();
+            let weight = fund.weight().to_wu() + 2 + witness_weight;
+            (weight.div_ceil(4) * fee_rate, fund.input.len() as u64)
+        };
+
+        let (fee, input_count) = actual_fee(&offer_params, &accept_params);
+        let estimate =
+            estimate_fund_transaction_fee(offer_params.clone(), accept_params.clone(), fee_rate)
+                .unwrap();
+        assert!(estimate >= fee);
+        assert!(estimate - fee <= input_count * fee_rate);
+
+        // P2SH-P2WPKH inputs are sized with their scriptSig
+        let mut nested_params = accept_params.clone();
+        nested_params.inputs[0].script_sig = vec![1; 20];
+        let (nested_fee, input_count) = actual_fee(&offer_params, &nested_params);
+        let nested_estimate =
+            estimate_fund_transaction_fee(offer_params.clone(), nested_params, fee_rate).unwrap();
+        // OP_0, a push opcode and the 20 byte redeem script
+        assert_eq!(nested_estimate - estimate, 22 * fee_rate);
+        assert!(nested_estimate >= nested_fee);
+        assert!(nested_estimate - nested_fee <= input_count * fee_rate);
+
+        // A party without change does not add a change output
+        let no_change = PartyParams {
+            change_script_pubkey: None,
+            ..accept_params.clone()
+        };
+        let without_change =
+            estimate_fund_transaction_fee(offer_params.clone(), no_change, fee_rate).unwrap();
+        assert_eq!(
+            estimate - without_change,
+            (8 + 1 + accept_params.change_script_pubkey.as_ref().unwrap().len() as u64) * fee_rate
+        );
+
+        assert!(estimate_fund_transaction_fee(offer_params, accept_params, u64::MAX).is_err());
+    }
 
+    #[test]
+    fn test_find_cet_by_payout() {
+        let (offer_params, _) = get_party_params(1_000_000_000, 100_000_000, None);
+        let (accept_params, _) = get_party_params(1_000_000_000, 100_000_000, Some(2));
+        // Mirrored outcomes pay the same amounts, only the scripts tell them apart
+        let outcomes = vec![
+            Payout {
+                offer: 150_000_000,
+                accept: 50_000_000,
+            },
+            Payout {
+                offer: 50_000_000,
+                accept: 150_000_000,
+            },
+            Payout {
+                offer: 200_000_000,
+                accept: 0,
+            },
+        ];
         let dlc_txs = create_dlc_transactions(
-            payouts_test(),
-            offer_party_params.clone(),
-            accept_party_params.clone(),
+            outcomes.clone(),
+            offer_params.clone(),
+            accept_params.clone(),
             100,
             4,
             10,
@@ -2478,32 +10803,160 @@ mod tests {
             0,
         )
         .unwrap();
+        let find = |cets: Vec<Transaction>, offer_amount: u64, accept_amount: u64| {
+            find_cet_by_payout(
+                cets,
+                offer_amount,
+                accept_amount,
+                offer_params.payout_script_pubkey.clone(),
+                accept_params.payout_script_pubkey.clone(),
+            )
+        };
 
-        let cet = dlc_txs.cets[0].clone();
-        let funding_script_pubkey = ddk_dlc::make_funding_redeemscript(
-            &PublicKey::from_slice(&offer_party_params.fund_pubkey).unwrap(),
-            &PublicKey::from_slice(&accept_party_params.fund_pubkey).unwrap(),
+        for (i, outcome) in outcomes.iter().enumerate() {
+            assert_eq!(
+                find(dlc_txs.cets.clone(), outcome.offer, outcome.accept).unwrap(),
+                i as u32
+            );
+        }
+        // Swapping the scripts finds the mirrored outcome
+        assert_eq!(
+            find_cet_by_payout(
+                dlc_txs.cets.clone(),
+                150_000_000,
+                50_000_000,
+                accept_params.payout_script_pubkey.clone(),
+                offer_params.payout_script_pubkey.clone(),
+            )
+            .unwrap(),
+            1
         );
 
-        // Invalid oracle info (wrong pubkey length)
-        let invalid_oracle_info = vec![OracleInfo {
-            public_key: vec![0x00; 20], // Invalid: should be 32 bytes for x-only
-            nonces: vec![vec![0x00; 32]],
-        }];
+        assert!(matches!(
+            find(dlc_txs.cets.clone(), 120_000_000, 80_000_000),
+            Err(DLCError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            find(dlc_txs.cets.clone(), 0, 200_000_000),
+            Err(DLCError::InvalidArgument(_))
+        ));
 
-        let msgs = vec![vec![vec![0u8; 32]]];
+        let same_payouts = create_dlc_transactions(
+            payouts_test(),
+            offer_params.clone(),
+            accept_params.clone(),
+            100,
+            4,
+            10,
+            10,
+            0,
+            0,
+        )
+        .unwrap();
+        match find(same_payouts.cets, 100_000_000, 100_000_000) {
+            Err(DLCError::InvalidArgument(msg)) => assert!(msg.starts_with("More than one")),
+            _ => panic!("expected identical CETs to be ambiguous"),
+        }
+    }
 
-        let result = get_cet_adaptor_signature_inputs(
-            cet,
-            invalid_oracle_info,
-            funding_script_pubkey.into_bytes(),
-            100_000,
-            msgs,
+    #[test]
+    fn test_create_cet_with_info() {
+        let secp = Secp256k1::new();
+        let offer_script = get_p2wpkh_script_pubkey(&secp).into_bytes();
+        let accept_script = get_p2wpkh_script_pubkey(&secp).into_bytes();
+        let fund_tx_id = Txid::all_zeros().to_string();
+        let build = |offer: u64, accept: u64| {
+            create_cet_with_info(
+                TxOutput {
+                    value: offer,
+                    script_pubkey: offer_script.clone(),
+                },
+                1,
+                TxOutput {
+                    value: accept,
+                    script_pubkey: accept_script.clone(),
+                },
+                2,
+                fund_tx_id.clone(),
+                0,
+                10,
+            )
+            .unwrap()
+        };
+
+        let both = build(100_000_000, 99_999_000);
+        assert!(!both.offer_output_dropped);
+        assert!(!both.accept_output_dropped);
+        assert_eq!(both.transaction.outputs.len(), 2);
+
+        let winner_take_all = build(0, 199_999_000);
+        assert!(winner_take_all.offer_output_dropped);
+        assert!(!winner_take_all.accept_output_dropped);
+        assert_eq!(winner_take_all.transaction.outputs.len(), 1);
+        assert_eq!(
+            winner_take_all.transaction.outputs[0].script_pubkey,
+            accept_script
         );
 
-        assert!(
-            result.is_err(),
-            "Should fail with invalid oracle public key"
+        let dust = build(199_999_000, DUST_LIMIT - 1);
+        assert!(!dust.offer_output_dropped);
+        assert!(dust.accept_output_dropped);
+        assert_eq!(dust.transaction.outputs.len(), 1);
+    }
+
+    #[test]
+    fn test_is_dust_output_for_feerate() {
+        let p2wpkh = ScriptBuf::new_p2wpkh(&WPubkeyHash::all_zeros()).into_bytes();
+        let (_, pk, _, _) = create_test_keys();
+        let p2tr =
+            ScriptBuf::new_p2tr(get_secp_context(), pk.x_only_public_key().0, None).into_bytes();
+        let output = |value: u64, script_pubkey: &Vec<u8>| TxOutput {
+            value,
+            script_pubkey: script_pubkey.clone(),
+        };
+
+        // At 20 sat/vB, (31 + 67) vB for P2WPKH and (43 + 67) vB for P2TR
+        assert_eq!(dust_threshold(&p2wpkh, 20), 1_960);
+        assert_eq!(dust_threshold(&p2tr, 20), 2_200);
+        assert!(!is_dust_output_for_feerate(output(2_000, &p2wpkh), 20));
+        assert!(is_dust_output_for_feerate(output(2_000, &p2tr), 20));
+        assert!(!is_dust_output(output(2_000, &p2tr)));
+
+        // Low fee rates fall back to the flat dust limit
+        assert!(is_dust_output_for_feerate(
+            output(DUST_LIMIT - 1, &p2wpkh),
+            1
+        ));
+        assert!(!is_dust_output_for_feerate(output(DUST_LIMIT, &p2tr), 1));
+    }
+
+    #[test]
+    fn test_dlc_error_codes() {
+        let errors = [
+            DLCError::InvalidSignature,
+            DLCError::InvalidPublicKey,
+            DLCError::InvalidTransaction,
+            DLCError::InsufficientFunds,
+            DLCError::InvalidArgument("bad".to_string()),
+            DLCError::SerializationError,
+            DLCError::Secp256k1Error("bad".to_string()),
+            DLCError::MiniscriptError,
+            DLCError::InvalidNetwork,
+            DLCError::KeyError(ExtendedKey::InvalidMnemonic),
+            DLCError::KeyError(ExtendedKey::InvalidXpriv),
+            DLCError::KeyError(ExtendedKey::InvalidXpub),
+            DLCError::KeyError(ExtendedKey::InvalidDerivationPath),
+        ];
+        let codes = errors.iter().map(DLCError::code).collect::<HashSet<_>>();
+        assert_eq!(codes.len(), errors.len());
+        assert!(!codes.contains(&0));
+
+        // Codes do not depend on the message
+        assert_eq!(
+            DLCError::InvalidArgument("a".to_string()).code(),
+            DLCError::InvalidArgument("b".to_string()).code()
         );
+        assert_eq!(DLCError::InsufficientFunds.code(), 4);
+        assert_eq!(DLCError::InvalidSignature.code(), 1);
     }
 }
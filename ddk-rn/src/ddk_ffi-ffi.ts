@@ -23,6 +23,14 @@ interface NativeModuleInterface {
     buffer: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): string;
+  ubrn_uniffi_ddk_ffi_fn_func_adaptor_signature_parse(
+    bytes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_adaptor_signature_serialize(
+    sig: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_add_signature_to_transaction(
     tx: Uint8Array,
     signature: Uint8Array,
@@ -30,6 +38,86 @@ interface NativeModuleInterface {
     inputIndex: number,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_add_witnesses_to_transaction(
+    tx: Uint8Array,
+    witnesses: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_address_to_script_pubkey(
+    address: Uint8Array,
+    network: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_aggregate_oracle_s_values(
+    signatures: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_cet_fees(
+    cets: Uint8Array,
+    fundOutputValue: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_cet_nonce_dependencies(
+    msgs: Uint8Array,
+    oracleInfos: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_cet_to_outcome(
+    cet: Uint8Array,
+    outcomes: Uint8Array,
+    localScript: Uint8Array,
+    remoteScript: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_change_across_fee_rates(
+    params: Uint8Array,
+    feeRates: Uint8Array,
+    remoteCollateral: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_check_fee_sanity(
+    tx: Uint8Array,
+    inputAmounts: Uint8Array,
+    maxFeeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): void;
+  ubrn_uniffi_ddk_ffi_fn_func_complete_dlc_flow(
+    offerParams: Uint8Array,
+    acceptParams: Uint8Array,
+    outcomes: Uint8Array,
+    oracleInfos: Uint8Array,
+    msgs: Uint8Array,
+    offerFundSk: Uint8Array,
+    acceptFundSk: Uint8Array,
+    feeRate: bigint,
+    refundLocktime: number,
+    fundLockTime: number,
+    cetLockTime: number,
+    fundOutputSerialId: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_compute_cet_fee(
+    payoutScriptLen: number,
+    feeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_compute_fund_fee(
+    inputCount: number,
+    extraWitnessLengths: Uint8Array,
+    feeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_contract_fingerprint(
+    dlcTxs: Uint8Array,
+    oracleInfos: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_contract_pubkeys(
+    dlcTxs: Uint8Array,
+    localFundPubkey: Uint8Array,
+    remoteFundPubkey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_convert_mnemonic_to_seed(
     mnemonic: Uint8Array,
     passphrase: Uint8Array,
@@ -59,6 +147,15 @@ interface NativeModuleInterface {
     msgs: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_signature_multi_oracle(
+    cet: Uint8Array,
+    oracleInfos: Uint8Array,
+    fundingSk: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    totalCollateral: bigint,
+    msgs: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_oracle_info(
     cets: Uint8Array,
     oracleInfo: Uint8Array,
@@ -76,6 +173,28 @@ interface NativeModuleInterface {
     fundOutputValue: bigint,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_cet_checked(
+    localOutput: Uint8Array,
+    localPayoutSerialId: bigint,
+    remoteOutput: Uint8Array,
+    remotePayoutSerialId: bigint,
+    fundTxId: Uint8Array,
+    fundVout: number,
+    lockTime: number,
+    fundOutputValue: bigint,
+    feeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_cet_with_info(
+    localOutput: Uint8Array,
+    localPayoutSerialId: bigint,
+    remoteOutput: Uint8Array,
+    remotePayoutSerialId: bigint,
+    fundTxId: Uint8Array,
+    fundVout: number,
+    lockTime: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_create_cets(
     fundTxId: Uint8Array,
     fundVout: number,
@@ -87,6 +206,24 @@ interface NativeModuleInterface {
     remoteSerialId: bigint,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_cets_checked(
+    fundTxId: Uint8Array,
+    fundVout: number,
+    localFinalScriptPubkey: Uint8Array,
+    remoteFinalScriptPubkey: Uint8Array,
+    outcomes: Uint8Array,
+    totalCollateral: bigint,
+    lockTime: number,
+    localSerialId: bigint,
+    remoteSerialId: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_dlc_input_signature(
+    txn: Uint8Array,
+    dlcInput: Uint8Array,
+    localPrivkey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions(
     outcomes: Uint8Array,
     localParams: Uint8Array,
@@ -99,6 +236,45 @@ interface NativeModuleInterface {
     contractFlags: number,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions_batch(
+    requests: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions_unchecked(
+    outcomes: Uint8Array,
+    localParams: Uint8Array,
+    remoteParams: Uint8Array,
+    refundLocktime: number,
+    feeRate: bigint,
+    fundLockTime: number,
+    cetLockTime: number,
+    fundOutputSerialId: bigint,
+    contractFlags: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions_with_fee_output(
+    outcomes: Uint8Array,
+    localParams: Uint8Array,
+    remoteParams: Uint8Array,
+    refundLocktime: number,
+    feeRate: bigint,
+    fundLockTime: number,
+    cetLockTime: number,
+    fundOutputSerialId: bigint,
+    contractFlags: number,
+    feeOutput: Uint8Array,
+    feeOutputSerialId: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_create_enumeration_adaptor_sigs(
+    cets: Uint8Array,
+    oracleInfo: Uint8Array,
+    fundingSecretKey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    fundOutputValue: bigint,
+    outcomes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_create_extkey_from_parent_path(
     extkey: Uint8Array,
     path: Uint8Array,
@@ -143,11 +319,132 @@ interface NativeModuleInterface {
     path: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_decompose_schnorr_signature(
+    signature: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_decrypt_and_verify_cet_signature(
+    adaptorSig: Uint8Array,
+    oracleSignatures: Uint8Array,
+    cet: Uint8Array,
+    pubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    fundOutputValue: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_decrypt_cet_adaptor_signature(
+    adaptorSignature: Uint8Array,
+    oracleSignatures: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_derive_privkey_from_seed(
+    seed: Uint8Array,
+    network: Uint8Array,
+    path: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_derive_pubkey_from_seed(
+    seed: Uint8Array,
+    network: Uint8Array,
+    path: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_deserialize_settlement_state(
+    bytes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_diff_cet_refund(
+    cet: Uint8Array,
+    refund: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_diff_party_params(
+    a: Uint8Array,
+    b: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_dlc_input_info_from_fund_hex(
+    fundTxHex: Uint8Array,
+    fundVout: number,
+    localFundPubkey: Uint8Array,
+    remoteFundPubkey: Uint8Array,
+    fundAmount: bigint,
+    maxWitnessLen: number,
+    inputSerialId: bigint,
+    contractId: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_dlc_input_sighash(
+    txn: Uint8Array,
+    dlcInput: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_dlc_transactions_equal(
+    a: Uint8Array,
+    b: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_dlc_transactions_to_fund_psbt(
+    dlcTxs: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_dummy_transaction(
+    inputCount: number,
+    outputCount: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_estimate_cet_fee_taproot(
+    feeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_estimate_contract_cost(
+    base: number,
+    nbDigits: number,
+    nbOracles: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_estimate_fund_transaction_fee(
+    localParams: Uint8Array,
+    remoteParams: Uint8Array,
+    feeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_extkeys_match(
+    xpriv: Uint8Array,
+    xpub: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
   ubrn_uniffi_ddk_ffi_fn_func_extract_ecdsa_signature_from_oracle_signatures(
     oracleSignatures: Uint8Array,
     adaptorSignature: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_finalize_fund_psbt(
+    psbtBytes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_finalize_refund_transaction(
+    refundTx: Uint8Array,
+    localSignature: Uint8Array,
+    remoteSignature: Uint8Array,
+    localFundPubkey: Uint8Array,
+    remoteFundPubkey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_find_cet_by_payout(
+    cets: Uint8Array,
+    offerAmount: bigint,
+    acceptAmount: bigint,
+    offerScript: Uint8Array,
+    acceptScript: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_funding_signing_plan(
+    fundTx: Uint8Array,
+    ownedOutpoints: Uint8Array,
+    amounts: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_get_cet_adaptor_signature_inputs(
     cet: Uint8Array,
     oracleInfo: Uint8Array,
@@ -164,7 +461,21 @@ interface NativeModuleInterface {
   ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_get_change_output_and_fees(
     params: Uint8Array,
+    otherCollateral: bigint,
     feeRate: bigint,
+    extraFee: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_get_extkey_fingerprint(
+    extkey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_get_fund_output_value(
+    dlcTxs: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_get_network_from_extkey(
+    extkey: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_get_pubkey_from_extkey(
@@ -184,15 +495,136 @@ interface NativeModuleInterface {
     inputs: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_get_transaction_txid(
+    tx: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_get_transaction_wtxid(
+    tx: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_get_xpub_from_xpriv(
     xpriv: Uint8Array,
     network: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_hash_outcome(
+    outcome: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_hash_outcomes(
+    outcomes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_is_dust_output(
     output: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_is_dust_output_for_feerate(
+    output: Uint8Array,
+    feeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_is_valid_network(
+    network: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_max_safe_fee_rate(
+    localParams: Uint8Array,
+    remoteParams: Uint8Array,
+    outcomes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_message_template(
+    oracleInfos: Uint8Array,
+    cetCount: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_min_viable_collateral(
+    outcomesRatios: Uint8Array,
+    feeRate: bigint,
+    localScript: Uint8Array,
+    remoteScript: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_outcome_signature_point(
+    oraclePubkey: Uint8Array,
+    nonce: Uint8Array,
+    message: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_party_params_deserialize(
+    bytes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_party_params_from_descriptor(
+    fundPubkey: Uint8Array,
+    changeDescriptor: Uint8Array,
+    payoutDescriptor: Uint8Array,
+    inputs: Uint8Array,
+    inputAmount: bigint,
+    collateral: bigint,
+    network: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_party_params_serialize(
+    params: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_preview_change(
+    inputs: Uint8Array,
+    inputAmounts: Uint8Array,
+    collateral: bigint,
+    feeRate: bigint,
+    changeScript: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_pubkey_to_address(
+    pubkey: Uint8Array,
+    network: Uint8Array,
+    addressType: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_rebuild_refund(
+    dlcTxs: Uint8Array,
+    localFinalScriptPubkey: Uint8Array,
+    remoteFinalScriptPubkey: Uint8Array,
+    localAmount: bigint,
+    remoteAmount: bigint,
+    newLockTime: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_recover_adaptor_secret(
+    adaptorSignature: Uint8Array,
+    finalSignature: Uint8Array,
+    adaptorPoint: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_rekey_contract(
+    localParams: Uint8Array,
+    newFundPubkey: Uint8Array,
+    remoteParams: Uint8Array,
+    outcomes: Uint8Array,
+    refundLocktime: number,
+    feeRate: bigint,
+    fundLockTime: number,
+    cetLockTime: number,
+    fundOutputSerialId: bigint,
+    contractFlags: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_safe_fund_output_serial_id(
+    localParams: Uint8Array,
+    remoteParams: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): bigint;
+  ubrn_uniffi_ddk_ffi_fn_func_serialize_settlement_state(
+    dlcTxs: Uint8Array,
+    adaptorSigs: Uint8Array,
+    oracleInfos: Uint8Array,
+    contractId: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_sign_cet(
     cet: Uint8Array,
     adaptorSignature: Uint8Array,
@@ -203,6 +635,28 @@ interface NativeModuleInterface {
     fundOutputValue: bigint,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_sign_cet_checked(
+    cet: Uint8Array,
+    adaptorSignature: Uint8Array,
+    oracleSignatures: Uint8Array,
+    fundingSecretKey: Uint8Array,
+    otherPubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    fundOutputValue: bigint,
+    oracleInfos: Uint8Array,
+    msgs: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_sign_cet_with_external_signature(
+    cet: Uint8Array,
+    adaptorSignature: Uint8Array,
+    oracleSignatures: Uint8Array,
+    externalSignature: Uint8Array,
+    otherPubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    fundOutputValue: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_sign_fund_transaction_input(
     fundTransaction: Uint8Array,
     privkey: Uint8Array,
@@ -218,6 +672,76 @@ interface NativeModuleInterface {
     remoteSignature: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_sign_refund_transaction(
+    refundTx: Uint8Array,
+    fundingSecretKey: Uint8Array,
+    otherPubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    fundOutputValue: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_transaction_from_hex(
+    hex: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_transaction_to_hex(
+    tx: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_transactions_equivalent(
+    a: Uint8Array,
+    b: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_validate_adaptor_sig_bytes(
+    bytes: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): void;
+  ubrn_uniffi_ddk_ffi_fn_func_validate_cets_share_funding(
+    cets: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_validate_dlc_transactions(
+    dlcTxs: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): void;
+  ubrn_uniffi_ddk_ffi_fn_func_validate_mnemonic(
+    mnemonic: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): void;
+  ubrn_uniffi_ddk_ffi_fn_func_validate_oracle_count(
+    oracleInfos: Uint8Array,
+    required: number,
+    uniffi_out_err: UniffiRustCallStatus
+  ): void;
+  ubrn_uniffi_ddk_ffi_fn_func_validate_party_params(
+    params: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): void;
+  ubrn_uniffi_ddk_ffi_fn_func_validate_transaction_consistency(
+    tx: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): void;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_adaptor_points_wellformed(
+    adaptorPoints: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_adaptor_proof(
+    adaptorSig: Uint8Array,
+    adaptorPoint: Uint8Array,
+    message: Uint8Array,
+    pubkey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_adaptor_sig_binds_cet(
+    adaptorSig: Uint8Array,
+    cet: Uint8Array,
+    adaptorPoint: Uint8Array,
+    pubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    totalCollateral: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
   ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sig_from_oracle_info(
     adaptorSig: Uint8Array,
     cet: Uint8Array,
@@ -228,6 +752,16 @@ interface NativeModuleInterface {
     msgs: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_detailed(
+    adaptorSigs: Uint8Array,
+    cets: Uint8Array,
+    oracleInfo: Uint8Array,
+    pubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    totalCollateral: bigint,
+    msgs: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
   ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_from_oracle_info(
     adaptorSigs: Uint8Array,
     cets: Uint8Array,
@@ -238,6 +772,29 @@ interface NativeModuleInterface {
     msgs: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus
   ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_with_points(
+    adaptorSigs: Uint8Array,
+    cets: Uint8Array,
+    adaptorPoints: Uint8Array,
+    pubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    totalCollateral: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_collateral_invariant(
+    dlcTxs: Uint8Array,
+    offerParams: Uint8Array,
+    acceptParams: Uint8Array,
+    feeRate: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_dlc_input_signature(
+    txn: Uint8Array,
+    dlcInput: Uint8Array,
+    signature: Uint8Array,
+    signerPubkey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
   ubrn_uniffi_ddk_ffi_fn_func_verify_fund_tx_signature(
     fundTx: Uint8Array,
     signature: Uint8Array,
@@ -247,39 +804,185 @@ interface NativeModuleInterface {
     inputAmount: bigint,
     uniffi_out_err: UniffiRustCallStatus
   ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_funding_matches_params(
+    fundTx: Uint8Array,
+    localParams: Uint8Array,
+    remoteParams: Uint8Array,
+    feeRate: bigint,
+    fundOutputSerialId: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_funding_script_consistency(
+    dlcTxs: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_inputs_unspent(
+    params: Uint8Array,
+    knownUtxos: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_multisig_fund_input_signature(
+    fundTx: Uint8Array,
+    signature: Uint8Array,
+    pubkey: Uint8Array,
+    witnessScript: Uint8Array,
+    txid: Uint8Array,
+    vout: number,
+    inputAmount: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_oracle_attestation(
+    oraclePubkey: Uint8Array,
+    nonce: Uint8Array,
+    message: Uint8Array,
+    signature: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_refund_payouts(
+    refund: Uint8Array,
+    localScript: Uint8Array,
+    expectedLocal: bigint,
+    remoteScript: Uint8Array,
+    expectedRemote: bigint,
+    feeTolerance: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
+  ubrn_uniffi_ddk_ffi_fn_func_verify_refund_transaction_signature(
+    refundTx: Uint8Array,
+    signature: Uint8Array,
+    pubkey: Uint8Array,
+    fundingScriptPubkey: Uint8Array,
+    fundOutputValue: bigint,
+    uniffi_out_err: UniffiRustCallStatus
+  ): number;
   ubrn_uniffi_ddk_ffi_fn_func_version(
     uniffi_out_err: UniffiRustCallStatus
   ): Uint8Array;
+  ubrn_uniffi_ddk_ffi_checksum_func_adaptor_signature_parse(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_adaptor_signature_serialize(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_add_signature_to_transaction(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_add_witnesses_to_transaction(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_address_to_script_pubkey(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_aggregate_oracle_s_values(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_cet_fees(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_cet_nonce_dependencies(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_cet_to_outcome(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_change_across_fee_rates(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_check_fee_sanity(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_complete_dlc_flow(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_compute_cet_fee(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_compute_fund_fee(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_contract_fingerprint(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_contract_pubkeys(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_convert_mnemonic_to_seed(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_cet(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_points_from_oracle_info(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_from_oracle_info(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_multi_oracle(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_oracle_info(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_points(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_cet_checked(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_cet_with_info(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_cets(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_cets_checked(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_input_signature(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions_batch(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions_unchecked(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions_with_fee_output(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_create_enumeration_adaptor_sigs(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_extkey_from_parent_path(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_extkey_from_seed(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_fund_tx_locking_script(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_refund_transaction(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_spliced_dlc_transactions(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_create_xpriv_from_parent_path(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_decompose_schnorr_signature(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_decrypt_and_verify_cet_signature(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_decrypt_cet_adaptor_signature(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_derive_privkey_from_seed(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_derive_pubkey_from_seed(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_deserialize_settlement_state(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_diff_cet_refund(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_diff_party_params(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_dlc_input_info_from_fund_hex(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_dlc_input_sighash(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_dlc_transactions_equal(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_dlc_transactions_to_fund_psbt(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_dummy_transaction(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_estimate_cet_fee_taproot(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_estimate_contract_cost(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_estimate_fund_transaction_fee(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_extkeys_match(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_extract_ecdsa_signature_from_oracle_signatures(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_finalize_fund_psbt(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_finalize_refund_transaction(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_find_cet_by_payout(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_funding_signing_plan(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_get_cet_adaptor_signature_inputs(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_get_cet_sighash(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_get_change_output_and_fees(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_get_extkey_fingerprint(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_get_fund_output_value(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_get_network_from_extkey(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_get_pubkey_from_extkey(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_get_raw_funding_transaction_input_signature(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_get_total_input_vsize(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_get_transaction_txid(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_get_transaction_wtxid(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_get_xpub_from_xpriv(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_hash_outcome(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_hash_outcomes(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_is_dust_output(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_is_dust_output_for_feerate(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_is_valid_network(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_max_safe_fee_rate(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_message_template(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_min_viable_collateral(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_outcome_signature_point(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_party_params_deserialize(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_party_params_from_descriptor(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_party_params_serialize(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_preview_change(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_pubkey_to_address(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_rebuild_refund(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_recover_adaptor_secret(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_rekey_contract(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_safe_fund_output_serial_id(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_serialize_settlement_state(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_sign_cet(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_sign_cet_checked(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_sign_cet_with_external_signature(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_sign_fund_transaction_input(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_sign_multi_sig_input(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_sign_refund_transaction(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_transaction_from_hex(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_transaction_to_hex(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_transactions_equivalent(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_validate_adaptor_sig_bytes(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_validate_cets_share_funding(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_validate_dlc_transactions(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_validate_mnemonic(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_validate_oracle_count(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_validate_party_params(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_validate_transaction_consistency(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_adaptor_points_wellformed(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_adaptor_proof(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_adaptor_sig_binds_cet(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sig_from_oracle_info(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_detailed(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_from_oracle_info(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_with_points(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_collateral_invariant(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_dlc_input_signature(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_verify_fund_tx_signature(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_funding_matches_params(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_funding_script_consistency(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_inputs_unspent(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_multisig_fund_input_signature(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_oracle_attestation(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_refund_payouts(): number;
+  ubrn_uniffi_ddk_ffi_checksum_func_verify_refund_transaction_signature(): number;
   ubrn_uniffi_ddk_ffi_checksum_func_version(): number;
   ubrn_ffi_ddk_ffi_uniffi_contract_version(): number;
 }
@@ -36,6 +36,7 @@ import {
   FfiConverterArray,
   FfiConverterBool,
   FfiConverterInt32,
+  FfiConverterInt64,
   FfiConverterOptional,
   FfiConverterUInt32,
   FfiConverterUInt64,
@@ -61,6 +62,43 @@ const uniffiIsDebug =
   true;
 // Public interface members begin here.
 
+export function adaptorSignatureParse(
+  bytes: Array</*u8*/ number>
+): AdaptorSignature /*throws*/ {
+  return FfiConverterTypeAdaptorSignature.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_adaptor_signature_parse`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_adaptor_signature_parse;
+        })()(FfiConverterArrayUInt8.lower(bytes), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function adaptorSignatureSerialize(
+  sig: AdaptorSignature
+): Array</*u8*/ number> {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_adaptor_signature_serialize`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_adaptor_signature_serialize;
+        })()(FfiConverterTypeAdaptorSignature.lower(sig), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
 export function addSignatureToTransaction(
   tx: Transaction,
   signature: Array</*u8*/ number>,
@@ -91,23 +129,25 @@ export function addSignatureToTransaction(
     )
   );
 }
-export function convertMnemonicToSeed(
-  mnemonic: string,
-  passphrase: string | undefined
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
+export function addWitnessesToTransaction(
+  tx: Transaction,
+  witnesses: Array<WitnessEntry>
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_convert_mnemonic_to_seed`);
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_add_witnesses_to_transaction`
+          );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_convert_mnemonic_to_seed;
+            .ubrn_uniffi_ddk_ffi_fn_func_add_witnesses_to_transaction;
         })()(
-          FfiConverterString.lower(mnemonic),
-          FfiConverterOptionalString.lower(passphrase),
+          FfiConverterTypeTransaction.lower(tx),
+          FfiConverterArrayTypeWitnessEntry.lower(witnesses),
           callStatus
         );
       },
@@ -115,32 +155,23 @@ export function convertMnemonicToSeed(
     )
   );
 }
-export function createCet(
-  localOutput: TxOutput,
-  localPayoutSerialId: /*u64*/ bigint,
-  remoteOutput: TxOutput,
-  remotePayoutSerialId: /*u64*/ bigint,
-  fundTxId: string,
-  fundVout: /*u32*/ number,
-  lockTime: /*u32*/ number
-): Transaction /*throws*/ {
-  return FfiConverterTypeTransaction.lift(
+export function addressToScriptPubkey(
+  address: string,
+  network: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_create_cet`);
-          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_create_cet;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_address_to_script_pubkey`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_address_to_script_pubkey;
         })()(
-          FfiConverterTypeTxOutput.lower(localOutput),
-          FfiConverterUInt64.lower(localPayoutSerialId),
-          FfiConverterTypeTxOutput.lower(remoteOutput),
-          FfiConverterUInt64.lower(remotePayoutSerialId),
-          FfiConverterString.lower(fundTxId),
-          FfiConverterUInt32.lower(fundVout),
-          FfiConverterUInt32.lower(lockTime),
+          FfiConverterString.lower(address),
+          FfiConverterString.lower(network),
           callStatus
         );
       },
@@ -148,59 +179,41 @@ export function createCet(
     )
   );
 }
-export function createCetAdaptorPointsFromOracleInfo(
-  oracleInfo: Array<OracleInfo>,
-  msgs: Array<Array<Array<Array</*u8*/ number>>>>
-): Array<Array</*u8*/ number>> /*throws*/ {
-  return FfiConverterArrayArrayUInt8.lift(
+export function aggregateOracleSValues(
+  signatures: Array<Array</*u8*/ number>>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_points_from_oracle_info`
-          );
+          console.debug(`-- uniffi_ddk_ffi_fn_func_aggregate_oracle_s_values`);
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_points_from_oracle_info;
-        })()(
-          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
-          FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
-          callStatus
-        );
+            .ubrn_uniffi_ddk_ffi_fn_func_aggregate_oracle_s_values;
+        })()(FfiConverterArrayArrayUInt8.lower(signatures), callStatus);
       },
       /*liftString:*/ FfiConverterString.lift
     )
   );
 }
-export function createCetAdaptorSignatureFromOracleInfo(
-  cet: Transaction,
-  oracleInfo: OracleInfo,
-  fundingSk: Array</*u8*/ number>,
-  fundingScriptPubkey: Array</*u8*/ number>,
-  totalCollateral: /*u64*/ bigint,
-  msgs: Array<Array</*u8*/ number>>
-): AdaptorSignature /*throws*/ {
-  return FfiConverterTypeAdaptorSignature.lift(
+export function cetFees(
+  cets: Array<Transaction>,
+  fundOutputValue: /*u64*/ bigint
+): Array</*u64*/ bigint> /*throws*/ {
+  return FfiConverterArrayUInt64.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_signature_from_oracle_info`
-          );
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_signature_from_oracle_info;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_cet_fees`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_cet_fees;
         })()(
-          FfiConverterTypeTransaction.lower(cet),
-          FfiConverterTypeOracleInfo.lower(oracleInfo),
-          FfiConverterArrayUInt8.lower(fundingSk),
-          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
-          FfiConverterUInt64.lower(totalCollateral),
-          FfiConverterArrayArrayUInt8.lower(msgs),
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterUInt64.lower(fundOutputValue),
           callStatus
         );
       },
@@ -208,33 +221,23 @@ export function createCetAdaptorSignatureFromOracleInfo(
     )
   );
 }
-export function createCetAdaptorSigsFromOracleInfo(
-  cets: Array<Transaction>,
-  oracleInfo: Array<OracleInfo>,
-  fundingSecretKey: Array</*u8*/ number>,
-  fundingScriptPubkey: Array</*u8*/ number>,
-  fundOutputValue: /*u64*/ bigint,
-  msgs: Array<Array<Array<Array</*u8*/ number>>>>
-): Array<AdaptorSignature> /*throws*/ {
-  return FfiConverterArrayTypeAdaptorSignature.lift(
+export function cetNonceDependencies(
+  msgs: Array<Array<Array<Array</*u8*/ number>>>>,
+  oracleInfos: Array<OracleInfo>
+): Array<Array<Array</*u8*/ number>>> /*throws*/ {
+  return FfiConverterArrayArrayArrayUInt8.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_oracle_info`
-          );
+          console.debug(`-- uniffi_ddk_ffi_fn_func_cet_nonce_dependencies`);
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_oracle_info;
+            .ubrn_uniffi_ddk_ffi_fn_func_cet_nonce_dependencies;
         })()(
-          FfiConverterArrayTypeTransaction.lower(cets),
-          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
-          FfiConverterArrayUInt8.lower(fundingSecretKey),
-          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
-          FfiConverterUInt64.lower(fundOutputValue),
           FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
           callStatus
         );
       },
@@ -242,31 +245,26 @@ export function createCetAdaptorSigsFromOracleInfo(
     )
   );
 }
-export function createCetAdaptorSigsFromPoints(
-  cets: Array<Transaction>,
-  adaptorPoints: Array<Array</*u8*/ number>>,
-  fundingSecretKey: Array</*u8*/ number>,
-  fundingScriptPubkey: Array</*u8*/ number>,
-  fundOutputValue: /*u64*/ bigint
-): Array<AdaptorSignature> /*throws*/ {
-  return FfiConverterArrayTypeAdaptorSignature.lift(
+export function cetToOutcome(
+  cet: Transaction,
+  outcomes: Array<Payout>,
+  localScript: Array</*u8*/ number>,
+  remoteScript: Array</*u8*/ number>
+): /*u32*/ number /*throws*/ {
+  return FfiConverterUInt32.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_points`
-          );
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_points;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_cet_to_outcome`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_cet_to_outcome;
         })()(
-          FfiConverterArrayTypeTransaction.lower(cets),
-          FfiConverterArrayArrayUInt8.lower(adaptorPoints),
-          FfiConverterArrayUInt8.lower(fundingSecretKey),
-          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
-          FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterArrayUInt8.lower(localScript),
+          FfiConverterArrayUInt8.lower(remoteScript),
           callStatus
         );
       },
@@ -274,34 +272,25 @@ export function createCetAdaptorSigsFromPoints(
     )
   );
 }
-export function createCets(
-  fundTxId: string,
-  fundVout: /*u32*/ number,
-  localFinalScriptPubkey: Array</*u8*/ number>,
-  remoteFinalScriptPubkey: Array</*u8*/ number>,
-  outcomes: Array<Payout>,
-  lockTime: /*u32*/ number,
-  localSerialId: /*u64*/ bigint,
-  remoteSerialId: /*u64*/ bigint
-): Array<Transaction> /*throws*/ {
-  return FfiConverterArrayTypeTransaction.lift(
+export function changeAcrossFeeRates(
+  params: PartyParams,
+  feeRates: Array</*u64*/ bigint>,
+  remoteCollateral: /*u64*/ bigint
+): Array</*i64*/ bigint> /*throws*/ {
+  return FfiConverterArrayInt64.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_create_cets`);
-          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_create_cets;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_change_across_fee_rates`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_change_across_fee_rates;
         })()(
-          FfiConverterString.lower(fundTxId),
-          FfiConverterUInt32.lower(fundVout),
-          FfiConverterArrayUInt8.lower(localFinalScriptPubkey),
-          FfiConverterArrayUInt8.lower(remoteFinalScriptPubkey),
-          FfiConverterArrayTypePayout.lower(outcomes),
-          FfiConverterUInt32.lower(lockTime),
-          FfiConverterUInt64.lower(localSerialId),
-          FfiConverterUInt64.lower(remoteSerialId),
+          FfiConverterTypePartyParams.lower(params),
+          FfiConverterArrayUInt64.lower(feeRates),
+          FfiConverterUInt64.lower(remoteCollateral),
           callStatus
         );
       },
@@ -309,37 +298,63 @@ export function createCets(
     )
   );
 }
-export function createDlcTransactions(
+export function checkFeeSanity(
+  tx: Transaction,
+  inputAmounts: Array</*u64*/ bigint>,
+  maxFeeRate: /*u64*/ bigint
+): void /*throws*/ {
+  uniffiCaller.rustCallWithError(
+    /*liftError:*/ FfiConverterTypeDLCError.lift.bind(FfiConverterTypeDLCError),
+    /*caller:*/ (callStatus) => {
+      (() => {
+        console.debug(`-- uniffi_ddk_ffi_fn_func_check_fee_sanity`);
+        return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_check_fee_sanity;
+      })()(
+        FfiConverterTypeTransaction.lower(tx),
+        FfiConverterArrayUInt64.lower(inputAmounts),
+        FfiConverterUInt64.lower(maxFeeRate),
+        callStatus
+      );
+    },
+    /*liftString:*/ FfiConverterString.lift
+  );
+}
+export function completeDlcFlow(
+  offerParams: PartyParams,
+  acceptParams: PartyParams,
   outcomes: Array<Payout>,
-  localParams: PartyParams,
-  remoteParams: PartyParams,
-  refundLocktime: /*u32*/ number,
+  oracleInfos: Array<OracleInfo>,
+  msgs: Array<Array<Array<Array</*u8*/ number>>>>,
+  offerFundSk: Array</*u8*/ number>,
+  acceptFundSk: Array</*u8*/ number>,
   feeRate: /*u64*/ bigint,
+  refundLocktime: /*u32*/ number,
   fundLockTime: /*u32*/ number,
   cetLockTime: /*u32*/ number,
-  fundOutputSerialId: /*u64*/ bigint,
-  contractFlags: /*u8*/ number
-): DlcTransactions /*throws*/ {
-  return FfiConverterTypeDlcTransactions.lift(
+  fundOutputSerialId: /*u64*/ bigint
+): CompletedContract /*throws*/ {
+  return FfiConverterTypeCompletedContract.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_create_dlc_transactions`);
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_complete_dlc_flow`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_complete_dlc_flow;
         })()(
+          FfiConverterTypePartyParams.lower(offerParams),
+          FfiConverterTypePartyParams.lower(acceptParams),
           FfiConverterArrayTypePayout.lower(outcomes),
-          FfiConverterTypePartyParams.lower(localParams),
-          FfiConverterTypePartyParams.lower(remoteParams),
-          FfiConverterUInt32.lower(refundLocktime),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
+          FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
+          FfiConverterArrayUInt8.lower(offerFundSk),
+          FfiConverterArrayUInt8.lower(acceptFundSk),
           FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt32.lower(refundLocktime),
           FfiConverterUInt32.lower(fundLockTime),
           FfiConverterUInt32.lower(cetLockTime),
           FfiConverterUInt64.lower(fundOutputSerialId),
-          FfiConverterUInt8.lower(contractFlags),
           callStatus
         );
       },
@@ -347,25 +362,19 @@ export function createDlcTransactions(
     )
   );
 }
-export function createExtkeyFromParentPath(
-  extkey: Array</*u8*/ number>,
-  path: string
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
-    uniffiCaller.rustCallWithError(
-      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
-        FfiConverterTypeDLCError
-      ),
+export function computeCetFee(
+  payoutScriptLen: /*u32*/ number,
+  feeRate: /*u64*/ bigint
+): /*u64*/ bigint {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCall(
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_extkey_from_parent_path`
-          );
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_extkey_from_parent_path;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_compute_cet_fee`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_compute_cet_fee;
         })()(
-          FfiConverterArrayUInt8.lower(extkey),
-          FfiConverterString.lower(path),
+          FfiConverterUInt32.lower(payoutScriptLen),
+          FfiConverterUInt64.lower(feeRate),
           callStatus
         );
       },
@@ -373,9 +382,31 @@ export function createExtkeyFromParentPath(
     )
   );
 }
-export function createExtkeyFromSeed(
-  seed: Array</*u8*/ number>,
-  network: string
+export function computeFundFee(
+  inputCount: /*u32*/ number,
+  extraWitnessLengths: Array</*u32*/ number>,
+  feeRate: /*u64*/ bigint
+): /*u64*/ bigint {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_compute_fund_fee`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_compute_fund_fee;
+        })()(
+          FfiConverterUInt32.lower(inputCount),
+          FfiConverterArrayUInt32.lower(extraWitnessLengths),
+          FfiConverterUInt64.lower(feeRate),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function contractFingerprint(
+  dlcTxs: DlcTransactions,
+  oracleInfos: Array<OracleInfo>
 ): Array</*u8*/ number> /*throws*/ {
   return FfiConverterArrayUInt8.lift(
     uniffiCaller.rustCallWithError(
@@ -384,12 +415,12 @@ export function createExtkeyFromSeed(
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_create_extkey_from_seed`);
+          console.debug(`-- uniffi_ddk_ffi_fn_func_contract_fingerprint`);
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_extkey_from_seed;
+            .ubrn_uniffi_ddk_ffi_fn_func_contract_fingerprint;
         })()(
-          FfiConverterArrayUInt8.lower(seed),
-          FfiConverterString.lower(network),
+          FfiConverterTypeDlcTransactions.lower(dlcTxs),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
           callStatus
         );
       },
@@ -397,23 +428,19 @@ export function createExtkeyFromSeed(
     )
   );
 }
-export function createFundTxLockingScript(
+export function contractPubkeys(
+  dlcTxs: DlcTransactions,
   localFundPubkey: Array</*u8*/ number>,
   remoteFundPubkey: Array</*u8*/ number>
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
-    uniffiCaller.rustCallWithError(
-      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
-        FfiConverterTypeDLCError
-      ),
+): Array<Array</*u8*/ number>> {
+  return FfiConverterArrayArrayUInt8.lift(
+    uniffiCaller.rustCall(
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_fund_tx_locking_script`
-          );
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_fund_tx_locking_script;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_contract_pubkeys`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_contract_pubkeys;
         })()(
+          FfiConverterTypeDlcTransactions.lower(dlcTxs),
           FfiConverterArrayUInt8.lower(localFundPubkey),
           FfiConverterArrayUInt8.lower(remoteFundPubkey),
           callStatus
@@ -423,33 +450,23 @@ export function createFundTxLockingScript(
     )
   );
 }
-export function createRefundTransaction(
-  localFinalScriptPubkey: Array</*u8*/ number>,
-  remoteFinalScriptPubkey: Array</*u8*/ number>,
-  localAmount: /*u64*/ bigint,
-  remoteAmount: /*u64*/ bigint,
-  lockTime: /*u32*/ number,
-  fundTxId: string,
-  fundVout: /*u32*/ number
-): Transaction /*throws*/ {
-  return FfiConverterTypeTransaction.lift(
-    uniffiCaller.rustCallWithError(
+export function convertMnemonicToSeed(
+  mnemonic: string,
+  passphrase: string | undefined
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_create_refund_transaction`);
+          console.debug(`-- uniffi_ddk_ffi_fn_func_convert_mnemonic_to_seed`);
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_refund_transaction;
+            .ubrn_uniffi_ddk_ffi_fn_func_convert_mnemonic_to_seed;
         })()(
-          FfiConverterArrayUInt8.lower(localFinalScriptPubkey),
-          FfiConverterArrayUInt8.lower(remoteFinalScriptPubkey),
-          FfiConverterUInt64.lower(localAmount),
-          FfiConverterUInt64.lower(remoteAmount),
-          FfiConverterUInt32.lower(lockTime),
-          FfiConverterString.lower(fundTxId),
-          FfiConverterUInt32.lower(fundVout),
+          FfiConverterString.lower(mnemonic),
+          FfiConverterOptionalString.lower(passphrase),
           callStatus
         );
       },
@@ -457,39 +474,32 @@ export function createRefundTransaction(
     )
   );
 }
-export function createSplicedDlcTransactions(
-  outcomes: Array<Payout>,
-  localParams: PartyParams,
-  remoteParams: PartyParams,
-  refundLocktime: /*u32*/ number,
-  feeRate: /*u64*/ bigint,
-  fundLockTime: /*u32*/ number,
-  cetLockTime: /*u32*/ number,
-  fundOutputSerialId: /*u64*/ bigint,
-  contractFlags: /*u8*/ number
-): DlcTransactions /*throws*/ {
-  return FfiConverterTypeDlcTransactions.lift(
+export function createCet(
+  localOutput: TxOutput,
+  localPayoutSerialId: /*u64*/ bigint,
+  remoteOutput: TxOutput,
+  remotePayoutSerialId: /*u64*/ bigint,
+  fundTxId: string,
+  fundVout: /*u32*/ number,
+  lockTime: /*u32*/ number
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_spliced_dlc_transactions`
-          );
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_spliced_dlc_transactions;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_cet`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_create_cet;
         })()(
-          FfiConverterArrayTypePayout.lower(outcomes),
-          FfiConverterTypePartyParams.lower(localParams),
-          FfiConverterTypePartyParams.lower(remoteParams),
-          FfiConverterUInt32.lower(refundLocktime),
-          FfiConverterUInt64.lower(feeRate),
-          FfiConverterUInt32.lower(fundLockTime),
-          FfiConverterUInt32.lower(cetLockTime),
-          FfiConverterUInt64.lower(fundOutputSerialId),
-          FfiConverterUInt8.lower(contractFlags),
+          FfiConverterTypeTxOutput.lower(localOutput),
+          FfiConverterUInt64.lower(localPayoutSerialId),
+          FfiConverterTypeTxOutput.lower(remoteOutput),
+          FfiConverterUInt64.lower(remotePayoutSerialId),
+          FfiConverterString.lower(fundTxId),
+          FfiConverterUInt32.lower(fundVout),
+          FfiConverterUInt32.lower(lockTime),
           callStatus
         );
       },
@@ -497,13 +507,11 @@ export function createSplicedDlcTransactions(
     )
   );
 }
-export function createXprivFromParentPath(
-  seedOrXpriv: Array</*u8*/ number>,
-  baseDerivationPath: string,
-  network: string,
-  path: string
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
+export function createCetAdaptorPointsFromOracleInfo(
+  oracleInfo: Array<OracleInfo>,
+  msgs: Array<Array<Array<Array</*u8*/ number>>>>
+): Array<Array</*u8*/ number>> /*throws*/ {
+  return FfiConverterArrayArrayUInt8.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
@@ -511,15 +519,13 @@ export function createXprivFromParentPath(
       /*caller:*/ (callStatus) => {
         return (() => {
           console.debug(
-            `-- uniffi_ddk_ffi_fn_func_create_xpriv_from_parent_path`
+            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_points_from_oracle_info`
           );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_create_xpriv_from_parent_path;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_points_from_oracle_info;
         })()(
-          FfiConverterArrayUInt8.lower(seedOrXpriv),
-          FfiConverterString.lower(baseDerivationPath),
-          FfiConverterString.lower(network),
-          FfiConverterString.lower(path),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
           callStatus
         );
       },
@@ -527,11 +533,15 @@ export function createXprivFromParentPath(
     )
   );
 }
-export function extractEcdsaSignatureFromOracleSignatures(
-  oracleSignatures: Array<Array</*u8*/ number>>,
-  adaptorSignature: Array</*u8*/ number>
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
+export function createCetAdaptorSignatureFromOracleInfo(
+  cet: Transaction,
+  oracleInfo: OracleInfo,
+  fundingSk: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  totalCollateral: /*u64*/ bigint,
+  msgs: Array<Array</*u8*/ number>>
+): AdaptorSignature /*throws*/ {
+  return FfiConverterTypeAdaptorSignature.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
@@ -539,13 +549,17 @@ export function extractEcdsaSignatureFromOracleSignatures(
       /*caller:*/ (callStatus) => {
         return (() => {
           console.debug(
-            `-- uniffi_ddk_ffi_fn_func_extract_ecdsa_signature_from_oracle_signatures`
+            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_signature_from_oracle_info`
           );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_extract_ecdsa_signature_from_oracle_signatures;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_signature_from_oracle_info;
         })()(
-          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
-          FfiConverterArrayUInt8.lower(adaptorSignature),
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayUInt8.lower(fundingSk),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(totalCollateral),
+          FfiConverterArrayArrayUInt8.lower(msgs),
           callStatus
         );
       },
@@ -553,14 +567,15 @@ export function extractEcdsaSignatureFromOracleSignatures(
     )
   );
 }
-export function getCetAdaptorSignatureInputs(
+export function createCetAdaptorSignatureMultiOracle(
   cet: Transaction,
-  oracleInfo: Array<OracleInfo>,
+  oracleInfos: Array<OracleInfo>,
+  fundingSk: Array</*u8*/ number>,
   fundingScriptPubkey: Array</*u8*/ number>,
-  fundOutputValue: /*u64*/ bigint,
+  totalCollateral: /*u64*/ bigint,
   msgs: Array<Array<Array</*u8*/ number>>>
-): CetAdaptorSignatureDebugInfo /*throws*/ {
-  return FfiConverterTypeCetAdaptorSignatureDebugInfo.lift(
+): AdaptorSignature /*throws*/ {
+  return FfiConverterTypeAdaptorSignature.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
@@ -568,15 +583,16 @@ export function getCetAdaptorSignatureInputs(
       /*caller:*/ (callStatus) => {
         return (() => {
           console.debug(
-            `-- uniffi_ddk_ffi_fn_func_get_cet_adaptor_signature_inputs`
+            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_signature_multi_oracle`
           );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_get_cet_adaptor_signature_inputs;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_signature_multi_oracle;
         })()(
           FfiConverterTypeTransaction.lower(cet),
-          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
+          FfiConverterArrayUInt8.lower(fundingSk),
           FfiConverterArrayUInt8.lower(fundingScriptPubkey),
-          FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterUInt64.lower(totalCollateral),
           FfiConverterArrayArrayArrayUInt8.lower(msgs),
           callStatus
         );
@@ -585,24 +601,33 @@ export function getCetAdaptorSignatureInputs(
     )
   );
 }
-export function getCetSighash(
-  cet: Transaction,
+export function createCetAdaptorSigsFromOracleInfo(
+  cets: Array<Transaction>,
+  oracleInfo: Array<OracleInfo>,
+  fundingSecretKey: Array</*u8*/ number>,
   fundingScriptPubkey: Array</*u8*/ number>,
-  fundOutputValue: /*u64*/ bigint
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
+  fundOutputValue: /*u64*/ bigint,
+  msgs: Array<Array<Array<Array</*u8*/ number>>>>
+): Array<AdaptorSignature> /*throws*/ {
+  return FfiConverterArrayTypeAdaptorSignature.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_get_cet_sighash`);
-          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_get_cet_sighash;
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_oracle_info`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_oracle_info;
         })()(
-          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayUInt8.lower(fundingSecretKey),
           FfiConverterArrayUInt8.lower(fundingScriptPubkey),
           FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
           callStatus
         );
       },
@@ -610,23 +635,31 @@ export function getCetSighash(
     )
   );
 }
-export function getChangeOutputAndFees(
-  params: PartyParams,
-  feeRate: /*u64*/ bigint
-): ChangeOutputAndFees /*throws*/ {
-  return FfiConverterTypeChangeOutputAndFees.lift(
+export function createCetAdaptorSigsFromPoints(
+  cets: Array<Transaction>,
+  adaptorPoints: Array<Array</*u8*/ number>>,
+  fundingSecretKey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint
+): Array<AdaptorSignature> /*throws*/ {
+  return FfiConverterArrayTypeAdaptorSignature.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_get_change_output_and_fees`);
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_points`
+          );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_get_change_output_and_fees;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_adaptor_sigs_from_points;
         })()(
-          FfiConverterTypePartyParams.lower(params),
-          FfiConverterUInt64.lower(feeRate),
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterArrayArrayUInt8.lower(adaptorPoints),
+          FfiConverterArrayUInt8.lower(fundingSecretKey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
           callStatus
         );
       },
@@ -634,23 +667,36 @@ export function getChangeOutputAndFees(
     )
   );
 }
-export function getPubkeyFromExtkey(
-  extkey: Array</*u8*/ number>,
-  network: string
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
+export function createCetChecked(
+  localOutput: TxOutput,
+  localPayoutSerialId: /*u64*/ bigint,
+  remoteOutput: TxOutput,
+  remotePayoutSerialId: /*u64*/ bigint,
+  fundTxId: string,
+  fundVout: /*u32*/ number,
+  lockTime: /*u32*/ number,
+  fundOutputValue: /*u64*/ bigint,
+  feeRate: /*u64*/ bigint
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_get_pubkey_from_extkey`);
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_get_pubkey_from_extkey;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_cet_checked`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_create_cet_checked;
         })()(
-          FfiConverterArrayUInt8.lower(extkey),
-          FfiConverterString.lower(network),
+          FfiConverterTypeTxOutput.lower(localOutput),
+          FfiConverterUInt64.lower(localPayoutSerialId),
+          FfiConverterTypeTxOutput.lower(remoteOutput),
+          FfiConverterUInt64.lower(remotePayoutSerialId),
+          FfiConverterString.lower(fundTxId),
+          FfiConverterUInt32.lower(fundVout),
+          FfiConverterUInt32.lower(lockTime),
+          FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterUInt64.lower(feeRate),
           callStatus
         );
       },
@@ -658,31 +704,33 @@ export function getPubkeyFromExtkey(
     )
   );
 }
-export function getRawFundingTransactionInputSignature(
-  fundingTransaction: Transaction,
-  privkey: Array</*u8*/ number>,
-  prevTxId: string,
-  prevTxVout: /*u32*/ number,
-  value: /*u64*/ bigint
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
+export function createCetWithInfo(
+  localOutput: TxOutput,
+  localPayoutSerialId: /*u64*/ bigint,
+  remoteOutput: TxOutput,
+  remotePayoutSerialId: /*u64*/ bigint,
+  fundTxId: string,
+  fundVout: /*u32*/ number,
+  lockTime: /*u32*/ number
+): CetBuildResult /*throws*/ {
+  return FfiConverterTypeCetBuildResult.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_get_raw_funding_transaction_input_signature`
-          );
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_cet_with_info`);
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_get_raw_funding_transaction_input_signature;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_cet_with_info;
         })()(
-          FfiConverterTypeTransaction.lower(fundingTransaction),
-          FfiConverterArrayUInt8.lower(privkey),
-          FfiConverterString.lower(prevTxId),
-          FfiConverterUInt32.lower(prevTxVout),
-          FfiConverterUInt64.lower(value),
+          FfiConverterTypeTxOutput.lower(localOutput),
+          FfiConverterUInt64.lower(localPayoutSerialId),
+          FfiConverterTypeTxOutput.lower(remoteOutput),
+          FfiConverterUInt64.lower(remotePayoutSerialId),
+          FfiConverterString.lower(fundTxId),
+          FfiConverterUInt32.lower(fundVout),
+          FfiConverterUInt32.lower(lockTime),
           callStatus
         );
       },
@@ -690,36 +738,34 @@ export function getRawFundingTransactionInputSignature(
     )
   );
 }
-export function getTotalInputVsize(inputs: Array<TxInputInfo>): /*u32*/ number {
-  return FfiConverterUInt32.lift(
-    uniffiCaller.rustCall(
-      /*caller:*/ (callStatus) => {
-        return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_get_total_input_vsize`);
-          return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_get_total_input_vsize;
-        })()(FfiConverterArrayTypeTxInputInfo.lower(inputs), callStatus);
-      },
-      /*liftString:*/ FfiConverterString.lift
-    )
-  );
-}
-export function getXpubFromXpriv(
-  xpriv: Array</*u8*/ number>,
-  network: string
-): Array</*u8*/ number> /*throws*/ {
-  return FfiConverterArrayUInt8.lift(
+export function createCets(
+  fundTxId: string,
+  fundVout: /*u32*/ number,
+  localFinalScriptPubkey: Array</*u8*/ number>,
+  remoteFinalScriptPubkey: Array</*u8*/ number>,
+  outcomes: Array<Payout>,
+  lockTime: /*u32*/ number,
+  localSerialId: /*u64*/ bigint,
+  remoteSerialId: /*u64*/ bigint
+): Array<Transaction> /*throws*/ {
+  return FfiConverterArrayTypeTransaction.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_get_xpub_from_xpriv`);
-          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_get_xpub_from_xpriv;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_cets`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_create_cets;
         })()(
-          FfiConverterArrayUInt8.lower(xpriv),
-          FfiConverterString.lower(network),
+          FfiConverterString.lower(fundTxId),
+          FfiConverterUInt32.lower(fundVout),
+          FfiConverterArrayUInt8.lower(localFinalScriptPubkey),
+          FfiConverterArrayUInt8.lower(remoteFinalScriptPubkey),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterUInt32.lower(lockTime),
+          FfiConverterUInt64.lower(localSerialId),
+          FfiConverterUInt64.lower(remoteSerialId),
           callStatus
         );
       },
@@ -727,45 +773,36 @@ export function getXpubFromXpriv(
     )
   );
 }
-export function isDustOutput(output: TxOutput): boolean {
-  return FfiConverterBool.lift(
-    uniffiCaller.rustCall(
-      /*caller:*/ (callStatus) => {
-        return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_is_dust_output`);
-          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_is_dust_output;
-        })()(FfiConverterTypeTxOutput.lower(output), callStatus);
-      },
-      /*liftString:*/ FfiConverterString.lift
-    )
-  );
-}
-export function signCet(
-  cet: Transaction,
-  adaptorSignature: Array</*u8*/ number>,
-  oracleSignatures: Array<Array</*u8*/ number>>,
-  fundingSecretKey: Array</*u8*/ number>,
-  otherPubkey: Array</*u8*/ number>,
-  fundingScriptPubkey: Array</*u8*/ number>,
-  fundOutputValue: /*u64*/ bigint
-): Transaction /*throws*/ {
-  return FfiConverterTypeTransaction.lift(
+export function createCetsChecked(
+  fundTxId: string,
+  fundVout: /*u32*/ number,
+  localFinalScriptPubkey: Array</*u8*/ number>,
+  remoteFinalScriptPubkey: Array</*u8*/ number>,
+  outcomes: Array<Payout>,
+  totalCollateral: /*u64*/ bigint,
+  lockTime: /*u32*/ number,
+  localSerialId: /*u64*/ bigint,
+  remoteSerialId: /*u64*/ bigint
+): Array<Transaction> /*throws*/ {
+  return FfiConverterArrayTypeTransaction.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_sign_cet`);
-          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_sign_cet;
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_cets_checked`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_create_cets_checked;
         })()(
-          FfiConverterTypeTransaction.lower(cet),
-          FfiConverterArrayUInt8.lower(adaptorSignature),
-          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
-          FfiConverterArrayUInt8.lower(fundingSecretKey),
-          FfiConverterArrayUInt8.lower(otherPubkey),
-          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
-          FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterString.lower(fundTxId),
+          FfiConverterUInt32.lower(fundVout),
+          FfiConverterArrayUInt8.lower(localFinalScriptPubkey),
+          FfiConverterArrayUInt8.lower(remoteFinalScriptPubkey),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterUInt64.lower(totalCollateral),
+          FfiConverterUInt32.lower(lockTime),
+          FfiConverterUInt64.lower(localSerialId),
+          FfiConverterUInt64.lower(remoteSerialId),
           callStatus
         );
       },
@@ -773,31 +810,25 @@ export function signCet(
     )
   );
 }
-export function signFundTransactionInput(
-  fundTransaction: Transaction,
-  privkey: Array</*u8*/ number>,
-  prevTxId: string,
-  prevTxVout: /*u32*/ number,
-  value: /*u64*/ bigint
-): Transaction /*throws*/ {
-  return FfiConverterTypeTransaction.lift(
+export function createDlcInputSignature(
+  txn: Transaction,
+  dlcInput: DlcInputInfo,
+  localPrivkey: Array</*u8*/ number>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(
-            `-- uniffi_ddk_ffi_fn_func_sign_fund_transaction_input`
-          );
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_dlc_input_signature`);
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_sign_fund_transaction_input;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_dlc_input_signature;
         })()(
-          FfiConverterTypeTransaction.lower(fundTransaction),
-          FfiConverterArrayUInt8.lower(privkey),
-          FfiConverterString.lower(prevTxId),
-          FfiConverterUInt32.lower(prevTxVout),
-          FfiConverterUInt64.lower(value),
+          FfiConverterTypeTransaction.lower(txn),
+          FfiConverterTypeDlcInputInfo.lower(dlcInput),
+          FfiConverterArrayUInt8.lower(localPrivkey),
           callStatus
         );
       },
@@ -805,27 +836,37 @@ export function signFundTransactionInput(
     )
   );
 }
-export function signMultiSigInput(
-  tx: Transaction,
-  dlcInput: DlcInputInfo,
-  localPrivkey: Array</*u8*/ number>,
-  remoteSignature: Array</*u8*/ number>
-): Transaction /*throws*/ {
-  return FfiConverterTypeTransaction.lift(
+export function createDlcTransactions(
+  outcomes: Array<Payout>,
+  localParams: PartyParams,
+  remoteParams: PartyParams,
+  refundLocktime: /*u32*/ number,
+  feeRate: /*u64*/ bigint,
+  fundLockTime: /*u32*/ number,
+  cetLockTime: /*u32*/ number,
+  fundOutputSerialId: /*u64*/ bigint,
+  contractFlags: /*u8*/ number
+): DlcTransactions /*throws*/ {
+  return FfiConverterTypeDlcTransactions.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_sign_multi_sig_input`);
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_dlc_transactions`);
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_sign_multi_sig_input;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions;
         })()(
-          FfiConverterTypeTransaction.lower(tx),
-          FfiConverterTypeDlcInputInfo.lower(dlcInput),
-          FfiConverterArrayUInt8.lower(localPrivkey),
-          FfiConverterArrayUInt8.lower(remoteSignature),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterUInt32.lower(refundLocktime),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt32.lower(fundLockTime),
+          FfiConverterUInt32.lower(cetLockTime),
+          FfiConverterUInt64.lower(fundOutputSerialId),
+          FfiConverterUInt8.lower(contractFlags),
           callStatus
         );
       },
@@ -833,32 +874,23 @@ export function signMultiSigInput(
     )
   );
 }
-export function verifyCetAdaptorSigFromOracleInfo(
-  adaptorSig: AdaptorSignature,
-  cet: Transaction,
-  oracleInfo: Array<OracleInfo>,
-  pubkey: Array</*u8*/ number>,
-  fundingScriptPubkey: Array</*u8*/ number>,
-  totalCollateral: /*u64*/ bigint,
-  msgs: Array<Array<Array</*u8*/ number>>>
-): boolean {
-  return FfiConverterBool.lift(
-    uniffiCaller.rustCall(
+export function createDlcTransactionsBatch(
+  requests: Array<DlcTransactionRequest>
+): Array<DlcTransactions> /*throws*/ {
+  return FfiConverterArrayTypeDlcTransactions.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
       /*caller:*/ (callStatus) => {
         return (() => {
           console.debug(
-            `-- uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sig_from_oracle_info`
+            `-- uniffi_ddk_ffi_fn_func_create_dlc_transactions_batch`
           );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sig_from_oracle_info;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions_batch;
         })()(
-          FfiConverterTypeAdaptorSignature.lower(adaptorSig),
-          FfiConverterTypeTransaction.lower(cet),
-          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
-          FfiConverterArrayUInt8.lower(pubkey),
-          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
-          FfiConverterUInt64.lower(totalCollateral),
-          FfiConverterArrayArrayArrayUInt8.lower(msgs),
+          FfiConverterArrayTypeDlcTransactionRequest.lower(requests),
           callStatus
         );
       },
@@ -866,32 +898,39 @@ export function verifyCetAdaptorSigFromOracleInfo(
     )
   );
 }
-export function verifyCetAdaptorSigsFromOracleInfo(
-  adaptorSigs: Array<AdaptorSignature>,
-  cets: Array<Transaction>,
-  oracleInfo: Array<OracleInfo>,
-  pubkey: Array</*u8*/ number>,
-  fundingScriptPubkey: Array</*u8*/ number>,
-  totalCollateral: /*u64*/ bigint,
-  msgs: Array<Array<Array<Array</*u8*/ number>>>>
-): boolean {
-  return FfiConverterBool.lift(
-    uniffiCaller.rustCall(
+export function createDlcTransactionsUnchecked(
+  outcomes: Array<Payout>,
+  localParams: PartyParams,
+  remoteParams: PartyParams,
+  refundLocktime: /*u32*/ number,
+  feeRate: /*u64*/ bigint,
+  fundLockTime: /*u32*/ number,
+  cetLockTime: /*u32*/ number,
+  fundOutputSerialId: /*u64*/ bigint,
+  contractFlags: /*u8*/ number
+): DlcTransactions /*throws*/ {
+  return FfiConverterTypeDlcTransactions.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
       /*caller:*/ (callStatus) => {
         return (() => {
           console.debug(
-            `-- uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_from_oracle_info`
+            `-- uniffi_ddk_ffi_fn_func_create_dlc_transactions_unchecked`
           );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_from_oracle_info;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions_unchecked;
         })()(
-          FfiConverterArrayTypeAdaptorSignature.lower(adaptorSigs),
-          FfiConverterArrayTypeTransaction.lower(cets),
-          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
-          FfiConverterArrayUInt8.lower(pubkey),
-          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
-          FfiConverterUInt64.lower(totalCollateral),
-          FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterUInt32.lower(refundLocktime),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt32.lower(fundLockTime),
+          FfiConverterUInt32.lower(cetLockTime),
+          FfiConverterUInt64.lower(fundOutputSerialId),
+          FfiConverterUInt8.lower(contractFlags),
           callStatus
         );
       },
@@ -899,31 +938,43 @@ export function verifyCetAdaptorSigsFromOracleInfo(
     )
   );
 }
-export function verifyFundTxSignature(
-  fundTx: Transaction,
-  signature: Array</*u8*/ number>,
-  pubkey: Array</*u8*/ number>,
-  txid: string,
-  vout: /*u32*/ number,
-  inputAmount: /*u64*/ bigint
-): boolean /*throws*/ {
-  return FfiConverterBool.lift(
+export function createDlcTransactionsWithFeeOutput(
+  outcomes: Array<Payout>,
+  localParams: PartyParams,
+  remoteParams: PartyParams,
+  refundLocktime: /*u32*/ number,
+  feeRate: /*u64*/ bigint,
+  fundLockTime: /*u32*/ number,
+  cetLockTime: /*u32*/ number,
+  fundOutputSerialId: /*u64*/ bigint,
+  contractFlags: /*u8*/ number,
+  feeOutput: TxOutput | undefined,
+  feeOutputSerialId: /*u64*/ bigint
+): DlcTransactions /*throws*/ {
+  return FfiConverterTypeDlcTransactions.lift(
     uniffiCaller.rustCallWithError(
       /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
         FfiConverterTypeDLCError
       ),
       /*caller:*/ (callStatus) => {
         return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_verify_fund_tx_signature`);
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_dlc_transactions_with_fee_output`
+          );
           return nativeModule()
-            .ubrn_uniffi_ddk_ffi_fn_func_verify_fund_tx_signature;
+            .ubrn_uniffi_ddk_ffi_fn_func_create_dlc_transactions_with_fee_output;
         })()(
-          FfiConverterTypeTransaction.lower(fundTx),
-          FfiConverterArrayUInt8.lower(signature),
-          FfiConverterArrayUInt8.lower(pubkey),
-          FfiConverterString.lower(txid),
-          FfiConverterUInt32.lower(vout),
-          FfiConverterUInt64.lower(inputAmount),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterUInt32.lower(refundLocktime),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt32.lower(fundLockTime),
+          FfiConverterUInt32.lower(cetLockTime),
+          FfiConverterUInt64.lower(fundOutputSerialId),
+          FfiConverterUInt8.lower(contractFlags),
+          FfiConverterOptionalTypeTxOutput.lower(feeOutput),
+          FfiConverterUInt64.lower(feeOutputSerialId),
           callStatus
         );
       },
@@ -931,44 +982,3561 @@ export function verifyFundTxSignature(
     )
   );
 }
-export function version(): string {
-  return FfiConverterString.lift(
-    uniffiCaller.rustCall(
-      /*caller:*/ (callStatus) => {
-        return (() => {
-          console.debug(`-- uniffi_ddk_ffi_fn_func_version`);
-          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_version;
-        })()(callStatus);
-      },
-      /*liftString:*/ FfiConverterString.lift
-    )
-  );
-}
+export function createEnumerationAdaptorSigs(
+  cets: Array<Transaction>,
+  oracleInfo: OracleInfo,
+  fundingSecretKey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint,
+  outcomes: Array<string>
+): Array<AdaptorSignature> /*throws*/ {
+  return FfiConverterArrayTypeAdaptorSignature.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_enumeration_adaptor_sigs`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_enumeration_adaptor_sigs;
+        })()(
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayUInt8.lower(fundingSecretKey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterArrayString.lower(outcomes),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function createExtkeyFromParentPath(
+  extkey: Array</*u8*/ number>,
+  path: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_extkey_from_parent_path`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_extkey_from_parent_path;
+        })()(
+          FfiConverterArrayUInt8.lower(extkey),
+          FfiConverterString.lower(path),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function createExtkeyFromSeed(
+  seed: Array</*u8*/ number>,
+  network: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_extkey_from_seed`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_extkey_from_seed;
+        })()(
+          FfiConverterArrayUInt8.lower(seed),
+          FfiConverterString.lower(network),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function createFundTxLockingScript(
+  localFundPubkey: Array</*u8*/ number>,
+  remoteFundPubkey: Array</*u8*/ number>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_fund_tx_locking_script`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_fund_tx_locking_script;
+        })()(
+          FfiConverterArrayUInt8.lower(localFundPubkey),
+          FfiConverterArrayUInt8.lower(remoteFundPubkey),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function createRefundTransaction(
+  localFinalScriptPubkey: Array</*u8*/ number>,
+  remoteFinalScriptPubkey: Array</*u8*/ number>,
+  localAmount: /*u64*/ bigint,
+  remoteAmount: /*u64*/ bigint,
+  lockTime: /*u32*/ number,
+  fundTxId: string,
+  fundVout: /*u32*/ number
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_create_refund_transaction`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_refund_transaction;
+        })()(
+          FfiConverterArrayUInt8.lower(localFinalScriptPubkey),
+          FfiConverterArrayUInt8.lower(remoteFinalScriptPubkey),
+          FfiConverterUInt64.lower(localAmount),
+          FfiConverterUInt64.lower(remoteAmount),
+          FfiConverterUInt32.lower(lockTime),
+          FfiConverterString.lower(fundTxId),
+          FfiConverterUInt32.lower(fundVout),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function createSplicedDlcTransactions(
+  outcomes: Array<Payout>,
+  localParams: PartyParams,
+  remoteParams: PartyParams,
+  refundLocktime: /*u32*/ number,
+  feeRate: /*u64*/ bigint,
+  fundLockTime: /*u32*/ number,
+  cetLockTime: /*u32*/ number,
+  fundOutputSerialId: /*u64*/ bigint,
+  contractFlags: /*u8*/ number
+): DlcTransactions /*throws*/ {
+  return FfiConverterTypeDlcTransactions.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_spliced_dlc_transactions`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_spliced_dlc_transactions;
+        })()(
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterUInt32.lower(refundLocktime),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt32.lower(fundLockTime),
+          FfiConverterUInt32.lower(cetLockTime),
+          FfiConverterUInt64.lower(fundOutputSerialId),
+          FfiConverterUInt8.lower(contractFlags),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function createXprivFromParentPath(
+  seedOrXpriv: Array</*u8*/ number>,
+  baseDerivationPath: string,
+  network: string,
+  path: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_create_xpriv_from_parent_path`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_create_xpriv_from_parent_path;
+        })()(
+          FfiConverterArrayUInt8.lower(seedOrXpriv),
+          FfiConverterString.lower(baseDerivationPath),
+          FfiConverterString.lower(network),
+          FfiConverterString.lower(path),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function decomposeSchnorrSignature(
+  signature: Array</*u8*/ number>
+): SchnorrParts /*throws*/ {
+  return FfiConverterTypeSchnorrParts.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_decompose_schnorr_signature`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_decompose_schnorr_signature;
+        })()(FfiConverterArrayUInt8.lower(signature), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function decryptAndVerifyCetSignature(
+  adaptorSig: Array</*u8*/ number>,
+  oracleSignatures: Array<Array</*u8*/ number>>,
+  cet: Transaction,
+  pubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_decrypt_and_verify_cet_signature`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_decrypt_and_verify_cet_signature;
+        })()(
+          FfiConverterArrayUInt8.lower(adaptorSig),
+          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function decryptCetAdaptorSignature(
+  adaptorSignature: Array</*u8*/ number>,
+  oracleSignatures: Array<Array</*u8*/ number>>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_decrypt_cet_adaptor_signature`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_decrypt_cet_adaptor_signature;
+        })()(
+          FfiConverterArrayUInt8.lower(adaptorSignature),
+          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function derivePrivkeyFromSeed(
+  seed: Array</*u8*/ number>,
+  network: string,
+  path: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_derive_privkey_from_seed`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_derive_privkey_from_seed;
+        })()(
+          FfiConverterArrayUInt8.lower(seed),
+          FfiConverterString.lower(network),
+          FfiConverterString.lower(path),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function derivePubkeyFromSeed(
+  seed: Array</*u8*/ number>,
+  network: string,
+  path: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_derive_pubkey_from_seed`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_derive_pubkey_from_seed;
+        })()(
+          FfiConverterArrayUInt8.lower(seed),
+          FfiConverterString.lower(network),
+          FfiConverterString.lower(path),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function deserializeSettlementState(
+  bytes: Array</*u8*/ number>
+): SettlementState /*throws*/ {
+  return FfiConverterTypeSettlementState.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_deserialize_settlement_state`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_deserialize_settlement_state;
+        })()(FfiConverterArrayUInt8.lower(bytes), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function diffCetRefund(
+  cet: Transaction,
+  refund: Transaction
+): CetRefundDiff {
+  return FfiConverterTypeCetRefundDiff.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_diff_cet_refund`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_diff_cet_refund;
+        })()(
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterTypeTransaction.lower(refund),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function diffPartyParams(
+  a: PartyParams,
+  b: PartyParams
+): PartyParamsDiff {
+  return FfiConverterTypePartyParamsDiff.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_diff_party_params`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_diff_party_params;
+        })()(
+          FfiConverterTypePartyParams.lower(a),
+          FfiConverterTypePartyParams.lower(b),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function dlcInputInfoFromFundHex(
+  fundTxHex: string,
+  fundVout: /*u32*/ number,
+  localFundPubkey: Array</*u8*/ number>,
+  remoteFundPubkey: Array</*u8*/ number>,
+  fundAmount: /*u64*/ bigint,
+  maxWitnessLen: /*u32*/ number,
+  inputSerialId: /*u64*/ bigint,
+  contractId: Array</*u8*/ number>
+): DlcInputInfo /*throws*/ {
+  return FfiConverterTypeDlcInputInfo.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_dlc_input_info_from_fund_hex`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_dlc_input_info_from_fund_hex;
+        })()(
+          FfiConverterString.lower(fundTxHex),
+          FfiConverterUInt32.lower(fundVout),
+          FfiConverterArrayUInt8.lower(localFundPubkey),
+          FfiConverterArrayUInt8.lower(remoteFundPubkey),
+          FfiConverterUInt64.lower(fundAmount),
+          FfiConverterUInt32.lower(maxWitnessLen),
+          FfiConverterUInt64.lower(inputSerialId),
+          FfiConverterArrayUInt8.lower(contractId),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function dlcInputSighash(
+  txn: Transaction,
+  dlcInput: DlcInputInfo
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_dlc_input_sighash`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_dlc_input_sighash;
+        })()(
+          FfiConverterTypeTransaction.lower(txn),
+          FfiConverterTypeDlcInputInfo.lower(dlcInput),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function dlcTransactionsEqual(
+  a: DlcTransactions,
+  b: DlcTransactions
+): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_dlc_transactions_equal`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_dlc_transactions_equal;
+        })()(
+          FfiConverterTypeDlcTransactions.lower(a),
+          FfiConverterTypeDlcTransactions.lower(b),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function dlcTransactionsToFundPsbt(
+  dlcTxs: DlcTransactions
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_dlc_transactions_to_fund_psbt`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_dlc_transactions_to_fund_psbt;
+        })()(FfiConverterTypeDlcTransactions.lower(dlcTxs), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function dummyTransaction(
+  inputCount: /*u32*/ number,
+  outputCount: /*u32*/ number
+): Transaction {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_dummy_transaction`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_dummy_transaction;
+        })()(
+          FfiConverterUInt32.lower(inputCount),
+          FfiConverterUInt32.lower(outputCount),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function estimateCetFeeTaproot(feeRate: /*u64*/ bigint): /*u64*/ bigint {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_estimate_cet_fee_taproot`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_estimate_cet_fee_taproot;
+        })()(FfiConverterUInt64.lower(feeRate), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function estimateContractCost(
+  base: /*u32*/ number,
+  nbDigits: /*u32*/ number,
+  nbOracles: /*u32*/ number
+): ContractCost /*throws*/ {
+  return FfiConverterTypeContractCost.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_estimate_contract_cost`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_estimate_contract_cost;
+        })()(
+          FfiConverterUInt32.lower(base),
+          FfiConverterUInt32.lower(nbDigits),
+          FfiConverterUInt32.lower(nbOracles),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function estimateFundTransactionFee(
+  localParams: PartyParams,
+  remoteParams: PartyParams,
+  feeRate: /*u64*/ bigint
+): /*u64*/ bigint /*throws*/ {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_estimate_fund_transaction_fee`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_estimate_fund_transaction_fee;
+        })()(
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterUInt64.lower(feeRate),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function extkeysMatch(
+  xpriv: Array</*u8*/ number>,
+  xpub: Array</*u8*/ number>
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_extkeys_match`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_extkeys_match;
+        })()(
+          FfiConverterArrayUInt8.lower(xpriv),
+          FfiConverterArrayUInt8.lower(xpub),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function extractEcdsaSignatureFromOracleSignatures(
+  oracleSignatures: Array<Array</*u8*/ number>>,
+  adaptorSignature: Array</*u8*/ number>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_extract_ecdsa_signature_from_oracle_signatures`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_extract_ecdsa_signature_from_oracle_signatures;
+        })()(
+          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
+          FfiConverterArrayUInt8.lower(adaptorSignature),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function finalizeFundPsbt(
+  psbtBytes: Array</*u8*/ number>
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_finalize_fund_psbt`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_finalize_fund_psbt;
+        })()(FfiConverterArrayUInt8.lower(psbtBytes), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function finalizeRefundTransaction(
+  refundTx: Transaction,
+  localSignature: Array</*u8*/ number>,
+  remoteSignature: Array</*u8*/ number>,
+  localFundPubkey: Array</*u8*/ number>,
+  remoteFundPubkey: Array</*u8*/ number>
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_finalize_refund_transaction`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_finalize_refund_transaction;
+        })()(
+          FfiConverterTypeTransaction.lower(refundTx),
+          FfiConverterArrayUInt8.lower(localSignature),
+          FfiConverterArrayUInt8.lower(remoteSignature),
+          FfiConverterArrayUInt8.lower(localFundPubkey),
+          FfiConverterArrayUInt8.lower(remoteFundPubkey),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function findCetByPayout(
+  cets: Array<Transaction>,
+  offerAmount: /*u64*/ bigint,
+  acceptAmount: /*u64*/ bigint,
+  offerScript: Array</*u8*/ number>,
+  acceptScript: Array</*u8*/ number>
+): /*u32*/ number /*throws*/ {
+  return FfiConverterUInt32.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_find_cet_by_payout`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_find_cet_by_payout;
+        })()(
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterUInt64.lower(offerAmount),
+          FfiConverterUInt64.lower(acceptAmount),
+          FfiConverterArrayUInt8.lower(offerScript),
+          FfiConverterArrayUInt8.lower(acceptScript),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function fundingSigningPlan(
+  fundTx: Transaction,
+  ownedOutpoints: Array<string>,
+  amounts: Array</*u64*/ bigint>
+): Array<SigningStep> /*throws*/ {
+  return FfiConverterArrayTypeSigningStep.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_funding_signing_plan`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_funding_signing_plan;
+        })()(
+          FfiConverterTypeTransaction.lower(fundTx),
+          FfiConverterArrayString.lower(ownedOutpoints),
+          FfiConverterArrayUInt64.lower(amounts),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getCetAdaptorSignatureInputs(
+  cet: Transaction,
+  oracleInfo: Array<OracleInfo>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint,
+  msgs: Array<Array<Array</*u8*/ number>>>
+): CetAdaptorSignatureDebugInfo /*throws*/ {
+  return FfiConverterTypeCetAdaptorSignatureDebugInfo.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_get_cet_adaptor_signature_inputs`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_cet_adaptor_signature_inputs;
+        })()(
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterArrayArrayArrayUInt8.lower(msgs),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getCetSighash(
+  cet: Transaction,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_cet_sighash`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_get_cet_sighash;
+        })()(
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getChangeOutputAndFees(
+  params: PartyParams,
+  otherCollateral: /*u64*/ bigint,
+  feeRate: /*u64*/ bigint,
+  extraFee: /*u64*/ bigint
+): ChangeOutputAndFees /*throws*/ {
+  return FfiConverterTypeChangeOutputAndFees.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_change_output_and_fees`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_change_output_and_fees;
+        })()(
+          FfiConverterTypePartyParams.lower(params),
+          FfiConverterUInt64.lower(otherCollateral),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt64.lower(extraFee),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getExtkeyFingerprint(
+  extkey: Array</*u8*/ number>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_extkey_fingerprint`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_extkey_fingerprint;
+        })()(FfiConverterArrayUInt8.lower(extkey), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getFundOutputValue(
+  dlcTxs: DlcTransactions
+): /*u64*/ bigint /*throws*/ {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_fund_output_value`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_fund_output_value;
+        })()(FfiConverterTypeDlcTransactions.lower(dlcTxs), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getNetworkFromExtkey(
+  extkey: Array</*u8*/ number>
+): string /*throws*/ {
+  return FfiConverterString.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_network_from_extkey`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_network_from_extkey;
+        })()(FfiConverterArrayUInt8.lower(extkey), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getPubkeyFromExtkey(
+  extkey: Array</*u8*/ number>,
+  network: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_pubkey_from_extkey`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_pubkey_from_extkey;
+        })()(
+          FfiConverterArrayUInt8.lower(extkey),
+          FfiConverterString.lower(network),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getRawFundingTransactionInputSignature(
+  fundingTransaction: Transaction,
+  privkey: Array</*u8*/ number>,
+  prevTxId: string,
+  prevTxVout: /*u32*/ number,
+  value: /*u64*/ bigint
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_get_raw_funding_transaction_input_signature`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_raw_funding_transaction_input_signature;
+        })()(
+          FfiConverterTypeTransaction.lower(fundingTransaction),
+          FfiConverterArrayUInt8.lower(privkey),
+          FfiConverterString.lower(prevTxId),
+          FfiConverterUInt32.lower(prevTxVout),
+          FfiConverterUInt64.lower(value),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getTotalInputVsize(inputs: Array<TxInputInfo>): /*u32*/ number {
+  return FfiConverterUInt32.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_total_input_vsize`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_total_input_vsize;
+        })()(FfiConverterArrayTypeTxInputInfo.lower(inputs), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getTransactionTxid(tx: Transaction): string /*throws*/ {
+  return FfiConverterString.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_transaction_txid`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_transaction_txid;
+        })()(FfiConverterTypeTransaction.lower(tx), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getTransactionWtxid(tx: Transaction): string /*throws*/ {
+  return FfiConverterString.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_transaction_wtxid`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_get_transaction_wtxid;
+        })()(FfiConverterTypeTransaction.lower(tx), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function getXpubFromXpriv(
+  xpriv: Array</*u8*/ number>,
+  network: string
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_get_xpub_from_xpriv`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_get_xpub_from_xpriv;
+        })()(
+          FfiConverterArrayUInt8.lower(xpriv),
+          FfiConverterString.lower(network),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function hashOutcome(outcome: string): Array</*u8*/ number> {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_hash_outcome`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_hash_outcome;
+        })()(FfiConverterString.lower(outcome), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function hashOutcomes(
+  outcomes: Array<string>
+): Array<Array</*u8*/ number>> {
+  return FfiConverterArrayArrayUInt8.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_hash_outcomes`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_hash_outcomes;
+        })()(FfiConverterArrayString.lower(outcomes), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function isDustOutput(output: TxOutput): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_is_dust_output`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_is_dust_output;
+        })()(FfiConverterTypeTxOutput.lower(output), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function isDustOutputForFeerate(
+  output: TxOutput,
+  feeRate: /*u64*/ bigint
+): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_is_dust_output_for_feerate`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_is_dust_output_for_feerate;
+        })()(
+          FfiConverterTypeTxOutput.lower(output),
+          FfiConverterUInt64.lower(feeRate),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function isValidNetwork(network: string): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_is_valid_network`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_is_valid_network;
+        })()(FfiConverterString.lower(network), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function maxSafeFeeRate(
+  localParams: PartyParams,
+  remoteParams: PartyParams,
+  outcomes: Array<Payout>
+): /*u64*/ bigint /*throws*/ {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_max_safe_fee_rate`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_max_safe_fee_rate;
+        })()(
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function messageTemplate(
+  oracleInfos: Array<OracleInfo>,
+  cetCount: /*u32*/ number
+): Array<Array<Array<Array</*u8*/ number>>>> /*throws*/ {
+  return FfiConverterArrayArrayArrayArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_message_template`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_message_template;
+        })()(
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
+          FfiConverterUInt32.lower(cetCount),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function minViableCollateral(
+  outcomesRatios: Array<PayoutRatio>,
+  feeRate: /*u64*/ bigint,
+  localScript: Array</*u8*/ number>,
+  remoteScript: Array</*u8*/ number>
+): /*u64*/ bigint /*throws*/ {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_min_viable_collateral`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_min_viable_collateral;
+        })()(
+          FfiConverterArrayTypePayoutRatio.lower(outcomesRatios),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterArrayUInt8.lower(localScript),
+          FfiConverterArrayUInt8.lower(remoteScript),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function outcomeSignaturePoint(
+  oraclePubkey: Array</*u8*/ number>,
+  nonce: Array</*u8*/ number>,
+  message: Array</*u8*/ number>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_outcome_signature_point`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_outcome_signature_point;
+        })()(
+          FfiConverterArrayUInt8.lower(oraclePubkey),
+          FfiConverterArrayUInt8.lower(nonce),
+          FfiConverterArrayUInt8.lower(message),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function partyParamsDeserialize(
+  bytes: Array</*u8*/ number>
+): PartyParams /*throws*/ {
+  return FfiConverterTypePartyParams.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_party_params_deserialize`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_party_params_deserialize;
+        })()(FfiConverterArrayUInt8.lower(bytes), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function partyParamsFromDescriptor(
+  fundPubkey: Array</*u8*/ number>,
+  changeDescriptor: string,
+  payoutDescriptor: string,
+  inputs: Array<TxInputInfo>,
+  inputAmount: /*u64*/ bigint,
+  collateral: /*u64*/ bigint,
+  network: string
+): PartyParams /*throws*/ {
+  return FfiConverterTypePartyParams.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_party_params_from_descriptor`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_party_params_from_descriptor;
+        })()(
+          FfiConverterArrayUInt8.lower(fundPubkey),
+          FfiConverterString.lower(changeDescriptor),
+          FfiConverterString.lower(payoutDescriptor),
+          FfiConverterArrayTypeTxInputInfo.lower(inputs),
+          FfiConverterUInt64.lower(inputAmount),
+          FfiConverterUInt64.lower(collateral),
+          FfiConverterString.lower(network),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function partyParamsSerialize(
+  params: PartyParams
+): Array</*u8*/ number> {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_party_params_serialize`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_party_params_serialize;
+        })()(FfiConverterTypePartyParams.lower(params), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function previewChange(
+  inputs: Array<TxInputInfo>,
+  inputAmounts: Array</*u64*/ bigint>,
+  collateral: /*u64*/ bigint,
+  feeRate: /*u64*/ bigint,
+  changeScript: Array</*u8*/ number>
+): /*i64*/ bigint /*throws*/ {
+  return FfiConverterInt64.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_preview_change`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_preview_change;
+        })()(
+          FfiConverterArrayTypeTxInputInfo.lower(inputs),
+          FfiConverterArrayUInt64.lower(inputAmounts),
+          FfiConverterUInt64.lower(collateral),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterArrayUInt8.lower(changeScript),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function pubkeyToAddress(
+  pubkey: Array</*u8*/ number>,
+  network: string,
+  addressType: string
+): string /*throws*/ {
+  return FfiConverterString.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_pubkey_to_address`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_pubkey_to_address;
+        })()(
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterString.lower(network),
+          FfiConverterString.lower(addressType),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function rebuildRefund(
+  dlcTxs: DlcTransactions,
+  localFinalScriptPubkey: Array</*u8*/ number>,
+  remoteFinalScriptPubkey: Array</*u8*/ number>,
+  localAmount: /*u64*/ bigint,
+  remoteAmount: /*u64*/ bigint,
+  newLockTime: /*u32*/ number
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_rebuild_refund`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_rebuild_refund;
+        })()(
+          FfiConverterTypeDlcTransactions.lower(dlcTxs),
+          FfiConverterArrayUInt8.lower(localFinalScriptPubkey),
+          FfiConverterArrayUInt8.lower(remoteFinalScriptPubkey),
+          FfiConverterUInt64.lower(localAmount),
+          FfiConverterUInt64.lower(remoteAmount),
+          FfiConverterUInt32.lower(newLockTime),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function recoverAdaptorSecret(
+  adaptorSignature: Array</*u8*/ number>,
+  finalSignature: Array</*u8*/ number>,
+  adaptorPoint: Array</*u8*/ number>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_recover_adaptor_secret`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_recover_adaptor_secret;
+        })()(
+          FfiConverterArrayUInt8.lower(adaptorSignature),
+          FfiConverterArrayUInt8.lower(finalSignature),
+          FfiConverterArrayUInt8.lower(adaptorPoint),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function rekeyContract(
+  localParams: PartyParams,
+  newFundPubkey: Array</*u8*/ number>,
+  remoteParams: PartyParams,
+  outcomes: Array<Payout>,
+  refundLocktime: /*u32*/ number,
+  feeRate: /*u64*/ bigint,
+  fundLockTime: /*u32*/ number,
+  cetLockTime: /*u32*/ number,
+  fundOutputSerialId: /*u64*/ bigint,
+  contractFlags: /*u8*/ number
+): DlcTransactions /*throws*/ {
+  return FfiConverterTypeDlcTransactions.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_rekey_contract`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_rekey_contract;
+        })()(
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterArrayUInt8.lower(newFundPubkey),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterArrayTypePayout.lower(outcomes),
+          FfiConverterUInt32.lower(refundLocktime),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt32.lower(fundLockTime),
+          FfiConverterUInt32.lower(cetLockTime),
+          FfiConverterUInt64.lower(fundOutputSerialId),
+          FfiConverterUInt8.lower(contractFlags),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function safeFundOutputSerialId(
+  localParams: PartyParams,
+  remoteParams: PartyParams
+): /*u64*/ bigint {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_safe_fund_output_serial_id`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_safe_fund_output_serial_id;
+        })()(
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function serializeSettlementState(
+  dlcTxs: DlcTransactions,
+  adaptorSigs: Array<AdaptorSignature>,
+  oracleInfos: Array<OracleInfo>,
+  contractId: Array</*u8*/ number>
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_serialize_settlement_state`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_serialize_settlement_state;
+        })()(
+          FfiConverterTypeDlcTransactions.lower(dlcTxs),
+          FfiConverterArrayTypeAdaptorSignature.lower(adaptorSigs),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
+          FfiConverterArrayUInt8.lower(contractId),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function signCet(
+  cet: Transaction,
+  adaptorSignature: Array</*u8*/ number>,
+  oracleSignatures: Array<Array</*u8*/ number>>,
+  fundingSecretKey: Array</*u8*/ number>,
+  otherPubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_sign_cet`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_sign_cet;
+        })()(
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayUInt8.lower(adaptorSignature),
+          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
+          FfiConverterArrayUInt8.lower(fundingSecretKey),
+          FfiConverterArrayUInt8.lower(otherPubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function signCetChecked(
+  cet: Transaction,
+  adaptorSignature: Array</*u8*/ number>,
+  oracleSignatures: Array<Array</*u8*/ number>>,
+  fundingSecretKey: Array</*u8*/ number>,
+  otherPubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint,
+  oracleInfos: Array<OracleInfo>,
+  msgs: Array<Array<Array</*u8*/ number>>>
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_sign_cet_checked`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_sign_cet_checked;
+        })()(
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayUInt8.lower(adaptorSignature),
+          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
+          FfiConverterArrayUInt8.lower(fundingSecretKey),
+          FfiConverterArrayUInt8.lower(otherPubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
+          FfiConverterArrayArrayArrayUInt8.lower(msgs),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function signCetWithExternalSignature(
+  cet: Transaction,
+  adaptorSignature: Array</*u8*/ number>,
+  oracleSignatures: Array<Array</*u8*/ number>>,
+  externalSignature: Array</*u8*/ number>,
+  otherPubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_sign_cet_with_external_signature`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_sign_cet_with_external_signature;
+        })()(
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayUInt8.lower(adaptorSignature),
+          FfiConverterArrayArrayUInt8.lower(oracleSignatures),
+          FfiConverterArrayUInt8.lower(externalSignature),
+          FfiConverterArrayUInt8.lower(otherPubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function signFundTransactionInput(
+  fundTransaction: Transaction,
+  privkey: Array</*u8*/ number>,
+  prevTxId: string,
+  prevTxVout: /*u32*/ number,
+  value: /*u64*/ bigint
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_sign_fund_transaction_input`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_sign_fund_transaction_input;
+        })()(
+          FfiConverterTypeTransaction.lower(fundTransaction),
+          FfiConverterArrayUInt8.lower(privkey),
+          FfiConverterString.lower(prevTxId),
+          FfiConverterUInt32.lower(prevTxVout),
+          FfiConverterUInt64.lower(value),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function signMultiSigInput(
+  tx: Transaction,
+  dlcInput: DlcInputInfo,
+  localPrivkey: Array</*u8*/ number>,
+  remoteSignature: Array</*u8*/ number>
+): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_sign_multi_sig_input`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_sign_multi_sig_input;
+        })()(
+          FfiConverterTypeTransaction.lower(tx),
+          FfiConverterTypeDlcInputInfo.lower(dlcInput),
+          FfiConverterArrayUInt8.lower(localPrivkey),
+          FfiConverterArrayUInt8.lower(remoteSignature),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function signRefundTransaction(
+  refundTx: Transaction,
+  fundingSecretKey: Array</*u8*/ number>,
+  otherPubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint
+): Array</*u8*/ number> /*throws*/ {
+  return FfiConverterArrayUInt8.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_sign_refund_transaction`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_sign_refund_transaction;
+        })()(
+          FfiConverterTypeTransaction.lower(refundTx),
+          FfiConverterArrayUInt8.lower(fundingSecretKey),
+          FfiConverterArrayUInt8.lower(otherPubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function transactionFromHex(hex: string): Transaction /*throws*/ {
+  return FfiConverterTypeTransaction.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_transaction_from_hex`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_transaction_from_hex;
+        })()(FfiConverterString.lower(hex), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function transactionToHex(tx: Transaction): string {
+  return FfiConverterString.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_transaction_to_hex`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_transaction_to_hex;
+        })()(FfiConverterTypeTransaction.lower(tx), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function transactionsEquivalent(
+  a: Transaction,
+  b: Transaction
+): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_transactions_equivalent`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_transactions_equivalent;
+        })()(
+          FfiConverterTypeTransaction.lower(a),
+          FfiConverterTypeTransaction.lower(b),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function validateAdaptorSigBytes(
+  bytes: Array</*u8*/ number>
+): void /*throws*/ {
+  uniffiCaller.rustCallWithError(
+    /*liftError:*/ FfiConverterTypeDLCError.lift.bind(FfiConverterTypeDLCError),
+    /*caller:*/ (callStatus) => {
+      (() => {
+        console.debug(`-- uniffi_ddk_ffi_fn_func_validate_adaptor_sig_bytes`);
+        return nativeModule()
+          .ubrn_uniffi_ddk_ffi_fn_func_validate_adaptor_sig_bytes;
+      })()(FfiConverterArrayUInt8.lower(bytes), callStatus);
+    },
+    /*liftString:*/ FfiConverterString.lift
+  );
+}
+export function validateCetsShareFunding(
+  cets: Array<Transaction>
+): string /*throws*/ {
+  return FfiConverterString.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_validate_cets_share_funding`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_validate_cets_share_funding;
+        })()(FfiConverterArrayTypeTransaction.lower(cets), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function validateDlcTransactions(
+  dlcTxs: DlcTransactions
+): void /*throws*/ {
+  uniffiCaller.rustCallWithError(
+    /*liftError:*/ FfiConverterTypeDLCError.lift.bind(FfiConverterTypeDLCError),
+    /*caller:*/ (callStatus) => {
+      (() => {
+        console.debug(`-- uniffi_ddk_ffi_fn_func_validate_dlc_transactions`);
+        return nativeModule()
+          .ubrn_uniffi_ddk_ffi_fn_func_validate_dlc_transactions;
+      })()(FfiConverterTypeDlcTransactions.lower(dlcTxs), callStatus);
+    },
+    /*liftString:*/ FfiConverterString.lift
+  );
+}
+export function validateMnemonic(mnemonic: string): void /*throws*/ {
+  uniffiCaller.rustCallWithError(
+    /*liftError:*/ FfiConverterTypeDLCError.lift.bind(FfiConverterTypeDLCError),
+    /*caller:*/ (callStatus) => {
+      (() => {
+        console.debug(`-- uniffi_ddk_ffi_fn_func_validate_mnemonic`);
+        return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_validate_mnemonic;
+      })()(FfiConverterString.lower(mnemonic), callStatus);
+    },
+    /*liftString:*/ FfiConverterString.lift
+  );
+}
+export function validateOracleCount(
+  oracleInfos: Array<OracleInfo>,
+  required: /*u32*/ number
+): void /*throws*/ {
+  uniffiCaller.rustCallWithError(
+    /*liftError:*/ FfiConverterTypeDLCError.lift.bind(FfiConverterTypeDLCError),
+    /*caller:*/ (callStatus) => {
+      (() => {
+        console.debug(`-- uniffi_ddk_ffi_fn_func_validate_oracle_count`);
+        return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_validate_oracle_count;
+      })()(
+        FfiConverterArrayTypeOracleInfo.lower(oracleInfos),
+        FfiConverterUInt32.lower(required),
+        callStatus
+      );
+    },
+    /*liftString:*/ FfiConverterString.lift
+  );
+}
+export function validatePartyParams(params: PartyParams): void /*throws*/ {
+  uniffiCaller.rustCallWithError(
+    /*liftError:*/ FfiConverterTypeDLCError.lift.bind(FfiConverterTypeDLCError),
+    /*caller:*/ (callStatus) => {
+      (() => {
+        console.debug(`-- uniffi_ddk_ffi_fn_func_validate_party_params`);
+        return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_validate_party_params;
+      })()(FfiConverterTypePartyParams.lower(params), callStatus);
+    },
+    /*liftString:*/ FfiConverterString.lift
+  );
+}
+export function validateTransactionConsistency(
+  tx: Transaction
+): void /*throws*/ {
+  uniffiCaller.rustCallWithError(
+    /*liftError:*/ FfiConverterTypeDLCError.lift.bind(FfiConverterTypeDLCError),
+    /*caller:*/ (callStatus) => {
+      (() => {
+        console.debug(
+          `-- uniffi_ddk_ffi_fn_func_validate_transaction_consistency`
+        );
+        return nativeModule()
+          .ubrn_uniffi_ddk_ffi_fn_func_validate_transaction_consistency;
+      })()(FfiConverterTypeTransaction.lower(tx), callStatus);
+    },
+    /*liftString:*/ FfiConverterString.lift
+  );
+}
+export function verifyAdaptorPointsWellformed(
+  adaptorPoints: Array<Array</*u8*/ number>>
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_adaptor_points_wellformed`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_adaptor_points_wellformed;
+        })()(FfiConverterArrayArrayUInt8.lower(adaptorPoints), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyAdaptorProof(
+  adaptorSig: Array</*u8*/ number>,
+  adaptorPoint: Array</*u8*/ number>,
+  message: Array</*u8*/ number>,
+  pubkey: Array</*u8*/ number>
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_verify_adaptor_proof`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_adaptor_proof;
+        })()(
+          FfiConverterArrayUInt8.lower(adaptorSig),
+          FfiConverterArrayUInt8.lower(adaptorPoint),
+          FfiConverterArrayUInt8.lower(message),
+          FfiConverterArrayUInt8.lower(pubkey),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyAdaptorSigBindsCet(
+  adaptorSig: Array</*u8*/ number>,
+  cet: Transaction,
+  adaptorPoint: Array</*u8*/ number>,
+  pubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  totalCollateral: /*u64*/ bigint
+): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_adaptor_sig_binds_cet`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_adaptor_sig_binds_cet;
+        })()(
+          FfiConverterArrayUInt8.lower(adaptorSig),
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayUInt8.lower(adaptorPoint),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(totalCollateral),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyCetAdaptorSigFromOracleInfo(
+  adaptorSig: AdaptorSignature,
+  cet: Transaction,
+  oracleInfo: Array<OracleInfo>,
+  pubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  totalCollateral: /*u64*/ bigint,
+  msgs: Array<Array<Array</*u8*/ number>>>
+): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sig_from_oracle_info`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sig_from_oracle_info;
+        })()(
+          FfiConverterTypeAdaptorSignature.lower(adaptorSig),
+          FfiConverterTypeTransaction.lower(cet),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(totalCollateral),
+          FfiConverterArrayArrayArrayUInt8.lower(msgs),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyCetAdaptorSigsDetailed(
+  adaptorSigs: Array<AdaptorSignature>,
+  cets: Array<Transaction>,
+  oracleInfo: Array<OracleInfo>,
+  pubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  totalCollateral: /*u64*/ bigint,
+  msgs: Array<Array<Array<Array</*u8*/ number>>>>
+): Array<boolean> /*throws*/ {
+  return FfiConverterArrayBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_detailed`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_detailed;
+        })()(
+          FfiConverterArrayTypeAdaptorSignature.lower(adaptorSigs),
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(totalCollateral),
+          FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyCetAdaptorSigsFromOracleInfo(
+  adaptorSigs: Array<AdaptorSignature>,
+  cets: Array<Transaction>,
+  oracleInfo: Array<OracleInfo>,
+  pubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  totalCollateral: /*u64*/ bigint,
+  msgs: Array<Array<Array<Array</*u8*/ number>>>>
+): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_from_oracle_info`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_from_oracle_info;
+        })()(
+          FfiConverterArrayTypeAdaptorSignature.lower(adaptorSigs),
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterArrayTypeOracleInfo.lower(oracleInfo),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(totalCollateral),
+          FfiConverterArrayArrayArrayArrayUInt8.lower(msgs),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyCetAdaptorSigsWithPoints(
+  adaptorSigs: Array<Array</*u8*/ number>>,
+  cets: Array<Transaction>,
+  adaptorPoints: Array<Array</*u8*/ number>>,
+  pubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  totalCollateral: /*u64*/ bigint
+): Array<boolean> /*throws*/ {
+  return FfiConverterArrayBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_with_points`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_cet_adaptor_sigs_with_points;
+        })()(
+          FfiConverterArrayArrayUInt8.lower(adaptorSigs),
+          FfiConverterArrayTypeTransaction.lower(cets),
+          FfiConverterArrayArrayUInt8.lower(adaptorPoints),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(totalCollateral),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyCollateralInvariant(
+  dlcTxs: DlcTransactions,
+  offerParams: PartyParams,
+  acceptParams: PartyParams,
+  feeRate: /*u64*/ bigint
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_collateral_invariant`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_collateral_invariant;
+        })()(
+          FfiConverterTypeDlcTransactions.lower(dlcTxs),
+          FfiConverterTypePartyParams.lower(offerParams),
+          FfiConverterTypePartyParams.lower(acceptParams),
+          FfiConverterUInt64.lower(feeRate),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyDlcInputSignature(
+  txn: Transaction,
+  dlcInput: DlcInputInfo,
+  signature: Array</*u8*/ number>,
+  signerPubkey: Array</*u8*/ number>
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_verify_dlc_input_signature`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_dlc_input_signature;
+        })()(
+          FfiConverterTypeTransaction.lower(txn),
+          FfiConverterTypeDlcInputInfo.lower(dlcInput),
+          FfiConverterArrayUInt8.lower(signature),
+          FfiConverterArrayUInt8.lower(signerPubkey),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyFundTxSignature(
+  fundTx: Transaction,
+  signature: Array</*u8*/ number>,
+  pubkey: Array</*u8*/ number>,
+  txid: string,
+  vout: /*u32*/ number,
+  inputAmount: /*u64*/ bigint
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_verify_fund_tx_signature`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_fund_tx_signature;
+        })()(
+          FfiConverterTypeTransaction.lower(fundTx),
+          FfiConverterArrayUInt8.lower(signature),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterString.lower(txid),
+          FfiConverterUInt32.lower(vout),
+          FfiConverterUInt64.lower(inputAmount),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyFundingMatchesParams(
+  fundTx: Transaction,
+  localParams: PartyParams,
+  remoteParams: PartyParams,
+  feeRate: /*u64*/ bigint,
+  fundOutputSerialId: /*u64*/ bigint
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_funding_matches_params`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_funding_matches_params;
+        })()(
+          FfiConverterTypeTransaction.lower(fundTx),
+          FfiConverterTypePartyParams.lower(localParams),
+          FfiConverterTypePartyParams.lower(remoteParams),
+          FfiConverterUInt64.lower(feeRate),
+          FfiConverterUInt64.lower(fundOutputSerialId),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyFundingScriptConsistency(
+  dlcTxs: DlcTransactions
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_funding_script_consistency`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_funding_script_consistency;
+        })()(FfiConverterTypeDlcTransactions.lower(dlcTxs), callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyInputsUnspent(
+  params: PartyParams,
+  knownUtxos: Array<string>
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_verify_inputs_unspent`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_inputs_unspent;
+        })()(
+          FfiConverterTypePartyParams.lower(params),
+          FfiConverterArrayString.lower(knownUtxos),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyMultisigFundInputSignature(
+  fundTx: Transaction,
+  signature: Array</*u8*/ number>,
+  pubkey: Array</*u8*/ number>,
+  witnessScript: Array</*u8*/ number>,
+  txid: string,
+  vout: /*u32*/ number,
+  inputAmount: /*u64*/ bigint
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_multisig_fund_input_signature`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_multisig_fund_input_signature;
+        })()(
+          FfiConverterTypeTransaction.lower(fundTx),
+          FfiConverterArrayUInt8.lower(signature),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(witnessScript),
+          FfiConverterString.lower(txid),
+          FfiConverterUInt32.lower(vout),
+          FfiConverterUInt64.lower(inputAmount),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyOracleAttestation(
+  oraclePubkey: Array</*u8*/ number>,
+  nonce: Array</*u8*/ number>,
+  message: Array</*u8*/ number>,
+  signature: Array</*u8*/ number>
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_verify_oracle_attestation`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_oracle_attestation;
+        })()(
+          FfiConverterArrayUInt8.lower(oraclePubkey),
+          FfiConverterArrayUInt8.lower(nonce),
+          FfiConverterArrayUInt8.lower(message),
+          FfiConverterArrayUInt8.lower(signature),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyRefundPayouts(
+  refund: Transaction,
+  localScript: Array</*u8*/ number>,
+  expectedLocal: /*u64*/ bigint,
+  remoteScript: Array</*u8*/ number>,
+  expectedRemote: /*u64*/ bigint,
+  feeTolerance: /*u64*/ bigint
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_verify_refund_payouts`);
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_refund_payouts;
+        })()(
+          FfiConverterTypeTransaction.lower(refund),
+          FfiConverterArrayUInt8.lower(localScript),
+          FfiConverterUInt64.lower(expectedLocal),
+          FfiConverterArrayUInt8.lower(remoteScript),
+          FfiConverterUInt64.lower(expectedRemote),
+          FfiConverterUInt64.lower(feeTolerance),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function verifyRefundTransactionSignature(
+  refundTx: Transaction,
+  signature: Array</*u8*/ number>,
+  pubkey: Array</*u8*/ number>,
+  fundingScriptPubkey: Array</*u8*/ number>,
+  fundOutputValue: /*u64*/ bigint
+): boolean /*throws*/ {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCallWithError(
+      /*liftError:*/ FfiConverterTypeDLCError.lift.bind(
+        FfiConverterTypeDLCError
+      ),
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(
+            `-- uniffi_ddk_ffi_fn_func_verify_refund_transaction_signature`
+          );
+          return nativeModule()
+            .ubrn_uniffi_ddk_ffi_fn_func_verify_refund_transaction_signature;
+        })()(
+          FfiConverterTypeTransaction.lower(refundTx),
+          FfiConverterArrayUInt8.lower(signature),
+          FfiConverterArrayUInt8.lower(pubkey),
+          FfiConverterArrayUInt8.lower(fundingScriptPubkey),
+          FfiConverterUInt64.lower(fundOutputValue),
+          callStatus
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+export function version(): string {
+  return FfiConverterString.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return (() => {
+          console.debug(`-- uniffi_ddk_ffi_fn_func_version`);
+          return nativeModule().ubrn_uniffi_ddk_ffi_fn_func_version;
+        })()(callStatus);
+      },
+      /*liftString:*/ FfiConverterString.lift
+    )
+  );
+}
+
+export type AdaptorSignature = {
+  signature: Array</*u8*/ number>;
+  proof: Array</*u8*/ number>;
+};
+
+/**
+ * Generated factory for {@link AdaptorSignature} record objects.
+ */
+export const AdaptorSignature = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<AdaptorSignature, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link AdaptorSignature}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link AdaptorSignature}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<AdaptorSignature>,
+  });
+})();
+
+const FfiConverterTypeAdaptorSignature = (() => {
+  type TypeName = AdaptorSignature;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        signature: FfiConverterArrayUInt8.read(from),
+        proof: FfiConverterArrayUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterArrayUInt8.write(value.signature, into);
+      FfiConverterArrayUInt8.write(value.proof, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterArrayUInt8.allocationSize(value.signature) +
+        FfiConverterArrayUInt8.allocationSize(value.proof)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type CetAdaptorSignatureDebugInfo = {
+  sighash: Array</*u8*/ number>;
+  adaptorPoint: Array</*u8*/ number>;
+  inputIndex: /*u32*/ number;
+  scriptPubkey: Array</*u8*/ number>;
+  value: /*u64*/ bigint;
+  cetTxid: string;
+  cetRaw: Array</*u8*/ number>;
+};
+
+/**
+ * Generated factory for {@link CetAdaptorSignatureDebugInfo} record objects.
+ */
+export const CetAdaptorSignatureDebugInfo = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<
+      CetAdaptorSignatureDebugInfo,
+      ReturnType<typeof defaults>
+    >(defaults);
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CetAdaptorSignatureDebugInfo}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CetAdaptorSignatureDebugInfo}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () =>
+      Object.freeze(defaults()) as Partial<CetAdaptorSignatureDebugInfo>,
+  });
+})();
+
+const FfiConverterTypeCetAdaptorSignatureDebugInfo = (() => {
+  type TypeName = CetAdaptorSignatureDebugInfo;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        sighash: FfiConverterArrayUInt8.read(from),
+        adaptorPoint: FfiConverterArrayUInt8.read(from),
+        inputIndex: FfiConverterUInt32.read(from),
+        scriptPubkey: FfiConverterArrayUInt8.read(from),
+        value: FfiConverterUInt64.read(from),
+        cetTxid: FfiConverterString.read(from),
+        cetRaw: FfiConverterArrayUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterArrayUInt8.write(value.sighash, into);
+      FfiConverterArrayUInt8.write(value.adaptorPoint, into);
+      FfiConverterUInt32.write(value.inputIndex, into);
+      FfiConverterArrayUInt8.write(value.scriptPubkey, into);
+      FfiConverterUInt64.write(value.value, into);
+      FfiConverterString.write(value.cetTxid, into);
+      FfiConverterArrayUInt8.write(value.cetRaw, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterArrayUInt8.allocationSize(value.sighash) +
+        FfiConverterArrayUInt8.allocationSize(value.adaptorPoint) +
+        FfiConverterUInt32.allocationSize(value.inputIndex) +
+        FfiConverterArrayUInt8.allocationSize(value.scriptPubkey) +
+        FfiConverterUInt64.allocationSize(value.value) +
+        FfiConverterString.allocationSize(value.cetTxid) +
+        FfiConverterArrayUInt8.allocationSize(value.cetRaw)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type CetBuildResult = {
+  transaction: Transaction;
+  offerOutputDropped: boolean;
+  acceptOutputDropped: boolean;
+};
+
+/**
+ * Generated factory for {@link CetBuildResult} record objects.
+ */
+export const CetBuildResult = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<CetBuildResult, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CetBuildResult}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CetBuildResult}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<CetBuildResult>,
+  });
+})();
+
+const FfiConverterTypeCetBuildResult = (() => {
+  type TypeName = CetBuildResult;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        transaction: FfiConverterTypeTransaction.read(from),
+        offerOutputDropped: FfiConverterBool.read(from),
+        acceptOutputDropped: FfiConverterBool.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeTransaction.write(value.transaction, into);
+      FfiConverterBool.write(value.offerOutputDropped, into);
+      FfiConverterBool.write(value.acceptOutputDropped, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeTransaction.allocationSize(value.transaction) +
+        FfiConverterBool.allocationSize(value.offerOutputDropped) +
+        FfiConverterBool.allocationSize(value.acceptOutputDropped)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type CetRefundDiff = {
+  sameFundingOutpoint: boolean;
+  identicalPayouts: boolean;
+  cetOnlyOutputs: Array<TxOutput>;
+  refundOnlyOutputs: Array<TxOutput>;
+};
+
+/**
+ * Generated factory for {@link CetRefundDiff} record objects.
+ */
+export const CetRefundDiff = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<CetRefundDiff, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CetRefundDiff}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CetRefundDiff}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<CetRefundDiff>,
+  });
+})();
+
+const FfiConverterTypeCetRefundDiff = (() => {
+  type TypeName = CetRefundDiff;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        sameFundingOutpoint: FfiConverterBool.read(from),
+        identicalPayouts: FfiConverterBool.read(from),
+        cetOnlyOutputs: FfiConverterArrayTypeTxOutput.read(from),
+        refundOnlyOutputs: FfiConverterArrayTypeTxOutput.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterBool.write(value.sameFundingOutpoint, into);
+      FfiConverterBool.write(value.identicalPayouts, into);
+      FfiConverterArrayTypeTxOutput.write(value.cetOnlyOutputs, into);
+      FfiConverterArrayTypeTxOutput.write(value.refundOnlyOutputs, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterBool.allocationSize(value.sameFundingOutpoint) +
+        FfiConverterBool.allocationSize(value.identicalPayouts) +
+        FfiConverterArrayTypeTxOutput.allocationSize(value.cetOnlyOutputs) +
+        FfiConverterArrayTypeTxOutput.allocationSize(value.refundOnlyOutputs)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type ChangeOutputAndFees = {
+  changeOutput: TxOutput;
+  fundFee: /*u64*/ bigint;
+  cetFee: /*u64*/ bigint;
+};
+
+/**
+ * Generated factory for {@link ChangeOutputAndFees} record objects.
+ */
+export const ChangeOutputAndFees = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<ChangeOutputAndFees, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link ChangeOutputAndFees}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link ChangeOutputAndFees}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<ChangeOutputAndFees>,
+  });
+})();
+
+const FfiConverterTypeChangeOutputAndFees = (() => {
+  type TypeName = ChangeOutputAndFees;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        changeOutput: FfiConverterTypeTxOutput.read(from),
+        fundFee: FfiConverterUInt64.read(from),
+        cetFee: FfiConverterUInt64.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeTxOutput.write(value.changeOutput, into);
+      FfiConverterUInt64.write(value.fundFee, into);
+      FfiConverterUInt64.write(value.cetFee, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeTxOutput.allocationSize(value.changeOutput) +
+        FfiConverterUInt64.allocationSize(value.fundFee) +
+        FfiConverterUInt64.allocationSize(value.cetFee)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type CompletedContract = {
+  dlcTransactions: DlcTransactions;
+  fundOutputValue: /*u64*/ bigint;
+  offerAdaptorSignatures: Array<AdaptorSignature>;
+  acceptAdaptorSignatures: Array<AdaptorSignature>;
+  signedRefund: Transaction;
+};
+
+/**
+ * Generated factory for {@link CompletedContract} record objects.
+ */
+export const CompletedContract = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<CompletedContract, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CompletedContract}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CompletedContract}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<CompletedContract>,
+  });
+})();
+
+const FfiConverterTypeCompletedContract = (() => {
+  type TypeName = CompletedContract;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        dlcTransactions: FfiConverterTypeDlcTransactions.read(from),
+        fundOutputValue: FfiConverterUInt64.read(from),
+        offerAdaptorSignatures:
+          FfiConverterArrayTypeAdaptorSignature.read(from),
+        acceptAdaptorSignatures:
+          FfiConverterArrayTypeAdaptorSignature.read(from),
+        signedRefund: FfiConverterTypeTransaction.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeDlcTransactions.write(value.dlcTransactions, into);
+      FfiConverterUInt64.write(value.fundOutputValue, into);
+      FfiConverterArrayTypeAdaptorSignature.write(
+        value.offerAdaptorSignatures,
+        into
+      );
+      FfiConverterArrayTypeAdaptorSignature.write(
+        value.acceptAdaptorSignatures,
+        into
+      );
+      FfiConverterTypeTransaction.write(value.signedRefund, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeDlcTransactions.allocationSize(value.dlcTransactions) +
+        FfiConverterUInt64.allocationSize(value.fundOutputValue) +
+        FfiConverterArrayTypeAdaptorSignature.allocationSize(
+          value.offerAdaptorSignatures
+        ) +
+        FfiConverterArrayTypeAdaptorSignature.allocationSize(
+          value.acceptAdaptorSignatures
+        ) +
+        FfiConverterTypeTransaction.allocationSize(value.signedRefund)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type ContractCost = {
+  cetCount: /*u64*/ bigint;
+  transactionBytes: /*u64*/ bigint;
+  adaptorSigningOperations: /*u64*/ bigint;
+};
+
+/**
+ * Generated factory for {@link ContractCost} record objects.
+ */
+export const ContractCost = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<ContractCost, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link ContractCost}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link ContractCost}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<ContractCost>,
+  });
+})();
+
+const FfiConverterTypeContractCost = (() => {
+  type TypeName = ContractCost;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        cetCount: FfiConverterUInt64.read(from),
+        transactionBytes: FfiConverterUInt64.read(from),
+        adaptorSigningOperations: FfiConverterUInt64.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterUInt64.write(value.cetCount, into);
+      FfiConverterUInt64.write(value.transactionBytes, into);
+      FfiConverterUInt64.write(value.adaptorSigningOperations, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterUInt64.allocationSize(value.cetCount) +
+        FfiConverterUInt64.allocationSize(value.transactionBytes) +
+        FfiConverterUInt64.allocationSize(value.adaptorSigningOperations)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type DlcInputInfo = {
+  fundTx: Transaction;
+  fundVout: /*u32*/ number;
+  localFundPubkey: Array</*u8*/ number>;
+  remoteFundPubkey: Array</*u8*/ number>;
+  fundAmount: /*u64*/ bigint;
+  maxWitnessLen: /*u32*/ number;
+  inputSerialId: /*u64*/ bigint;
+  contractId: Array</*u8*/ number>;
+};
+
+/**
+ * Generated factory for {@link DlcInputInfo} record objects.
+ */
+export const DlcInputInfo = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<DlcInputInfo, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link DlcInputInfo}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link DlcInputInfo}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<DlcInputInfo>,
+  });
+})();
+
+const FfiConverterTypeDlcInputInfo = (() => {
+  type TypeName = DlcInputInfo;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        fundTx: FfiConverterTypeTransaction.read(from),
+        fundVout: FfiConverterUInt32.read(from),
+        localFundPubkey: FfiConverterArrayUInt8.read(from),
+        remoteFundPubkey: FfiConverterArrayUInt8.read(from),
+        fundAmount: FfiConverterUInt64.read(from),
+        maxWitnessLen: FfiConverterUInt32.read(from),
+        inputSerialId: FfiConverterUInt64.read(from),
+        contractId: FfiConverterArrayUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeTransaction.write(value.fundTx, into);
+      FfiConverterUInt32.write(value.fundVout, into);
+      FfiConverterArrayUInt8.write(value.localFundPubkey, into);
+      FfiConverterArrayUInt8.write(value.remoteFundPubkey, into);
+      FfiConverterUInt64.write(value.fundAmount, into);
+      FfiConverterUInt32.write(value.maxWitnessLen, into);
+      FfiConverterUInt64.write(value.inputSerialId, into);
+      FfiConverterArrayUInt8.write(value.contractId, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeTransaction.allocationSize(value.fundTx) +
+        FfiConverterUInt32.allocationSize(value.fundVout) +
+        FfiConverterArrayUInt8.allocationSize(value.localFundPubkey) +
+        FfiConverterArrayUInt8.allocationSize(value.remoteFundPubkey) +
+        FfiConverterUInt64.allocationSize(value.fundAmount) +
+        FfiConverterUInt32.allocationSize(value.maxWitnessLen) +
+        FfiConverterUInt64.allocationSize(value.inputSerialId) +
+        FfiConverterArrayUInt8.allocationSize(value.contractId)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type DlcTransactionRequest = {
+  outcomes: Array<Payout>;
+  localParams: PartyParams;
+  remoteParams: PartyParams;
+  refundLocktime: /*u32*/ number;
+  feeRate: /*u64*/ bigint;
+  fundLockTime: /*u32*/ number;
+  cetLockTime: /*u32*/ number;
+  fundOutputSerialId: /*u64*/ bigint;
+  contractFlags: /*u8*/ number;
+};
+
+/**
+ * Generated factory for {@link DlcTransactionRequest} record objects.
+ */
+export const DlcTransactionRequest = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<
+      DlcTransactionRequest,
+      ReturnType<typeof defaults>
+    >(defaults);
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link DlcTransactionRequest}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link DlcTransactionRequest}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<DlcTransactionRequest>,
+  });
+})();
+
+const FfiConverterTypeDlcTransactionRequest = (() => {
+  type TypeName = DlcTransactionRequest;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        outcomes: FfiConverterArrayTypePayout.read(from),
+        localParams: FfiConverterTypePartyParams.read(from),
+        remoteParams: FfiConverterTypePartyParams.read(from),
+        refundLocktime: FfiConverterUInt32.read(from),
+        feeRate: FfiConverterUInt64.read(from),
+        fundLockTime: FfiConverterUInt32.read(from),
+        cetLockTime: FfiConverterUInt32.read(from),
+        fundOutputSerialId: FfiConverterUInt64.read(from),
+        contractFlags: FfiConverterUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterArrayTypePayout.write(value.outcomes, into);
+      FfiConverterTypePartyParams.write(value.localParams, into);
+      FfiConverterTypePartyParams.write(value.remoteParams, into);
+      FfiConverterUInt32.write(value.refundLocktime, into);
+      FfiConverterUInt64.write(value.feeRate, into);
+      FfiConverterUInt32.write(value.fundLockTime, into);
+      FfiConverterUInt32.write(value.cetLockTime, into);
+      FfiConverterUInt64.write(value.fundOutputSerialId, into);
+      FfiConverterUInt8.write(value.contractFlags, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterArrayTypePayout.allocationSize(value.outcomes) +
+        FfiConverterTypePartyParams.allocationSize(value.localParams) +
+        FfiConverterTypePartyParams.allocationSize(value.remoteParams) +
+        FfiConverterUInt32.allocationSize(value.refundLocktime) +
+        FfiConverterUInt64.allocationSize(value.feeRate) +
+        FfiConverterUInt32.allocationSize(value.fundLockTime) +
+        FfiConverterUInt32.allocationSize(value.cetLockTime) +
+        FfiConverterUInt64.allocationSize(value.fundOutputSerialId) +
+        FfiConverterUInt8.allocationSize(value.contractFlags)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type DlcTransactions = {
+  fund: Transaction;
+  cets: Array<Transaction>;
+  refund: Transaction;
+  fundingScriptPubkey: Array</*u8*/ number>;
+};
+
+/**
+ * Generated factory for {@link DlcTransactions} record objects.
+ */
+export const DlcTransactions = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<DlcTransactions, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link DlcTransactions}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link DlcTransactions}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<DlcTransactions>,
+  });
+})();
+
+const FfiConverterTypeDlcTransactions = (() => {
+  type TypeName = DlcTransactions;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        fund: FfiConverterTypeTransaction.read(from),
+        cets: FfiConverterArrayTypeTransaction.read(from),
+        refund: FfiConverterTypeTransaction.read(from),
+        fundingScriptPubkey: FfiConverterArrayUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeTransaction.write(value.fund, into);
+      FfiConverterArrayTypeTransaction.write(value.cets, into);
+      FfiConverterTypeTransaction.write(value.refund, into);
+      FfiConverterArrayUInt8.write(value.fundingScriptPubkey, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeTransaction.allocationSize(value.fund) +
+        FfiConverterArrayTypeTransaction.allocationSize(value.cets) +
+        FfiConverterTypeTransaction.allocationSize(value.refund) +
+        FfiConverterArrayUInt8.allocationSize(value.fundingScriptPubkey)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type OracleInfo = {
+  publicKey: Array</*u8*/ number>;
+  nonces: Array<Array</*u8*/ number>>;
+};
+
+/**
+ * Generated factory for {@link OracleInfo} record objects.
+ */
+export const OracleInfo = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<OracleInfo, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link OracleInfo}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link OracleInfo}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<OracleInfo>,
+  });
+})();
+
+const FfiConverterTypeOracleInfo = (() => {
+  type TypeName = OracleInfo;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        publicKey: FfiConverterArrayUInt8.read(from),
+        nonces: FfiConverterArrayArrayUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterArrayUInt8.write(value.publicKey, into);
+      FfiConverterArrayArrayUInt8.write(value.nonces, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterArrayUInt8.allocationSize(value.publicKey) +
+        FfiConverterArrayArrayUInt8.allocationSize(value.nonces)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type PartyParams = {
+  fundPubkey: Array</*u8*/ number>;
+  changeScriptPubkey: Array</*u8*/ number> | undefined;
+  changeSerialId: /*u64*/ bigint;
+  payoutScriptPubkey: Array</*u8*/ number>;
+  payoutSerialId: /*u64*/ bigint;
+  inputs: Array<TxInputInfo>;
+  inputAmount: /*u64*/ bigint;
+  collateral: /*u64*/ bigint;
+  dlcInputs: Array<DlcInputInfo>;
+};
+
+/**
+ * Generated factory for {@link PartyParams} record objects.
+ */
+export const PartyParams = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<PartyParams, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link PartyParams}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link PartyParams}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<PartyParams>,
+  });
+})();
+
+const FfiConverterTypePartyParams = (() => {
+  type TypeName = PartyParams;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        fundPubkey: FfiConverterArrayUInt8.read(from),
+        changeScriptPubkey: FfiConverterOptionalArrayUInt8.read(from),
+        changeSerialId: FfiConverterUInt64.read(from),
+        payoutScriptPubkey: FfiConverterArrayUInt8.read(from),
+        payoutSerialId: FfiConverterUInt64.read(from),
+        inputs: FfiConverterArrayTypeTxInputInfo.read(from),
+        inputAmount: FfiConverterUInt64.read(from),
+        collateral: FfiConverterUInt64.read(from),
+        dlcInputs: FfiConverterArrayTypeDlcInputInfo.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterArrayUInt8.write(value.fundPubkey, into);
+      FfiConverterOptionalArrayUInt8.write(value.changeScriptPubkey, into);
+      FfiConverterUInt64.write(value.changeSerialId, into);
+      FfiConverterArrayUInt8.write(value.payoutScriptPubkey, into);
+      FfiConverterUInt64.write(value.payoutSerialId, into);
+      FfiConverterArrayTypeTxInputInfo.write(value.inputs, into);
+      FfiConverterUInt64.write(value.inputAmount, into);
+      FfiConverterUInt64.write(value.collateral, into);
+      FfiConverterArrayTypeDlcInputInfo.write(value.dlcInputs, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterArrayUInt8.allocationSize(value.fundPubkey) +
+        FfiConverterOptionalArrayUInt8.allocationSize(
+          value.changeScriptPubkey
+        ) +
+        FfiConverterUInt64.allocationSize(value.changeSerialId) +
+        FfiConverterArrayUInt8.allocationSize(value.payoutScriptPubkey) +
+        FfiConverterUInt64.allocationSize(value.payoutSerialId) +
+        FfiConverterArrayTypeTxInputInfo.allocationSize(value.inputs) +
+        FfiConverterUInt64.allocationSize(value.inputAmount) +
+        FfiConverterUInt64.allocationSize(value.collateral) +
+        FfiConverterArrayTypeDlcInputInfo.allocationSize(value.dlcInputs)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type PartyParamsDiff = {
+  fundPubkey: boolean;
+  changeScriptPubkey: boolean;
+  payoutScriptPubkey: boolean;
+  inputAmount: boolean;
+  collateral: boolean;
+  inputs: boolean;
+  dlcInputs: boolean;
+  serialIds: boolean;
+};
+
+/**
+ * Generated factory for {@link PartyParamsDiff} record objects.
+ */
+export const PartyParamsDiff = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<PartyParamsDiff, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link PartyParamsDiff}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link PartyParamsDiff}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<PartyParamsDiff>,
+  });
+})();
+
+const FfiConverterTypePartyParamsDiff = (() => {
+  type TypeName = PartyParamsDiff;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        fundPubkey: FfiConverterBool.read(from),
+        changeScriptPubkey: FfiConverterBool.read(from),
+        payoutScriptPubkey: FfiConverterBool.read(from),
+        inputAmount: FfiConverterBool.read(from),
+        collateral: FfiConverterBool.read(from),
+        inputs: FfiConverterBool.read(from),
+        dlcInputs: FfiConverterBool.read(from),
+        serialIds: FfiConverterBool.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterBool.write(value.fundPubkey, into);
+      FfiConverterBool.write(value.changeScriptPubkey, into);
+      FfiConverterBool.write(value.payoutScriptPubkey, into);
+      FfiConverterBool.write(value.inputAmount, into);
+      FfiConverterBool.write(value.collateral, into);
+      FfiConverterBool.write(value.inputs, into);
+      FfiConverterBool.write(value.dlcInputs, into);
+      FfiConverterBool.write(value.serialIds, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterBool.allocationSize(value.fundPubkey) +
+        FfiConverterBool.allocationSize(value.changeScriptPubkey) +
+        FfiConverterBool.allocationSize(value.payoutScriptPubkey) +
+        FfiConverterBool.allocationSize(value.inputAmount) +
+        FfiConverterBool.allocationSize(value.collateral) +
+        FfiConverterBool.allocationSize(value.inputs) +
+        FfiConverterBool.allocationSize(value.dlcInputs) +
+        FfiConverterBool.allocationSize(value.serialIds)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type Payout = {
+  offer: /*u64*/ bigint;
+  accept: /*u64*/ bigint;
+};
+
+/**
+ * Generated factory for {@link Payout} record objects.
+ */
+export const Payout = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<Payout, ReturnType<typeof defaults>>(defaults);
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link Payout}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link Payout}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<Payout>,
+  });
+})();
+
+const FfiConverterTypePayout = (() => {
+  type TypeName = Payout;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        offer: FfiConverterUInt64.read(from),
+        accept: FfiConverterUInt64.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterUInt64.write(value.offer, into);
+      FfiConverterUInt64.write(value.accept, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterUInt64.allocationSize(value.offer) +
+        FfiConverterUInt64.allocationSize(value.accept)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type PayoutRatio = {
+  offer: /*u32*/ number;
+  accept: /*u32*/ number;
+};
+
+/**
+ * Generated factory for {@link PayoutRatio} record objects.
+ */
+export const PayoutRatio = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<PayoutRatio, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link PayoutRatio}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link PayoutRatio}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<PayoutRatio>,
+  });
+})();
+
+const FfiConverterTypePayoutRatio = (() => {
+  type TypeName = PayoutRatio;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        offer: FfiConverterUInt32.read(from),
+        accept: FfiConverterUInt32.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterUInt32.write(value.offer, into);
+      FfiConverterUInt32.write(value.accept, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterUInt32.allocationSize(value.offer) +
+        FfiConverterUInt32.allocationSize(value.accept)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type SchnorrParts = {
+  nonce: Array</*u8*/ number>;
+  sValue: Array</*u8*/ number>;
+};
+
+/**
+ * Generated factory for {@link SchnorrParts} record objects.
+ */
+export const SchnorrParts = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<SchnorrParts, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link SchnorrParts}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link SchnorrParts}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<SchnorrParts>,
+  });
+})();
+
+const FfiConverterTypeSchnorrParts = (() => {
+  type TypeName = SchnorrParts;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        nonce: FfiConverterArrayUInt8.read(from),
+        sValue: FfiConverterArrayUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterArrayUInt8.write(value.nonce, into);
+      FfiConverterArrayUInt8.write(value.sValue, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterArrayUInt8.allocationSize(value.nonce) +
+        FfiConverterArrayUInt8.allocationSize(value.sValue)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type SettlementState = {
+  dlcTransactions: DlcTransactions;
+  adaptorSignatures: Array<AdaptorSignature>;
+  oracleInfos: Array<OracleInfo>;
+  contractId: Array</*u8*/ number>;
+};
+
+/**
+ * Generated factory for {@link SettlementState} record objects.
+ */
+export const SettlementState = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<SettlementState, ReturnType<typeof defaults>>(
+      defaults
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link SettlementState}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link SettlementState}, with defaults specified
+     * in Rust, in the {@link ddk_ffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link ddk_ffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<SettlementState>,
+  });
+})();
+
+const FfiConverterTypeSettlementState = (() => {
+  type TypeName = SettlementState;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        dlcTransactions: FfiConverterTypeDlcTransactions.read(from),
+        adaptorSignatures: FfiConverterArrayTypeAdaptorSignature.read(from),
+        oracleInfos: FfiConverterArrayTypeOracleInfo.read(from),
+        contractId: FfiConverterArrayUInt8.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeDlcTransactions.write(value.dlcTransactions, into);
+      FfiConverterArrayTypeAdaptorSignature.write(
+        value.adaptorSignatures,
+        into
+      );
+      FfiConverterArrayTypeOracleInfo.write(value.oracleInfos, into);
+      FfiConverterArrayUInt8.write(value.contractId, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeDlcTransactions.allocationSize(value.dlcTransactions) +
+        FfiConverterArrayTypeAdaptorSignature.allocationSize(
+          value.adaptorSignatures
+        ) +
+        FfiConverterArrayTypeOracleInfo.allocationSize(value.oracleInfos) +
+        FfiConverterArrayUInt8.allocationSize(value.contractId)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
 
-export type AdaptorSignature = {
-  signature: Array</*u8*/ number>;
-  proof: Array</*u8*/ number>;
+export type SigningStep = {
+  inputIndex: /*u32*/ number;
+  scriptType: string;
+  amount: /*u64*/ bigint;
 };
 
 /**
- * Generated factory for {@link AdaptorSignature} record objects.
+ * Generated factory for {@link SigningStep} record objects.
  */
-export const AdaptorSignature = (() => {
+export const SigningStep = (() => {
   const defaults = () => ({});
   const create = (() => {
-    return uniffiCreateRecord<AdaptorSignature, ReturnType<typeof defaults>>(
+    return uniffiCreateRecord<SigningStep, ReturnType<typeof defaults>>(
       defaults
     );
   })();
   return Object.freeze({
     /**
-     * Create a frozen instance of {@link AdaptorSignature}, with defaults specified
+     * Create a frozen instance of {@link SigningStep}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     create,
 
     /**
-     * Create a frozen instance of {@link AdaptorSignature}, with defaults specified
+     * Create a frozen instance of {@link SigningStep}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     new: create,
@@ -976,63 +4544,63 @@ export const AdaptorSignature = (() => {
     /**
      * Defaults specified in the {@link ddk_ffi} crate.
      */
-    defaults: () => Object.freeze(defaults()) as Partial<AdaptorSignature>,
+    defaults: () => Object.freeze(defaults()) as Partial<SigningStep>,
   });
 })();
 
-const FfiConverterTypeAdaptorSignature = (() => {
-  type TypeName = AdaptorSignature;
+const FfiConverterTypeSigningStep = (() => {
+  type TypeName = SigningStep;
   class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
       return {
-        signature: FfiConverterArrayUInt8.read(from),
-        proof: FfiConverterArrayUInt8.read(from),
+        inputIndex: FfiConverterUInt32.read(from),
+        scriptType: FfiConverterString.read(from),
+        amount: FfiConverterUInt64.read(from),
       };
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterArrayUInt8.write(value.signature, into);
-      FfiConverterArrayUInt8.write(value.proof, into);
+      FfiConverterUInt32.write(value.inputIndex, into);
+      FfiConverterString.write(value.scriptType, into);
+      FfiConverterUInt64.write(value.amount, into);
     }
     allocationSize(value: TypeName): number {
       return (
-        FfiConverterArrayUInt8.allocationSize(value.signature) +
-        FfiConverterArrayUInt8.allocationSize(value.proof)
+        FfiConverterUInt32.allocationSize(value.inputIndex) +
+        FfiConverterString.allocationSize(value.scriptType) +
+        FfiConverterUInt64.allocationSize(value.amount)
       );
     }
   }
   return new FFIConverter();
 })();
 
-export type CetAdaptorSignatureDebugInfo = {
-  sighash: Array</*u8*/ number>;
-  adaptorPoint: Array</*u8*/ number>;
-  inputIndex: /*u32*/ number;
-  scriptPubkey: Array</*u8*/ number>;
-  value: /*u64*/ bigint;
-  cetTxid: string;
-  cetRaw: Array</*u8*/ number>;
+export type Transaction = {
+  version: /*i32*/ number;
+  lockTime: /*u32*/ number;
+  inputs: Array<TxInput>;
+  outputs: Array<TxOutput>;
+  rawBytes: Array</*u8*/ number>;
 };
 
 /**
- * Generated factory for {@link CetAdaptorSignatureDebugInfo} record objects.
+ * Generated factory for {@link Transaction} record objects.
  */
-export const CetAdaptorSignatureDebugInfo = (() => {
+export const Transaction = (() => {
   const defaults = () => ({});
   const create = (() => {
-    return uniffiCreateRecord<
-      CetAdaptorSignatureDebugInfo,
-      ReturnType<typeof defaults>
-    >(defaults);
+    return uniffiCreateRecord<Transaction, ReturnType<typeof defaults>>(
+      defaults
+    );
   })();
   return Object.freeze({
     /**
-     * Create a frozen instance of {@link CetAdaptorSignatureDebugInfo}, with defaults specified
+     * Create a frozen instance of {@link Transaction}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     create,
 
     /**
-     * Create a frozen instance of {@link CetAdaptorSignatureDebugInfo}, with defaults specified
+     * Create a frozen instance of {@link Transaction}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     new: create,
@@ -1040,74 +4608,67 @@ export const CetAdaptorSignatureDebugInfo = (() => {
     /**
      * Defaults specified in the {@link ddk_ffi} crate.
      */
-    defaults: () =>
-      Object.freeze(defaults()) as Partial<CetAdaptorSignatureDebugInfo>,
+    defaults: () => Object.freeze(defaults()) as Partial<Transaction>,
   });
 })();
 
-const FfiConverterTypeCetAdaptorSignatureDebugInfo = (() => {
-  type TypeName = CetAdaptorSignatureDebugInfo;
+const FfiConverterTypeTransaction = (() => {
+  type TypeName = Transaction;
   class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
       return {
-        sighash: FfiConverterArrayUInt8.read(from),
-        adaptorPoint: FfiConverterArrayUInt8.read(from),
-        inputIndex: FfiConverterUInt32.read(from),
-        scriptPubkey: FfiConverterArrayUInt8.read(from),
-        value: FfiConverterUInt64.read(from),
-        cetTxid: FfiConverterString.read(from),
-        cetRaw: FfiConverterArrayUInt8.read(from),
+        version: FfiConverterInt32.read(from),
+        lockTime: FfiConverterUInt32.read(from),
+        inputs: FfiConverterArrayTypeTxInput.read(from),
+        outputs: FfiConverterArrayTypeTxOutput.read(from),
+        rawBytes: FfiConverterArrayUInt8.read(from),
       };
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterArrayUInt8.write(value.sighash, into);
-      FfiConverterArrayUInt8.write(value.adaptorPoint, into);
-      FfiConverterUInt32.write(value.inputIndex, into);
-      FfiConverterArrayUInt8.write(value.scriptPubkey, into);
-      FfiConverterUInt64.write(value.value, into);
-      FfiConverterString.write(value.cetTxid, into);
-      FfiConverterArrayUInt8.write(value.cetRaw, into);
+      FfiConverterInt32.write(value.version, into);
+      FfiConverterUInt32.write(value.lockTime, into);
+      FfiConverterArrayTypeTxInput.write(value.inputs, into);
+      FfiConverterArrayTypeTxOutput.write(value.outputs, into);
+      FfiConverterArrayUInt8.write(value.rawBytes, into);
     }
     allocationSize(value: TypeName): number {
       return (
-        FfiConverterArrayUInt8.allocationSize(value.sighash) +
-        FfiConverterArrayUInt8.allocationSize(value.adaptorPoint) +
-        FfiConverterUInt32.allocationSize(value.inputIndex) +
-        FfiConverterArrayUInt8.allocationSize(value.scriptPubkey) +
-        FfiConverterUInt64.allocationSize(value.value) +
-        FfiConverterString.allocationSize(value.cetTxid) +
-        FfiConverterArrayUInt8.allocationSize(value.cetRaw)
+        FfiConverterInt32.allocationSize(value.version) +
+        FfiConverterUInt32.allocationSize(value.lockTime) +
+        FfiConverterArrayTypeTxInput.allocationSize(value.inputs) +
+        FfiConverterArrayTypeTxOutput.allocationSize(value.outputs) +
+        FfiConverterArrayUInt8.allocationSize(value.rawBytes)
       );
     }
   }
   return new FFIConverter();
 })();
 
-export type ChangeOutputAndFees = {
-  changeOutput: TxOutput;
-  fundFee: /*u64*/ bigint;
-  cetFee: /*u64*/ bigint;
+export type TxInput = {
+  txid: string;
+  vout: /*u32*/ number;
+  scriptSig: Array</*u8*/ number>;
+  sequence: /*u32*/ number;
+  witness: Array<Array</*u8*/ number>>;
 };
 
 /**
- * Generated factory for {@link ChangeOutputAndFees} record objects.
+ * Generated factory for {@link TxInput} record objects.
  */
-export const ChangeOutputAndFees = (() => {
+export const TxInput = (() => {
   const defaults = () => ({});
   const create = (() => {
-    return uniffiCreateRecord<ChangeOutputAndFees, ReturnType<typeof defaults>>(
-      defaults
-    );
+    return uniffiCreateRecord<TxInput, ReturnType<typeof defaults>>(defaults);
   })();
   return Object.freeze({
     /**
-     * Create a frozen instance of {@link ChangeOutputAndFees}, with defaults specified
+     * Create a frozen instance of {@link TxInput}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     create,
 
     /**
-     * Create a frozen instance of {@link ChangeOutputAndFees}, with defaults specified
+     * Create a frozen instance of {@link TxInput}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     new: create,
@@ -1115,66 +4676,69 @@ export const ChangeOutputAndFees = (() => {
     /**
      * Defaults specified in the {@link ddk_ffi} crate.
      */
-    defaults: () => Object.freeze(defaults()) as Partial<ChangeOutputAndFees>,
+    defaults: () => Object.freeze(defaults()) as Partial<TxInput>,
   });
 })();
 
-const FfiConverterTypeChangeOutputAndFees = (() => {
-  type TypeName = ChangeOutputAndFees;
+const FfiConverterTypeTxInput = (() => {
+  type TypeName = TxInput;
   class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
       return {
-        changeOutput: FfiConverterTypeTxOutput.read(from),
-        fundFee: FfiConverterUInt64.read(from),
-        cetFee: FfiConverterUInt64.read(from),
+        txid: FfiConverterString.read(from),
+        vout: FfiConverterUInt32.read(from),
+        scriptSig: FfiConverterArrayUInt8.read(from),
+        sequence: FfiConverterUInt32.read(from),
+        witness: FfiConverterArrayArrayUInt8.read(from),
       };
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterTypeTxOutput.write(value.changeOutput, into);
-      FfiConverterUInt64.write(value.fundFee, into);
-      FfiConverterUInt64.write(value.cetFee, into);
+      FfiConverterString.write(value.txid, into);
+      FfiConverterUInt32.write(value.vout, into);
+      FfiConverterArrayUInt8.write(value.scriptSig, into);
+      FfiConverterUInt32.write(value.sequence, into);
+      FfiConverterArrayArrayUInt8.write(value.witness, into);
     }
     allocationSize(value: TypeName): number {
       return (
-        FfiConverterTypeTxOutput.allocationSize(value.changeOutput) +
-        FfiConverterUInt64.allocationSize(value.fundFee) +
-        FfiConverterUInt64.allocationSize(value.cetFee)
+        FfiConverterString.allocationSize(value.txid) +
+        FfiConverterUInt32.allocationSize(value.vout) +
+        FfiConverterArrayUInt8.allocationSize(value.scriptSig) +
+        FfiConverterUInt32.allocationSize(value.sequence) +
+        FfiConverterArrayArrayUInt8.allocationSize(value.witness)
       );
     }
   }
   return new FFIConverter();
 })();
 
-export type DlcInputInfo = {
-  fundTx: Transaction;
-  fundVout: /*u32*/ number;
-  localFundPubkey: Array</*u8*/ number>;
-  remoteFundPubkey: Array</*u8*/ number>;
-  fundAmount: /*u64*/ bigint;
-  maxWitnessLen: /*u32*/ number;
-  inputSerialId: /*u64*/ bigint;
-  contractId: Array</*u8*/ number>;
+export type TxInputInfo = {
+  txid: string;
+  vout: /*u32*/ number;
+  scriptSig: Array</*u8*/ number>;
+  maxWitnessLength: /*u32*/ number;
+  serialId: /*u64*/ bigint;
 };
 
 /**
- * Generated factory for {@link DlcInputInfo} record objects.
+ * Generated factory for {@link TxInputInfo} record objects.
  */
-export const DlcInputInfo = (() => {
+export const TxInputInfo = (() => {
   const defaults = () => ({});
   const create = (() => {
-    return uniffiCreateRecord<DlcInputInfo, ReturnType<typeof defaults>>(
+    return uniffiCreateRecord<TxInputInfo, ReturnType<typeof defaults>>(
       defaults
     );
   })();
   return Object.freeze({
     /**
-     * Create a frozen instance of {@link DlcInputInfo}, with defaults specified
+     * Create a frozen instance of {@link TxInputInfo}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     create,
 
     /**
-     * Create a frozen instance of {@link DlcInputInfo}, with defaults specified
+     * Create a frozen instance of {@link TxInputInfo}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     new: create,
@@ -1182,77 +4746,64 @@ export const DlcInputInfo = (() => {
     /**
      * Defaults specified in the {@link ddk_ffi} crate.
      */
-    defaults: () => Object.freeze(defaults()) as Partial<DlcInputInfo>,
+    defaults: () => Object.freeze(defaults()) as Partial<TxInputInfo>,
   });
 })();
 
-const FfiConverterTypeDlcInputInfo = (() => {
-  type TypeName = DlcInputInfo;
+const FfiConverterTypeTxInputInfo = (() => {
+  type TypeName = TxInputInfo;
   class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
       return {
-        fundTx: FfiConverterTypeTransaction.read(from),
-        fundVout: FfiConverterUInt32.read(from),
-        localFundPubkey: FfiConverterArrayUInt8.read(from),
-        remoteFundPubkey: FfiConverterArrayUInt8.read(from),
-        fundAmount: FfiConverterUInt64.read(from),
-        maxWitnessLen: FfiConverterUInt32.read(from),
-        inputSerialId: FfiConverterUInt64.read(from),
-        contractId: FfiConverterArrayUInt8.read(from),
+        txid: FfiConverterString.read(from),
+        vout: FfiConverterUInt32.read(from),
+        scriptSig: FfiConverterArrayUInt8.read(from),
+        maxWitnessLength: FfiConverterUInt32.read(from),
+        serialId: FfiConverterUInt64.read(from),
       };
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterTypeTransaction.write(value.fundTx, into);
-      FfiConverterUInt32.write(value.fundVout, into);
-      FfiConverterArrayUInt8.write(value.localFundPubkey, into);
-      FfiConverterArrayUInt8.write(value.remoteFundPubkey, into);
-      FfiConverterUInt64.write(value.fundAmount, into);
-      FfiConverterUInt32.write(value.maxWitnessLen, into);
-      FfiConverterUInt64.write(value.inputSerialId, into);
-      FfiConverterArrayUInt8.write(value.contractId, into);
+      FfiConverterString.write(value.txid, into);
+      FfiConverterUInt32.write(value.vout, into);
+      FfiConverterArrayUInt8.write(value.scriptSig, into);
+      FfiConverterUInt32.write(value.maxWitnessLength, into);
+      FfiConverterUInt64.write(value.serialId, into);
     }
     allocationSize(value: TypeName): number {
       return (
-        FfiConverterTypeTransaction.allocationSize(value.fundTx) +
-        FfiConverterUInt32.allocationSize(value.fundVout) +
-        FfiConverterArrayUInt8.allocationSize(value.localFundPubkey) +
-        FfiConverterArrayUInt8.allocationSize(value.remoteFundPubkey) +
-        FfiConverterUInt64.allocationSize(value.fundAmount) +
-        FfiConverterUInt32.allocationSize(value.maxWitnessLen) +
-        FfiConverterUInt64.allocationSize(value.inputSerialId) +
-        FfiConverterArrayUInt8.allocationSize(value.contractId)
+        FfiConverterString.allocationSize(value.txid) +
+        FfiConverterUInt32.allocationSize(value.vout) +
+        FfiConverterArrayUInt8.allocationSize(value.scriptSig) +
+        FfiConverterUInt32.allocationSize(value.maxWitnessLength) +
+        FfiConverterUInt64.allocationSize(value.serialId)
       );
     }
   }
   return new FFIConverter();
 })();
 
-export type DlcTransactions = {
-  fund: Transaction;
-  cets: Array<Transaction>;
-  refund: Transaction;
-  fundingScriptPubkey: Array</*u8*/ number>;
+export type TxOutput = {
+  value: /*u64*/ bigint;
+  scriptPubkey: Array</*u8*/ number>;
 };
 
 /**
- * Generated factory for {@link DlcTransactions} record objects.
+ * Generated factory for {@link TxOutput} record objects.
  */
-export const DlcTransactions = (() => {
+export const TxOutput = (() => {
   const defaults = () => ({});
   const create = (() => {
-    return uniffiCreateRecord<DlcTransactions, ReturnType<typeof defaults>>(
-      defaults
-    );
+    return uniffiCreateRecord<TxOutput, ReturnType<typeof defaults>>(defaults);
   })();
   return Object.freeze({
     /**
-     * Create a frozen instance of {@link DlcTransactions}, with defaults specified
+     * Create a frozen instance of {@link TxOutput}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     create,
 
     /**
-     * Create a frozen instance of {@link DlcTransactions}, with defaults specified
+     * Create a frozen instance of {@link TxOutput}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     new: create,
@@ -1260,63 +4811,57 @@ export const DlcTransactions = (() => {
     /**
      * Defaults specified in the {@link ddk_ffi} crate.
      */
-    defaults: () => Object.freeze(defaults()) as Partial<DlcTransactions>,
+    defaults: () => Object.freeze(defaults()) as Partial<TxOutput>,
   });
 })();
 
-const FfiConverterTypeDlcTransactions = (() => {
-  type TypeName = DlcTransactions;
+const FfiConverterTypeTxOutput = (() => {
+  type TypeName = TxOutput;
   class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
       return {
-        fund: FfiConverterTypeTransaction.read(from),
-        cets: FfiConverterArrayTypeTransaction.read(from),
-        refund: FfiConverterTypeTransaction.read(from),
-        fundingScriptPubkey: FfiConverterArrayUInt8.read(from),
+        value: FfiConverterUInt64.read(from),
+        scriptPubkey: FfiConverterArrayUInt8.read(from),
       };
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterTypeTransaction.write(value.fund, into);
-      FfiConverterArrayTypeTransaction.write(value.cets, into);
-      FfiConverterTypeTransaction.write(value.refund, into);
-      FfiConverterArrayUInt8.write(value.fundingScriptPubkey, into);
+      FfiConverterUInt64.write(value.value, into);
+      FfiConverterArrayUInt8.write(value.scriptPubkey, into);
     }
     allocationSize(value: TypeName): number {
       return (
-        FfiConverterTypeTransaction.allocationSize(value.fund) +
-        FfiConverterArrayTypeTransaction.allocationSize(value.cets) +
-        FfiConverterTypeTransaction.allocationSize(value.refund) +
-        FfiConverterArrayUInt8.allocationSize(value.fundingScriptPubkey)
+        FfiConverterUInt64.allocationSize(value.value) +
+        FfiConverterArrayUInt8.allocationSize(value.scriptPubkey)
       );
     }
   }
   return new FFIConverter();
 })();
 
-export type OracleInfo = {
-  publicKey: Array</*u8*/ number>;
-  nonces: Array<Array</*u8*/ number>>;
+export type WitnessEntry = {
+  inputIndex: /*u32*/ number;
+  items: Array<Array</*u8*/ number>>;
 };
 
 /**
- * Generated factory for {@link OracleInfo} record objects.
+ * Generated factory for {@link WitnessEntry} record objects.
  */
-export const OracleInfo = (() => {
+export const WitnessEntry = (() => {
   const defaults = () => ({});
   const create = (() => {
-    return uniffiCreateRecord<OracleInfo, ReturnType<typeof defaults>>(
+    return uniffiCreateRecord<WitnessEntry, ReturnType<typeof defaults>>(
       defaults
     );
   })();
   return Object.freeze({
     /**
-     * Create a frozen instance of {@link OracleInfo}, with defaults specified
+     * Create a frozen instance of {@link WitnessEntry}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     create,
 
     /**
-     * Create a frozen instance of {@link OracleInfo}, with defaults specified
+     * Create a frozen instance of {@link WitnessEntry}, with defaults specified
      * in Rust, in the {@link ddk_ffi} crate.
      */
     new: create,
@@ -1324,1195 +4869,1371 @@ export const OracleInfo = (() => {
     /**
      * Defaults specified in the {@link ddk_ffi} crate.
      */
-    defaults: () => Object.freeze(defaults()) as Partial<OracleInfo>,
+    defaults: () => Object.freeze(defaults()) as Partial<WitnessEntry>,
   });
 })();
 
-const FfiConverterTypeOracleInfo = (() => {
-  type TypeName = OracleInfo;
+const FfiConverterTypeWitnessEntry = (() => {
+  type TypeName = WitnessEntry;
   class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
       return {
-        publicKey: FfiConverterArrayUInt8.read(from),
-        nonces: FfiConverterArrayArrayUInt8.read(from),
+        inputIndex: FfiConverterUInt32.read(from),
+        items: FfiConverterArrayArrayUInt8.read(from),
       };
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterArrayUInt8.write(value.publicKey, into);
-      FfiConverterArrayArrayUInt8.write(value.nonces, into);
+      FfiConverterUInt32.write(value.inputIndex, into);
+      FfiConverterArrayArrayUInt8.write(value.items, into);
     }
     allocationSize(value: TypeName): number {
       return (
-        FfiConverterArrayUInt8.allocationSize(value.publicKey) +
-        FfiConverterArrayArrayUInt8.allocationSize(value.nonces)
+        FfiConverterUInt32.allocationSize(value.inputIndex) +
+        FfiConverterArrayArrayUInt8.allocationSize(value.items)
       );
     }
   }
   return new FFIConverter();
 })();
 
-export type PartyParams = {
-  fundPubkey: Array</*u8*/ number>;
-  changeScriptPubkey: Array</*u8*/ number>;
-  changeSerialId: /*u64*/ bigint;
-  payoutScriptPubkey: Array</*u8*/ number>;
-  payoutSerialId: /*u64*/ bigint;
-  inputs: Array<TxInputInfo>;
-  inputAmount: /*u64*/ bigint;
-  collateral: /*u64*/ bigint;
-  dlcInputs: Array<DlcInputInfo>;
+const stringConverter = {
+  stringToBytes: (s: string) =>
+    uniffiCaller.rustCall((status) =>
+      nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer(
+        s,
+        status
+      )
+    ),
+  bytesToString: (ab: UniffiByteArray) =>
+    uniffiCaller.rustCall((status) =>
+      nativeModule().ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
+        ab,
+        status
+      )
+    ),
+  stringByteLength: (s: string) =>
+    uniffiCaller.rustCall((status) =>
+      nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length(
+        s,
+        status
+      )
+    ),
 };
+const FfiConverterString = uniffiCreateFfiConverterString(stringConverter);
 
-/**
- * Generated factory for {@link PartyParams} record objects.
- */
-export const PartyParams = (() => {
-  const defaults = () => ({});
-  const create = (() => {
-    return uniffiCreateRecord<PartyParams, ReturnType<typeof defaults>>(
-      defaults
-    );
-  })();
-  return Object.freeze({
+// Flat error type: DlcError
+export enum DlcError_Tags {
+  InvalidSignature = 'InvalidSignature',
+  InvalidPublicKey = 'InvalidPublicKey',
+  InvalidTransaction = 'InvalidTransaction',
+  InsufficientFunds = 'InsufficientFunds',
+  InvalidArgument = 'InvalidArgument',
+  SerializationError = 'SerializationError',
+  Secp256k1Error = 'Secp256k1Error',
+  MiniscriptError = 'MiniscriptError',
+  InvalidNetwork = 'InvalidNetwork',
+  KeyError = 'KeyError',
+}
+export const DlcError = (() => {
+  class InvalidSignature extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 1;
+
+    public readonly tag = DlcError_Tags.InvalidSignature;
+
+    constructor(message: string) {
+      super('DlcError', 'InvalidSignature', message);
+    }
+
+    static instanceOf(e: any): e is InvalidSignature {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 1;
+    }
+  }
+  class InvalidPublicKey extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 2;
+
+    public readonly tag = DlcError_Tags.InvalidPublicKey;
+
+    constructor(message: string) {
+      super('DlcError', 'InvalidPublicKey', message);
+    }
+
+    static instanceOf(e: any): e is InvalidPublicKey {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 2;
+    }
+  }
+  class InvalidTransaction extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 3;
+
+    public readonly tag = DlcError_Tags.InvalidTransaction;
+
+    constructor(message: string) {
+      super('DlcError', 'InvalidTransaction', message);
+    }
+
+    static instanceOf(e: any): e is InvalidTransaction {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 3;
+    }
+  }
+  class InsufficientFunds extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 4;
+
+    public readonly tag = DlcError_Tags.InsufficientFunds;
+
+    constructor(message: string) {
+      super('DlcError', 'InsufficientFunds', message);
+    }
+
+    static instanceOf(e: any): e is InsufficientFunds {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 4;
+    }
+  }
+  class InvalidArgument extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 5;
+
+    public readonly tag = DlcError_Tags.InvalidArgument;
+
+    constructor(message: string) {
+      super('DlcError', 'InvalidArgument', message);
+    }
+
+    static instanceOf(e: any): e is InvalidArgument {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 5;
+    }
+  }
+  class SerializationError extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 6;
+
+    public readonly tag = DlcError_Tags.SerializationError;
+
+    constructor(message: string) {
+      super('DlcError', 'SerializationError', message);
+    }
+
+    static instanceOf(e: any): e is SerializationError {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 6;
+    }
+  }
+  class Secp256k1Error extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 7;
+
+    public readonly tag = DlcError_Tags.Secp256k1Error;
+
+    constructor(message: string) {
+      super('DlcError', 'Secp256k1Error', message);
+    }
+
+    static instanceOf(e: any): e is Secp256k1Error {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 7;
+    }
+  }
+  class MiniscriptError extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
     /**
-     * Create a frozen instance of {@link PartyParams}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    create,
+    readonly [variantOrdinalSymbol] = 8;
+
+    public readonly tag = DlcError_Tags.MiniscriptError;
+
+    constructor(message: string) {
+      super('DlcError', 'MiniscriptError', message);
+    }
 
+    static instanceOf(e: any): e is MiniscriptError {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 8;
+    }
+  }
+  class InvalidNetwork extends UniffiError {
     /**
-     * Create a frozen instance of {@link PartyParams}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    new: create,
-
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
     /**
-     * Defaults specified in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    defaults: () => Object.freeze(defaults()) as Partial<PartyParams>,
-  });
-})();
+    readonly [variantOrdinalSymbol] = 9;
 
-const FfiConverterTypePartyParams = (() => {
-  type TypeName = PartyParams;
-  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
-    read(from: RustBuffer): TypeName {
-      return {
-        fundPubkey: FfiConverterArrayUInt8.read(from),
-        changeScriptPubkey: FfiConverterArrayUInt8.read(from),
-        changeSerialId: FfiConverterUInt64.read(from),
-        payoutScriptPubkey: FfiConverterArrayUInt8.read(from),
-        payoutSerialId: FfiConverterUInt64.read(from),
-        inputs: FfiConverterArrayTypeTxInputInfo.read(from),
-        inputAmount: FfiConverterUInt64.read(from),
-        collateral: FfiConverterUInt64.read(from),
-        dlcInputs: FfiConverterArrayTypeDlcInputInfo.read(from),
-      };
-    }
-    write(value: TypeName, into: RustBuffer): void {
-      FfiConverterArrayUInt8.write(value.fundPubkey, into);
-      FfiConverterArrayUInt8.write(value.changeScriptPubkey, into);
-      FfiConverterUInt64.write(value.changeSerialId, into);
-      FfiConverterArrayUInt8.write(value.payoutScriptPubkey, into);
-      FfiConverterUInt64.write(value.payoutSerialId, into);
-      FfiConverterArrayTypeTxInputInfo.write(value.inputs, into);
-      FfiConverterUInt64.write(value.inputAmount, into);
-      FfiConverterUInt64.write(value.collateral, into);
-      FfiConverterArrayTypeDlcInputInfo.write(value.dlcInputs, into);
+    public readonly tag = DlcError_Tags.InvalidNetwork;
+
+    constructor(message: string) {
+      super('DlcError', 'InvalidNetwork', message);
     }
-    allocationSize(value: TypeName): number {
-      return (
-        FfiConverterArrayUInt8.allocationSize(value.fundPubkey) +
-        FfiConverterArrayUInt8.allocationSize(value.changeScriptPubkey) +
-        FfiConverterUInt64.allocationSize(value.changeSerialId) +
-        FfiConverterArrayUInt8.allocationSize(value.payoutScriptPubkey) +
-        FfiConverterUInt64.allocationSize(value.payoutSerialId) +
-        FfiConverterArrayTypeTxInputInfo.allocationSize(value.inputs) +
-        FfiConverterUInt64.allocationSize(value.inputAmount) +
-        FfiConverterUInt64.allocationSize(value.collateral) +
-        FfiConverterArrayTypeDlcInputInfo.allocationSize(value.dlcInputs)
-      );
+
+    static instanceOf(e: any): e is InvalidNetwork {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 9;
     }
   }
-  return new FFIConverter();
-})();
-
-export type Payout = {
-  offer: /*u64*/ bigint;
-  accept: /*u64*/ bigint;
-};
-
-/**
- * Generated factory for {@link Payout} record objects.
- */
-export const Payout = (() => {
-  const defaults = () => ({});
-  const create = (() => {
-    return uniffiCreateRecord<Payout, ReturnType<typeof defaults>>(defaults);
-  })();
-  return Object.freeze({
+  class KeyError extends UniffiError {
     /**
-     * Create a frozen instance of {@link Payout}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    create,
-
+    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
     /**
-     * Create a frozen instance of {@link Payout}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    new: create,
+    readonly [variantOrdinalSymbol] = 10;
 
-    /**
-     * Defaults specified in the {@link ddk_ffi} crate.
-     */
-    defaults: () => Object.freeze(defaults()) as Partial<Payout>,
-  });
+    public readonly tag = DlcError_Tags.KeyError;
+
+    constructor(message: string) {
+      super('DlcError', 'KeyError', message);
+    }
+
+    static instanceOf(e: any): e is KeyError {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 10;
+    }
+  }
+
+  // Utility function which does not rely on instanceof.
+  function instanceOf(e: any): e is DlcError {
+    return (e as any)[uniffiTypeNameSymbol] === 'DlcError';
+  }
+  return {
+    InvalidSignature,
+    InvalidPublicKey,
+    InvalidTransaction,
+    InsufficientFunds,
+    InvalidArgument,
+    SerializationError,
+    Secp256k1Error,
+    MiniscriptError,
+    InvalidNetwork,
+    KeyError,
+    instanceOf,
+  };
 })();
 
-const FfiConverterTypePayout = (() => {
-  type TypeName = Payout;
-  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+// Union type for DlcError error type.
+
+export type DlcError = InstanceType<
+  (typeof DlcError)[keyof Omit<typeof DlcError, 'instanceOf'>]
+>;
+
+const FfiConverterTypeDLCError = (() => {
+  const intConverter = FfiConverterInt32;
+  type TypeName = DlcError;
+  class FfiConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
-      return {
-        offer: FfiConverterUInt64.read(from),
-        accept: FfiConverterUInt64.read(from),
-      };
+      switch (intConverter.read(from)) {
+        case 1:
+          return new DlcError.InvalidSignature(FfiConverterString.read(from));
+
+        case 2:
+          return new DlcError.InvalidPublicKey(FfiConverterString.read(from));
+
+        case 3:
+          return new DlcError.InvalidTransaction(FfiConverterString.read(from));
+
+        case 4:
+          return new DlcError.InsufficientFunds(FfiConverterString.read(from));
+
+        case 5:
+          return new DlcError.InvalidArgument(FfiConverterString.read(from));
+
+        case 6:
+          return new DlcError.SerializationError(FfiConverterString.read(from));
+
+        case 7:
+          return new DlcError.Secp256k1Error(FfiConverterString.read(from));
+
+        case 8:
+          return new DlcError.MiniscriptError(FfiConverterString.read(from));
+
+        case 9:
+          return new DlcError.InvalidNetwork(FfiConverterString.read(from));
+
+        case 10:
+          return new DlcError.KeyError(FfiConverterString.read(from));
+
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterUInt64.write(value.offer, into);
-      FfiConverterUInt64.write(value.accept, into);
+      const obj = value as any;
+      const index = obj[variantOrdinalSymbol] as number;
+      intConverter.write(index, into);
     }
     allocationSize(value: TypeName): number {
-      return (
-        FfiConverterUInt64.allocationSize(value.offer) +
-        FfiConverterUInt64.allocationSize(value.accept)
-      );
+      return intConverter.allocationSize(0);
     }
   }
-  return new FFIConverter();
+  return new FfiConverter();
 })();
 
-export type Transaction = {
-  version: /*i32*/ number;
-  lockTime: /*u32*/ number;
-  inputs: Array<TxInput>;
-  outputs: Array<TxOutput>;
-  rawBytes: Array</*u8*/ number>;
-};
+// Flat error type: ExtendedKey
+export enum ExtendedKey_Tags {
+  InvalidMnemonic = 'InvalidMnemonic',
+  InvalidXpriv = 'InvalidXpriv',
+  InvalidXpub = 'InvalidXpub',
+  InvalidDerivationPath = 'InvalidDerivationPath',
+}
+export const ExtendedKey = (() => {
+  class InvalidMnemonic extends UniffiError {
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
+    /**
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [variantOrdinalSymbol] = 1;
 
-/**
- * Generated factory for {@link Transaction} record objects.
- */
-export const Transaction = (() => {
-  const defaults = () => ({});
-  const create = (() => {
-    return uniffiCreateRecord<Transaction, ReturnType<typeof defaults>>(
-      defaults
-    );
-  })();
-  return Object.freeze({
+    public readonly tag = ExtendedKey_Tags.InvalidMnemonic;
+
+    constructor(message: string) {
+      super('ExtendedKey', 'InvalidMnemonic', message);
+    }
+
+    static instanceOf(e: any): e is InvalidMnemonic {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 1;
+    }
+  }
+  class InvalidXpriv extends UniffiError {
     /**
-     * Create a frozen instance of {@link Transaction}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
+     */
+    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
+    /**
+     * @private
+     * This field is private and should not be used.
      */
-    create,
+    readonly [variantOrdinalSymbol] = 2;
+
+    public readonly tag = ExtendedKey_Tags.InvalidXpriv;
+
+    constructor(message: string) {
+      super('ExtendedKey', 'InvalidXpriv', message);
+    }
 
+    static instanceOf(e: any): e is InvalidXpriv {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 2;
+    }
+  }
+  class InvalidXpub extends UniffiError {
     /**
-     * Create a frozen instance of {@link Transaction}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    new: create,
-
+    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
     /**
-     * Defaults specified in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    defaults: () => Object.freeze(defaults()) as Partial<Transaction>,
-  });
-})();
+    readonly [variantOrdinalSymbol] = 3;
 
-const FfiConverterTypeTransaction = (() => {
-  type TypeName = Transaction;
-  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
-    read(from: RustBuffer): TypeName {
-      return {
-        version: FfiConverterInt32.read(from),
-        lockTime: FfiConverterUInt32.read(from),
-        inputs: FfiConverterArrayTypeTxInput.read(from),
-        outputs: FfiConverterArrayTypeTxOutput.read(from),
-        rawBytes: FfiConverterArrayUInt8.read(from),
-      };
-    }
-    write(value: TypeName, into: RustBuffer): void {
-      FfiConverterInt32.write(value.version, into);
-      FfiConverterUInt32.write(value.lockTime, into);
-      FfiConverterArrayTypeTxInput.write(value.inputs, into);
-      FfiConverterArrayTypeTxOutput.write(value.outputs, into);
-      FfiConverterArrayUInt8.write(value.rawBytes, into);
+    public readonly tag = ExtendedKey_Tags.InvalidXpub;
+
+    constructor(message: string) {
+      super('ExtendedKey', 'InvalidXpub', message);
     }
-    allocationSize(value: TypeName): number {
-      return (
-        FfiConverterInt32.allocationSize(value.version) +
-        FfiConverterUInt32.allocationSize(value.lockTime) +
-        FfiConverterArrayTypeTxInput.allocationSize(value.inputs) +
-        FfiConverterArrayTypeTxOutput.allocationSize(value.outputs) +
-        FfiConverterArrayUInt8.allocationSize(value.rawBytes)
-      );
+
+    static instanceOf(e: any): e is InvalidXpub {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 3;
     }
   }
-  return new FFIConverter();
-})();
-
-export type TxInput = {
-  txid: string;
-  vout: /*u32*/ number;
-  scriptSig: Array</*u8*/ number>;
-  sequence: /*u32*/ number;
-  witness: Array<Array</*u8*/ number>>;
-};
-
-/**
- * Generated factory for {@link TxInput} record objects.
- */
-export const TxInput = (() => {
-  const defaults = () => ({});
-  const create = (() => {
-    return uniffiCreateRecord<TxInput, ReturnType<typeof defaults>>(defaults);
-  })();
-  return Object.freeze({
+  class InvalidDerivationPath extends UniffiError {
     /**
-     * Create a frozen instance of {@link TxInput}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    create,
-
+    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
     /**
-     * Create a frozen instance of {@link TxInput}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
+     * @private
+     * This field is private and should not be used.
      */
-    new: create,
+    readonly [variantOrdinalSymbol] = 4;
 
-    /**
-     * Defaults specified in the {@link ddk_ffi} crate.
-     */
-    defaults: () => Object.freeze(defaults()) as Partial<TxInput>,
-  });
+    public readonly tag = ExtendedKey_Tags.InvalidDerivationPath;
+
+    constructor(message: string) {
+      super('ExtendedKey', 'InvalidDerivationPath', message);
+    }
+
+    static instanceOf(e: any): e is InvalidDerivationPath {
+      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 4;
+    }
+  }
+
+  // Utility function which does not rely on instanceof.
+  function instanceOf(e: any): e is ExtendedKey {
+    return (e as any)[uniffiTypeNameSymbol] === 'ExtendedKey';
+  }
+  return {
+    InvalidMnemonic,
+    InvalidXpriv,
+    InvalidXpub,
+    InvalidDerivationPath,
+    instanceOf,
+  };
 })();
 
-const FfiConverterTypeTxInput = (() => {
-  type TypeName = TxInput;
-  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+// Union type for ExtendedKey error type.
+
+export type ExtendedKey = InstanceType<
+  (typeof ExtendedKey)[keyof Omit<typeof ExtendedKey, 'instanceOf'>]
+>;
+
+const FfiConverterTypeExtendedKey = (() => {
+  const intConverter = FfiConverterInt32;
+  type TypeName = ExtendedKey;
+  class FfiConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
-      return {
-        txid: FfiConverterString.read(from),
-        vout: FfiConverterUInt32.read(from),
-        scriptSig: FfiConverterArrayUInt8.read(from),
-        sequence: FfiConverterUInt32.read(from),
-        witness: FfiConverterArrayArrayUInt8.read(from),
-      };
+      switch (intConverter.read(from)) {
+        case 1:
+          return new ExtendedKey.InvalidMnemonic(FfiConverterString.read(from));
+
+        case 2:
+          return new ExtendedKey.InvalidXpriv(FfiConverterString.read(from));
+
+        case 3:
+          return new ExtendedKey.InvalidXpub(FfiConverterString.read(from));
+
+        case 4:
+          return new ExtendedKey.InvalidDerivationPath(
+            FfiConverterString.read(from)
+          );
+
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
     }
     write(value: TypeName, into: RustBuffer): void {
-      FfiConverterString.write(value.txid, into);
-      FfiConverterUInt32.write(value.vout, into);
-      FfiConverterArrayUInt8.write(value.scriptSig, into);
-      FfiConverterUInt32.write(value.sequence, into);
-      FfiConverterArrayArrayUInt8.write(value.witness, into);
+      const obj = value as any;
+      const index = obj[variantOrdinalSymbol] as number;
+      intConverter.write(index, into);
     }
     allocationSize(value: TypeName): number {
-      return (
-        FfiConverterString.allocationSize(value.txid) +
-        FfiConverterUInt32.allocationSize(value.vout) +
-        FfiConverterArrayUInt8.allocationSize(value.scriptSig) +
-        FfiConverterUInt32.allocationSize(value.sequence) +
-        FfiConverterArrayArrayUInt8.allocationSize(value.witness)
-      );
+      return intConverter.allocationSize(0);
     }
   }
-  return new FFIConverter();
+  return new FfiConverter();
 })();
 
-export type TxInputInfo = {
-  txid: string;
-  vout: /*u32*/ number;
-  scriptSig: Array</*u8*/ number>;
-  maxWitnessLength: /*u32*/ number;
-  serialId: /*u64*/ bigint;
-};
+// FfiConverter for TxOutput | undefined
+const FfiConverterOptionalTypeTxOutput = new FfiConverterOptional(
+  FfiConverterTypeTxOutput
+);
+
+// FfiConverter for string | undefined
+const FfiConverterOptionalString = new FfiConverterOptional(FfiConverterString);
+
+// FfiConverter for Array<AdaptorSignature>
+const FfiConverterArrayTypeAdaptorSignature = new FfiConverterArray(
+  FfiConverterTypeAdaptorSignature
+);
+
+// FfiConverter for Array<DlcInputInfo>
+const FfiConverterArrayTypeDlcInputInfo = new FfiConverterArray(
+  FfiConverterTypeDlcInputInfo
+);
+
+// FfiConverter for Array<DlcTransactionRequest>
+const FfiConverterArrayTypeDlcTransactionRequest = new FfiConverterArray(
+  FfiConverterTypeDlcTransactionRequest
+);
+
+// FfiConverter for Array<DlcTransactions>
+const FfiConverterArrayTypeDlcTransactions = new FfiConverterArray(
+  FfiConverterTypeDlcTransactions
+);
+
+// FfiConverter for Array<OracleInfo>
+const FfiConverterArrayTypeOracleInfo = new FfiConverterArray(
+  FfiConverterTypeOracleInfo
+);
+
+// FfiConverter for Array<Payout>
+const FfiConverterArrayTypePayout = new FfiConverterArray(
+  FfiConverterTypePayout
+);
+
+// FfiConverter for Array<PayoutRatio>
+const FfiConverterArrayTypePayoutRatio = new FfiConverterArray(
+  FfiConverterTypePayoutRatio
+);
+
+// FfiConverter for Array<SigningStep>
+const FfiConverterArrayTypeSigningStep = new FfiConverterArray(
+  FfiConverterTypeSigningStep
+);
+
+// FfiConverter for Array<Transaction>
+const FfiConverterArrayTypeTransaction = new FfiConverterArray(
+  FfiConverterTypeTransaction
+);
+
+// FfiConverter for Array<TxInput>
+const FfiConverterArrayTypeTxInput = new FfiConverterArray(
+  FfiConverterTypeTxInput
+);
+
+// FfiConverter for Array<TxInputInfo>
+const FfiConverterArrayTypeTxInputInfo = new FfiConverterArray(
+  FfiConverterTypeTxInputInfo
+);
+
+// FfiConverter for Array<TxOutput>
+const FfiConverterArrayTypeTxOutput = new FfiConverterArray(
+  FfiConverterTypeTxOutput
+);
+
+// FfiConverter for Array<WitnessEntry>
+const FfiConverterArrayTypeWitnessEntry = new FfiConverterArray(
+  FfiConverterTypeWitnessEntry
+);
+
+// FfiConverter for Array</*u8*/number>
+const FfiConverterArrayUInt8 = new FfiConverterArray(FfiConverterUInt8);
+
+// FfiConverter for Array</*u32*/number>
+const FfiConverterArrayUInt32 = new FfiConverterArray(FfiConverterUInt32);
+
+// FfiConverter for Array</*u64*/bigint>
+const FfiConverterArrayUInt64 = new FfiConverterArray(FfiConverterUInt64);
+
+// FfiConverter for Array</*i64*/bigint>
+const FfiConverterArrayInt64 = new FfiConverterArray(FfiConverterInt64);
+
+// FfiConverter for Array<boolean>
+const FfiConverterArrayBool = new FfiConverterArray(FfiConverterBool);
+
+// FfiConverter for Array<string>
+const FfiConverterArrayString = new FfiConverterArray(FfiConverterString);
+
+// FfiConverter for Array</*u8*/number> | undefined
+const FfiConverterOptionalArrayUInt8 = new FfiConverterOptional(
+  FfiConverterArrayUInt8
+);
+
+// FfiConverter for Array<Array</*u8*/number>>
+const FfiConverterArrayArrayUInt8 = new FfiConverterArray(
+  FfiConverterArrayUInt8
+);
+
+// FfiConverter for Array<Array<Array</*u8*/number>>>
+const FfiConverterArrayArrayArrayUInt8 = new FfiConverterArray(
+  FfiConverterArrayArrayUInt8
+);
+
+// FfiConverter for Array<Array<Array<Array</*u8*/number>>>>
+const FfiConverterArrayArrayArrayArrayUInt8 = new FfiConverterArray(
+  FfiConverterArrayArrayArrayUInt8
+);
 
 /**
- * Generated factory for {@link TxInputInfo} record objects.
+ * This should be called before anything else.
+ *
+ * It is likely that this is being done for you by the library's `index.ts`.
+ *
+ * It checks versions of uniffi between when the Rust scaffolding was generated
+ * and when the bindings were generated.
+ *
+ * It also initializes the machinery to enable Rust to talk back to Javascript.
  */
-export const TxInputInfo = (() => {
-  const defaults = () => ({});
-  const create = (() => {
-    return uniffiCreateRecord<TxInputInfo, ReturnType<typeof defaults>>(
-      defaults
+function uniffiEnsureInitialized() {
+  // Get the bindings contract version from our ComponentInterface
+  const bindingsContractVersion = 29;
+  // Get the scaffolding contract version by calling the into the dylib
+  const scaffoldingContractVersion =
+    nativeModule().ubrn_ffi_ddk_ffi_uniffi_contract_version();
+  if (bindingsContractVersion !== scaffoldingContractVersion) {
+    throw new UniffiInternalError.ContractVersionMismatch(
+      scaffoldingContractVersion,
+      bindingsContractVersion
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_adaptor_signature_parse() !==
+    48355
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_adaptor_signature_parse'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_adaptor_signature_serialize() !==
+    2165
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_adaptor_signature_serialize'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_add_signature_to_transaction() !==
+    1337
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_add_signature_to_transaction'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_add_witnesses_to_transaction() !==
+    59841
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_add_witnesses_to_transaction'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_address_to_script_pubkey() !==
+    32166
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_address_to_script_pubkey'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_aggregate_oracle_s_values() !==
+    53335
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_aggregate_oracle_s_values'
+    );
+  }
+  if (nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_cet_fees() !== 36315) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_cet_fees'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_cet_nonce_dependencies() !==
+    18730
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_cet_nonce_dependencies'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_cet_to_outcome() !== 28737
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_cet_to_outcome'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_change_across_fee_rates() !==
+    7480
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_change_across_fee_rates'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_check_fee_sanity() !==
+    51465
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_check_fee_sanity'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_complete_dlc_flow() !==
+    38054
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_complete_dlc_flow'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_compute_cet_fee() !== 30990
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_compute_cet_fee'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_compute_fund_fee() !==
+    44076
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_compute_fund_fee'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_contract_fingerprint() !==
+    49010
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_contract_fingerprint'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_contract_pubkeys() !==
+    43389
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_contract_pubkeys'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_convert_mnemonic_to_seed() !==
+    65049
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_convert_mnemonic_to_seed'
+    );
+  }
+  if (nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet() !== 23081) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_points_from_oracle_info() !==
+    6552
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_points_from_oracle_info'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_from_oracle_info() !==
+    65174
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_from_oracle_info'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_multi_oracle() !==
+    8151
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_multi_oracle'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_oracle_info() !==
+    15699
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_oracle_info'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_points() !==
+    33705
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_points'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_checked() !==
+    50174
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet_checked'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_with_info() !==
+    45589
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cet_with_info'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cets() !== 50529
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cets'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cets_checked() !==
+    52929
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_cets_checked'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_input_signature() !==
+    5030
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_dlc_input_signature'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions() !==
+    51134
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_dlc_transactions'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions_batch() !==
+    50080
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_dlc_transactions_batch'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions_unchecked() !==
+    11119
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_dlc_transactions_unchecked'
     );
-  })();
-  return Object.freeze({
-    /**
-     * Create a frozen instance of {@link TxInputInfo}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
-     */
-    create,
-
-    /**
-     * Create a frozen instance of {@link TxInputInfo}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
-     */
-    new: create,
-
-    /**
-     * Defaults specified in the {@link ddk_ffi} crate.
-     */
-    defaults: () => Object.freeze(defaults()) as Partial<TxInputInfo>,
-  });
-})();
-
-const FfiConverterTypeTxInputInfo = (() => {
-  type TypeName = TxInputInfo;
-  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
-    read(from: RustBuffer): TypeName {
-      return {
-        txid: FfiConverterString.read(from),
-        vout: FfiConverterUInt32.read(from),
-        scriptSig: FfiConverterArrayUInt8.read(from),
-        maxWitnessLength: FfiConverterUInt32.read(from),
-        serialId: FfiConverterUInt64.read(from),
-      };
-    }
-    write(value: TypeName, into: RustBuffer): void {
-      FfiConverterString.write(value.txid, into);
-      FfiConverterUInt32.write(value.vout, into);
-      FfiConverterArrayUInt8.write(value.scriptSig, into);
-      FfiConverterUInt32.write(value.maxWitnessLength, into);
-      FfiConverterUInt64.write(value.serialId, into);
-    }
-    allocationSize(value: TypeName): number {
-      return (
-        FfiConverterString.allocationSize(value.txid) +
-        FfiConverterUInt32.allocationSize(value.vout) +
-        FfiConverterArrayUInt8.allocationSize(value.scriptSig) +
-        FfiConverterUInt32.allocationSize(value.maxWitnessLength) +
-        FfiConverterUInt64.allocationSize(value.serialId)
-      );
-    }
   }
-  return new FFIConverter();
-})();
-
-export type TxOutput = {
-  value: /*u64*/ bigint;
-  scriptPubkey: Array</*u8*/ number>;
-};
-
-/**
- * Generated factory for {@link TxOutput} record objects.
- */
-export const TxOutput = (() => {
-  const defaults = () => ({});
-  const create = (() => {
-    return uniffiCreateRecord<TxOutput, ReturnType<typeof defaults>>(defaults);
-  })();
-  return Object.freeze({
-    /**
-     * Create a frozen instance of {@link TxOutput}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
-     */
-    create,
-
-    /**
-     * Create a frozen instance of {@link TxOutput}, with defaults specified
-     * in Rust, in the {@link ddk_ffi} crate.
-     */
-    new: create,
-
-    /**
-     * Defaults specified in the {@link ddk_ffi} crate.
-     */
-    defaults: () => Object.freeze(defaults()) as Partial<TxOutput>,
-  });
-})();
-
-const FfiConverterTypeTxOutput = (() => {
-  type TypeName = TxOutput;
-  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
-    read(from: RustBuffer): TypeName {
-      return {
-        value: FfiConverterUInt64.read(from),
-        scriptPubkey: FfiConverterArrayUInt8.read(from),
-      };
-    }
-    write(value: TypeName, into: RustBuffer): void {
-      FfiConverterUInt64.write(value.value, into);
-      FfiConverterArrayUInt8.write(value.scriptPubkey, into);
-    }
-    allocationSize(value: TypeName): number {
-      return (
-        FfiConverterUInt64.allocationSize(value.value) +
-        FfiConverterArrayUInt8.allocationSize(value.scriptPubkey)
-      );
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions_with_fee_output() !==
+    4960
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_dlc_transactions_with_fee_output'
+    );
   }
-  return new FFIConverter();
-})();
-
-const stringConverter = {
-  stringToBytes: (s: string) =>
-    uniffiCaller.rustCall((status) =>
-      nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer(
-        s,
-        status
-      )
-    ),
-  bytesToString: (ab: UniffiByteArray) =>
-    uniffiCaller.rustCall((status) =>
-      nativeModule().ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
-        ab,
-        status
-      )
-    ),
-  stringByteLength: (s: string) =>
-    uniffiCaller.rustCall((status) =>
-      nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length(
-        s,
-        status
-      )
-    ),
-};
-const FfiConverterString = uniffiCreateFfiConverterString(stringConverter);
-
-// Flat error type: DlcError
-export enum DlcError_Tags {
-  InvalidSignature = 'InvalidSignature',
-  InvalidPublicKey = 'InvalidPublicKey',
-  InvalidTransaction = 'InvalidTransaction',
-  InsufficientFunds = 'InsufficientFunds',
-  InvalidArgument = 'InvalidArgument',
-  SerializationError = 'SerializationError',
-  Secp256k1Error = 'Secp256k1Error',
-  MiniscriptError = 'MiniscriptError',
-  InvalidNetwork = 'InvalidNetwork',
-  KeyError = 'KeyError',
-}
-export const DlcError = (() => {
-  class InvalidSignature extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 1;
-
-    public readonly tag = DlcError_Tags.InvalidSignature;
-
-    constructor(message: string) {
-      super('DlcError', 'InvalidSignature', message);
-    }
-
-    static instanceOf(e: any): e is InvalidSignature {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 1;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_enumeration_adaptor_sigs() !==
+    38991
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_enumeration_adaptor_sigs'
+    );
   }
-  class InvalidPublicKey extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 2;
-
-    public readonly tag = DlcError_Tags.InvalidPublicKey;
-
-    constructor(message: string) {
-      super('DlcError', 'InvalidPublicKey', message);
-    }
-
-    static instanceOf(e: any): e is InvalidPublicKey {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 2;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_extkey_from_parent_path() !==
+    26671
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_extkey_from_parent_path'
+    );
   }
-  class InvalidTransaction extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 3;
-
-    public readonly tag = DlcError_Tags.InvalidTransaction;
-
-    constructor(message: string) {
-      super('DlcError', 'InvalidTransaction', message);
-    }
-
-    static instanceOf(e: any): e is InvalidTransaction {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 3;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_extkey_from_seed() !==
+    12526
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_extkey_from_seed'
+    );
   }
-  class InsufficientFunds extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 4;
-
-    public readonly tag = DlcError_Tags.InsufficientFunds;
-
-    constructor(message: string) {
-      super('DlcError', 'InsufficientFunds', message);
-    }
-
-    static instanceOf(e: any): e is InsufficientFunds {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 4;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_fund_tx_locking_script() !==
+    2761
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_fund_tx_locking_script'
+    );
   }
-  class InvalidArgument extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 5;
-
-    public readonly tag = DlcError_Tags.InvalidArgument;
-
-    constructor(message: string) {
-      super('DlcError', 'InvalidArgument', message);
-    }
-
-    static instanceOf(e: any): e is InvalidArgument {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 5;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_refund_transaction() !==
+    57261
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_refund_transaction'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_spliced_dlc_transactions() !==
+    31130
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_spliced_dlc_transactions'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_xpriv_from_parent_path() !==
+    34808
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_create_xpriv_from_parent_path'
+    );
   }
-  class SerializationError extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 6;
-
-    public readonly tag = DlcError_Tags.SerializationError;
-
-    constructor(message: string) {
-      super('DlcError', 'SerializationError', message);
-    }
-
-    static instanceOf(e: any): e is SerializationError {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 6;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_decompose_schnorr_signature() !==
+    61033
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_decompose_schnorr_signature'
+    );
   }
-  class Secp256k1Error extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 7;
-
-    public readonly tag = DlcError_Tags.Secp256k1Error;
-
-    constructor(message: string) {
-      super('DlcError', 'Secp256k1Error', message);
-    }
-
-    static instanceOf(e: any): e is Secp256k1Error {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 7;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_decrypt_and_verify_cet_signature() !==
+    22718
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_decrypt_and_verify_cet_signature'
+    );
   }
-  class MiniscriptError extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 8;
-
-    public readonly tag = DlcError_Tags.MiniscriptError;
-
-    constructor(message: string) {
-      super('DlcError', 'MiniscriptError', message);
-    }
-
-    static instanceOf(e: any): e is MiniscriptError {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 8;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_decrypt_cet_adaptor_signature() !==
+    13878
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_decrypt_cet_adaptor_signature'
+    );
   }
-  class InvalidNetwork extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 9;
-
-    public readonly tag = DlcError_Tags.InvalidNetwork;
-
-    constructor(message: string) {
-      super('DlcError', 'InvalidNetwork', message);
-    }
-
-    static instanceOf(e: any): e is InvalidNetwork {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 9;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_derive_privkey_from_seed() !==
+    64464
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_derive_privkey_from_seed'
+    );
   }
-  class KeyError extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'DlcError';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 10;
-
-    public readonly tag = DlcError_Tags.KeyError;
-
-    constructor(message: string) {
-      super('DlcError', 'KeyError', message);
-    }
-
-    static instanceOf(e: any): e is KeyError {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 10;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_derive_pubkey_from_seed() !==
+    12770
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_derive_pubkey_from_seed'
+    );
   }
-
-  // Utility function which does not rely on instanceof.
-  function instanceOf(e: any): e is DlcError {
-    return (e as any)[uniffiTypeNameSymbol] === 'DlcError';
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_deserialize_settlement_state() !==
+    26243
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_deserialize_settlement_state'
+    );
   }
-  return {
-    InvalidSignature,
-    InvalidPublicKey,
-    InvalidTransaction,
-    InsufficientFunds,
-    InvalidArgument,
-    SerializationError,
-    Secp256k1Error,
-    MiniscriptError,
-    InvalidNetwork,
-    KeyError,
-    instanceOf,
-  };
-})();
-
-// Union type for DlcError error type.
-
-export type DlcError = InstanceType<
-  (typeof DlcError)[keyof Omit<typeof DlcError, 'instanceOf'>]
->;
-
-const FfiConverterTypeDLCError = (() => {
-  const intConverter = FfiConverterInt32;
-  type TypeName = DlcError;
-  class FfiConverter extends AbstractFfiConverterByteArray<TypeName> {
-    read(from: RustBuffer): TypeName {
-      switch (intConverter.read(from)) {
-        case 1:
-          return new DlcError.InvalidSignature(FfiConverterString.read(from));
-
-        case 2:
-          return new DlcError.InvalidPublicKey(FfiConverterString.read(from));
-
-        case 3:
-          return new DlcError.InvalidTransaction(FfiConverterString.read(from));
-
-        case 4:
-          return new DlcError.InsufficientFunds(FfiConverterString.read(from));
-
-        case 5:
-          return new DlcError.InvalidArgument(FfiConverterString.read(from));
-
-        case 6:
-          return new DlcError.SerializationError(FfiConverterString.read(from));
-
-        case 7:
-          return new DlcError.Secp256k1Error(FfiConverterString.read(from));
-
-        case 8:
-          return new DlcError.MiniscriptError(FfiConverterString.read(from));
-
-        case 9:
-          return new DlcError.InvalidNetwork(FfiConverterString.read(from));
-
-        case 10:
-          return new DlcError.KeyError(FfiConverterString.read(from));
-
-        default:
-          throw new UniffiInternalError.UnexpectedEnumCase();
-      }
-    }
-    write(value: TypeName, into: RustBuffer): void {
-      const obj = value as any;
-      const index = obj[variantOrdinalSymbol] as number;
-      intConverter.write(index, into);
-    }
-    allocationSize(value: TypeName): number {
-      return intConverter.allocationSize(0);
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_diff_cet_refund() !== 25657
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_diff_cet_refund'
+    );
   }
-  return new FfiConverter();
-})();
-
-// Flat error type: ExtendedKey
-export enum ExtendedKey_Tags {
-  InvalidMnemonic = 'InvalidMnemonic',
-  InvalidXpriv = 'InvalidXpriv',
-  InvalidXpub = 'InvalidXpub',
-  InvalidDerivationPath = 'InvalidDerivationPath',
-}
-export const ExtendedKey = (() => {
-  class InvalidMnemonic extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 1;
-
-    public readonly tag = ExtendedKey_Tags.InvalidMnemonic;
-
-    constructor(message: string) {
-      super('ExtendedKey', 'InvalidMnemonic', message);
-    }
-
-    static instanceOf(e: any): e is InvalidMnemonic {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 1;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_diff_party_params() !==
+    59817
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_diff_party_params'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_dlc_input_info_from_fund_hex() !==
+    53709
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_dlc_input_info_from_fund_hex'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_dlc_input_sighash() !==
+    41557
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_dlc_input_sighash'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_dlc_transactions_equal() !==
+    57398
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_dlc_transactions_equal'
+    );
   }
-  class InvalidXpriv extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 2;
-
-    public readonly tag = ExtendedKey_Tags.InvalidXpriv;
-
-    constructor(message: string) {
-      super('ExtendedKey', 'InvalidXpriv', message);
-    }
-
-    static instanceOf(e: any): e is InvalidXpriv {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 2;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_dlc_transactions_to_fund_psbt() !==
+    33439
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_dlc_transactions_to_fund_psbt'
+    );
   }
-  class InvalidXpub extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 3;
-
-    public readonly tag = ExtendedKey_Tags.InvalidXpub;
-
-    constructor(message: string) {
-      super('ExtendedKey', 'InvalidXpub', message);
-    }
-
-    static instanceOf(e: any): e is InvalidXpub {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 3;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_dummy_transaction() !==
+    24001
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_dummy_transaction'
+    );
   }
-  class InvalidDerivationPath extends UniffiError {
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [uniffiTypeNameSymbol]: string = 'ExtendedKey';
-    /**
-     * @private
-     * This field is private and should not be used.
-     */
-    readonly [variantOrdinalSymbol] = 4;
-
-    public readonly tag = ExtendedKey_Tags.InvalidDerivationPath;
-
-    constructor(message: string) {
-      super('ExtendedKey', 'InvalidDerivationPath', message);
-    }
-
-    static instanceOf(e: any): e is InvalidDerivationPath {
-      return instanceOf(e) && (e as any)[variantOrdinalSymbol] === 4;
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_estimate_cet_fee_taproot() !==
+    4249
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_estimate_cet_fee_taproot'
+    );
   }
-
-  // Utility function which does not rely on instanceof.
-  function instanceOf(e: any): e is ExtendedKey {
-    return (e as any)[uniffiTypeNameSymbol] === 'ExtendedKey';
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_estimate_contract_cost() !==
+    46553
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_estimate_contract_cost'
+    );
   }
-  return {
-    InvalidMnemonic,
-    InvalidXpriv,
-    InvalidXpub,
-    InvalidDerivationPath,
-    instanceOf,
-  };
-})();
-
-// Union type for ExtendedKey error type.
-
-export type ExtendedKey = InstanceType<
-  (typeof ExtendedKey)[keyof Omit<typeof ExtendedKey, 'instanceOf'>]
->;
-
-const FfiConverterTypeExtendedKey = (() => {
-  const intConverter = FfiConverterInt32;
-  type TypeName = ExtendedKey;
-  class FfiConverter extends AbstractFfiConverterByteArray<TypeName> {
-    read(from: RustBuffer): TypeName {
-      switch (intConverter.read(from)) {
-        case 1:
-          return new ExtendedKey.InvalidMnemonic(FfiConverterString.read(from));
-
-        case 2:
-          return new ExtendedKey.InvalidXpriv(FfiConverterString.read(from));
-
-        case 3:
-          return new ExtendedKey.InvalidXpub(FfiConverterString.read(from));
-
-        case 4:
-          return new ExtendedKey.InvalidDerivationPath(
-            FfiConverterString.read(from)
-          );
-
-        default:
-          throw new UniffiInternalError.UnexpectedEnumCase();
-      }
-    }
-    write(value: TypeName, into: RustBuffer): void {
-      const obj = value as any;
-      const index = obj[variantOrdinalSymbol] as number;
-      intConverter.write(index, into);
-    }
-    allocationSize(value: TypeName): number {
-      return intConverter.allocationSize(0);
-    }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_estimate_fund_transaction_fee() !==
+    42663
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_estimate_fund_transaction_fee'
+    );
   }
-  return new FfiConverter();
-})();
-
-// FfiConverter for string | undefined
-const FfiConverterOptionalString = new FfiConverterOptional(FfiConverterString);
-
-// FfiConverter for Array<AdaptorSignature>
-const FfiConverterArrayTypeAdaptorSignature = new FfiConverterArray(
-  FfiConverterTypeAdaptorSignature
-);
-
-// FfiConverter for Array<DlcInputInfo>
-const FfiConverterArrayTypeDlcInputInfo = new FfiConverterArray(
-  FfiConverterTypeDlcInputInfo
-);
-
-// FfiConverter for Array<OracleInfo>
-const FfiConverterArrayTypeOracleInfo = new FfiConverterArray(
-  FfiConverterTypeOracleInfo
-);
-
-// FfiConverter for Array<Payout>
-const FfiConverterArrayTypePayout = new FfiConverterArray(
-  FfiConverterTypePayout
-);
-
-// FfiConverter for Array<Transaction>
-const FfiConverterArrayTypeTransaction = new FfiConverterArray(
-  FfiConverterTypeTransaction
-);
-
-// FfiConverter for Array<TxInput>
-const FfiConverterArrayTypeTxInput = new FfiConverterArray(
-  FfiConverterTypeTxInput
-);
-
-// FfiConverter for Array<TxInputInfo>
-const FfiConverterArrayTypeTxInputInfo = new FfiConverterArray(
-  FfiConverterTypeTxInputInfo
-);
-
-// FfiConverter for Array<TxOutput>
-const FfiConverterArrayTypeTxOutput = new FfiConverterArray(
-  FfiConverterTypeTxOutput
-);
-
-// FfiConverter for Array</*u8*/number>
-const FfiConverterArrayUInt8 = new FfiConverterArray(FfiConverterUInt8);
-
-// FfiConverter for Array<Array</*u8*/number>>
-const FfiConverterArrayArrayUInt8 = new FfiConverterArray(
-  FfiConverterArrayUInt8
-);
-
-// FfiConverter for Array<Array<Array</*u8*/number>>>
-const FfiConverterArrayArrayArrayUInt8 = new FfiConverterArray(
-  FfiConverterArrayArrayUInt8
-);
-
-// FfiConverter for Array<Array<Array<Array</*u8*/number>>>>
-const FfiConverterArrayArrayArrayArrayUInt8 = new FfiConverterArray(
-  FfiConverterArrayArrayArrayUInt8
-);
-
-/**
- * This should be called before anything else.
- *
- * It is likely that this is being done for you by the library's `index.ts`.
- *
- * It checks versions of uniffi between when the Rust scaffolding was generated
- * and when the bindings were generated.
- *
- * It also initializes the machinery to enable Rust to talk back to Javascript.
- */
-function uniffiEnsureInitialized() {
-  // Get the bindings contract version from our ComponentInterface
-  const bindingsContractVersion = 29;
-  // Get the scaffolding contract version by calling the into the dylib
-  const scaffoldingContractVersion =
-    nativeModule().ubrn_ffi_ddk_ffi_uniffi_contract_version();
-  if (bindingsContractVersion !== scaffoldingContractVersion) {
-    throw new UniffiInternalError.ContractVersionMismatch(
-      scaffoldingContractVersion,
-      bindingsContractVersion
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_extkeys_match() !== 51508
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_extkeys_match'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_extract_ecdsa_signature_from_oracle_signatures() !==
+    8006
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_extract_ecdsa_signature_from_oracle_signatures'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_finalize_fund_psbt() !==
+    591
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_finalize_fund_psbt'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_finalize_refund_transaction() !==
+    3683
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_finalize_refund_transaction'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_find_cet_by_payout() !==
+    50406
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_find_cet_by_payout'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_funding_signing_plan() !==
+    29519
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_funding_signing_plan'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_cet_adaptor_signature_inputs() !==
+    14595
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_get_cet_adaptor_signature_inputs'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_add_signature_to_transaction() !==
-    1337
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_cet_sighash() !== 10643
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_add_signature_to_transaction'
+      'uniffi_ddk_ffi_checksum_func_get_cet_sighash'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_convert_mnemonic_to_seed() !==
-    65049
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_change_output_and_fees() !==
+    9192
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_convert_mnemonic_to_seed'
+      'uniffi_ddk_ffi_checksum_func_get_change_output_and_fees'
     );
   }
-  if (nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet() !== 23081) {
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_extkey_fingerprint() !==
+    10126
+  ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_cet'
+      'uniffi_ddk_ffi_checksum_func_get_extkey_fingerprint'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_points_from_oracle_info() !==
-    6552
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_fund_output_value() !==
+    51560
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_points_from_oracle_info'
+      'uniffi_ddk_ffi_checksum_func_get_fund_output_value'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_from_oracle_info() !==
-    65174
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_network_from_extkey() !==
+    64567
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_signature_from_oracle_info'
+      'uniffi_ddk_ffi_checksum_func_get_network_from_extkey'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_oracle_info() !==
-    15699
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_pubkey_from_extkey() !==
+    64637
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_oracle_info'
+      'uniffi_ddk_ffi_checksum_func_get_pubkey_from_extkey'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_points() !==
-    33705
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_raw_funding_transaction_input_signature() !==
+    35708
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_cet_adaptor_sigs_from_points'
+      'uniffi_ddk_ffi_checksum_func_get_raw_funding_transaction_input_signature'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_cets() !== 50529
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_total_input_vsize() !==
+    38213
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_cets'
+      'uniffi_ddk_ffi_checksum_func_get_total_input_vsize'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_dlc_transactions() !==
-    51134
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_transaction_txid() !==
+    51485
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_dlc_transactions'
+      'uniffi_ddk_ffi_checksum_func_get_transaction_txid'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_extkey_from_parent_path() !==
-    26671
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_transaction_wtxid() !==
+    2315
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_extkey_from_parent_path'
+      'uniffi_ddk_ffi_checksum_func_get_transaction_wtxid'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_extkey_from_seed() !==
-    12526
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_xpub_from_xpriv() !==
+    44344
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_extkey_from_seed'
+      'uniffi_ddk_ffi_checksum_func_get_xpub_from_xpriv'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_fund_tx_locking_script() !==
-    2761
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_hash_outcome() !== 28757
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_fund_tx_locking_script'
+      'uniffi_ddk_ffi_checksum_func_hash_outcome'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_refund_transaction() !==
-    57261
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_hash_outcomes() !== 55551
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_refund_transaction'
+      'uniffi_ddk_ffi_checksum_func_hash_outcomes'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_spliced_dlc_transactions() !==
-    31130
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_is_dust_output() !== 64174
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_spliced_dlc_transactions'
+      'uniffi_ddk_ffi_checksum_func_is_dust_output'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_create_xpriv_from_parent_path() !==
-    34808
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_is_dust_output_for_feerate() !==
+    36360
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_create_xpriv_from_parent_path'
+      'uniffi_ddk_ffi_checksum_func_is_dust_output_for_feerate'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_extract_ecdsa_signature_from_oracle_signatures() !==
-    8006
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_is_valid_network() !==
+    21933
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_extract_ecdsa_signature_from_oracle_signatures'
+      'uniffi_ddk_ffi_checksum_func_is_valid_network'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_cet_adaptor_signature_inputs() !==
-    14595
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_max_safe_fee_rate() !==
+    46780
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_get_cet_adaptor_signature_inputs'
+      'uniffi_ddk_ffi_checksum_func_max_safe_fee_rate'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_cet_sighash() !== 10643
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_message_template() !==
+    13738
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_get_cet_sighash'
+      'uniffi_ddk_ffi_checksum_func_message_template'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_change_output_and_fees() !==
-    44150
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_min_viable_collateral() !==
+    15285
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_get_change_output_and_fees'
+      'uniffi_ddk_ffi_checksum_func_min_viable_collateral'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_pubkey_from_extkey() !==
-    64637
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_outcome_signature_point() !==
+    16098
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_get_pubkey_from_extkey'
+      'uniffi_ddk_ffi_checksum_func_outcome_signature_point'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_raw_funding_transaction_input_signature() !==
-    35708
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_party_params_deserialize() !==
+    36254
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_get_raw_funding_transaction_input_signature'
+      'uniffi_ddk_ffi_checksum_func_party_params_deserialize'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_total_input_vsize() !==
-    38213
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_party_params_from_descriptor() !==
+    4231
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_get_total_input_vsize'
+      'uniffi_ddk_ffi_checksum_func_party_params_from_descriptor'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_get_xpub_from_xpriv() !==
-    44344
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_party_params_serialize() !==
+    60060
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_get_xpub_from_xpriv'
+      'uniffi_ddk_ffi_checksum_func_party_params_serialize'
     );
   }
   if (
-    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_is_dust_output() !== 64174
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_preview_change() !== 8359
   ) {
     throw new UniffiInternalError.ApiChecksumMismatch(
-      'uniffi_ddk_ffi_checksum_func_is_dust_output'
+      'uniffi_ddk_ffi_checksum_func_preview_change'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_pubkey_to_address() !==
+    11160
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_pubkey_to_address'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_rebuild_refund() !== 33054
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_rebuild_refund'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_recover_adaptor_secret() !==
+    47843
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_recover_adaptor_secret'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_rekey_contract() !== 14901
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_rekey_contract'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_safe_fund_output_serial_id() !==
+    25531
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_safe_fund_output_serial_id'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_serialize_settlement_state() !==
+    3096
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_serialize_settlement_state'
     );
   }
   if (nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_sign_cet() !== 43957) {
@@ -2520,6 +6241,22 @@ function uniffiEnsureInitialized() {
       'uniffi_ddk_ffi_checksum_func_sign_cet'
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_sign_cet_checked() !==
+    44523
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_sign_cet_checked'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_sign_cet_with_external_signature() !==
+    8801
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_sign_cet_with_external_signature'
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_sign_fund_transaction_input() !==
     50531
@@ -2536,6 +6273,118 @@ function uniffiEnsureInitialized() {
       'uniffi_ddk_ffi_checksum_func_sign_multi_sig_input'
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_sign_refund_transaction() !==
+    10736
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_sign_refund_transaction'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_transaction_from_hex() !==
+    63574
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_transaction_from_hex'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_transaction_to_hex() !==
+    44322
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_transaction_to_hex'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_transactions_equivalent() !==
+    60889
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_transactions_equivalent'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_validate_adaptor_sig_bytes() !==
+    8659
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_validate_adaptor_sig_bytes'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_validate_cets_share_funding() !==
+    46434
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_validate_cets_share_funding'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_validate_dlc_transactions() !==
+    28817
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_validate_dlc_transactions'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_validate_mnemonic() !==
+    33300
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_validate_mnemonic'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_validate_oracle_count() !==
+    63012
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_validate_oracle_count'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_validate_party_params() !==
+    31105
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_validate_party_params'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_validate_transaction_consistency() !==
+    25749
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_validate_transaction_consistency'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_adaptor_points_wellformed() !==
+    37250
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_adaptor_points_wellformed'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_adaptor_proof() !==
+    57353
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_adaptor_proof'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_adaptor_sig_binds_cet() !==
+    15622
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_adaptor_sig_binds_cet'
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sig_from_oracle_info() !==
     52232
@@ -2544,6 +6393,14 @@ function uniffiEnsureInitialized() {
       'uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sig_from_oracle_info'
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_detailed() !==
+    32842
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_detailed'
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_from_oracle_info() !==
     14181
@@ -2552,6 +6409,30 @@ function uniffiEnsureInitialized() {
       'uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_from_oracle_info'
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_with_points() !==
+    58822
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_cet_adaptor_sigs_with_points'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_collateral_invariant() !==
+    18988
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_collateral_invariant'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_dlc_input_signature() !==
+    41975
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_dlc_input_signature'
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_fund_tx_signature() !==
     27316
@@ -2560,6 +6441,62 @@ function uniffiEnsureInitialized() {
       'uniffi_ddk_ffi_checksum_func_verify_fund_tx_signature'
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_funding_matches_params() !==
+    45218
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_funding_matches_params'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_funding_script_consistency() !==
+    10220
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_funding_script_consistency'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_inputs_unspent() !==
+    46009
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_inputs_unspent'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_multisig_fund_input_signature() !==
+    5465
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_multisig_fund_input_signature'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_oracle_attestation() !==
+    57307
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_oracle_attestation'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_refund_payouts() !==
+    2616
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_refund_payouts'
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_verify_refund_transaction_signature() !==
+    28393
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      'uniffi_ddk_ffi_checksum_func_verify_refund_transaction_signature'
+    );
+  }
   if (nativeModule().ubrn_uniffi_ddk_ffi_checksum_func_version() !== 22317) {
     throw new UniffiInternalError.ApiChecksumMismatch(
       'uniffi_ddk_ffi_checksum_func_version'
@@ -2572,17 +6509,28 @@ export default Object.freeze({
   converters: {
     FfiConverterTypeAdaptorSignature,
     FfiConverterTypeCetAdaptorSignatureDebugInfo,
+    FfiConverterTypeCetBuildResult,
+    FfiConverterTypeCetRefundDiff,
     FfiConverterTypeChangeOutputAndFees,
+    FfiConverterTypeCompletedContract,
+    FfiConverterTypeContractCost,
     FfiConverterTypeDLCError,
     FfiConverterTypeDlcInputInfo,
+    FfiConverterTypeDlcTransactionRequest,
     FfiConverterTypeDlcTransactions,
     FfiConverterTypeExtendedKey,
     FfiConverterTypeOracleInfo,
     FfiConverterTypePartyParams,
+    FfiConverterTypePartyParamsDiff,
     FfiConverterTypePayout,
+    FfiConverterTypePayoutRatio,
+    FfiConverterTypeSchnorrParts,
+    FfiConverterTypeSettlementState,
+    FfiConverterTypeSigningStep,
     FfiConverterTypeTransaction,
     FfiConverterTypeTxInput,
     FfiConverterTypeTxInputInfo,
     FfiConverterTypeTxOutput,
+    FfiConverterTypeWitnessEntry,
   },
 });
@@ -402,7 +402,9 @@ describe('DDK TypeScript Bindings', () => {
 
     const result = ddk.getChangeOutputAndFees(
       partyParams,
+      partyParams.collateral, // otherCollateral
       4n, // feeRate
+      0n, // extraFee
     )
 
     expect(result.changeOutput).toBeDefined()
@@ -470,4 +472,18 @@ describe('DDK TypeScript Bindings', () => {
       )
     }).toThrow()
   })
+
+  test('errors carry a stable code and message as JSON', () => {
+    let error: Error | undefined
+    try {
+      ddk.transactionFromHex('zz')
+    } catch (e) {
+      error = e as Error
+    }
+
+    expect(error).toBeDefined()
+    const payload = JSON.parse(error!.message)
+    expect(payload.code).toBe(6)
+    expect(payload.message).toBe('Serialization error')
+  })
 })
@@ -25,6 +25,16 @@ pub fn buffer_to_vec(buffer: &Buffer) -> Vec<u8> {
   buffer.to_vec()
 }
 
+// Convert a ddk_ffi DLCError to a NAPI error whose reason is a JSON
+// `{"code": number, "message": string}` payload, `code` being DLCError::code
+pub fn dlc_error(e: ddk_ffi::DLCError) -> Error {
+  let payload = serde_json::json!({
+    "code": e.code(),
+    "message": e.to_string(),
+  });
+  Error::from_reason(payload.to_string())
+}
+
 // Convert ddk_ffi Transaction to NAPI Transaction
 impl From<ddk_ffi::Transaction> for Transaction {
   fn from(tx: ddk_ffi::Transaction) -> Self {
@@ -148,6 +158,15 @@ impl From<ddk_ffi::Payout> for Payout {
     }
   }
 }
+
+impl From<PayoutRatio> for ddk_ffi::PayoutRatio {
+  fn from(ratio: PayoutRatio) -> Self {
+    ddk_ffi::PayoutRatio {
+      offer: ratio.offer,
+      accept: ratio.accept,
+    }
+  }
+}
 // Convert NAPI DlcInputInfo to ddk_ffi DlcInputInfo
 impl TryFrom<DlcInputInfo> for ddk_ffi::DlcInputInfo {
   type Error = napi::Error;
@@ -196,7 +215,7 @@ impl TryFrom<PartyParams> for ddk_ffi::PartyParams {
 
     Ok(ddk_ffi::PartyParams {
       fund_pubkey: params.fund_pubkey.to_vec(),
-      change_script_pubkey: params.change_script_pubkey.to_vec(),
+      change_script_pubkey: params.change_script_pubkey.map(|script| script.to_vec()),
       change_serial_id: bigint_to_u64(&params.change_serial_id)?,
       payout_script_pubkey: params.payout_script_pubkey.to_vec(),
       payout_serial_id: bigint_to_u64(&params.payout_serial_id)?,
@@ -213,7 +232,7 @@ impl From<ddk_ffi::PartyParams> for PartyParams {
   fn from(params: ddk_ffi::PartyParams) -> Self {
     PartyParams {
       fund_pubkey: Buffer::from(params.fund_pubkey),
-      change_script_pubkey: Buffer::from(params.change_script_pubkey),
+      change_script_pubkey: params.change_script_pubkey.map(Buffer::from),
       change_serial_id: BigInt::from(params.change_serial_id),
       payout_script_pubkey: Buffer::from(params.payout_script_pubkey),
       payout_serial_id: BigInt::from(params.payout_serial_id),
@@ -237,6 +256,45 @@ impl From<ddk_ffi::DlcTransactions> for DlcTransactions {
   }
 }
 
+// Convert NAPI DlcTransactions to ddk_ffi DlcTransactions
+impl TryFrom<DlcTransactions> for ddk_ffi::DlcTransactions {
+  type Error = napi::Error;
+
+  fn try_from(txs: DlcTransactions) -> Result<Self> {
+    let cets: Result<Vec<_>> = txs.cets.into_iter().map(TryInto::try_into).collect();
+    Ok(ddk_ffi::DlcTransactions {
+      fund: txs.fund.try_into()?,
+      cets: cets?,
+      refund: txs.refund.try_into()?,
+      funding_script_pubkey: txs.funding_script_pubkey.to_vec(),
+    })
+  }
+}
+
+impl TryFrom<DlcTransactionRequest> for ddk_ffi::DlcTransactionRequest {
+  type Error = napi::Error;
+
+  fn try_from(request: DlcTransactionRequest) -> Result<Self> {
+    let outcomes: Result<Vec<_>> = request
+      .outcomes
+      .into_iter()
+      .map(TryInto::try_into)
+      .collect();
+
+    Ok(ddk_ffi::DlcTransactionRequest {
+      outcomes: outcomes?,
+      local_params: request.local_params.try_into()?,
+      remote_params: request.remote_params.try_into()?,
+      refund_locktime: request.refund_locktime,
+      fee_rate: bigint_to_u64(&request.fee_rate)?,
+      fund_lock_time: request.fund_lock_time,
+      cet_lock_time: request.cet_lock_time,
+      fund_output_serial_id: bigint_to_u64(&request.fund_output_serial_id)?,
+      contract_flags: request.contract_flags,
+    })
+  }
+}
+
 // Convert ddk_ffi ChangeOutputAndFees to NAPI ChangeOutputAndFees
 impl From<ddk_ffi::ChangeOutputAndFees> for ChangeOutputAndFees {
   fn from(fees: ddk_ffi::ChangeOutputAndFees) -> Self {
@@ -258,6 +316,15 @@ impl From<OracleInfo> for ddk_ffi::OracleInfo {
   }
 }
 
+impl From<ddk_ffi::OracleInfo> for OracleInfo {
+  fn from(info: ddk_ffi::OracleInfo) -> Self {
+    OracleInfo {
+      public_key: Buffer::from(info.public_key),
+      nonces: info.nonces.into_iter().map(Buffer::from).collect(),
+    }
+  }
+}
+
 // Convert ddk_ffi AdaptorSignature to NAPI AdaptorSignature
 impl From<ddk_ffi::AdaptorSignature> for AdaptorSignature {
   fn from(sig: ddk_ffi::AdaptorSignature) -> Self {
@@ -276,3 +343,125 @@ impl From<AdaptorSignature> for ddk_ffi::AdaptorSignature {
     }
   }
 }
+
+// Convert ddk_ffi CetRefundDiff to NAPI CetRefundDiff
+impl From<ddk_ffi::CetRefundDiff> for CetRefundDiff {
+  fn from(diff: ddk_ffi::CetRefundDiff) -> Self {
+    CetRefundDiff {
+      same_funding_outpoint: diff.same_funding_outpoint,
+      identical_payouts: diff.identical_payouts,
+      cet_only_outputs: diff.cet_only_outputs.into_iter().map(Into::into).collect(),
+      refund_only_outputs: diff
+        .refund_only_outputs
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+    }
+  }
+}
+
+// Convert ddk_ffi ContractCost to NAPI ContractCost
+impl From<ddk_ffi::ContractCost> for ContractCost {
+  fn from(cost: ddk_ffi::ContractCost) -> Self {
+    ContractCost {
+      cet_count: BigInt::from(cost.cet_count),
+      transaction_bytes: BigInt::from(cost.transaction_bytes),
+      adaptor_signing_operations: BigInt::from(cost.adaptor_signing_operations),
+    }
+  }
+}
+
+// Convert ddk_ffi CompletedContract to NAPI CompletedContract
+impl From<ddk_ffi::CompletedContract> for CompletedContract {
+  fn from(contract: ddk_ffi::CompletedContract) -> Self {
+    CompletedContract {
+      dlc_transactions: contract.dlc_transactions.into(),
+      fund_output_value: BigInt::from(contract.fund_output_value),
+      offer_adaptor_signatures: contract
+        .offer_adaptor_signatures
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+      accept_adaptor_signatures: contract
+        .accept_adaptor_signatures
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+      signed_refund: contract.signed_refund.into(),
+    }
+  }
+}
+
+// Convert ddk_ffi SettlementState to NAPI SettlementState
+impl From<ddk_ffi::SettlementState> for SettlementState {
+  fn from(state: ddk_ffi::SettlementState) -> Self {
+    SettlementState {
+      dlc_transactions: state.dlc_transactions.into(),
+      adaptor_signatures: state
+        .adaptor_signatures
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+      oracle_infos: state.oracle_infos.into_iter().map(Into::into).collect(),
+      contract_id: Buffer::from(state.contract_id),
+    }
+  }
+}
+
+// Convert ddk_ffi SigningStep to NAPI SigningStep
+impl From<ddk_ffi::SigningStep> for SigningStep {
+  fn from(step: ddk_ffi::SigningStep) -> Self {
+    SigningStep {
+      input_index: step.input_index,
+      script_type: step.script_type,
+      amount: BigInt::from(step.amount),
+    }
+  }
+}
+
+// Convert NAPI WitnessEntry to ddk_ffi WitnessEntry
+impl From<WitnessEntry> for ddk_ffi::WitnessEntry {
+  fn from(entry: WitnessEntry) -> Self {
+    ddk_ffi::WitnessEntry {
+      input_index: entry.input_index,
+      items: entry.items.into_iter().map(|item| item.to_vec()).collect(),
+    }
+  }
+}
+
+// Convert ddk_ffi SchnorrParts to NAPI SchnorrParts
+impl From<ddk_ffi::SchnorrParts> for SchnorrParts {
+  fn from(parts: ddk_ffi::SchnorrParts) -> Self {
+    SchnorrParts {
+      nonce: Buffer::from(parts.nonce),
+      s_value: Buffer::from(parts.s_value),
+    }
+  }
+}
+
+// Convert ddk_ffi CetBuildResult to NAPI CetBuildResult
+impl From<ddk_ffi::CetBuildResult> for CetBuildResult {
+  fn from(result: ddk_ffi::CetBuildResult) -> Self {
+    CetBuildResult {
+      transaction: result.transaction.into(),
+      offer_output_dropped: result.offer_output_dropped,
+      accept_output_dropped: result.accept_output_dropped,
+    }
+  }
+}
+
+// Convert ddk_ffi PartyParamsDiff to NAPI PartyParamsDiff
+impl From<ddk_ffi::PartyParamsDiff> for PartyParamsDiff {
+  fn from(diff: ddk_ffi::PartyParamsDiff) -> Self {
+    PartyParamsDiff {
+      fund_pubkey: diff.fund_pubkey,
+      change_script_pubkey: diff.change_script_pubkey,
+      payout_script_pubkey: diff.payout_script_pubkey,
+      input_amount: diff.input_amount,
+      collateral: diff.collateral,
+      inputs: diff.inputs,
+      dlc_inputs: diff.dlc_inputs,
+      serial_ids: diff.serial_ids,
+    }
+  }
+}
@@ -1,4 +1,5 @@
 #![deny(clippy::all)]
+#![allow(clippy::too_many_arguments)]
 
 pub mod conversions;
 mod types;
@@ -33,8 +34,8 @@ pub fn create_fund_tx_locking_script(
   let local_pubkey = buffer_to_vec(&local_fund_pubkey);
   let remote_pubkey = buffer_to_vec(&remote_fund_pubkey);
 
-  let result = ddk_ffi::create_fund_tx_locking_script(local_pubkey, remote_pubkey)
-    .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  let result =
+    ddk_ffi::create_fund_tx_locking_script(local_pubkey, remote_pubkey).map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
@@ -68,7 +69,163 @@ pub fn create_dlc_transactions(
     bigint_to_u64(&fund_output_serial_id)?,
     contract_flags,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Create DLC transactions without checking the refund locktime is after the CET locktime.
+#[napi]
+pub fn create_dlc_transactions_unchecked(
+  outcomes: Vec<Payout>,
+  local_params: PartyParams,
+  remote_params: PartyParams,
+  refund_locktime: u32,
+  fee_rate: BigInt,
+  fund_lock_time: u32,
+  cet_lock_time: u32,
+  fund_output_serial_id: BigInt,
+  contract_flags: u8,
+) -> Result<DlcTransactions> {
+  let ffi_outcomes: Result<Vec<ddk_ffi::Payout>> =
+    outcomes.into_iter().map(TryInto::try_into).collect();
+
+  let ffi_local_params = local_params.try_into()?;
+  let ffi_remote_params = remote_params.try_into()?;
+
+  let result = ddk_ffi::create_dlc_transactions_unchecked(
+    ffi_outcomes?,
+    ffi_local_params,
+    ffi_remote_params,
+    refund_locktime,
+    bigint_to_u64(&fee_rate)?,
+    fund_lock_time,
+    cet_lock_time,
+    bigint_to_u64(&fund_output_serial_id)?,
+    contract_flags,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Create DLC transactions whose CETs also pay an optional fee output. The
+/// parties also fund its CET fee; a dust or empty-script fee output is rejected.
+#[napi]
+pub fn create_dlc_transactions_with_fee_output(
+  outcomes: Vec<Payout>,
+  local_params: PartyParams,
+  remote_params: PartyParams,
+  refund_locktime: u32,
+  fee_rate: BigInt,
+  fund_lock_time: u32,
+  cet_lock_time: u32,
+  fund_output_serial_id: BigInt,
+  contract_flags: u8,
+  fee_output: Option<TxOutput>,
+  fee_output_serial_id: BigInt,
+) -> Result<DlcTransactions> {
+  let ffi_outcomes: Result<Vec<ddk_ffi::Payout>> =
+    outcomes.into_iter().map(TryInto::try_into).collect();
+
+  let result = ddk_ffi::create_dlc_transactions_with_fee_output(
+    ffi_outcomes?,
+    local_params.try_into()?,
+    remote_params.try_into()?,
+    refund_locktime,
+    bigint_to_u64(&fee_rate)?,
+    fund_lock_time,
+    cet_lock_time,
+    bigint_to_u64(&fund_output_serial_id)?,
+    contract_flags,
+    fee_output.map(TryInto::try_into).transpose()?,
+    bigint_to_u64(&fee_output_serial_id)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Report which fields of two party params differ, inputs compared regardless of order.
+#[napi]
+pub fn diff_party_params(a: PartyParams, b: PartyParams) -> Result<PartyParamsDiff> {
+  let result = ddk_ffi::diff_party_params(a.try_into()?, b.try_into()?);
+
+  Ok(result.into())
+}
+
+/// Pick a deterministic fund output serial id distinct from both parties' serial ids.
+#[napi]
+pub fn safe_fund_output_serial_id(
+  local_params: PartyParams,
+  remote_params: PartyParams,
+) -> Result<BigInt> {
+  let result =
+    ddk_ffi::safe_fund_output_serial_id(local_params.try_into()?, remote_params.try_into()?);
+
+  Ok(u64_to_bigint(result))
+}
+
+/// Create the transactions of several contracts in one call, in request order
+#[napi]
+pub fn create_dlc_transactions_batch(
+  requests: Vec<DlcTransactionRequest>,
+) -> Result<Vec<DlcTransactions>> {
+  let ffi_requests: Result<Vec<ddk_ffi::DlcTransactionRequest>> =
+    requests.into_iter().map(TryInto::try_into).collect();
+
+  let result = ddk_ffi::create_dlc_transactions_batch(ffi_requests?).map_err(dlc_error)?;
+
+  Ok(result.into_iter().map(Into::into).collect())
+}
+
+#[napi]
+pub fn verify_funding_matches_params(
+  fund_tx: Transaction,
+  local_params: PartyParams,
+  remote_params: PartyParams,
+  fee_rate: BigInt,
+  fund_output_serial_id: BigInt,
+) -> Result<bool> {
+  ddk_ffi::verify_funding_matches_params(
+    fund_tx.try_into()?,
+    local_params.try_into()?,
+    remote_params.try_into()?,
+    bigint_to_u64(&fee_rate)?,
+    bigint_to_u64(&fund_output_serial_id)?,
+  )
+  .map_err(dlc_error)
+}
+
+#[napi]
+pub fn rekey_contract(
+  local_params: PartyParams,
+  new_fund_pubkey: Buffer,
+  remote_params: PartyParams,
+  outcomes: Vec<Payout>,
+  refund_locktime: u32,
+  fee_rate: BigInt,
+  fund_lock_time: u32,
+  cet_lock_time: u32,
+  fund_output_serial_id: BigInt,
+  contract_flags: u8,
+) -> Result<DlcTransactions> {
+  let ffi_outcomes: Result<Vec<ddk_ffi::Payout>> =
+    outcomes.into_iter().map(TryInto::try_into).collect();
+
+  let result = ddk_ffi::rekey_contract(
+    local_params.try_into()?,
+    buffer_to_vec(&new_fund_pubkey),
+    remote_params.try_into()?,
+    ffi_outcomes?,
+    refund_locktime,
+    bigint_to_u64(&fee_rate)?,
+    fund_lock_time,
+    cet_lock_time,
+    bigint_to_u64(&fund_output_serial_id)?,
+    contract_flags,
+  )
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
@@ -102,7 +259,7 @@ pub fn create_spliced_dlc_transactions(
     bigint_to_u64(&fund_output_serial_id)?,
     contract_flags,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
@@ -126,7 +283,61 @@ pub fn create_cet(
     fund_vout,
     lock_time,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Create a CET, rejecting payouts that leave less than the CET fee at `fee_rate`.
+#[napi]
+pub fn create_cet_checked(
+  local_output: TxOutput,
+  local_payout_serial_id: BigInt,
+  remote_output: TxOutput,
+  remote_payout_serial_id: BigInt,
+  fund_tx_id: String,
+  fund_vout: u32,
+  lock_time: u32,
+  fund_output_value: BigInt,
+  fee_rate: BigInt,
+) -> Result<Transaction> {
+  let result = ddk_ffi::create_cet_checked(
+    local_output.try_into()?,
+    bigint_to_u64(&local_payout_serial_id)?,
+    remote_output.try_into()?,
+    bigint_to_u64(&remote_payout_serial_id)?,
+    fund_tx_id,
+    fund_vout,
+    lock_time,
+    bigint_to_u64(&fund_output_value)?,
+    bigint_to_u64(&fee_rate)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Create a CET, also reporting which payout outputs were dropped as dust.
+#[napi]
+pub fn create_cet_with_info(
+  local_output: TxOutput,
+  local_payout_serial_id: BigInt,
+  remote_output: TxOutput,
+  remote_payout_serial_id: BigInt,
+  fund_tx_id: String,
+  fund_vout: u32,
+  lock_time: u32,
+) -> Result<CetBuildResult> {
+  let result = ddk_ffi::create_cet_with_info(
+    local_output.try_into()?,
+    bigint_to_u64(&local_payout_serial_id)?,
+    remote_output.try_into()?,
+    bigint_to_u64(&remote_payout_serial_id)?,
+    fund_tx_id,
+    fund_vout,
+    lock_time,
+  )
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
@@ -155,7 +366,39 @@ pub fn create_cets(
     bigint_to_u64(&local_serial_id)?,
     bigint_to_u64(&remote_serial_id)?,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
+
+  Ok(result.into_iter().map(Into::into).collect())
+}
+
+/// Create CETs, rejecting any payout whose offer + accept is not the total collateral.
+#[napi]
+pub fn create_cets_checked(
+  fund_tx_id: String,
+  fund_vout: u32,
+  local_final_script_pubkey: Buffer,
+  remote_final_script_pubkey: Buffer,
+  outcomes: Vec<Payout>,
+  total_collateral: BigInt,
+  lock_time: u32,
+  local_serial_id: BigInt,
+  remote_serial_id: BigInt,
+) -> Result<Vec<Transaction>> {
+  let ffi_outcomes: Result<Vec<ddk_ffi::Payout>> =
+    outcomes.into_iter().map(TryInto::try_into).collect();
+
+  let result = ddk_ffi::create_cets_checked(
+    fund_tx_id,
+    fund_vout,
+    buffer_to_vec(&local_final_script_pubkey),
+    buffer_to_vec(&remote_final_script_pubkey),
+    ffi_outcomes?,
+    bigint_to_u64(&total_collateral)?,
+    lock_time,
+    bigint_to_u64(&local_serial_id)?,
+    bigint_to_u64(&remote_serial_id)?,
+  )
+  .map_err(dlc_error)?;
 
   Ok(result.into_iter().map(Into::into).collect())
 }
@@ -179,101 +422,439 @@ pub fn create_refund_transaction(
     fund_tx_id,
     fund_vout,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
 
+/// Hex encode a transaction, witnesses included.
 #[napi]
-pub fn is_dust_output(output: TxOutput) -> Result<bool> {
-  let ffi_output = output.try_into()?;
-  Ok(ddk_ffi::is_dust_output(ffi_output))
+pub fn transaction_to_hex(tx: Transaction) -> Result<String> {
+  Ok(ddk_ffi::transaction_to_hex(tx.try_into()?))
 }
 
+/// Decode a hex encoded transaction.
 #[napi]
-pub fn get_change_output_and_fees(
-  params: PartyParams,
-  fee_rate: BigInt,
-) -> Result<ChangeOutputAndFees> {
-  let result = ddk_ffi::get_change_output_and_fees(params.try_into()?, bigint_to_u64(&fee_rate)?)
-    .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+pub fn transaction_from_hex(hex: String) -> Result<Transaction> {
+  let result = ddk_ffi::transaction_from_hex(hex).map_err(dlc_error)?;
 
   Ok(result.into())
 }
 
+/// Encode party params in the deterministic wire format shared by all SDKs.
 #[napi]
-pub fn get_total_input_vsize(inputs: Vec<TxInputInfo>) -> Result<u32> {
-  let ffi_inputs: Result<Vec<ddk_ffi::TxInputInfo>> =
-    inputs.into_iter().map(TryInto::try_into).collect();
-
-  Ok(ddk_ffi::get_total_input_vsize(ffi_inputs?))
+pub fn party_params_serialize(params: PartyParams) -> Result<Buffer> {
+  Ok(vec_to_buffer(ddk_ffi::party_params_serialize(
+    params.try_into()?,
+  )))
 }
 
+/// Decode party params from the deterministic wire format.
 #[napi]
-pub fn verify_fund_tx_signature(
-  fund_tx: Transaction,
-  signature: Buffer,
-  pubkey: Buffer,
-  txid: String,
-  vout: u32,
-  input_amount: BigInt,
-) -> Result<bool> {
-  let result = ddk_ffi::verify_fund_tx_signature(
-    fund_tx.try_into()?,
-    buffer_to_vec(&signature),
-    buffer_to_vec(&pubkey),
-    txid,
-    vout,
-    bigint_to_u64(&input_amount)?,
-  )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+pub fn party_params_deserialize(bytes: Buffer) -> Result<PartyParams> {
+  let result = ddk_ffi::party_params_deserialize(buffer_to_vec(&bytes)).map_err(dlc_error)?;
 
-  Ok(result)
+  Ok(result.into())
 }
 
+/// Wrap the unsigned fund transaction of a contract into a serialized PSBT.
 #[napi]
-pub fn get_raw_funding_transaction_input_signature(
-  funding_transaction: Transaction,
-  privkey: Buffer,
-  prev_tx_id: String,
-  prev_tx_vout: u32,
-  value: BigInt,
-) -> Result<Buffer> {
-  let result = ddk_ffi::get_raw_funding_transaction_input_signature(
-    funding_transaction.try_into()?,
-    buffer_to_vec(&privkey),
-    prev_tx_id,
-    prev_tx_vout,
-    bigint_to_u64(&value)?,
-  )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+pub fn dlc_transactions_to_fund_psbt(dlc_txs: DlcTransactions) -> Result<Buffer> {
+  let result = ddk_ffi::dlc_transactions_to_fund_psbt(dlc_txs.try_into()?).map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
 
+/// Extract the signed transaction from a PSBT whose inputs all have a final script witness.
 #[napi]
-pub fn verify_cet_adaptor_sig_from_oracle_info(
-  adaptor_sig: AdaptorSignature,
-  cet: Transaction,
-  oracle_info: Vec<OracleInfo>,
-  pubkey: Buffer,
-  funding_script_pubkey: Buffer,
-  total_collateral: BigInt,
-  msgs: Vec<Vec<Buffer>>,
-) -> bool {
-  let ffi_oracle_info = oracle_info.into_iter().map(|info| info.into()).collect();
-  let ffi_msgs = msgs
-    .into_iter()
-    .map(|msg| msg.iter().map(buffer_to_vec).collect())
-    .collect();
+pub fn finalize_fund_psbt(psbt_bytes: Buffer) -> Result<Transaction> {
+  let result = ddk_ffi::finalize_fund_psbt(buffer_to_vec(&psbt_bytes)).map_err(dlc_error)?;
 
-  let Ok(ffi_cet) = cet.try_into() else {
-    return false;
-  };
+  Ok(result.into())
+}
 
-  let Ok(ffi_amount) = bigint_to_u64(&total_collateral) else {
-    return false;
-  };
+/// Get the txid of a transaction.
+#[napi]
+pub fn get_transaction_txid(tx: Transaction) -> Result<String> {
+  ddk_ffi::get_transaction_txid(tx.try_into()?).map_err(dlc_error)
+}
+
+/// Get the witness txid of a transaction.
+#[napi]
+pub fn get_transaction_wtxid(tx: Transaction) -> Result<String> {
+  ddk_ffi::get_transaction_wtxid(tx.try_into()?).map_err(dlc_error)
+}
+
+/// Check that a transaction's structured fields match its raw bytes.
+#[napi]
+pub fn validate_transaction_consistency(tx: Transaction) -> Result<()> {
+  ddk_ffi::validate_transaction_consistency(tx.try_into()?).map_err(dlc_error)
+}
+
+#[napi]
+pub fn is_dust_output(output: TxOutput) -> Result<bool> {
+  let ffi_output = output.try_into()?;
+  Ok(ddk_ffi::is_dust_output(ffi_output))
+}
+
+/// Check if an output is dust at fee_rate, using the script-dependent threshold.
+#[napi]
+pub fn is_dust_output_for_feerate(output: TxOutput, fee_rate: BigInt) -> Result<bool> {
+  Ok(ddk_ffi::is_dust_output_for_feerate(
+    output.try_into()?,
+    bigint_to_u64(&fee_rate)?,
+  ))
+}
+
+#[napi]
+pub fn get_change_output_and_fees(
+  params: PartyParams,
+  other_collateral: BigInt,
+  fee_rate: BigInt,
+  extra_fee: BigInt,
+) -> Result<ChangeOutputAndFees> {
+  let result = ddk_ffi::get_change_output_and_fees(
+    params.try_into()?,
+    bigint_to_u64(&other_collateral)?,
+    bigint_to_u64(&fee_rate)?,
+    bigint_to_u64(&extra_fee)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// A party's share of the funding fee with P2WPKH inputs and change.
+#[napi]
+pub fn compute_fund_fee(
+  input_count: u32,
+  extra_witness_lengths: Vec<u32>,
+  fee_rate: BigInt,
+) -> Result<BigInt> {
+  let result = ddk_ffi::compute_fund_fee(
+    input_count,
+    extra_witness_lengths,
+    bigint_to_u64(&fee_rate)?,
+  );
+
+  Ok(u64_to_bigint(result))
+}
+
+/// A party's share of the CET or refund fee for its payout script.
+#[napi]
+pub fn compute_cet_fee(payout_script_len: u32, fee_rate: BigInt) -> Result<BigInt> {
+  let result = ddk_ffi::compute_cet_fee(payout_script_len, bigint_to_u64(&fee_rate)?);
+
+  Ok(u64_to_bigint(result))
+}
+
+/// Error if the effective fee rate of a transaction exceeds maxFeeRate sat/vB.
+#[napi]
+pub fn check_fee_sanity(
+  tx: Transaction,
+  input_amounts: Vec<BigInt>,
+  max_fee_rate: BigInt,
+) -> Result<()> {
+  let ffi_amounts: Result<Vec<u64>> = input_amounts.iter().map(bigint_to_u64).collect();
+
+  ddk_ffi::check_fee_sanity(tx.try_into()?, ffi_amounts?, bigint_to_u64(&max_fee_rate)?)
+    .map_err(dlc_error)
+}
+
+#[napi]
+pub fn estimate_cet_fee_taproot(fee_rate: BigInt) -> Result<BigInt> {
+  let result = ddk_ffi::estimate_cet_fee_taproot(bigint_to_u64(&fee_rate)?);
+
+  Ok(u64_to_bigint(result))
+}
+
+#[napi]
+pub fn preview_change(
+  inputs: Vec<TxInputInfo>,
+  input_amounts: Vec<BigInt>,
+  collateral: BigInt,
+  fee_rate: BigInt,
+  change_script: Buffer,
+) -> Result<i64> {
+  let ffi_inputs: Result<Vec<ddk_ffi::TxInputInfo>> =
+    inputs.into_iter().map(TryInto::try_into).collect();
+  let ffi_input_amounts: Result<Vec<u64>> = input_amounts.iter().map(bigint_to_u64).collect();
+
+  ddk_ffi::preview_change(
+    ffi_inputs?,
+    ffi_input_amounts?,
+    bigint_to_u64(&collateral)?,
+    bigint_to_u64(&fee_rate)?,
+    buffer_to_vec(&change_script),
+  )
+  .map_err(dlc_error)
+}
+
+/// Change a party would get at each fee rate, negative where its inputs fall short.
+#[napi]
+pub fn change_across_fee_rates(
+  params: PartyParams,
+  fee_rates: Vec<BigInt>,
+  remote_collateral: BigInt,
+) -> Result<Vec<i64>> {
+  let ffi_fee_rates: Result<Vec<u64>> = fee_rates.iter().map(bigint_to_u64).collect();
+
+  ddk_ffi::change_across_fee_rates(
+    params.try_into()?,
+    ffi_fee_rates?,
+    bigint_to_u64(&remote_collateral)?,
+  )
+  .map_err(dlc_error)
+}
+
+/// Smallest collateral keeping every non-zero payout above dust at a fee rate.
+#[napi]
+pub fn min_viable_collateral(
+  outcomes_ratios: Vec<PayoutRatio>,
+  fee_rate: BigInt,
+  local_script: Buffer,
+  remote_script: Buffer,
+) -> Result<BigInt> {
+  let result = ddk_ffi::min_viable_collateral(
+    outcomes_ratios.into_iter().map(Into::into).collect(),
+    bigint_to_u64(&fee_rate)?,
+    buffer_to_vec(&local_script),
+    buffer_to_vec(&remote_script),
+  )
+  .map_err(dlc_error)?;
+
+  Ok(u64_to_bigint(result))
+}
+
+/// Highest fee rate at which no change output or non-zero payout is dust.
+#[napi]
+pub fn max_safe_fee_rate(
+  local_params: PartyParams,
+  remote_params: PartyParams,
+  outcomes: Vec<Payout>,
+) -> Result<BigInt> {
+  let ffi_outcomes: Result<Vec<ddk_ffi::Payout>> =
+    outcomes.into_iter().map(TryInto::try_into).collect();
+
+  let result = ddk_ffi::max_safe_fee_rate(
+    local_params.try_into()?,
+    remote_params.try_into()?,
+    ffi_outcomes?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(u64_to_bigint(result))
+}
+
+/// Check every input of a party appears in a set of `txid:vout` unspent outpoints.
+#[napi]
+pub fn verify_inputs_unspent(params: PartyParams, known_utxos: Vec<String>) -> Result<bool> {
+  ddk_ffi::verify_inputs_unspent(params.try_into()?, known_utxos).map_err(dlc_error)
+}
+
+/// List the funding inputs spending the owned `txid:vout` outpoints, in input order.
+#[napi]
+pub fn funding_signing_plan(
+  fund_tx: Transaction,
+  owned_outpoints: Vec<String>,
+  amounts: Vec<BigInt>,
+) -> Result<Vec<SigningStep>> {
+  let ffi_amounts: Result<Vec<u64>> = amounts.iter().map(bigint_to_u64).collect();
+
+  let result = ddk_ffi::funding_signing_plan(fund_tx.try_into()?, owned_outpoints, ffi_amounts?)
+    .map_err(dlc_error)?;
+
+  Ok(result.into_iter().map(Into::into).collect())
+}
+
+/// Check a party's payout and change scripts are P2WPKH, P2WSH or P2TR.
+#[napi]
+pub fn validate_party_params(params: PartyParams) -> Result<()> {
+  ddk_ffi::validate_party_params(params.try_into()?).map_err(dlc_error)
+}
+
+/// Build party params whose change and payout scripts come from output descriptors.
+#[napi]
+pub fn party_params_from_descriptor(
+  fund_pubkey: Buffer,
+  change_descriptor: String,
+  payout_descriptor: String,
+  inputs: Vec<TxInputInfo>,
+  input_amount: BigInt,
+  collateral: BigInt,
+  network: String,
+) -> Result<PartyParams> {
+  let ffi_inputs: Result<Vec<ddk_ffi::TxInputInfo>> =
+    inputs.into_iter().map(TryInto::try_into).collect();
+
+  let result = ddk_ffi::party_params_from_descriptor(
+    buffer_to_vec(&fund_pubkey),
+    change_descriptor,
+    payout_descriptor,
+    ffi_inputs?,
+    bigint_to_u64(&input_amount)?,
+    bigint_to_u64(&collateral)?,
+    network,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+#[napi]
+pub fn get_total_input_vsize(inputs: Vec<TxInputInfo>) -> Result<u32> {
+  let ffi_inputs: Result<Vec<ddk_ffi::TxInputInfo>> =
+    inputs.into_iter().map(TryInto::try_into).collect();
+
+  Ok(ddk_ffi::get_total_input_vsize(ffi_inputs?))
+}
+
+/// Estimate the fee of the whole funding transaction, both parties' inputs and change included.
+#[napi]
+pub fn estimate_fund_transaction_fee(
+  local_params: PartyParams,
+  remote_params: PartyParams,
+  fee_rate: BigInt,
+) -> Result<BigInt> {
+  let result = ddk_ffi::estimate_fund_transaction_fee(
+    local_params.try_into()?,
+    remote_params.try_into()?,
+    bigint_to_u64(&fee_rate)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(u64_to_bigint(result))
+}
+
+#[napi]
+pub fn verify_fund_tx_signature(
+  fund_tx: Transaction,
+  signature: Buffer,
+  pubkey: Buffer,
+  txid: String,
+  vout: u32,
+  input_amount: BigInt,
+) -> Result<bool> {
+  let result = ddk_ffi::verify_fund_tx_signature(
+    fund_tx.try_into()?,
+    buffer_to_vec(&signature),
+    buffer_to_vec(&pubkey),
+    txid,
+    vout,
+    bigint_to_u64(&input_amount)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result)
+}
+
+#[napi]
+pub fn verify_multisig_fund_input_signature(
+  fund_tx: Transaction,
+  signature: Buffer,
+  pubkey: Buffer,
+  witness_script: Buffer,
+  txid: String,
+  vout: u32,
+  input_amount: BigInt,
+) -> Result<bool> {
+  let result = ddk_ffi::verify_multisig_fund_input_signature(
+    fund_tx.try_into()?,
+    buffer_to_vec(&signature),
+    buffer_to_vec(&pubkey),
+    buffer_to_vec(&witness_script),
+    txid,
+    vout,
+    bigint_to_u64(&input_amount)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result)
+}
+
+/// Check that a refund pays each party its collateral, less at most feeTolerance.
+#[napi]
+pub fn verify_refund_payouts(
+  refund: Transaction,
+  local_script: Buffer,
+  expected_local: BigInt,
+  remote_script: Buffer,
+  expected_remote: BigInt,
+  fee_tolerance: BigInt,
+) -> Result<bool> {
+  ddk_ffi::verify_refund_payouts(
+    refund.try_into()?,
+    buffer_to_vec(&local_script),
+    bigint_to_u64(&expected_local)?,
+    buffer_to_vec(&remote_script),
+    bigint_to_u64(&expected_remote)?,
+    bigint_to_u64(&fee_tolerance)?,
+  )
+  .map_err(dlc_error)
+}
+
+/// Verify a counterparty's signature on the refund's 2-of-2 funding input.
+#[napi]
+pub fn verify_refund_transaction_signature(
+  refund_tx: Transaction,
+  signature: Buffer,
+  pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  fund_output_value: BigInt,
+) -> Result<bool> {
+  ddk_ffi::verify_refund_transaction_signature(
+    refund_tx.try_into()?,
+    buffer_to_vec(&signature),
+    buffer_to_vec(&pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&fund_output_value)?,
+  )
+  .map_err(dlc_error)
+}
+
+#[napi]
+pub fn get_raw_funding_transaction_input_signature(
+  funding_transaction: Transaction,
+  privkey: Buffer,
+  prev_tx_id: String,
+  prev_tx_vout: u32,
+  value: BigInt,
+) -> Result<Buffer> {
+  let result = ddk_ffi::get_raw_funding_transaction_input_signature(
+    funding_transaction.try_into()?,
+    buffer_to_vec(&privkey),
+    prev_tx_id,
+    prev_tx_vout,
+    bigint_to_u64(&value)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+#[napi]
+pub fn verify_cet_adaptor_sig_from_oracle_info(
+  adaptor_sig: AdaptorSignature,
+  cet: Transaction,
+  oracle_info: Vec<OracleInfo>,
+  pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  total_collateral: BigInt,
+  msgs: Vec<Vec<Buffer>>,
+) -> bool {
+  let ffi_oracle_info = oracle_info.into_iter().map(|info| info.into()).collect();
+  let ffi_msgs = msgs
+    .into_iter()
+    .map(|msg| msg.iter().map(buffer_to_vec).collect())
+    .collect();
+
+  let Ok(ffi_cet) = cet.try_into() else {
+    return false;
+  };
+
+  let Ok(ffi_amount) = bigint_to_u64(&total_collateral) else {
+    return false;
+  };
 
   ddk_ffi::verify_cet_adaptor_sig_from_oracle_info(
     adaptor_sig.into(),
@@ -286,6 +867,59 @@ pub fn verify_cet_adaptor_sig_from_oracle_info(
   )
 }
 
+/// Build a DlcInputInfo from a fund transaction hex, checking fund_vout holds fund_amount.
+#[napi]
+pub fn dlc_input_info_from_fund_hex(
+  fund_tx_hex: String,
+  fund_vout: u32,
+  local_fund_pubkey: Buffer,
+  remote_fund_pubkey: Buffer,
+  fund_amount: BigInt,
+  max_witness_len: u32,
+  input_serial_id: BigInt,
+  contract_id: Buffer,
+) -> Result<DlcInputInfo> {
+  let result = ddk_ffi::dlc_input_info_from_fund_hex(
+    fund_tx_hex,
+    fund_vout,
+    buffer_to_vec(&local_fund_pubkey),
+    buffer_to_vec(&remote_fund_pubkey),
+    bigint_to_u64(&fund_amount)?,
+    max_witness_len,
+    bigint_to_u64(&input_serial_id)?,
+    buffer_to_vec(&contract_id),
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Sighash of the input spending the DLC input, for external signers.
+#[napi]
+pub fn dlc_input_sighash(txn: Transaction, dlc_input: DlcInputInfo) -> Result<Buffer> {
+  let result =
+    ddk_ffi::dlc_input_sighash(txn.try_into()?, dlc_input.try_into()?).map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Sign the input spending the DLC input with the local key alone, to send to the counterparty.
+#[napi]
+pub fn create_dlc_input_signature(
+  txn: Transaction,
+  dlc_input: DlcInputInfo,
+  local_privkey: Buffer,
+) -> Result<Buffer> {
+  let result = ddk_ffi::create_dlc_input_signature(
+    txn.try_into()?,
+    dlc_input.try_into()?,
+    buffer_to_vec(&local_privkey),
+  )
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
 #[napi]
 pub fn sign_multi_sig_input(
   tx: Transaction,
@@ -299,11 +933,28 @@ pub fn sign_multi_sig_input(
     buffer_to_vec(&local_privkey),
     buffer_to_vec(&remote_signature),
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
 
+/// Verify a counterparty's signature for the input spending the DLC input before combining.
+#[napi]
+pub fn verify_dlc_input_signature(
+  txn: Transaction,
+  dlc_input: DlcInputInfo,
+  signature: Buffer,
+  signer_pubkey: Buffer,
+) -> Result<bool> {
+  ddk_ffi::verify_dlc_input_signature(
+    txn.try_into()?,
+    dlc_input.try_into()?,
+    buffer_to_vec(&signature),
+    buffer_to_vec(&signer_pubkey),
+  )
+  .map_err(dlc_error)
+}
+
 #[napi]
 pub fn add_signature_to_transaction(
   tx: Transaction,
@@ -317,7 +968,22 @@ pub fn add_signature_to_transaction(
     buffer_to_vec(&pubkey),
     input_index,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Set the full witness stack of each listed input in one call.
+#[napi]
+pub fn add_witnesses_to_transaction(
+  tx: Transaction,
+  witnesses: Vec<WitnessEntry>,
+) -> Result<Transaction> {
+  let result = ddk_ffi::add_witnesses_to_transaction(
+    tx.try_into()?,
+    witnesses.into_iter().map(Into::into).collect(),
+  )
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
@@ -366,6 +1032,94 @@ pub fn verify_cet_adaptor_sigs_from_oracle_info(
   )
 }
 
+/// Verify adaptor signatures from oracle info, one result per CET.
+#[napi]
+pub fn verify_cet_adaptor_sigs_detailed(
+  adaptor_sigs: Vec<AdaptorSignature>,
+  cets: Vec<Transaction>,
+  oracle_info: Vec<OracleInfo>,
+  pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  total_collateral: BigInt,
+  msgs: Vec<Vec<Vec<Buffer>>>,
+) -> Result<Vec<bool>> {
+  let ffi_cets: Result<Vec<ddk_ffi::Transaction>> =
+    cets.into_iter().map(TryInto::try_into).collect();
+  let ffi_msgs = msgs
+    .into_iter()
+    .map(|msg| {
+      msg
+        .iter()
+        .map(|msg| msg.iter().map(buffer_to_vec).collect())
+        .collect()
+    })
+    .collect();
+
+  ddk_ffi::verify_cet_adaptor_sigs_detailed(
+    adaptor_sigs.into_iter().map(Into::into).collect(),
+    ffi_cets?,
+    oracle_info.into_iter().map(Into::into).collect(),
+    buffer_to_vec(&pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&total_collateral)?,
+    ffi_msgs,
+  )
+  .map_err(dlc_error)
+}
+
+/// Verify adaptor signatures against precomputed adaptor points, one result per CET.
+#[napi]
+pub fn verify_cet_adaptor_sigs_with_points(
+  adaptor_sigs: Vec<Buffer>,
+  cets: Vec<Transaction>,
+  adaptor_points: Vec<Buffer>,
+  pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  total_collateral: BigInt,
+) -> Result<Vec<bool>> {
+  let ffi_cets: Result<Vec<ddk_ffi::Transaction>> =
+    cets.into_iter().map(TryInto::try_into).collect();
+
+  ddk_ffi::verify_cet_adaptor_sigs_with_points(
+    adaptor_sigs.iter().map(buffer_to_vec).collect(),
+    ffi_cets?,
+    adaptor_points.iter().map(buffer_to_vec).collect(),
+    buffer_to_vec(&pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&total_collateral)?,
+  )
+  .map_err(dlc_error)
+}
+
+/// Verify an adaptor signature against the exact CET bytes received.
+/// Any change to the CET invalidates the signature, even with the same adaptor point.
+#[napi]
+pub fn verify_adaptor_sig_binds_cet(
+  adaptor_sig: Buffer,
+  cet: Transaction,
+  adaptor_point: Buffer,
+  pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  total_collateral: BigInt,
+) -> bool {
+  let Ok(ffi_cet) = cet.try_into() else {
+    return false;
+  };
+
+  let Ok(ffi_amount) = bigint_to_u64(&total_collateral) else {
+    return false;
+  };
+
+  ddk_ffi::verify_adaptor_sig_binds_cet(
+    buffer_to_vec(&adaptor_sig),
+    ffi_cet,
+    buffer_to_vec(&adaptor_point),
+    buffer_to_vec(&pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    ffi_amount,
+  )
+}
+
 #[napi]
 pub fn sign_fund_transaction_input(
   fund_transaction: Transaction,
@@ -381,7 +1135,7 @@ pub fn sign_fund_transaction_input(
     prev_tx_vout,
     bigint_to_u64(&value)?,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
@@ -405,7 +1159,108 @@ pub fn sign_cet(
     buffer_to_vec(&funding_script_pubkey),
     bigint_to_u64(&fund_output_value)?,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Sign a CET with the funding signature produced by an external signer (e.g. MPC).
+#[napi]
+pub fn sign_cet_with_external_signature(
+  cet: Transaction,
+  adaptor_signature: Buffer,
+  oracle_signatures: Vec<Buffer>,
+  external_signature: Buffer,
+  other_pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  fund_output_value: BigInt,
+) -> Result<Transaction> {
+  let result = ddk_ffi::sign_cet_with_external_signature(
+    cet.try_into()?,
+    buffer_to_vec(&adaptor_signature),
+    oracle_signatures.iter().map(buffer_to_vec).collect(),
+    buffer_to_vec(&external_signature),
+    buffer_to_vec(&other_pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&fund_output_value)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Sign a CET after verifying the adaptor signature against the CET's oracle messages.
+#[napi]
+pub fn sign_cet_checked(
+  cet: Transaction,
+  adaptor_signature: Buffer,
+  oracle_signatures: Vec<Buffer>,
+  funding_secret_key: Buffer,
+  other_pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  fund_output_value: BigInt,
+  oracle_infos: Vec<OracleInfo>,
+  msgs: Vec<Vec<Buffer>>,
+) -> Result<Transaction> {
+  let ffi_msgs = msgs
+    .into_iter()
+    .map(|msg| msg.iter().map(buffer_to_vec).collect())
+    .collect();
+
+  let result = ddk_ffi::sign_cet_checked(
+    cet.try_into()?,
+    buffer_to_vec(&adaptor_signature),
+    oracle_signatures.iter().map(buffer_to_vec).collect(),
+    buffer_to_vec(&funding_secret_key),
+    buffer_to_vec(&other_pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&fund_output_value)?,
+    oracle_infos.into_iter().map(Into::into).collect(),
+    ffi_msgs,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Sign the funding input of a refund transaction, returning a DER signature.
+#[napi]
+pub fn sign_refund_transaction(
+  refund_tx: Transaction,
+  funding_secret_key: Buffer,
+  other_pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  fund_output_value: BigInt,
+) -> Result<Buffer> {
+  let result = ddk_ffi::sign_refund_transaction(
+    refund_tx.try_into()?,
+    buffer_to_vec(&funding_secret_key),
+    buffer_to_vec(&other_pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&fund_output_value)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Place both parties' refund signatures in the funding input witness.
+#[napi]
+pub fn finalize_refund_transaction(
+  refund_tx: Transaction,
+  local_signature: Buffer,
+  remote_signature: Buffer,
+  local_fund_pubkey: Buffer,
+  remote_fund_pubkey: Buffer,
+) -> Result<Transaction> {
+  let result = ddk_ffi::finalize_refund_transaction(
+    refund_tx.try_into()?,
+    buffer_to_vec(&local_signature),
+    buffer_to_vec(&remote_signature),
+    buffer_to_vec(&local_fund_pubkey),
+    buffer_to_vec(&remote_fund_pubkey),
+  )
+  .map_err(dlc_error)?;
 
   Ok(result.into())
 }
@@ -444,7 +1299,7 @@ pub fn create_cet_adaptor_sigs_from_oracle_info(
     bigint_to_u64(&fund_output_value)?,
     ffi_msgs,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   let result = sigs
     .into_iter()
@@ -454,6 +1309,32 @@ pub fn create_cet_adaptor_sigs_from_oracle_info(
   Ok(result)
 }
 
+/// Create enumeration adaptor signatures for one oracle and nonce, outcomes[i] for CET i.
+#[napi]
+pub fn create_enumeration_adaptor_sigs(
+  cets: Vec<Transaction>,
+  oracle_info: OracleInfo,
+  funding_secret_key: Buffer,
+  funding_script_pubkey: Buffer,
+  fund_output_value: BigInt,
+  outcomes: Vec<String>,
+) -> Result<Vec<AdaptorSignature>> {
+  let ffi_cets: Result<Vec<ddk_ffi::Transaction>> =
+    cets.into_iter().map(TryInto::try_into).collect();
+
+  let sigs = ddk_ffi::create_enumeration_adaptor_sigs(
+    ffi_cets?,
+    oracle_info.into(),
+    buffer_to_vec(&funding_secret_key),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&fund_output_value)?,
+    outcomes,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(sigs.into_iter().map(Into::into).collect())
+}
+
 #[napi]
 pub fn create_cet_adaptor_sigs_from_points(
   cets: Vec<Transaction>,
@@ -474,7 +1355,7 @@ pub fn create_cet_adaptor_sigs_from_points(
     buffer_to_vec(&funding_script_pubkey),
     bigint_to_u64(&fund_output_value)?,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   let result = sigs
     .into_iter()
@@ -504,47 +1385,239 @@ pub fn create_cet_adaptor_signature_from_oracle_info(
     bigint_to_u64(&total_collateral)?,
     ffi_msgs,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Create a CET adaptor signature attested by several oracles, msgs[i] for oracleInfos[i].
+#[napi]
+pub fn create_cet_adaptor_signature_multi_oracle(
+  cet: Transaction,
+  oracle_infos: Vec<OracleInfo>,
+  funding_sk: Buffer,
+  funding_script_pubkey: Buffer,
+  total_collateral: BigInt,
+  msgs: Vec<Vec<Buffer>>,
+) -> Result<AdaptorSignature> {
+  let ffi_oracle_infos = oracle_infos.into_iter().map(Into::into).collect();
+  let ffi_msgs = msgs
+    .into_iter()
+    .map(|msg| msg.iter().map(buffer_to_vec).collect())
+    .collect();
+
+  let result = ddk_ffi::create_cet_adaptor_signature_multi_oracle(
+    cet.try_into()?,
+    ffi_oracle_infos,
+    buffer_to_vec(&funding_sk),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&total_collateral)?,
+    ffi_msgs,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Canonical 162-byte encoding of an adaptor signature, proof included.
+#[napi]
+pub fn adaptor_signature_serialize(sig: AdaptorSignature) -> Buffer {
+  vec_to_buffer(ddk_ffi::adaptor_signature_serialize(sig.into()))
+}
+
+/// Parse the canonical 162-byte encoding of an adaptor signature.
+#[napi]
+pub fn adaptor_signature_parse(bytes: Buffer) -> Result<AdaptorSignature> {
+  let result = ddk_ffi::adaptor_signature_parse(buffer_to_vec(&bytes)).map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Verify an adaptor signature and its DLEQ proof over a message, without a CET.
+#[napi]
+pub fn verify_adaptor_proof(
+  adaptor_sig: Buffer,
+  adaptor_point: Buffer,
+  message: Buffer,
+  pubkey: Buffer,
+) -> Result<bool> {
+  ddk_ffi::verify_adaptor_proof(
+    buffer_to_vec(&adaptor_sig),
+    buffer_to_vec(&adaptor_point),
+    buffer_to_vec(&message),
+    buffer_to_vec(&pubkey),
+  )
+  .map_err(dlc_error)
+}
+
+/// Check that bytes have the length of a serialized ECDSA adaptor signature.
+#[napi]
+pub fn validate_adaptor_sig_bytes(bytes: Buffer) -> Result<()> {
+  ddk_ffi::validate_adaptor_sig_bytes(buffer_to_vec(&bytes)).map_err(dlc_error)
+}
+
+#[napi]
+pub fn create_cet_adaptor_points_from_oracle_info(
+  oracle_info: Vec<OracleInfo>,
+  msgs: Vec<Vec<Vec<Buffer>>>,
+) -> Result<Vec<Buffer>> {
+  let ffi_oracle_info: Vec<ddk_ffi::OracleInfo> =
+    oracle_info.into_iter().map(|info| info.into()).collect();
+
+  let ffi_msgs = msgs
+    .into_iter()
+    .map(|cet_msgs| {
+      // For each CET
+      cet_msgs
+        .into_iter()
+        .map(|outcome_msgs| {
+          // For each outcome
+          outcome_msgs.iter().map(buffer_to_vec).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  let points = ddk_ffi::create_cet_adaptor_points_from_oracle_info(ffi_oracle_info, ffi_msgs)
+    .map_err(dlc_error)?;
+
+  let result = points
+    .into_iter()
+    .map(Buffer::from)
+    .collect::<Vec<Buffer>>();
+
+  Ok(result)
+}
+
+/// Hash an outcome's UTF-8 bytes with sha256, the message an oracle signs for it.
+#[napi]
+pub fn hash_outcome(outcome: String) -> Buffer {
+  vec_to_buffer(ddk_ffi::hash_outcome(outcome))
+}
+
+/// Hash each outcome with sha256, keeping their order.
+#[napi]
+pub fn hash_outcomes(outcomes: Vec<String>) -> Vec<Buffer> {
+  ddk_ffi::hash_outcomes(outcomes)
+    .into_iter()
+    .map(vec_to_buffer)
+    .collect()
+}
+
+/// Compute the point s·G the oracle's signature over a message with a nonce will commit to.
+#[napi]
+pub fn outcome_signature_point(
+  oracle_pubkey: Buffer,
+  nonce: Buffer,
+  message: Buffer,
+) -> Result<Buffer> {
+  let result = ddk_ffi::outcome_signature_point(
+    buffer_to_vec(&oracle_pubkey),
+    buffer_to_vec(&nonce),
+    buffer_to_vec(&message),
+  )
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Check that an oracle attestation uses the announced nonce and signs the message.
+#[napi]
+pub fn verify_oracle_attestation(
+  oracle_pubkey: Buffer,
+  nonce: Buffer,
+  message: Buffer,
+  signature: Buffer,
+) -> Result<bool> {
+  ddk_ffi::verify_oracle_attestation(
+    buffer_to_vec(&oracle_pubkey),
+    buffer_to_vec(&nonce),
+    buffer_to_vec(&message),
+    buffer_to_vec(&signature),
+  )
+  .map_err(dlc_error)
+}
+
+/// Split a 64-byte Schnorr signature into its nonce (R) and s value.
+#[napi]
+pub fn decompose_schnorr_signature(signature: Buffer) -> Result<SchnorrParts> {
+  let result =
+    ddk_ffi::decompose_schnorr_signature(buffer_to_vec(&signature)).map_err(dlc_error)?;
 
   Ok(result.into())
 }
 
+/// Sum the s values of oracle attestations into the secret decrypting their adaptor signatures.
+#[napi]
+pub fn aggregate_oracle_s_values(signatures: Vec<Buffer>) -> Result<Buffer> {
+  let result = ddk_ffi::aggregate_oracle_s_values(signatures.iter().map(buffer_to_vec).collect())
+    .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// List, per CET, the oracle nonce points its messages commit to.
 #[napi]
-pub fn create_cet_adaptor_points_from_oracle_info(
-  oracle_info: Vec<OracleInfo>,
+pub fn cet_nonce_dependencies(
   msgs: Vec<Vec<Vec<Buffer>>>,
-) -> Result<Vec<Buffer>> {
-  let ffi_oracle_info: Vec<ddk_ffi::OracleInfo> = oracle_info
-    .into_iter()
-    .map(|info| info.into())
-    .collect();
-
+  oracle_infos: Vec<OracleInfo>,
+) -> Result<Vec<Vec<Buffer>>> {
   let ffi_msgs = msgs
     .into_iter()
     .map(|cet_msgs| {
-      // For each CET
       cet_msgs
-        .into_iter()
-        .map(|outcome_msgs| {
-          // For each outcome
-          outcome_msgs.iter().map(buffer_to_vec).collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>()
+        .iter()
+        .map(|oracle_msgs| oracle_msgs.iter().map(buffer_to_vec).collect())
+        .collect()
     })
-    .collect::<Vec<_>>();
+    .collect();
+  let ffi_oracle_infos = oracle_infos.into_iter().map(Into::into).collect();
 
-  let points = ddk_ffi::create_cet_adaptor_points_from_oracle_info(
-    ffi_oracle_info,
-    ffi_msgs,
+  let result = ddk_ffi::cet_nonce_dependencies(ffi_msgs, ffi_oracle_infos).map_err(dlc_error)?;
+
+  Ok(
+    result
+      .into_iter()
+      .map(|nonces| nonces.into_iter().map(vec_to_buffer).collect())
+      .collect(),
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+}
 
-  let result = points
-    .into_iter()
-    .map(|point| Buffer::from(point))
-    .collect::<Vec<Buffer>>();
+/// Check that exactly `required` oracle infos are given.
+#[napi]
+pub fn validate_oracle_count(oracle_infos: Vec<OracleInfo>, required: u32) -> Result<()> {
+  let ffi_oracle_infos = oracle_infos.into_iter().map(Into::into).collect();
 
-  Ok(result)
+  ddk_ffi::validate_oracle_count(ffi_oracle_infos, required).map_err(dlc_error)
+}
+
+/// Check that adaptor points are valid, distinct 33-byte compressed public keys.
+#[napi]
+pub fn verify_adaptor_points_wellformed(adaptor_points: Vec<Buffer>) -> Result<bool> {
+  ddk_ffi::verify_adaptor_points_wellformed(adaptor_points.iter().map(buffer_to_vec).collect())
+    .map_err(dlc_error)
+}
+
+/// Build an empty [cet][oracle][nonce] message matrix sized to the oracle nonces.
+#[napi]
+pub fn message_template(
+  oracle_infos: Vec<OracleInfo>,
+  cet_count: u32,
+) -> Result<Vec<Vec<Vec<Buffer>>>> {
+  let ffi_oracle_infos = oracle_infos.into_iter().map(Into::into).collect();
+  let result = ddk_ffi::message_template(ffi_oracle_infos, cet_count).map_err(dlc_error)?;
+
+  Ok(
+    result
+      .into_iter()
+      .map(|cet_msgs| {
+        cet_msgs
+          .into_iter()
+          .map(|oracle_msgs| oracle_msgs.into_iter().map(vec_to_buffer).collect())
+          .collect()
+      })
+      .collect(),
+  )
 }
 
 #[napi]
@@ -563,24 +1636,90 @@ pub fn extract_ecdsa_signature_from_oracle_signatures(
     ffi_oracle_signatures,
     ffi_adaptor_signature,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   Ok(Buffer::from(signature))
 }
 
+/// Decrypt a CET adaptor signature with the oracle signatures, returning a DER signature.
+#[napi]
+pub fn decrypt_cet_adaptor_signature(
+  adaptor_signature: Buffer,
+  oracle_signatures: Vec<Buffer>,
+) -> Result<Buffer> {
+  let result = ddk_ffi::decrypt_cet_adaptor_signature(
+    buffer_to_vec(&adaptor_signature),
+    oracle_signatures.iter().map(buffer_to_vec).collect(),
+  )
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Recover the oracle secret from an adaptor signature and its published decryption.
+#[napi]
+pub fn recover_adaptor_secret(
+  adaptor_signature: Buffer,
+  final_signature: Buffer,
+  adaptor_point: Buffer,
+) -> Result<Buffer> {
+  let result = ddk_ffi::recover_adaptor_secret(
+    buffer_to_vec(&adaptor_signature),
+    buffer_to_vec(&final_signature),
+    buffer_to_vec(&adaptor_point),
+  )
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Decrypt a CET adaptor signature and verify it against the CET before returning it.
+#[napi]
+pub fn decrypt_and_verify_cet_signature(
+  adaptor_sig: Buffer,
+  oracle_signatures: Vec<Buffer>,
+  cet: Transaction,
+  pubkey: Buffer,
+  funding_script_pubkey: Buffer,
+  fund_output_value: BigInt,
+) -> Result<Buffer> {
+  let result = ddk_ffi::decrypt_and_verify_cet_signature(
+    buffer_to_vec(&adaptor_sig),
+    oracle_signatures.iter().map(buffer_to_vec).collect(),
+    cet.try_into()?,
+    buffer_to_vec(&pubkey),
+    buffer_to_vec(&funding_script_pubkey),
+    bigint_to_u64(&fund_output_value)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
 #[napi]
 pub fn convert_mnemonic_to_seed(mnemonic: String, passphrase: Option<String>) -> Result<Buffer> {
-  let result = ddk_ffi::convert_mnemonic_to_seed(mnemonic, passphrase)
-    .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  let result = ddk_ffi::convert_mnemonic_to_seed(mnemonic, passphrase).map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
 
+/// Check a mnemonic, naming the unknown word or reporting a bad checksum or word count.
+#[napi]
+pub fn validate_mnemonic(mnemonic: String) -> Result<()> {
+  ddk_ffi::validate_mnemonic(mnemonic).map_err(dlc_error)
+}
+
+/// Check whether a network name is accepted. Names are case-insensitive and
+/// "mainnet" and "testnet3" are accepted as aliases.
+#[napi]
+pub fn is_valid_network(network: String) -> bool {
+  ddk_ffi::is_valid_network(network)
+}
+
 #[napi]
 pub fn create_extkey_from_seed(seed: Buffer, network: String) -> Result<Buffer> {
   let seed_bytes = buffer_to_vec(&seed);
-  let result = ddk_ffi::create_extkey_from_seed(seed_bytes, network)
-    .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  let result = ddk_ffi::create_extkey_from_seed(seed_bytes, network).map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
@@ -588,8 +1727,7 @@ pub fn create_extkey_from_seed(seed: Buffer, network: String) -> Result<Buffer>
 #[napi]
 pub fn create_extkey_from_parent_path(extkey: Buffer, path: String) -> Result<Buffer> {
   let extkey_bytes = buffer_to_vec(&extkey);
-  let result = ddk_ffi::create_extkey_from_parent_path(extkey_bytes, path)
-    .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  let result = ddk_ffi::create_extkey_from_parent_path(extkey_bytes, path).map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
@@ -597,12 +1735,58 @@ pub fn create_extkey_from_parent_path(extkey: Buffer, path: String) -> Result<Bu
 #[napi]
 pub fn get_pubkey_from_extkey(extkey: Buffer, network: String) -> Result<Buffer> {
   let extkey_bytes = buffer_to_vec(&extkey);
-  let result = ddk_ffi::get_pubkey_from_extkey(extkey_bytes, network)
-    .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  let result = ddk_ffi::get_pubkey_from_extkey(extkey_bytes, network).map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Get the network ("bitcoin" or "testnet") of an extended key from its version bytes.
+#[napi]
+pub fn get_network_from_extkey(extkey: Buffer) -> Result<String> {
+  ddk_ffi::get_network_from_extkey(buffer_to_vec(&extkey)).map_err(dlc_error)
+}
+
+/// Get the 4-byte BIP32 key fingerprint of an xpriv or xpub.
+#[napi]
+pub fn get_extkey_fingerprint(extkey: Buffer) -> Result<Buffer> {
+  let result = ddk_ffi::get_extkey_fingerprint(buffer_to_vec(&extkey)).map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Derive the 33-byte public key at a path from a 64-byte seed in one call.
+#[napi]
+pub fn derive_pubkey_from_seed(seed: Buffer, network: String, path: String) -> Result<Buffer> {
+  let result =
+    ddk_ffi::derive_pubkey_from_seed(buffer_to_vec(&seed), network, path).map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Derive the 32-byte private key at a path from a 64-byte seed in one call.
+#[napi]
+pub fn derive_privkey_from_seed(seed: Buffer, network: String, path: String) -> Result<Buffer> {
+  let result =
+    ddk_ffi::derive_privkey_from_seed(buffer_to_vec(&seed), network, path).map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Encode a compressed pubkey as a p2wpkh, p2tr or p2sh-p2wpkh address.
+#[napi]
+pub fn pubkey_to_address(pubkey: Buffer, network: String, address_type: String) -> Result<String> {
+  ddk_ffi::pubkey_to_address(buffer_to_vec(&pubkey), network, address_type).map_err(dlc_error)
+}
+
+/// Parse an address for a network and return its script pubkey.
+#[napi]
+pub fn address_to_script_pubkey(address: String, network: String) -> Result<Buffer> {
+  let result = ddk_ffi::address_to_script_pubkey(address, network).map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
 
+#[allow(deprecated)]
 #[napi]
 pub fn create_xpriv_from_parent_path(
   xpriv: Buffer,
@@ -613,7 +1797,7 @@ pub fn create_xpriv_from_parent_path(
   let xpriv_bytes = buffer_to_vec(&xpriv);
   let result =
     ddk_ffi::create_xpriv_from_parent_path(xpriv_bytes, base_derivation_path, network, path)
-      .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+      .map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
@@ -621,12 +1805,16 @@ pub fn create_xpriv_from_parent_path(
 #[napi]
 pub fn get_xpub_from_xpriv(xpriv: Buffer, network: String) -> Result<Buffer> {
   let xpriv_bytes = buffer_to_vec(&xpriv);
-  let result = ddk_ffi::get_xpub_from_xpriv(xpriv_bytes, network)
-    .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  let result = ddk_ffi::get_xpub_from_xpriv(xpriv_bytes, network).map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
 
+#[napi]
+pub fn extkeys_match(xpriv: Buffer, xpub: Buffer) -> Result<bool> {
+  ddk_ffi::extkeys_match(buffer_to_vec(&xpriv), buffer_to_vec(&xpub)).map_err(dlc_error)
+}
+
 /// Get all the inputs that go into creating a CET adaptor signature.
 /// Use this to compare values with Fordefi to debug signature mismatches.
 ///
@@ -646,10 +1834,8 @@ pub fn get_cet_adaptor_signature_inputs(
   fund_output_value: BigInt,
   msgs: Vec<Vec<Buffer>>,
 ) -> Result<CetAdaptorSignatureDebugInfo> {
-  let ffi_oracle_info: Vec<ddk_ffi::OracleInfo> = oracle_info
-    .into_iter()
-    .map(|info| info.into())
-    .collect();
+  let ffi_oracle_info: Vec<ddk_ffi::OracleInfo> =
+    oracle_info.into_iter().map(|info| info.into()).collect();
 
   let ffi_msgs: Vec<Vec<Vec<u8>>> = msgs
     .into_iter()
@@ -663,7 +1849,7 @@ pub fn get_cet_adaptor_signature_inputs(
     bigint_to_u64(&fund_output_value)?,
     ffi_msgs,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
 
   Ok(CetAdaptorSignatureDebugInfo {
     sighash: vec_to_buffer(result.sighash),
@@ -689,11 +1875,286 @@ pub fn get_cet_sighash(
     buffer_to_vec(&funding_script_pubkey),
     bigint_to_u64(&fund_output_value)?,
   )
-  .map_err(|e| Error::from_reason(format!("{:?}", e)))?;
+  .map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Report how a CET's payouts differ from the refund transaction's.
+#[napi]
+pub fn diff_cet_refund(cet: Transaction, refund: Transaction) -> Result<CetRefundDiff> {
+  let result = ddk_ffi::diff_cet_refund(cet.try_into()?, refund.try_into()?);
+
+  Ok(result.into())
+}
+
+/// Build a placeholder transaction with dummy outpoints and empty witnesses.
+#[napi]
+pub fn dummy_transaction(input_count: u32, output_count: u32) -> Transaction {
+  ddk_ffi::dummy_transaction(input_count, output_count).into()
+}
+
+/// Verify that the fund output is the P2WSH of the funding witness script.
+#[napi]
+pub fn verify_funding_script_consistency(dlc_txs: DlcTransactions) -> Result<bool> {
+  ddk_ffi::verify_funding_script_consistency(dlc_txs.try_into()?).map_err(dlc_error)
+}
+
+/// Check that every CET spends the same funding outpoint and return it as "txid:vout".
+#[napi]
+pub fn validate_cets_share_funding(cets: Vec<Transaction>) -> Result<String> {
+  let ffi_cets: Result<Vec<ddk_ffi::Transaction>> =
+    cets.into_iter().map(TryInto::try_into).collect();
+
+  ddk_ffi::validate_cets_share_funding(ffi_cets?).map_err(dlc_error)
+}
+
+/// Get the value of the fund output locked by the funding script, whatever its index.
+#[napi]
+pub fn get_fund_output_value(dlc_txs: DlcTransactions) -> Result<BigInt> {
+  let result = ddk_ffi::get_fund_output_value(dlc_txs.try_into()?).map_err(dlc_error)?;
+
+  Ok(u64_to_bigint(result))
+}
+
+/// Build a new refund spending the contract's funding output, e.g. with another locktime.
+#[napi]
+pub fn rebuild_refund(
+  dlc_txs: DlcTransactions,
+  local_final_script_pubkey: Buffer,
+  remote_final_script_pubkey: Buffer,
+  local_amount: BigInt,
+  remote_amount: BigInt,
+  new_lock_time: u32,
+) -> Result<Transaction> {
+  let result = ddk_ffi::rebuild_refund(
+    dlc_txs.try_into()?,
+    buffer_to_vec(&local_final_script_pubkey),
+    buffer_to_vec(&remote_final_script_pubkey),
+    bigint_to_u64(&local_amount)?,
+    bigint_to_u64(&remote_amount)?,
+    new_lock_time,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Check the CETs and refund spend only the funding output and pay out no more than it holds.
+#[napi]
+pub fn validate_dlc_transactions(dlc_txs: DlcTransactions) -> Result<()> {
+  ddk_ffi::validate_dlc_transactions(dlc_txs.try_into()?).map_err(dlc_error)
+}
+
+/// Get the fee each CET pays: the funding output value minus the CET's outputs.
+#[napi]
+pub fn cet_fees(cets: Vec<Transaction>, fund_output_value: BigInt) -> Result<Vec<BigInt>> {
+  let ffi_cets: Result<Vec<ddk_ffi::Transaction>> =
+    cets.into_iter().map(TryInto::try_into).collect();
+
+  let result =
+    ddk_ffi::cet_fees(ffi_cets?, bigint_to_u64(&fund_output_value)?).map_err(dlc_error)?;
+
+  Ok(result.into_iter().map(u64_to_bigint).collect())
+}
+
+/// Find the index of the first outcome whose payouts a CET pays.
+#[napi]
+pub fn cet_to_outcome(
+  cet: Transaction,
+  outcomes: Vec<Payout>,
+  local_script: Buffer,
+  remote_script: Buffer,
+) -> Result<u32> {
+  let ffi_outcomes: Result<Vec<ddk_ffi::Payout>> =
+    outcomes.into_iter().map(TryInto::try_into).collect();
+
+  ddk_ffi::cet_to_outcome(
+    cet.try_into()?,
+    ffi_outcomes?,
+    buffer_to_vec(&local_script),
+    buffer_to_vec(&remote_script),
+  )
+  .map_err(dlc_error)
+}
+
+/// Find the index of the only CET paying each amount to its script, in either output order.
+#[napi]
+pub fn find_cet_by_payout(
+  cets: Vec<Transaction>,
+  offer_amount: BigInt,
+  accept_amount: BigInt,
+  offer_script: Buffer,
+  accept_script: Buffer,
+) -> Result<u32> {
+  let ffi_cets: Result<Vec<ddk_ffi::Transaction>> =
+    cets.into_iter().map(TryInto::try_into).collect();
+
+  ddk_ffi::find_cet_by_payout(
+    ffi_cets?,
+    bigint_to_u64(&offer_amount)?,
+    bigint_to_u64(&accept_amount)?,
+    buffer_to_vec(&offer_script),
+    buffer_to_vec(&accept_script),
+  )
+  .map_err(dlc_error)
+}
+
+/// List the pubkeys of the fund keys, funding script and fund witnesses, deduplicated.
+#[napi]
+pub fn contract_pubkeys(
+  dlc_txs: DlcTransactions,
+  local_fund_pubkey: Buffer,
+  remote_fund_pubkey: Buffer,
+) -> Result<Vec<Buffer>> {
+  let result = ddk_ffi::contract_pubkeys(
+    dlc_txs.try_into()?,
+    buffer_to_vec(&local_fund_pubkey),
+    buffer_to_vec(&remote_fund_pubkey),
+  );
+
+  Ok(result.into_iter().map(vec_to_buffer).collect())
+}
+
+/// Verify the fund output holds both collaterals plus the CET fee reserve and the refund pays
+/// the collaterals back exactly.
+#[napi]
+pub fn verify_collateral_invariant(
+  dlc_txs: DlcTransactions,
+  offer_params: PartyParams,
+  accept_params: PartyParams,
+  fee_rate: BigInt,
+) -> Result<bool> {
+  ddk_ffi::verify_collateral_invariant(
+    dlc_txs.try_into()?,
+    offer_params.try_into()?,
+    accept_params.try_into()?,
+    bigint_to_u64(&fee_rate)?,
+  )
+  .map_err(dlc_error)
+}
+
+/// Estimate the CET count and size of a numeric contract before building it.
+#[napi]
+pub fn estimate_contract_cost(base: u32, nb_digits: u32, nb_oracles: u32) -> Result<ContractCost> {
+  let result = ddk_ffi::estimate_contract_cost(base, nb_digits, nb_oracles).map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Compare two contract builds, ignoring witnesses.
+#[napi]
+pub fn dlc_transactions_equal(a: DlcTransactions, b: DlcTransactions) -> bool {
+  let (Ok(ffi_a), Ok(ffi_b)) = (a.try_into(), b.try_into()) else {
+    return false;
+  };
+
+  ddk_ffi::dlc_transactions_equal(ffi_a, ffi_b)
+}
+
+/// Compare transactions ignoring input and output order and witnesses.
+#[napi]
+pub fn transactions_equivalent(a: Transaction, b: Transaction) -> bool {
+  let (Ok(ffi_a), Ok(ffi_b)) = (a.try_into(), b.try_into()) else {
+    return false;
+  };
+
+  ddk_ffi::transactions_equivalent(ffi_a, ffi_b)
+}
+
+/// Compute a stable 32-byte fingerprint of a contract for deduplication.
+#[napi]
+pub fn contract_fingerprint(
+  dlc_txs: DlcTransactions,
+  oracle_infos: Vec<OracleInfo>,
+) -> Result<Buffer> {
+  let ffi_oracle_infos = oracle_infos.into_iter().map(Into::into).collect();
+  let result =
+    ddk_ffi::contract_fingerprint(dlc_txs.try_into()?, ffi_oracle_infos).map_err(dlc_error)?;
+
+  Ok(vec_to_buffer(result))
+}
+
+/// Offer, accept and sign a contract between two local parties.
+///
+/// Test helper taking both parties' fund secret keys: never use it with real keys.
+#[napi]
+pub fn complete_dlc_flow(
+  offer_params: PartyParams,
+  accept_params: PartyParams,
+  outcomes: Vec<Payout>,
+  oracle_infos: Vec<OracleInfo>,
+  msgs: Vec<Vec<Vec<Buffer>>>,
+  offer_fund_sk: Buffer,
+  accept_fund_sk: Buffer,
+  fee_rate: BigInt,
+  refund_locktime: u32,
+  fund_lock_time: u32,
+  cet_lock_time: u32,
+  fund_output_serial_id: BigInt,
+) -> Result<CompletedContract> {
+  let ffi_outcomes: Result<Vec<ddk_ffi::Payout>> =
+    outcomes.into_iter().map(TryInto::try_into).collect();
+  let ffi_oracle_infos = oracle_infos.into_iter().map(Into::into).collect();
+  let ffi_msgs = msgs
+    .into_iter()
+    .map(|cet_msgs| {
+      cet_msgs
+        .into_iter()
+        .map(|oracle_msgs| oracle_msgs.iter().map(buffer_to_vec).collect())
+        .collect()
+    })
+    .collect();
+
+  let result = ddk_ffi::complete_dlc_flow(
+    offer_params.try_into()?,
+    accept_params.try_into()?,
+    ffi_outcomes?,
+    ffi_oracle_infos,
+    ffi_msgs,
+    buffer_to_vec(&offer_fund_sk),
+    buffer_to_vec(&accept_fund_sk),
+    bigint_to_u64(&fee_rate)?,
+    refund_locktime,
+    fund_lock_time,
+    cet_lock_time,
+    bigint_to_u64(&fund_output_serial_id)?,
+  )
+  .map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
+/// Serialize everything needed to resume settling a contract into a versioned blob.
+#[napi]
+pub fn serialize_settlement_state(
+  dlc_txs: DlcTransactions,
+  adaptor_sigs: Vec<AdaptorSignature>,
+  oracle_infos: Vec<OracleInfo>,
+  contract_id: Buffer,
+) -> Result<Buffer> {
+  let ffi_adaptor_sigs = adaptor_sigs.into_iter().map(Into::into).collect();
+  let ffi_oracle_infos = oracle_infos.into_iter().map(Into::into).collect();
+
+  let result = ddk_ffi::serialize_settlement_state(
+    dlc_txs.try_into()?,
+    ffi_adaptor_sigs,
+    ffi_oracle_infos,
+    buffer_to_vec(&contract_id),
+  )
+  .map_err(dlc_error)?;
 
   Ok(vec_to_buffer(result))
 }
 
+/// Restore settlement state written by `serializeSettlementState`.
+#[napi]
+pub fn deserialize_settlement_state(bytes: Buffer) -> Result<SettlementState> {
+  let result = ddk_ffi::deserialize_settlement_state(buffer_to_vec(&bytes)).map_err(dlc_error)?;
+
+  Ok(result.into())
+}
+
 // #[cfg(test)]
 // mod tests {
 //   use super::*;
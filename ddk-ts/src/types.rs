@@ -45,6 +45,13 @@ pub struct Payout {
   pub accept: BigInt,
 }
 
+// Relative share of the collateral each party receives for an outcome
+#[napi(object)]
+pub struct PayoutRatio {
+  pub offer: u32,
+  pub accept: u32,
+}
+
 // DLC input information - matches UDL exactly
 #[napi(object)]
 pub struct DlcInputInfo {
@@ -62,7 +69,8 @@ pub struct DlcInputInfo {
 #[napi(object)]
 pub struct PartyParams {
   pub fund_pubkey: Buffer,
-  pub change_script_pubkey: Buffer,
+  /// Omit when the party's inputs exactly cover collateral and fees
+  pub change_script_pubkey: Option<Buffer>,
   pub change_serial_id: BigInt,
   pub payout_script_pubkey: Buffer,
   pub payout_serial_id: BigInt,
@@ -72,6 +80,20 @@ pub struct PartyParams {
   pub dlc_inputs: Vec<DlcInputInfo>,
 }
 
+// Arguments of createDlcTransactions for one contract of a batch
+#[napi(object)]
+pub struct DlcTransactionRequest {
+  pub outcomes: Vec<Payout>,
+  pub local_params: PartyParams,
+  pub remote_params: PartyParams,
+  pub refund_locktime: u32,
+  pub fee_rate: BigInt,
+  pub fund_lock_time: u32,
+  pub cet_lock_time: u32,
+  pub fund_output_serial_id: BigInt,
+  pub contract_flags: u8,
+}
+
 // Container for all DLC transactions - matches UDL exactly
 #[napi(object)]
 pub struct DlcTransactions {
@@ -84,7 +106,9 @@ pub struct DlcTransactions {
 // Adaptor signature with proof - matches UDL exactly
 #[napi(object)]
 pub struct AdaptorSignature {
+  /// Canonical 162-byte encoding, DLEQ proof included
   pub signature: Buffer,
+  /// Left empty, the proof is part of `signature`
   pub proof: Buffer,
 }
 
@@ -122,3 +146,111 @@ pub struct CetAdaptorSignatureDebugInfo {
   /// Raw CET bytes for verification
   pub cet_raw: Buffer,
 }
+
+// How the payouts of a CET differ from those of the refund transaction
+#[napi(object)]
+pub struct CetRefundDiff {
+  /// Whether both transactions spend the same funding outpoint
+  pub same_funding_outpoint: bool,
+  /// Whether both transactions pay exactly the same outputs
+  pub identical_payouts: bool,
+  /// Outputs paid by the CET but not by the refund
+  pub cet_only_outputs: Vec<TxOutput>,
+  /// Outputs paid by the refund but not by the CET
+  pub refund_only_outputs: Vec<TxOutput>,
+}
+
+// Projected cost of building a numeric contract
+#[napi(object)]
+pub struct ContractCost {
+  /// Upper bound on the number of CETs (one per possible outcome)
+  pub cet_count: BigInt,
+  /// Approximate bytes of unsigned CET data
+  pub transaction_bytes: BigInt,
+  /// Approximate adaptor signing operations (one per oracle digit per CET)
+  pub adaptor_signing_operations: BigInt,
+}
+
+// Transactions and signatures of a contract that went through offer, accept and sign
+#[napi(object)]
+pub struct CompletedContract {
+  /// Fund, CETs and refund transactions, unsigned
+  pub dlc_transactions: DlcTransactions,
+  /// Value of the 2-of-2 fund output spent by the CETs and refund
+  pub fund_output_value: BigInt,
+  /// Offer party's adaptor signatures, one per CET
+  pub offer_adaptor_signatures: Vec<AdaptorSignature>,
+  /// Accept party's adaptor signatures, one per CET
+  pub accept_adaptor_signatures: Vec<AdaptorSignature>,
+  /// Refund transaction carrying both parties' signatures
+  pub signed_refund: Transaction,
+}
+
+// Everything needed to resume settling a contract after a restart
+#[napi(object)]
+pub struct SettlementState {
+  /// Fund, CETs and refund transactions of the contract
+  pub dlc_transactions: DlcTransactions,
+  /// Counterparty adaptor signatures, one per CET
+  pub adaptor_signatures: Vec<AdaptorSignature>,
+  /// Oracles whose attestations unlock the CETs
+  pub oracle_infos: Vec<OracleInfo>,
+  /// 32-byte contract identifier
+  pub contract_id: Buffer,
+}
+
+// One input of a funding transaction that a party has to sign
+#[napi(object)]
+pub struct SigningStep {
+  /// Index of the input in the funding transaction
+  pub input_index: u32,
+  /// "witness" for a native segwit input (P2WPKH, P2WSH or P2TR), otherwise
+  /// "p2sh-p2wpkh", "p2sh-p2wsh" or "p2sh" from the redeem script
+  pub script_type: String,
+  /// Value of the spent output, needed for the segwit sighash
+  pub amount: BigInt,
+}
+
+// Full witness stack for one input of a transaction
+#[napi(object)]
+pub struct WitnessEntry {
+  /// Index of the input in the transaction
+  pub input_index: u32,
+  /// Witness items, bottom of the stack first
+  pub items: Vec<Buffer>,
+}
+
+// The two halves of a BIP340 Schnorr signature
+#[napi(object)]
+pub struct SchnorrParts {
+  /// 32-byte x-only nonce point R
+  pub nonce: Buffer,
+  /// 32-byte s value
+  pub s_value: Buffer,
+}
+
+// A CET along with which payout outputs were dropped as dust
+#[napi(object)]
+pub struct CetBuildResult {
+  pub transaction: Transaction,
+  /// The offer (local) payout was under the dust limit and has no output
+  pub offer_output_dropped: bool,
+  /// The accept (remote) payout was under the dust limit and has no output
+  pub accept_output_dropped: bool,
+}
+
+// Which fields of two PartyParams differ
+#[napi(object)]
+pub struct PartyParamsDiff {
+  pub fund_pubkey: bool,
+  pub change_script_pubkey: bool,
+  pub payout_script_pubkey: bool,
+  pub input_amount: bool,
+  pub collateral: bool,
+  /// The inputs spend different outpoints or differ in script sig or witness length
+  pub inputs: bool,
+  /// The DLC inputs differ in anything but their serial ids
+  pub dlc_inputs: bool,
+  /// The change, payout or input serial ids differ
+  pub serial_ids: bool,
+}